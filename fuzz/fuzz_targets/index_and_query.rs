@@ -0,0 +1,5 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| indicium::fuzzing::fuzz_index_and_query(data));
\ No newline at end of file
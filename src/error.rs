@@ -0,0 +1,36 @@
+//! The error type returned by Indicium's fallible operations.
+
+// -----------------------------------------------------------------------------
+//
+/// An error returned by one of Indicium's fallible operations. New variants may
+/// be added in future versions.
+
+#[derive(Debug, thiserror::Error)]
+#[non_exhaustive]
+pub enum Error {
+    /// A setting has a value that can't be used. `setting` is the name of the
+    /// `SearchIndexBuilder` setting.
+    #[error("invalid `{setting}` setting: {reason}")]
+    InvalidSetting { setting: &'static str, reason: String },
+    /// A record has more keywords than the `maximum_keywords_per_record` cap
+    /// allows, and the cap's policy is to reject such records.
+    #[error("record has {keywords} keywords, exceeding the `maximum_keywords_per_record` cap of {maximum}")]
+    KeywordCapExceeded { keywords: usize, maximum: usize },
+    /// A search string exceeds one of the query limits, and the
+    /// `query_limit_policy` is to reject such queries. `limit` is the name of
+    /// the `SearchIndexBuilder` setting.
+    #[error("search string exceeds `{limit}`: {value} is greater than {maximum}")]
+    QueryLimitExceeded { limit: &'static str, value: usize, maximum: usize },
+    /// A record doesn't have a field of this name to update. Only records
+    /// inserted with named fields (see `Indexable::field_names`) can have a
+    /// single field updated.
+    #[error("no `{field}` field is indexed for the record")]
+    UnknownField { field: String },
+    /// The operation was cancelled through a `CancelToken`.
+    #[cfg(feature = "simple")]
+    #[error(transparent)]
+    Cancelled(#[from] crate::simple::Cancelled),
+    /// An I/O or data error occurred while reading or writing.
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+} // Error
\ No newline at end of file
@@ -0,0 +1,93 @@
+//! Entry functions for fuzzing the tokenizer and the query paths with
+//! arbitrary input. They are used by the `cargo fuzz` targets in the `fuzz`
+//! directory, and may also be called with the inputs of a fuzzing corpus from
+//! regular tests.
+//!
+//! Each function only panics if the search index does. Any input is accepted:
+//! bytes that aren't valid UTF-8 are replaced.
+
+use crate::simple::{AutocompleteType, SearchIndex, SearchIndexBuilder, SearchType, TokenizeMode};
+
+// -----------------------------------------------------------------------------
+//
+/// Splits fuzzer input into strings, on `\n`. Invalid UTF-8 is replaced.
+
+fn input_strings(data: &[u8]) -> Vec<String> {
+    String::from_utf8_lossy(data)
+        .split('\n')
+        .map(str::to_string)
+        .collect()
+} // fn
+
+// -----------------------------------------------------------------------------
+//
+/// Tokenizes the input, for indexing and for searching, with a few different
+/// settings.
+
+pub fn fuzz_tokenize(data: &[u8]) {
+    let string: String = String::from_utf8_lossy(data).into_owned();
+
+    [
+        SearchIndexBuilder::<usize>::default().build(),
+        SearchIndexBuilder::default().case_sensitive(true).split_pattern(None).build(),
+        SearchIndexBuilder::default()
+            .shingles(true)
+            .min_keyword_len(0)
+            .max_keyword_len(usize::MAX)
+            .maximum_query_length(Some(8))
+            .build(),
+    ]
+        .iter()
+        .for_each(|search_index| {
+            search_index.tokenize(&string, TokenizeMode::Indexing);
+            search_index.tokenize(&string, TokenizeMode::Searching);
+        }); // for_each
+} // fn
+
+// -----------------------------------------------------------------------------
+//
+/// Indexes every line of the input except the last, then uses the last line
+/// as a query for every search type and autocompletion type. Finally, every
+/// record is removed again.
+
+pub fn fuzz_index_and_query(data: &[u8]) {
+    let mut strings: Vec<String> = input_strings(data);
+    let query: String = strings.pop().unwrap_or_default();
+
+    let mut search_index: SearchIndex<usize> = SearchIndex::default();
+
+    strings
+        .iter()
+        .enumerate()
+        .for_each(|(key, string)| search_index.insert(&key, string));
+
+    query_all(&search_index, &query);
+
+    strings
+        .iter()
+        .enumerate()
+        .for_each(|(key, string)| search_index.remove(&key, string));
+
+    query_all(&search_index, &query);
+} // fn
+
+// -----------------------------------------------------------------------------
+//
+/// Runs the query with every search type and autocompletion type.
+
+fn query_all(search_index: &SearchIndex<usize>, query: &str) {
+    [SearchType::And, SearchType::Keyword, SearchType::Live, SearchType::Or]
+        .iter()
+        .for_each(|search_type| { search_index.search_type(search_type, query); });
+
+    [
+        AutocompleteType::Context,
+        AutocompleteType::Global,
+        AutocompleteType::Hybrid,
+        AutocompleteType::Keyword,
+    ]
+        .iter()
+        .for_each(|autocomplete_type| { search_index.autocomplete_type(autocomplete_type, query); });
+
+    search_index.autocomplete_last_keyword(query);
+} // fn
\ No newline at end of file
@@ -0,0 +1,199 @@
+//! Cursor-based pagination of search results for GraphQL servers, following
+//! the [Relay connection specification](https://relay.dev/graphql/connections.htm).
+//!
+//! Search results are returned in a stable order for a given search index and
+//! query, so a result's position (or offset) is used as its cursor. Cursors
+//! should be treated as opaque strings by clients.
+//!
+//! The [`SearchConnection`] type is framework agnostic. It can be converted
+//! into an [async-graphql](https://crates.io/crates/async-graphql) connection
+//! using the [`into_async_graphql`] method.
+//!
+//! [`SearchConnection`]: struct.SearchConnection.html
+//! [`into_async_graphql`]: struct.SearchConnection.html#method.into_async_graphql
+
+use async_graphql::connection::{Connection, Edge};
+use async_graphql::{OutputType, SimpleObject};
+use std::num::ParseIntError;
+
+// -----------------------------------------------------------------------------
+//
+/// Information about the current page of search results.
+
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct PageInfo {
+    /// Whether there are search results before this page.
+    pub has_previous_page: bool,
+    /// Whether there are search results after this page.
+    pub has_next_page: bool,
+    /// The cursor of the first search result on this page, if any.
+    pub start_cursor: Option<String>,
+    /// The cursor of the last search result on this page, if any.
+    pub end_cursor: Option<String>,
+} // PageInfo
+
+// -----------------------------------------------------------------------------
+//
+/// A single search result (or node) and its cursor.
+
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct SearchEdge<N> {
+    /// An opaque cursor that can be passed as the `after` argument to fetch
+    /// the search results following this one.
+    pub cursor: String,
+    /// The search result, as rendered by the caller.
+    pub node: N,
+} // SearchEdge
+
+// -----------------------------------------------------------------------------
+//
+/// A page of search results.
+
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct SearchConnection<N> {
+    /// The search results on this page.
+    pub edges: Vec<SearchEdge<N>>,
+    /// Information about this page.
+    pub page_info: PageInfo,
+    /// The total number of search results across all pages. Note that search
+    /// results are limited by the `maximum_search_results` setting (or the
+    /// argument given to `search_with`.)
+    pub total_count: usize,
+} // SearchConnection
+
+// -----------------------------------------------------------------------------
+//
+/// Additional connection fields exposed by [`into_async_graphql`].
+///
+/// [`into_async_graphql`]: struct.SearchConnection.html#method.into_async_graphql
+
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd, SimpleObject)]
+pub struct TotalCount {
+    /// The total number of search results across all pages.
+    pub total_count: usize,
+} // TotalCount
+
+// -----------------------------------------------------------------------------
+
+impl<N> SearchConnection<N> {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Builds a page of search results.
+    ///
+    /// * `search_results` are the keys returned from a search.
+    ///
+    /// * `after` is the cursor of the search result that the page should start
+    ///   after. If `None`, the page starts at the first search result. An
+    ///   invalid cursor returns an error.
+    ///
+    /// * `first` is the maximum number of search results on the page. If
+    ///   `None`, all remaining search results are returned.
+    ///
+    /// * `node` renders each key into the GraphQL node type.
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # use indicium::graphql::SearchConnection;
+    /// # use indicium::simple::SearchIndex;
+    /// # use pretty_assertions::assert_eq;
+    /// #
+    /// let mut search_index: SearchIndex<usize> = SearchIndex::default();
+    ///
+    /// search_index.insert(&0, &"William the Conqueror");
+    /// search_index.insert(&1, &"William Rufus");
+    /// search_index.insert(&2, &"William Adelin");
+    ///
+    /// let search_results = search_index.search("william");
+    ///
+    /// let page = SearchConnection::from_results(&search_results, None, Some(2), |key| *key)
+    ///     .unwrap();
+    ///
+    /// assert_eq!(page.total_count, 3);
+    /// assert_eq!(page.edges.iter().map(|edge| edge.node).collect::<Vec<_>>(), vec![0, 1]);
+    /// assert!(page.page_info.has_next_page);
+    ///
+    /// let after = page.page_info.end_cursor.as_deref();
+    /// let page = SearchConnection::from_results(&search_results, after, Some(2), |key| *key)
+    ///     .unwrap();
+    ///
+    /// assert_eq!(page.edges.iter().map(|edge| edge.node).collect::<Vec<_>>(), vec![2]);
+    /// assert!(!page.page_info.has_next_page);
+    /// ```
+
+    pub fn from_results<K>(
+        search_results: &[&K],
+        after: Option<&str>,
+        first: Option<usize>,
+        node: impl Fn(&K) -> N,
+    ) -> Result<SearchConnection<N>, ParseIntError> {
+
+        // The cursor is the offset of the search result. The page starts with
+        // the search result following the `after` cursor:
+        let start: usize = match after {
+            Some(cursor) => cursor.parse::<usize>()?.saturating_add(1),
+            None => 0,
+        }; // match
+
+        // Determine where the page ends:
+        let end: usize = match first {
+            Some(first) => start.saturating_add(first),
+            None => search_results.len(),
+        }.min(search_results.len()); // match
+
+        // Render the search results on this page:
+        let edges: Vec<SearchEdge<N>> = search_results
+            .iter()
+            .enumerate()
+            .skip(start)
+            .take(end.saturating_sub(start))
+            .map(|(offset, key)| SearchEdge {
+                cursor: offset.to_string(),
+                node: node(key),
+            }) // map
+            .collect();
+
+        let page_info = PageInfo {
+            has_previous_page: start > 0,
+            has_next_page: end < search_results.len(),
+            start_cursor: edges.first().map(|edge| edge.cursor.clone()),
+            end_cursor: edges.last().map(|edge| edge.cursor.clone()),
+        }; // PageInfo
+
+        Ok(SearchConnection {
+            edges,
+            page_info,
+            total_count: search_results.len(),
+        }) // SearchConnection
+
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Converts this page of search results into an
+    /// [async-graphql](https://crates.io/crates/async-graphql) connection. The
+    /// `totalCount` field is exposed on the connection.
+
+    pub fn into_async_graphql(self) -> Connection<String, N, TotalCount>
+    where
+        N: OutputType,
+    {
+
+        let mut connection = Connection::with_additional_fields(
+            self.page_info.has_previous_page,
+            self.page_info.has_next_page,
+            TotalCount { total_count: self.total_count },
+        ); // Connection
+
+        connection.edges.extend(
+            self.edges
+                .into_iter()
+                .map(|edge| Edge::new(edge.cursor, edge.node))
+        ); // extend
+
+        connection
+
+    } // fn
+
+} // impl
\ No newline at end of file
@@ -0,0 +1,81 @@
+use crate::ingest::Row;
+use crate::simple::SearchIndex;
+use std::io::{Error, ErrorKind, Read};
+
+// -----------------------------------------------------------------------------
+//
+/// Builds a `SearchIndex<String>` from comma-separated values. The first row
+/// must be a header row, which is used to find the `key_column` and the
+/// `text_columns` by name.
+///
+/// An error is returned if the `CSV` data is malformed, or if the key column
+/// or any of the text columns are not present in the header row.
+///
+/// Basic usage:
+///
+/// ```rust
+/// # use indicium::ingest::from_csv;
+/// # use pretty_assertions::assert_eq;
+/// #
+/// let csv = "\
+/// id,name,house
+/// 1,William the Conqueror,Normandy
+/// 2,William Rufus,Normandy
+/// 3,Henry Beauclerc,Normandy
+/// ";
+///
+/// let search_index = from_csv(csv.as_bytes(), "id", &["name", "house"]).unwrap();
+///
+/// assert_eq!(search_index.search("william"), vec![&"1".to_string(), &"2".to_string()]);
+/// ```
+
+#[tracing::instrument(level = "trace", name = "ingest csv", skip(reader))]
+pub fn from_csv<R: Read>(
+    reader: R,
+    key_column: &str,
+    text_columns: &[&str],
+) -> Result<SearchIndex<String>, Error> {
+
+    let mut csv_reader = csv::Reader::from_reader(reader);
+
+    // Find the position of a named column in the header row:
+    let headers = csv_reader.headers()?.clone();
+    let position = |column: &str| headers
+        .iter()
+        .position(|header| header == column)
+        .ok_or_else(|| Error::new(
+            ErrorKind::InvalidData,
+            format!("column `{column}` not found in CSV header"),
+        ));
+
+    let key_position: usize = position(key_column)?;
+    let text_positions: Vec<usize> = text_columns
+        .iter()
+        .map(|column| position(column))
+        .collect::<Result<Vec<usize>, Error>>()?;
+
+    let mut search_index: SearchIndex<String> = SearchIndex::default();
+
+    // Index every row's text columns under its key:
+    for record in csv_reader.records() {
+        let record = record?;
+
+        let key: String = record
+            .get(key_position)
+            .unwrap_or_default()
+            .to_string();
+
+        let row = Row(
+            text_positions
+                .iter()
+                .filter_map(|position| record.get(*position))
+                .map(ToString::to_string)
+                .collect()
+        ); // Row
+
+        search_index.insert(&key, &row);
+    } // for
+
+    Ok(search_index)
+
+} // fn
\ No newline at end of file
@@ -0,0 +1,92 @@
+use crate::ingest::Row;
+use crate::simple::SearchIndex;
+use serde_json::Value;
+use std::io::{BufRead, Error, ErrorKind};
+
+// -----------------------------------------------------------------------------
+//
+/// Renders a scalar `JSON` value as a string. Arrays and objects are not
+/// rendered.
+
+fn scalar_to_string(value: &Value) -> Option<String> {
+    match value {
+        Value::String(string) => Some(string.clone()),
+        Value::Number(number) => Some(number.to_string()),
+        Value::Bool(boolean) => Some(boolean.to_string()),
+        Value::Null | Value::Array(_) | Value::Object(_) => None,
+    } // match
+} // fn
+
+// -----------------------------------------------------------------------------
+//
+/// Builds a `SearchIndex<String>` from [JSON Lines](https://jsonlines.org/),
+/// where each non-blank line is a `JSON` object. The key is read from the
+/// `key_field` and the `text_fields` are indexed. String, number and boolean
+/// values are supported. Text fields that are missing (or are not scalars) are
+/// skipped.
+///
+/// An error is returned if a line can't be read or parsed, if a line isn't a
+/// `JSON` object, or if an object's key field is missing (or is not a scalar.)
+///
+/// Basic usage:
+///
+/// ```rust
+/// # use indicium::ingest::from_json_lines;
+/// # use pretty_assertions::assert_eq;
+/// #
+/// let json_lines = r#"
+/// {"id": 1, "name": "William the Conqueror", "house": "Normandy"}
+/// {"id": 2, "name": "William Rufus", "house": "Normandy"}
+/// {"id": 3, "name": "Henry Beauclerc", "house": "Normandy"}
+/// "#;
+///
+/// let search_index = from_json_lines(json_lines.as_bytes(), "id", &["name"]).unwrap();
+///
+/// assert_eq!(search_index.search("william"), vec![&"1".to_string(), &"2".to_string()]);
+/// ```
+
+#[tracing::instrument(level = "trace", name = "ingest json lines", skip(reader))]
+pub fn from_json_lines<R: BufRead>(
+    reader: R,
+    key_field: &str,
+    text_fields: &[&str],
+) -> Result<SearchIndex<String>, Error> {
+
+    let mut search_index: SearchIndex<String> = SearchIndex::default();
+
+    for (line_number, line) in reader.lines().enumerate() {
+        let line = line?;
+
+        // Blank lines, such as a trailing newline, are ignored:
+        if line.trim().is_empty() { continue }
+
+        let object = match serde_json::from_str::<Value>(&line)? {
+            Value::Object(object) => object,
+            _ => return Err(Error::new(
+                ErrorKind::InvalidData,
+                format!("line {} is not a JSON object", line_number + 1),
+            )), // Err
+        }; // match
+
+        let key: String = object
+            .get(key_field)
+            .and_then(scalar_to_string)
+            .ok_or_else(|| Error::new(
+                ErrorKind::InvalidData,
+                format!("line {} has no `{key_field}` field", line_number + 1),
+            ))?;
+
+        let row = Row(
+            text_fields
+                .iter()
+                .filter_map(|field| object.get(*field))
+                .filter_map(scalar_to_string)
+                .collect()
+        ); // Row
+
+        search_index.insert(&key, &row);
+    } // for
+
+    Ok(search_index)
+
+} // fn
\ No newline at end of file
@@ -0,0 +1,41 @@
+//! Helpers for building a search index directly from tabular data, without
+//! defining a custom `struct` and implementing the `Indexable` trait for it.
+//! These are intended for quick prototypes and data-exploration scripts.
+//!
+//! Each record's key is read from a key column (or field) as a `String`, and
+//! the text columns (or fields) are indexed. The resulting `SearchIndex` uses
+//! the default settings.
+//!
+//! * [`from_csv`] reads comma-separated values with a header row.
+//!
+//! * [`from_json_lines`] reads [JSON Lines](https://jsonlines.org/), one `JSON`
+//!   object per line.
+//!
+//! [`from_csv`]: fn.from_csv.html
+//! [`from_json_lines`]: fn.from_json_lines.html
+
+mod from_csv;
+mod from_json_lines;
+
+// -----------------------------------------------------------------------------
+
+pub use crate::ingest::from_csv::from_csv;
+pub use crate::ingest::from_json_lines::from_json_lines;
+
+// -----------------------------------------------------------------------------
+
+use crate::simple::Indexable;
+
+// -----------------------------------------------------------------------------
+//
+/// The text columns (or fields) of an ingested record.
+
+struct Row(Vec<String>);
+
+// -----------------------------------------------------------------------------
+
+impl Indexable for Row {
+    fn strings(&self) -> Vec<String> {
+        self.0.clone()
+    } // fn strings
+} // impl Indexable
\ No newline at end of file
@@ -0,0 +1,141 @@
+//! Server-side support for the
+//! [jQuery UI Autocomplete](https://jqueryui.com/autocomplete/) widget.
+//!
+//! When the widget's `source` option is set to a URL, jQuery UI will issue a
+//! request with a `term` query-string parameter every time the user types in
+//! the input box. The widget expects a JSON array of `{ label, value }`
+//! objects in response: `label` is displayed in the menu, and `value` is
+//! inserted into the input box when an item is chosen.
+//!
+//! Steps for processing a jQuery UI request:
+//!
+//! 1. Parse the query-string into a [`Request`] struct using your web
+//!    framework.
+//!
+//! 2. Call [`autocomplete_jquery_ui`] for keyword suggestions, or
+//!    [`search_jquery_ui`] to suggest records from your collection.
+//!
+//! 3. Serialize the returned `Vec<Item>` into `JSON` and return it to the
+//!    client.
+//!
+//! [`Request`]: struct.Request.html
+//! [`autocomplete_jquery_ui`]: ../simple/struct.SearchIndex.html#method.autocomplete_jquery_ui
+//! [`search_jquery_ui`]: ../simple/struct.SearchIndex.html#method.search_jquery_ui
+
+use crate::simple::{SearchIndex, SearchType};
+use serde::{Deserialize, Serialize};
+use std::{cmp::Ord, hash::Hash};
+
+// -----------------------------------------------------------------------------
+//
+/// Your web application will receive a query-string from the jQuery UI
+/// Autocomplete widget that needs to be parsed into this `Request` struct.
+
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd, Deserialize, Serialize)]
+pub struct Request {
+    /// The current text in the input box.
+    pub term: String,
+} // Request
+
+// -----------------------------------------------------------------------------
+//
+/// A single menu item for the jQuery UI Autocomplete widget.
+
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd, Deserialize, Serialize)]
+pub struct Item {
+    /// The text displayed in the autocomplete menu.
+    pub label: String,
+    /// The text inserted into the input box when the item is chosen.
+    pub value: String,
+} // Item
+
+// -----------------------------------------------------------------------------
+
+impl<K: Hash + Ord> SearchIndex<K> {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns the autocompletion options for the client's request, formatted
+    /// for the jQuery UI Autocomplete widget. Both the `label` and the `value`
+    /// are set to the autocompleted search string.
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # use indicium::jquery_ui::{Item, Request};
+    /// # use indicium::simple::SearchIndex;
+    /// # use pretty_assertions::assert_eq;
+    /// #
+    /// let mut search_index: SearchIndex<usize> = SearchIndex::default();
+    /// search_index.insert(&0, &"Cambodia");
+    ///
+    /// let request = Request { term: "camb".to_string() };
+    ///
+    /// assert_eq!(
+    ///     search_index.autocomplete_jquery_ui(&request),
+    ///     vec![Item { label: "cambodia".to_string(), value: "cambodia".to_string() }]
+    /// );
+    /// ```
+
+    #[tracing::instrument(level = "trace", name = "jquery ui autocomplete", skip(self))]
+    pub fn autocomplete_jquery_ui(&self, request: &Request) -> Vec<Item> {
+        self.autocomplete(&request.term)
+            .into_iter()
+            .map(|autocompletion| Item {
+                label: autocompletion.clone(),
+                value: autocompletion,
+            }) // map
+            .collect()
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Performs a `Live` search for the client's request and returns the
+    /// matching records, formatted for the jQuery UI Autocomplete widget. The
+    /// `collection_lookup` closure converts each resulting key into an `Item`.
+    /// Keys that return `None` are skipped.
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # use indicium::jquery_ui::{Item, Request};
+    /// # use indicium::simple::SearchIndex;
+    /// # use pretty_assertions::assert_eq;
+    /// #
+    /// let countries = vec!["Canada", "Cambodia"];
+    ///
+    /// let mut search_index: SearchIndex<usize> = SearchIndex::default();
+    ///
+    /// countries
+    ///     .iter()
+    ///     .enumerate()
+    ///     .for_each(|(index, country)| search_index.insert(&index, country));
+    ///
+    /// let request = Request { term: "camb".to_string() };
+    ///
+    /// let items = search_index.search_jquery_ui(&request, |key| {
+    ///     countries.get(*key).map(|country| Item {
+    ///         label: country.to_string(),
+    ///         value: key.to_string(),
+    ///     })
+    /// });
+    ///
+    /// assert_eq!(
+    ///     items,
+    ///     vec![Item { label: "Cambodia".to_string(), value: "1".to_string() }]
+    /// );
+    /// ```
+
+    #[tracing::instrument(level = "trace", name = "jquery ui search", skip(self, collection_lookup))]
+    pub fn search_jquery_ui(
+        &self,
+        request: &Request,
+        collection_lookup: impl Fn(&K) -> Option<Item>,
+    ) -> Vec<Item> {
+        self.search_type(&SearchType::Live, &request.term)
+            .into_iter()
+            .filter_map(collection_lookup)
+            .collect()
+    } // fn
+
+} // impl
\ No newline at end of file
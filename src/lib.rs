@@ -152,11 +152,20 @@
 //! However, the preferred method for large collections is to `insert` into the
 //! `SearchIndex` as you insert into your collection (Vec, HashMap, etc.)
 //!
+//! A slice can also be indexed in one call with
+//! `SearchIndex::from(&my_vec[..])`, which keys each record by its position.
+//! Owned key-value pairs can be `collect()`ed into a `SearchIndex` with the
+//! default settings.
+//!
 //! It's recommended to wrap your target collection (your `Vec`, `HashMap`,
 //! etc.) and this `SearchIndex` together in a new `struct` type. Then,
 //! implement the `insert`, `replace`, `remove`, etc. methods for this new
 //! `struct` type that will update both the collection and search index. This
 //! will ensure that both your collection and index are always synchronized.
+//! The `IndexedVec` and `IndexedHashMap` types are ready-made wrappers that do
+//! this for you. For large keys such as `String` paths,
+//! `SearchIndexBuilder::build_interned` makes an `InternedIndex` that keeps
+//! compact `u32` ids in its postings instead.
 //!
 //! Once the index has been populated, you can use the `search` and
 //! `autocomplete` methods.
@@ -247,8 +256,32 @@
 #![doc(html_favicon_url = "https://www.arkiteq.ca/crates/indicium/icon.png")]
 #![doc(html_logo_url = "https://www.arkiteq.ca/crates/indicium/logo.png")]
 
+mod error;
+pub use crate::error::Error;
+
 #[cfg(feature = "simple")]
 pub mod simple;
 
 #[cfg(feature = "select2")]
-pub mod select2;
\ No newline at end of file
+pub mod select2;
+
+#[cfg(feature = "jquery_ui")]
+pub mod jquery_ui;
+
+#[cfg(feature = "typeahead")]
+pub mod typeahead;
+
+#[cfg(feature = "graphql")]
+pub mod graphql;
+
+#[cfg(feature = "web")]
+pub mod web;
+
+#[cfg(feature = "ingest")]
+pub mod ingest;
+
+#[cfg(feature = "testing")]
+pub mod testing;
+
+#[cfg(feature = "fuzzing")]
+pub mod fuzzing;
\ No newline at end of file
@@ -0,0 +1,258 @@
+//! Configurable listing of all records for empty `Select2` queries.
+
+use crate::select2::Request;
+use crate::simple::SearchIndex;
+use crate::Error;
+use serde::{Deserialize, Serialize};
+use std::{fmt::Debug, hash::Hash, time::SystemTime};
+
+// -----------------------------------------------------------------------------
+//
+/// The order that records are listed in when the user hasn't entered a query
+/// and all records are returned.
+
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd, Deserialize, Serialize)]
+pub enum DumpOrder {
+    /// Records are listed in key order. This is the order `all_keys` returns
+    /// them in.
+    #[default]
+    Key,
+    /// Records are listed in the order that they were inserted, oldest first.
+    /// This requires the search index to be built with `posting_timestamps`
+    /// enabled, otherwise listing returns an `Error::InvalidSetting` error.
+    /// Records without timestamps (such as those loaded from a persisted
+    /// search index) are listed last, in key order.
+    Insertion,
+    /// Records are listed by the boost that the caller gives each key (its
+    /// popularity, for example), highest first. Records with the same boost
+    /// are listed in key order. The boost is supplied to
+    /// `search_select2_dump_boosted`; other listing methods return an
+    /// `Error::InvalidSetting` error for this order.
+    Boost,
+} // DumpOrder
+
+// -----------------------------------------------------------------------------
+//
+/// Settings for listing all records when the user hasn't entered a query. See
+/// [`search_select2_dump`].
+///
+/// [`search_select2_dump`]: ../simple/struct.SearchIndex.html#method.search_select2_dump
+
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd, Deserialize, Serialize)]
+pub struct DumpOptions {
+    /// The order that records are listed in.
+    pub order: DumpOrder,
+    /// The maximum number of records to list per page. The page is read from
+    /// the request (see `Request::page_number`), so that `Select2`'s infinite
+    /// scrolling can page through the listing. If `None`, every record is
+    /// listed on the first page.
+    pub maximum_results: Option<usize>,
+} // DumpOptions
+
+// -----------------------------------------------------------------------------
+
+impl<'a, K: 'a + Debug + Hash + Ord> SearchIndex<K> {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Like [`search_select2`], but the listing returned for an empty query is
+    /// ordered and paginated according to the provided `DumpOptions`. Keys
+    /// that the `promote` closure returns `true` for (recently used or already
+    /// selected records, for example) are listed first. If the user has
+    /// entered a query, the regular search results are returned.
+    ///
+    /// [`search_select2`]: struct.SearchIndex.html#method.search_select2
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error::InvalidSetting` error if the order is
+    /// `DumpOrder::Insertion` and the search index doesn't keep
+    /// `posting_timestamps`, or if the order is `DumpOrder::Boost` (use
+    /// `search_select2_dump_boosted` instead).
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # use indicium::select2::dump::{DumpOptions, DumpOrder};
+    /// # use indicium::select2::Request;
+    /// # use indicium::simple::SearchIndex;
+    /// # use pretty_assertions::assert_eq;
+    /// #
+    /// let mut search_index: SearchIndex<usize> = SearchIndex::default();
+    ///
+    /// search_index.insert(&0, &"Canada");
+    /// search_index.insert(&1, &"Cameroon");
+    /// search_index.insert(&2, &"Cambodia");
+    /// search_index.insert(&3, &"Chile");
+    ///
+    /// let mut request = Request {
+    ///     term: None,
+    ///     q: None,
+    ///     request_type: None,
+    ///     page: None,
+    /// };
+    ///
+    /// let dump_options = DumpOptions {
+    ///     order: DumpOrder::Key,
+    ///     maximum_results: Some(3),
+    /// };
+    ///
+    /// // List the recently used record 2 first:
+    /// assert_eq!(
+    ///     search_index.search_select2_dump(&request, &dump_options, |key| *key == 2).unwrap(),
+    ///     vec![&2, &0, &1]
+    /// );
+    ///
+    /// // The rest of the records are on the next page:
+    /// request.page = Some(2);
+    /// assert_eq!(
+    ///     search_index.search_select2_dump(&request, &dump_options, |key| *key == 2).unwrap(),
+    ///     vec![&3]
+    /// );
+    /// ```
+
+    #[tracing::instrument(level = "trace", name = "select2 dump", skip(self, promote))]
+    pub fn search_select2_dump(
+        &'a self,
+        request: &'a Request,
+        dump_options: &DumpOptions,
+        promote: impl Fn(&K) -> bool,
+    ) -> Result<Vec<&'a K>, Error> {
+        self.select2_dump(request, dump_options, &promote, None)
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Like [`search_select2_dump`], but with a `boost` closure that scores
+    /// each key for the `DumpOrder::Boost` order. The promoted records are
+    /// still listed first. For other orders, the boost isn't used.
+    ///
+    /// [`search_select2_dump`]: struct.SearchIndex.html#method.search_select2_dump
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error::InvalidSetting` error if the order is
+    /// `DumpOrder::Insertion` and the search index doesn't keep
+    /// `posting_timestamps`.
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # use indicium::select2::dump::{DumpOptions, DumpOrder};
+    /// # use indicium::select2::Request;
+    /// # use indicium::simple::SearchIndex;
+    /// # use pretty_assertions::assert_eq;
+    /// #
+    /// let mut search_index: SearchIndex<usize> = SearchIndex::default();
+    ///
+    /// search_index.insert(&0, &"Canada");
+    /// search_index.insert(&1, &"Cameroon");
+    /// search_index.insert(&2, &"Cambodia");
+    ///
+    /// let request = Request { term: None, q: None, request_type: None, page: None };
+    ///
+    /// let dump_options = DumpOptions {
+    ///     order: DumpOrder::Boost,
+    ///     maximum_results: None,
+    /// };
+    ///
+    /// // List the most popular records first:
+    /// let popularity = [10.0, 50.0, 10.0];
+    /// assert_eq!(
+    ///     search_index.search_select2_dump_boosted(
+    ///         &request,
+    ///         &dump_options,
+    ///         |_key| false,
+    ///         |key| popularity[*key],
+    ///     ).unwrap(),
+    ///     vec![&1, &0, &2]
+    /// );
+    /// ```
+
+    #[tracing::instrument(level = "trace", name = "select2 dump boosted", skip(self, promote, boost))]
+    pub fn search_select2_dump_boosted(
+        &'a self,
+        request: &'a Request,
+        dump_options: &DumpOptions,
+        promote: impl Fn(&K) -> bool,
+        boost: impl Fn(&K) -> f32,
+    ) -> Result<Vec<&'a K>, Error> {
+        self.select2_dump(request, dump_options, &promote, Some(&boost))
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Lists the records for an empty query, or searches if the user has
+    /// entered a query. See `search_select2_dump`.
+
+    fn select2_dump(
+        &'a self,
+        request: &'a Request,
+        dump_options: &DumpOptions,
+        promote: &dyn Fn(&K) -> bool,
+        boost: Option<&dyn Fn(&K) -> f32>,
+    ) -> Result<Vec<&'a K>, Error> {
+
+        // Check that the order can be used, even if the user entered a query,
+        // so that a misconfiguration isn't hidden until the listing is shown:
+        if dump_options.order == DumpOrder::Insertion && self.posting_times.is_none() {
+            return Err(Error::InvalidSetting {
+                setting: "order",
+                reason: "`DumpOrder::Insertion` requires `posting_timestamps`".to_string(),
+            }) // Err
+        } // if
+        if dump_options.order == DumpOrder::Boost && boost.is_none() {
+            return Err(Error::InvalidSetting {
+                setting: "order",
+                reason: "`DumpOrder::Boost` requires `search_select2_dump_boosted`".to_string(),
+            }) // Err
+        } // if
+
+        // If the user entered a query, perform a regular search:
+        if request.query_term(None).is_some() {
+            return Ok(self.search_select2(request))
+        } // if
+
+        // Get every record, whether or not there's a `dump_keyword`:
+        let mut keys: Vec<&K> = self.all_keys().collect();
+
+        match (dump_options.order, &self.posting_times, boost) {
+            // Order the records by the time they were inserted, using the
+            // earliest timestamp of each key's keywords:
+            (DumpOrder::Insertion, Some(posting_times), _) => {
+                keys.sort_by_cached_key(|key| {
+                    let inserted: Option<SystemTime> = posting_times
+                        .get(*key)
+                        .and_then(|keyword_times| keyword_times.values().min().copied());
+                    (inserted.is_none(), inserted)
+                }); // sort_by_cached_key
+            }, // Insertion
+            // Order the records by their boost, highest first. The sort is
+            // stable, so ties stay in key order:
+            (DumpOrder::Boost, _, Some(boost)) => {
+                let mut boosted: Vec<(f32, &K)> = keys.into_iter().map(|key| (boost(key), key)).collect();
+                boosted.sort_by(|(a, _), (b, _)| b.total_cmp(a));
+                keys = boosted.into_iter().map(|(_boost, key)| key).collect();
+            }, // Boost
+            _ => {},
+        } // match
+
+        // List the promoted records first, preserving the order otherwise. The
+        // sort is stable:
+        keys.sort_by_key(|key| !promote(key));
+
+        // Return the requested page of the listing:
+        if let Some(maximum_results) = dump_options.maximum_results {
+            let start: usize = maximum_results.saturating_mul(request.page_number() - 1);
+            keys = keys.into_iter().skip(start).take(maximum_results).collect();
+        } // if
+
+        // For debug builds:
+        #[cfg(debug_assertions)]
+        tracing::debug!("{} records listed for empty query.", keys.len());
+
+        Ok(keys)
+
+    } // fn
+
+} // impl
\ No newline at end of file
@@ -27,6 +27,18 @@ pub trait Selectable {
     fn record(&self) -> FlatRecord;
 } // Selectable
 
+// -----------------------------------------------------------------------------
+//
+/// References to `Selectable` records are also `Selectable`. This allows
+/// collection look-ups (such as `Vec::get` or `HashMap::get`) to be used
+/// without cloning the records.
+
+impl<T: Selectable + ?Sized> Selectable for &T {
+    fn record(&self) -> FlatRecord {
+        (**self).record()
+    } // fn
+} // impl
+
 // -----------------------------------------------------------------------------
 //
 /// For the `select2::flat` module to work with your records, you must implement
@@ -52,12 +52,22 @@
 //!
 //! [`FlatResults`]: flat/struct.FlatResults.html
 //! [`GroupedResults`]: grouped/struct.GroupedResults.html
+//!
+//! Alternatively, the [`select2_response`] helper performs steps #2 to #6 in
+//! a single call. It searches the index, paginates the results, looks up only
+//! the records that are on the requested page using a caller-provided closure,
+//! and returns a `FlatResults` struct that can be serialized with its
+//! `to_json` method.
+//!
+//! [`select2_response`]: response/fn.select2_response.html
 
 // Directories:
 pub mod flat;
 pub mod grouped;
 
 // Methods & structs:
+pub mod dump;
+pub mod response;
 pub mod search_select2;
 
 // -----------------------------------------------------------------------------
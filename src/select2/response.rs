@@ -0,0 +1,161 @@
+//! End-to-end helper for answering `Select2` AJAX requests.
+
+use crate::select2::flat::{FlatResults, Selectable};
+use crate::select2::{Pagination, Record, Request};
+use crate::simple::SearchIndex;
+use std::{fmt::Debug, hash::Hash, string::ToString};
+
+// -----------------------------------------------------------------------------
+//
+/// Performs the search for the client's `Select2` request, paginates the
+/// search results, and builds the response.
+///
+/// * `search_index` is the search index to be searched using the
+///   [`search_select2`] method.
+///
+/// * `collection_lookup` is a closure that returns the record for a key from
+///   your collection. It is only called for keys that are on the requested
+///   page, so expensive look-ups (a database, for example) are kept to a
+///   minimum. Keys that return `None` are skipped.
+///
+/// * `request` is the `Request` struct parsed from the client's query-string.
+///
+/// * `items_per_page` turns pagination ("infinite scrolling") on when set.
+///   `pagination.more` will be `true` when there are more pages of results
+///   available.
+///
+/// * `selected_record` is the `id` of the record to be marked as selected,
+///   if any.
+///
+/// [`search_select2`]: ../../simple/struct.SearchIndex.html#method.search_select2
+///
+/// Basic usage:
+///
+/// ```rust
+/// # use indicium::select2::flat::{FlatRecord, Selectable};
+/// # use indicium::select2::response::select2_response;
+/// # use indicium::select2::Request;
+/// # use indicium::simple::SearchIndex;
+/// # use pretty_assertions::assert_eq;
+/// #
+/// struct Country { name: String }
+///
+/// impl Selectable for Country {
+///     fn record(&self) -> FlatRecord {
+///         FlatRecord { text: self.name.clone(), selected: false, disabled: false }
+///     }
+/// }
+///
+/// let countries = vec![
+///     Country { name: "Canada".to_string() },
+///     Country { name: "Cameroon".to_string() },
+///     Country { name: "Cambodia".to_string() },
+/// ];
+///
+/// let mut search_index: SearchIndex<usize> = SearchIndex::default();
+///
+/// countries
+///     .iter()
+///     .enumerate()
+///     .for_each(|(index, country)| search_index.insert(&index, &country.name));
+///
+/// let request = Request {
+///     term: None,
+///     q: Some("cam".to_string()),
+///     request_type: None,
+///     page: Some(1),
+/// };
+///
+/// let response = select2_response(
+///     &search_index,
+///     &|key: &usize| countries.get(*key),
+///     &request,
+///     &Some(1),
+///     &None,
+/// );
+///
+/// assert_eq!(response.results.len(), 1);
+/// assert_eq!(response.results[0].text, "Cameroon");
+/// assert!(response.pagination.more);
+///
+/// assert_eq!(
+///     response.to_json().unwrap(),
+///     r#"{"results":[{"id":"1","text":"Cameroon","selected":false,"disabled":false}],"pagination":{"more":true}}"#
+/// );
+/// ```
+
+#[tracing::instrument(level = "trace", name = "select2 response", skip(search_index, collection_lookup))]
+pub fn select2_response<'a, K, S, F>(
+    search_index: &'a SearchIndex<K>,
+    collection_lookup: &F,
+    request: &'a Request,
+    items_per_page: &Option<usize>,
+    selected_record: &Option<String>,
+) -> FlatResults
+where
+    K: 'a + Debug + Hash + Ord + ToString,
+    S: Selectable,
+    F: Fn(&K) -> Option<S>,
+{
+
+    // Search the index for the client's query:
+    let search_results: Vec<&K> = search_index.search_select2(request);
+
+    // Determine which slice of the search results is on the requested page. If
+    // pagination is turned off, the entire result set is returned:
+    let (skip, take, more) = match items_per_page {
+        Some(items_per_page) => {
+            let page: usize = request.page_number();
+            (
+                items_per_page.saturating_mul(page - 1),
+                *items_per_page,
+                items_per_page.saturating_mul(page) < search_results.len(),
+            )
+        }, // Some
+        None => (0, search_results.len(), false),
+    }; // match
+
+    let results: Vec<Record> = search_results
+        // Iterate over each search result key:
+        .into_iter()
+        // Skip records so we start at beginning of the requested page:
+        .skip(skip)
+        // Only take a page's worth of records:
+        .take(take)
+        // Look-up the record for each key from the caller's collection. Keys
+        // that can not be found are skipped:
+        .filter_map(|key| collection_lookup(key).map(|value| (key, value)))
+        // Convert the `Selectable` record into the output `Record` format:
+        .map(|(key, value)| value.record().to_record(&key.to_string()))
+        // Check if this record was specified as being selected:
+        .map(|mut record| {
+            if let Some(selected_record) = selected_record {
+                record.selected = record.id == *selected_record;
+            } // if
+            record
+        }) // map
+        // Collect all Select2 records into a `Vec<Record>`:
+        .collect();
+
+    // Return Select2 `FlatResults` to caller:
+    FlatResults {
+        results,
+        pagination: Pagination { more },
+    } // FlatResults
+
+} // fn
+
+// -----------------------------------------------------------------------------
+
+impl FlatResults {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Serializes the results into the exact `JSON` structure expected by the
+    /// `Select2` jQuery plug-in, including the `pagination.more` field.
+
+    pub fn to_json(&self) -> Result<String, serde_json::Error> {
+        serde_json::to_string(self)
+    } // fn
+
+} // impl
\ No newline at end of file
@@ -1,7 +1,7 @@
 //! `Select2` interfaces to the `simple::SearchIndex`.
 
 use crate::select2::Request;
-use crate::simple::{SearchIndex, SearchType};
+use crate::simple::SearchIndex;
 use std::{fmt::Debug, hash::Hash};
 
 // -----------------------------------------------------------------------------
@@ -17,23 +17,67 @@ impl<'a, K: 'a + Debug + Hash + Ord> SearchIndex<K> {
         &'a self,
         request: &'a Request,
     ) -> Vec<&'a K> {
+        self.search_select2_excluding(request, &[])
+    } // fn
+
+    /// This search method is intended for `Select2` multi-select controls. It
+    /// returns the search results for the client's query, except for the keys
+    /// that have already been selected by the user. Already-selected keys are
+    /// filtered-out while the search index is traversed, so they will not use
+    /// up room in the search results.
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # use indicium::select2::Request;
+    /// # use indicium::simple::SearchIndex;
+    /// # use pretty_assertions::assert_eq;
+    /// #
+    /// let mut search_index: SearchIndex<usize> = SearchIndex::default();
+    ///
+    /// search_index.insert(&0, &"Canada");
+    /// search_index.insert(&1, &"Cameroon");
+    /// search_index.insert(&2, &"Cambodia");
+    ///
+    /// let request = Request {
+    ///     term: Some("ca".to_string()),
+    ///     q: None,
+    ///     request_type: None,
+    ///     page: None,
+    /// };
+    ///
+    /// assert_eq!(search_index.search_select2_excluding(&request, &[1]), vec![&0, &2]);
+    /// ```
+
+    #[tracing::instrument(level = "trace", name = "select2 search excluding", skip(self))]
+    pub fn search_select2_excluding(
+        &'a self,
+        request: &'a Request,
+        exclude_ids: &[K],
+    ) -> Vec<&'a K> {
 
         // Get query (or "search term"), if any:
-        let query_term: Option<&str> = request.query_term(self.dump_keyword());
+        let query_term: Option<&str> = request.query_term(None);
 
         if let Some(query_term) = query_term {
 
-            // If valid query provided, perform search of index:
-            self.search_with(
-                &SearchType::Live,
+            // If valid query provided, perform search of index. Keys that are
+            // in the exclusion list are filtered-out during the search:
+            self.search_live_filtered(
                 &self.max_keys_per_keyword(),
                 query_term,
-            ) // search_with
+                &|key| !exclude_ids.contains(key),
+            ) // search_live_filtered
+                .into_vec()
 
         } else {
 
-            // If no query (or "search term"), then return empty results:
-            Vec::new()
+            // If no query (or "search term"), then return every record, except
+            // for the keys in the exclusion list. This doesn't depend on the
+            // `dump_keyword`, so it works when the dump keyword is disabled:
+            self.all_keys()
+                .filter(|key| !exclude_ids.contains(key))
+                .collect()
 
         } // if
 
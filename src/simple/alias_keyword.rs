@@ -0,0 +1,187 @@
+use crate::simple::{IndexChange, SearchIndex};
+use kstring::KString;
+use std::collections::BTreeSet;
+use std::sync::Arc;
+use std::{clone::Clone, cmp::Ord};
+
+// -----------------------------------------------------------------------------
+
+impl<K: Clone + Ord> SearchIndex<K> {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Attaches every key of the `from` keyword to the `to` keyword, so that
+    /// searching for `to` also finds the records indexed under `from`. The
+    /// `from` keyword is kept. Returns the number of keys that were newly
+    /// attached to `to`.
+    ///
+    /// This is useful when a canonicalization rule is introduced after data
+    /// has been indexed, without having to re-index every record. Both
+    /// keywords are lowercased unless the search index is case sensitive.
+    /// Once `to` has `maximum_keys_per_keyword` keys, the remaining keys
+    /// aren't attached to it.
+    ///
+    /// With the default `Upsert` duplicate key policy, `remove` also detaches
+    /// the record's key from `to`. With the `Accumulate` policy, `remove`
+    /// only detaches the key from the keywords of the given strings, so the
+    /// key stays attached to `to`.
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # use indicium::simple::SearchIndex;
+    /// # use pretty_assertions::assert_eq;
+    /// #
+    /// let mut search_index: SearchIndex<usize> = SearchIndex::default();
+    /// search_index.insert(&0, &"Colour television");
+    /// search_index.insert(&1, &"Color printer");
+    ///
+    /// assert_eq!(search_index.alias_keyword("colour", "color"), 1);
+    ///
+    /// assert_eq!(search_index.search("color"), vec![&0, &1]);
+    /// assert_eq!(search_index.search("colour"), vec![&0]);
+    /// ```
+
+    #[tracing::instrument(level = "trace", name = "alias keyword", skip(self))]
+    pub fn alias_keyword(&mut self, from: &str, to: &str) -> usize {
+        self.record_op(|| IndexChange::Alias { from: from.to_string(), to: to.to_string() });
+        self.merge_keyword(from, to, true)
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Moves every key of the `from` keyword to the `to` keyword, and removes
+    /// the `from` keyword. If `to` already exists, the keys are merged.
+    /// Returns the number of keys that were newly attached to `to`.
+    ///
+    /// This is useful when a product name changes after data has been
+    /// indexed, without having to re-index every record. Both keywords are
+    /// lowercased unless the search index is case sensitive. As with
+    /// `alias_keyword`, the `maximum_keys_per_keyword` setting is applied to
+    /// `to`, and `remove` only detaches a record's key from `to` with the
+    /// `Upsert` duplicate key policy.
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # use indicium::simple::SearchIndex;
+    /// # use pretty_assertions::assert_eq;
+    /// #
+    /// let mut search_index: SearchIndex<usize> = SearchIndex::default();
+    /// search_index.insert(&0, &"Widget Classic");
+    /// search_index.insert(&1, &"Widget Pro");
+    ///
+    /// assert_eq!(search_index.rename_keyword("widget", "gadget"), 2);
+    ///
+    /// assert_eq!(search_index.search("gadget"), vec![&0, &1]);
+    /// assert!(!search_index.contains_keyword("widget"));
+    /// ```
+
+    #[tracing::instrument(level = "trace", name = "rename keyword", skip(self))]
+    pub fn rename_keyword(&mut self, from: &str, to: &str) -> usize {
+        self.record_op(|| IndexChange::Rename { from: from.to_string(), to: to.to_string() });
+        self.merge_keyword(from, to, false)
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Attaches the keys of the `from` keyword to the `to` keyword, keeping
+    /// or removing `from`. Used by `alias_keyword` and `rename_keyword`.
+
+    fn merge_keyword(&mut self, from: &str, to: &str, keep_from: bool) -> usize {
+
+        let (from, to): (KString, KString) = match self.case_sensitive {
+            true => (KString::from_ref(from), KString::from_ref(to)),
+            false => (KString::from(from.to_lowercase()), KString::from(to.to_lowercase())),
+        }; // match
+
+        if from == to { return 0 }
+
+        // Compacted whole-string keywords must be in the main dictionary
+        // before keys can be moved between them:
+        self.expand_whole_strings([&from, &to].into_iter());
+
+        let from_keys: BTreeSet<K> = match self.b_tree_map.get(&from) {
+            Some(keys) => keys.clone(),
+            None => return 0,
+        }; // match
+
+        // The indexed data is changing, so precomputed autocompletions are no
+        // longer current:
+        self.invalidate_autocompletions();
+
+        // If a snapshot shares the keywords, they're copied before being
+        // changed:
+        let b_tree_map = Arc::make_mut(&mut self.b_tree_map);
+
+        // Attach the keys to `to` until it reaches the maximum number of keys
+        // per keyword. Note that the `dump_keyword` does not observe this
+        // limit:
+        let maximum_keys = match self.dump_keyword.as_ref() == Some(&to) {
+            true => usize::MAX,
+            false => self.maximum_keys_per_keyword,
+        }; // match
+        let to_keys = b_tree_map.entry(to.clone()).or_default();
+        let mut saturated = false;
+        let attached: BTreeSet<K> = from_keys
+            .iter()
+            .filter(|key| match to_keys.contains(*key) {
+                true => false,
+                false if to_keys.len() < maximum_keys => to_keys.insert((*key).clone()),
+                false => { saturated = true; false },
+            }) // filter
+            .cloned()
+            .collect();
+        if to_keys.is_empty() { b_tree_map.remove(&to); }
+
+        if !keep_from {
+            b_tree_map.remove(&from);
+            self.keyword_usage.forget(&from);
+            self.whole_string_keywords.remove(&from);
+        } // if
+
+        // Record the saturated keyword, and report it the first time:
+        if saturated && self.saturated_keywords.insert(to.clone()) {
+            self.saturation_hook.call(&to);
+        } // if
+
+        // Update the keys' keywords in the reverse map:
+        if let Some(reverse_map) = &mut self.reverse_map {
+            from_keys.iter().for_each(|key| {
+                let key_keywords = reverse_map.entry(key.clone()).or_default();
+                if attached.contains(key) { key_keywords.insert(to.clone()); }
+                if !keep_from { key_keywords.remove(&from); }
+                if key_keywords.is_empty() { reverse_map.remove(key); }
+            }); // for_each
+        } // if
+
+        // Carry the keys' posting timestamps over to the new keyword:
+        if let Some(posting_times) = &mut self.posting_times {
+            from_keys.iter().for_each(|key| {
+                if let Some(keyword_times) = posting_times.get_mut(key) {
+                    let time = match keep_from {
+                        true => keyword_times.get(&from).copied(),
+                        false => keyword_times.remove(&from),
+                    }; // match
+                    if let Some(time) = time.filter(|_time| attached.contains(key)) {
+                        keyword_times.entry(to.clone()).or_insert(time);
+                    } // if
+                } // if
+            }); // for_each
+        } // if
+
+        self.sync_mirrors(&[from, to]);
+
+        // Record the size of the search index for metrics:
+        #[cfg(feature = "metrics")]
+        crate::simple::internal::metrics::record_index_size(self.b_tree_map.len());
+
+        // For debug builds:
+        #[cfg(debug_assertions)]
+        tracing::debug!("{} keys attached to the new keyword.", attached.len());
+
+        attached.len()
+
+    } // fn
+
+} // impl
\ No newline at end of file
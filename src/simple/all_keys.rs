@@ -0,0 +1,58 @@
+use crate::simple::internal::Keys;
+use crate::simple::search_index::SearchIndex;
+use std::cmp::Ord;
+
+// -----------------------------------------------------------------------------
+
+impl<K: Ord> SearchIndex<K> {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns every distinct key (or record) in the search index, in
+    /// ascending order.
+    ///
+    /// The keys are read from the `dump_keyword` or the reverse map if the
+    /// search index has one. Otherwise, the keys of every keyword are gathered.
+    /// Unlike searching for the `dump_keyword`, this works whether or not the
+    /// dump keyword is enabled.
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # use indicium::simple::{DuplicateKeyPolicy, SearchIndex, SearchIndexBuilder};
+    /// # use pretty_assertions::assert_eq;
+    /// #
+    /// let mut search_index: SearchIndex<usize> = SearchIndexBuilder::default()
+    ///     .duplicate_key_policy(DuplicateKeyPolicy::Accumulate)
+    ///     .dump_keyword(None)
+    ///     .reverse_map(false)
+    ///     .build();
+    ///
+    /// search_index.insert(&2, &"Edgar Ætheling");
+    /// search_index.insert(&0, &"Harold Godwinson");
+    /// search_index.insert(&1, &"Harold Hardrada");
+    /// search_index.compact_whole_strings();
+    ///
+    /// assert_eq!(search_index.all_keys().collect::<Vec<&usize>>(), vec![&0, &1, &2]);
+    /// ```
+
+    #[tracing::instrument(level = "trace", name = "all keys", skip(self))]
+    pub fn all_keys(&self) -> impl Iterator<Item = &K> + '_ {
+        let dump_keys = self.dump_keyword
+            .as_ref()
+            .and_then(|dump_keyword| self.b_tree_map.get(dump_keyword));
+
+        let keys: Box<dyn Iterator<Item = &K>> = match (dump_keys, &self.reverse_map) {
+            (Some(dump_keys), _) => Box::new(dump_keys.iter()),
+            (_, Some(reverse_map)) => Box::new(reverse_map.keys()),
+            _ => Box::new(self.b_tree_map
+                .values()
+                .flatten()
+                .chain(self.whole_strings.values())
+                .collect::<Keys<K>>()
+                .into_iter()),
+        }; // match
+        keys
+    } // fn
+
+} // impl
\ No newline at end of file
@@ -0,0 +1,113 @@
+use crate::simple::index_batch::Strings;
+use crate::simple::{IndexChange, IndexOp, SearchIndex};
+use std::io::{Error, ErrorKind};
+use std::{clone::Clone, cmp::Ord};
+
+// -----------------------------------------------------------------------------
+
+impl<K: Clone + Ord> SearchIndex<K> {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Applies ops drained from another search index's changelog (see
+    /// `drain_ops`) to this search index, reproducing the source's state on a
+    /// replica. The replica should be built with the same settings as the
+    /// source. Returns the number of ops applied.
+    ///
+    /// Ops are applied in sequence. Ops that have already been applied are
+    /// skipped, so ops may be safely redelivered (at-least-once delivery.) If
+    /// an op is missing, an error is returned and the ops after the gap are
+    /// not applied. The ops before the gap remain applied.
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # use indicium::simple::{SearchIndex, SearchIndexBuilder};
+    /// #
+    /// let mut source: SearchIndex<usize> = SearchIndexBuilder::default()
+    ///     .changelog(true)
+    ///     .build();
+    ///
+    /// let mut replica: SearchIndex<usize> = SearchIndex::default();
+    ///
+    /// source.insert(&0, &"William the Conqueror");
+    /// source.insert(&1, &"William Rufus");
+    /// let ops = source.drain_ops();
+    ///
+    /// assert_eq!(replica.apply_ops(ops.clone()).unwrap(), 2);
+    /// // Redelivered ops are skipped:
+    /// assert_eq!(replica.apply_ops(ops).unwrap(), 0);
+    /// assert_eq!(replica.applied_sequence(), 2);
+    ///
+    /// assert_eq!(replica.search("william"), source.search("william"));
+    /// ```
+
+    #[tracing::instrument(level = "trace", name = "apply ops", skip(self, ops))]
+    pub fn apply_ops(
+        &mut self,
+        ops: impl IntoIterator<Item = IndexOp<K>>,
+    ) -> Result<usize, Error> {
+
+        let mut applied: usize = 0;
+
+        for op in ops {
+            // Skip ops that have already been applied:
+            if op.sequence <= self.applied_sequence { continue }
+
+            // Refuse to skip over missing ops:
+            if op.sequence != self.applied_sequence + 1 {
+                let error_message = format!(
+                    "Op {} is missing. Received op {} after applying op {}.",
+                    self.applied_sequence + 1,
+                    op.sequence,
+                    self.applied_sequence,
+                ); // format!
+                return Err(Error::new(ErrorKind::InvalidData, error_message))
+            } // if
+
+            match op.change {
+                IndexChange::Insert { key, strings } => self.insert(&key, &Strings(strings, Vec::new())),
+                IndexChange::Remove { key, strings } => self.remove(&key, &Strings(strings, Vec::new())),
+                IndexChange::InsertWith { key, strings, options } =>
+                    self.insert_with(&key, &Strings(strings, Vec::new()), options),
+                IndexChange::InsertFields { key, fields, options } => {
+                    let (field_names, strings) = fields.into_iter().unzip();
+                    self.insert_with(&key, &Strings(strings, field_names), options)
+                }, // InsertFields
+                IndexChange::UpdateField { key, field, string } => {
+                    self.update_field(&key, &field, &string)
+                        .map_err(|error| Error::new(ErrorKind::InvalidData, error.to_string()))?;
+                }, // UpdateField
+                IndexChange::Clear => self.clear(),
+                IndexChange::Alias { from, to } => { self.alias_keyword(&from, &to); },
+                IndexChange::Rename { from, to } => { self.rename_keyword(&from, &to); },
+            } // match
+
+            self.applied_sequence = op.sequence;
+            applied += 1;
+        } // for
+
+        // Record the size of the search index for metrics:
+        #[cfg(feature = "metrics")]
+        crate::simple::internal::metrics::record_index_size(self.b_tree_map.len());
+
+        Ok(applied)
+
+    } // fn
+
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl<K: Ord> SearchIndex<K> {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns the sequence number of the last op applied with `apply_ops`, or
+    /// `0` if no ops have been applied.
+
+    pub fn applied_sequence(&self) -> u64 {
+        self.applied_sequence
+    } // fn
+
+} // impl
\ No newline at end of file
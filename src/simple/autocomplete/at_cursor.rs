@@ -0,0 +1,94 @@
+use crate::simple::SearchIndex;
+use std::ops::Range;
+use std::{cmp::Ord, hash::Hash};
+
+// -----------------------------------------------------------------------------
+
+impl<K: Hash + Ord> SearchIndex<K> {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns completions for the keyword containing the cursor, for editors
+    /// and search bars where the caret may be in the middle of the search
+    /// string. `cursor` is a byte offset into the string.
+    ///
+    /// The text of the keyword before the cursor is completed, and the rest of
+    /// the search string, on either side of the keyword, is used as context
+    /// in the same way as the preceding keywords are by [`autocomplete`].
+    /// Along with the completions, the byte range of the keyword is returned,
+    /// so that it may be replaced by the chosen completion. The completions
+    /// are for the keyword only, like [`autocomplete_last_keyword`].
+    ///
+    /// If the cursor isn't inside or at the end of a keyword, no completions
+    /// are returned and the range is empty.
+    ///
+    /// [`autocomplete`]: struct.SearchIndex.html#method.autocomplete
+    /// [`autocomplete_last_keyword`]: struct.SearchIndex.html#method.autocomplete_last_keyword
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # use indicium::simple::SearchIndex;
+    /// # use pretty_assertions::assert_eq;
+    /// #
+    /// let mut search_index: SearchIndex<usize> = SearchIndex::default();
+    /// search_index.insert(&0, &"William the Conqueror");
+    /// search_index.insert(&1, &"William Rufus");
+    /// search_index.insert(&2, &"Harold Godwinson");
+    ///
+    /// // The caret is after "wi", and "rufus" narrows the completions:
+    /// let (range, completions) = search_index.autocomplete_at("wixx rufus", 2);
+    ///
+    /// assert_eq!(range, 0..4);
+    /// assert_eq!(
+    ///     completions,
+    ///     vec!["william".to_string(), "william rufus".to_string()]
+    /// );
+    /// ```
+
+    #[tracing::instrument(level = "trace", name = "autocomplete at cursor", skip(self))]
+    pub fn autocomplete_at(&self, string: &str, cursor: usize) -> (Range<usize>, Vec<String>) {
+
+        // Keep the cursor inside the string and on a character boundary:
+        let mut cursor: usize = cursor.min(string.len());
+        while !string.is_char_boundary(cursor) { cursor -= 1 }
+
+        // Keywords are separated by the split pattern. If there is no split
+        // pattern, the whole string is one keyword:
+        let separator = |char: char| match &self.split_pattern {
+            Some(split_pattern) => split_pattern.contains(&char),
+            None => false,
+        }; // closure
+
+        // Find the keyword containing the cursor:
+        let start: usize = string[..cursor]
+            .char_indices()
+            .rev()
+            .find(|(_index, char)| separator(*char))
+            .map_or(0, |(index, char)| index + char.len_utf8());
+        let end: usize = string[cursor..]
+            .char_indices()
+            .find(|(_index, char)| separator(*char))
+            .map_or(string.len(), |(index, _char)| cursor + index);
+
+        // There's nothing to complete if the cursor isn't after the start of
+        // a keyword:
+        if start == cursor { return (cursor..cursor, Vec::new()) }
+
+        // Move the partial keyword to the end of the search string, so that
+        // the rest of the string is used as context:
+        let query: String = match self.split_pattern.as_ref().and_then(|split_pattern| split_pattern.first()) {
+            Some(separator) => [&string[..start], &string[end..], &string[start..cursor]]
+                .iter()
+                .filter(|part| !part.is_empty())
+                .copied()
+                .collect::<Vec<&str>>()
+                .join(&separator.to_string()),
+            None => string[..cursor].to_string(),
+        }; // match
+
+        (start..end, self.autocomplete_last_keyword(&query))
+
+    } // fn
+
+} // impl
\ No newline at end of file
@@ -1,9 +1,10 @@
 #![allow(unused_mut)]
 
 use crate::simple::internal::string_keywords::SplitContext;
+use crate::simple::internal::Keys;
 use crate::simple::SearchIndex;
 use kstring::KString;
-use std::{cmp::Ord, collections::BTreeSet, hash::Hash};
+use std::{cmp::Ord, hash::Hash};
 
 // -----------------------------------------------------------------------------
 
@@ -99,6 +100,22 @@ impl<K: Hash + Ord> SearchIndex<K> {
         maximum_autocomplete_options: &usize,
         string: &str,
     ) -> Vec<String> {
+        self.autocomplete_context_filtered(maximum_autocomplete_options, string, &|_key| true)
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// This is the `Context` autocompletion with a caller-provided key filter.
+    /// Keywords are only suggested if at least one of their keys passes the
+    /// filter, so that suggestions don't reveal records hidden from the user.
+
+    #[tracing::instrument(level = "trace", name = "filtered context autocomplete", skip(self, filter))]
+    pub(crate) fn autocomplete_context_filtered(
+        &self,
+        maximum_autocomplete_options: &usize,
+        string: &str,
+        filter: &dyn Fn(&K) -> bool,
+    ) -> Vec<String> {
 
         // Split search `String` into keywords according to the `SearchIndex`
         // settings. Force "use entire string as a keyword" option off:
@@ -116,8 +133,14 @@ impl<K: Hash + Ord> SearchIndex<K> {
         if let Some(last_keyword) = keywords.pop() {
 
             // Perform `And` search for entire string without the last keyword:
-            let search_results: BTreeSet<&K> =
-                self.internal_search_and(keywords.as_slice());
+            // If there's a context window, only the last few preceding keywords
+            // are used. Only keys that pass the caller's filter are kept:
+            let context: &[KString] = match self.context_window {
+                Some(context_window) => &keywords[keywords.len().saturating_sub(context_window)..],
+                None => keywords.as_slice(),
+            }; // match
+            let mut search_results: Keys<K> = self.internal_search_and(context);
+            search_results.retain(filter);
 
             // Intersect the autocompletions for the last keyword with the
             // search results for the preceding keywords. This way, only
@@ -141,9 +164,10 @@ impl<K: Hash + Ord> SearchIndex<K> {
                 .filter(|(keyword, _keys)| !keywords.contains(keyword))
                 // Only keep this autocompletion if it contains a key that the
                 // search results contain:
-                .filter(|(_keyword, keys)|
-                    search_results.is_empty() ||
-                        keys.iter().any(|key| search_results.contains(key))
+                .filter(|(keyword, keys)|
+                    self.suggestible(keyword, keys, &|key| filter(key) && (
+                        search_results.is_empty() || search_results.contains(key)
+                    ))
                 ) // filter
                 // Only return `maximum_autocomplete_options` number of
                 // keywords:
@@ -154,10 +178,20 @@ impl<K: Hash + Ord> SearchIndex<K> {
                 // Collect all keyword autocompletions into a `Vec`:
                 .collect();
 
-            // If `eddie` fuzzy matching enabled, examine the resulting
+            // Include any compacted whole-string keywords:
+            self.merge_whole_strings(
+                &mut autocompletions,
+                &last_keyword,
+                maximum_autocomplete_options,
+                &|whole_string, key| !keywords.contains(whole_string) && filter(key) && (
+                    search_results.is_empty() || search_results.contains(key)
+                ),
+            ); // merge_whole_strings
+
+            // If `eddie` fuzzy matching is used, examine the resulting
             // auto-complete options before using them:
             #[cfg(feature = "eddie")]
-            if autocompletions.is_empty() {
+            if autocompletions.is_empty() && self.uses_eddie() {
                 // No autocomplete options were found for the user's last
                 // (partial) keyword. Attempt to use fuzzy string search to find
                 // other autocomplete options:
@@ -166,6 +200,8 @@ impl<K: Hash + Ord> SearchIndex<K> {
                     &last_keyword,
                 ) // eddie_context_autocomplete
                     .into_iter()
+                    // Only keep keywords that may be suggested (see `suggestible`):
+                    .filter(|(keyword, keys)| self.suggestible(keyword, keys, filter))
                     // Only keep this autocompletion if hasn't already been used
                     // as a keyword:
                     .filter(|(keyword, _keys)| !keywords.contains(keyword))
@@ -180,10 +216,10 @@ impl<K: Hash + Ord> SearchIndex<K> {
                     .collect()
             } // if
 
-            // If `strsim` fuzzy matching enabled, examine the resulting
+            // If `strsim` fuzzy matching is used, examine the resulting
             // auto-complete options before using them:
-            #[cfg(all(feature = "strsim", not(feature = "eddie")))]
-            if autocompletions.is_empty() {
+            #[cfg(feature = "strsim")]
+            if autocompletions.is_empty() && self.uses_strsim() {
                 // No autocomplete options were found for the user's last
                 // (partial) keyword. Attempt to use fuzzy string search to find
                 // other autocomplete options:
@@ -192,6 +228,8 @@ impl<K: Hash + Ord> SearchIndex<K> {
                     &last_keyword,
                 ) // strsim_context_autocomplete
                     .into_iter()
+                    // Only keep keywords that may be suggested (see `suggestible`):
+                    .filter(|(keyword, keys)| self.suggestible(keyword, keys, filter))
                     // Only keep this autocompletion if hasn't already been used
                     // as a keyword:
                     .filter(|(keyword, _keys)| !keywords.contains(keyword))
@@ -102,6 +102,22 @@ impl<K: Hash + Ord> SearchIndex<K> {
         maximum_autocomplete_options: &usize,
         string: &str,
     ) -> Vec<String> {
+        self.autocomplete_global_filtered(maximum_autocomplete_options, string, &|_key| true)
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// This is the `Global` autocompletion with a caller-provided key filter.
+    /// Keywords are only suggested if at least one of their keys passes the
+    /// filter, so that suggestions don't reveal records hidden from the user.
+
+    #[tracing::instrument(level = "trace", name = "filtered global autocomplete", skip(self, filter))]
+    pub(crate) fn autocomplete_global_filtered(
+        &self,
+        maximum_autocomplete_options: &usize,
+        string: &str,
+        filter: &dyn Fn(&K) -> bool,
+    ) -> Vec<String> {
 
         // Split search `String` into keywords according to the `SearchIndex`
         // settings. Force "use entire string as a keyword" option off:
@@ -122,6 +138,8 @@ impl<K: Hash + Ord> SearchIndex<K> {
             let mut autocompletions: Vec<&KString> = self.b_tree_map
                 // Get matching keywords starting with (partial) keyword string:
                 .range(KString::from_ref(&last_keyword)..)
+                // Only keep keywords that may be suggested (see `suggestible`):
+                .filter(|(keyword, keys)| self.suggestible(keyword, keys, filter))
                 // `range` returns a key-value pair. We're autocompleting the
                 // key (keyword), so discard the value (record key):
                 .map(|(key, _value)| key)
@@ -150,15 +168,40 @@ impl<K: Hash + Ord> SearchIndex<K> {
                 // Collect all keyword autocompletions into a `Vec`:
                 .collect();
 
-            // If `eddie` fuzzy matching enabled, examine the resulting
+            // Include any compacted whole-string keywords:
+            self.merge_whole_strings(
+                &mut autocompletions,
+                &last_keyword,
+                maximum_autocomplete_options,
+                &|whole_string, key| !keywords.contains(whole_string) && filter(key),
+            ); // merge_whole_strings
+
+            // If enabled, fill any remaining slots with whole-string keywords
+            // that contain the last keyword as an interior token:
+            if self.interior_autocomplete {
+                let remaining = maximum_autocomplete_options.saturating_sub(autocompletions.len());
+                let interior_keywords: Vec<&KString> = self.interior_keywords(&last_keyword)
+                    .into_iter()
+                    .filter(|(interior, _keys)| !autocompletions.contains(interior))
+                    .filter(|(interior, _keys)| !keywords.contains(interior))
+                    .filter(|(interior, keys)| self.suggestible_entry(interior, *keys, filter))
+                    .take(remaining)
+                    .map(|(interior, _keys)| interior)
+                    .collect();
+                autocompletions.extend(interior_keywords);
+            } // if
+
+            // If `eddie` fuzzy matching is used, examine the resulting
             // auto-complete options before using them:
             #[cfg(feature = "eddie")]
-            if autocompletions.is_empty() {
+            if autocompletions.is_empty() && self.uses_eddie() {
                 // No autocomplete options were found for the user's last
                 // (partial) keyword. Attempt to use fuzzy string search to find
                 // other autocomplete options:
                 autocompletions = self.eddie_global_autocomplete(&last_keyword)
                     .into_iter()
+                    // Only keep keywords that may be suggested (see `suggestible`):
+                    .filter(|(keyword, keys)| self.suggestible(keyword, keys, filter))
                     // Only keep this autocompletion if hasn't already been used
                     // as a keyword:
                     .filter(|(keyword, _keys)| !keywords.contains(keyword))
@@ -173,15 +216,17 @@ impl<K: Hash + Ord> SearchIndex<K> {
                     .collect()
             } // if
 
-            // If `strsim` fuzzy matching enabled, examine the resulting
+            // If `strsim` fuzzy matching is used, examine the resulting
             // auto-complete options before using them:
-            #[cfg(all(feature = "strsim", not(feature = "eddie")))]
-            if autocompletions.is_empty() {
+            #[cfg(feature = "strsim")]
+            if autocompletions.is_empty() && self.uses_strsim() {
                 // No autocomplete options were found for the user's last
                 // (partial) keyword. Attempt to use fuzzy string search to find
                 // other autocomplete options:
                 autocompletions = self.strsim_global_autocomplete(&last_keyword)
                     .into_iter()
+                    // Only keep keywords that may be suggested (see `suggestible`):
+                    .filter(|(keyword, keys)| self.suggestible(keyword, keys, filter))
                     // Only keep this autocompletion if hasn't already been used
                     // as a keyword:
                     .filter(|(keyword, _keys)| !keywords.contains(keyword))
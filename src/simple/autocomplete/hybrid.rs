@@ -0,0 +1,80 @@
+use crate::simple::{AutocompleteType, SearchIndex};
+use std::{cmp::Ord, hash::Hash};
+
+// -----------------------------------------------------------------------------
+
+impl<K: Hash + Ord> SearchIndex<K> {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns matching autocompleted keywords for the provided search string,
+    /// along with the autocompletion type that produced them. `Context`
+    /// autocompletion is tried first. If the preceding keywords are too
+    /// restrictive and no options are found, `Global` autocompletion is used
+    /// instead.
+    ///
+    /// This is what `AutocompleteType::Hybrid` uses. Calling it directly lets
+    /// the user interface show when suggestions are outside of the current
+    /// context.
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # use indicium::simple::{AutocompleteType, SearchIndex};
+    /// # use pretty_assertions::assert_eq;
+    /// #
+    /// let mut search_index: SearchIndex<usize> = SearchIndex::default();
+    /// search_index.insert(&0, &"William the Conqueror");
+    /// search_index.insert(&1, &"Harold Godwinson");
+    ///
+    /// assert_eq!(
+    ///     search_index.autocomplete_hybrid(&5, "william con"),
+    ///     (AutocompleteType::Context, vec!["william conqueror".to_string()])
+    /// );
+    ///
+    /// assert_eq!(
+    ///     search_index.autocomplete_hybrid(&5, "william god"),
+    ///     (AutocompleteType::Global, vec!["william godwinson".to_string()])
+    /// );
+    /// ```
+
+    #[tracing::instrument(level = "trace", name = "hybrid autocomplete", skip(self))]
+    pub fn autocomplete_hybrid(
+        &self,
+        maximum_autocomplete_options: &usize,
+        string: &str,
+    ) -> (AutocompleteType, Vec<String>) {
+        self.autocomplete_hybrid_filtered(maximum_autocomplete_options, string, &|_key| true)
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// This is `autocomplete_hybrid` with a caller-provided key filter, which
+    /// is applied to both the `Context` and `Global` autocompletions.
+
+    #[tracing::instrument(level = "trace", name = "filtered hybrid autocomplete", skip(self, filter))]
+    pub(crate) fn autocomplete_hybrid_filtered(
+        &self,
+        maximum_autocomplete_options: &usize,
+        string: &str,
+        filter: &dyn Fn(&K) -> bool,
+    ) -> (AutocompleteType, Vec<String>) {
+
+        // Try contextual autocompletion first:
+        let autocomplete_options: Vec<String> =
+            self.autocomplete_context_filtered(maximum_autocomplete_options, string, filter);
+
+        if autocomplete_options.is_empty() {
+            // The context didn't produce any options. Fall back to global
+            // autocompletion:
+            (
+                AutocompleteType::Global,
+                self.autocomplete_global_filtered(maximum_autocomplete_options, string, filter),
+            )
+        } else {
+            (AutocompleteType::Context, autocomplete_options)
+        } // if
+
+    } // fn
+
+} // impl
\ No newline at end of file
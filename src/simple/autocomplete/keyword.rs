@@ -91,6 +91,22 @@ impl<K: Hash + Ord> SearchIndex<K> {
         maximum_autocomplete_options: &usize,
         keyword: &str,
     ) -> Vec<&str> {
+        self.autocomplete_keyword_filtered(maximum_autocomplete_options, keyword, &|_key| true)
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// This is the `Keyword` autocompletion with a caller-provided key filter.
+    /// Keywords are only suggested if at least one of their keys passes the
+    /// filter, so that suggestions don't reveal records hidden from the user.
+
+    #[tracing::instrument(level = "trace", name = "filtered keyword autocomplete", skip(self, filter))]
+    pub(crate) fn autocomplete_keyword_filtered(
+        &self,
+        maximum_autocomplete_options: &usize,
+        keyword: &str,
+        filter: &dyn Fn(&K) -> bool,
+    ) -> Vec<&str> {
 
         // If case sensitivity set, leave case intact. Otherwise, normalize
         // keyword to lower case:
@@ -104,9 +120,11 @@ impl<K: Hash + Ord> SearchIndex<K> {
         tracing::debug!("autocompleting: {:?}", keyword);
 
         // Attempt to get matching keywords from `BTreeMap`:
-        let autocomplete_options: Vec<&KString> = self.b_tree_map
+        let mut autocomplete_options: Vec<&KString> = self.b_tree_map
             // Get matching keywords starting with (partial) keyword string:
             .range(KString::from_ref(&keyword)..)
+            // Only keep keywords that may be suggested (see `suggestible`):
+            .filter(|(keyword, keys)| self.suggestible(keyword, keys, filter))
             // `range` returns a key-value pair. We're autocompleting the
             // key (keyword), so discard the value (record key):
             .map(|(key, _value)| key)
@@ -125,15 +143,39 @@ impl<K: Hash + Ord> SearchIndex<K> {
             // Collect all keyword autocompletions into a `Vec`:
             .collect();
 
-        // If `eddie` fuzzy matching enabled, examine the resulting
+        // Include any compacted whole-string keywords:
+        self.merge_whole_strings(
+            &mut autocomplete_options,
+            &keyword,
+            maximum_autocomplete_options,
+            &|_whole_string, key| filter(key),
+        ); // merge_whole_strings
+
+        // If enabled, fill any remaining slots with whole-string keywords that
+        // contain the keyword as an interior token:
+        if self.interior_autocomplete {
+            let remaining = maximum_autocomplete_options.saturating_sub(autocomplete_options.len());
+            let interior_keywords: Vec<&KString> = self.interior_keywords(&keyword)
+                .into_iter()
+                .filter(|(interior, _keys)| !autocomplete_options.contains(interior))
+                .filter(|(interior, keys)| self.suggestible_entry(interior, *keys, filter))
+                .take(remaining)
+                .map(|(interior, _keys)| interior)
+                .collect();
+            autocomplete_options.extend(interior_keywords);
+        } // if
+
+        // If `eddie` fuzzy matching is used, examine the resulting
         // auto-complete options before returning them:
         #[cfg(feature = "eddie")]
-        if autocomplete_options.is_empty() {
+        if autocomplete_options.is_empty() && self.uses_eddie() {
             // No autocomplete options were found for the user's last
             // (partial) keyword. Attempt to use fuzzy string search to find
             // other autocomplete options:
-            self.eddie_global_autocomplete(&keyword)
+            return self.eddie_global_autocomplete(&keyword)
                 .into_iter()
+                // Only keep keywords that may be suggested (see `suggestible`):
+                .filter(|(keyword, keys)| self.suggestible(keyword, keys, filter))
                 // Only return `maximum_autocomplete_options` number of
                 // keywords:
                 .take(*maximum_autocomplete_options)
@@ -143,20 +185,19 @@ impl<K: Hash + Ord> SearchIndex<K> {
                 .map(|(keyword, _keys)| keyword.as_str())
                 // Collect all keyword autocompletions into a `Vec`:
                 .collect()
-        } else {
-            // There were some matches. Return the results without processing:
-            autocomplete_options.into_iter().map(|kstring| kstring.as_str()).collect()
         } // if
 
-        // If `strsim` fuzzy matching enabled, examine the resulting
+        // If `strsim` fuzzy matching is used, examine the resulting
         // auto-complete options before returning them:
-        #[cfg(all(feature = "strsim", not(feature = "eddie")))]
-        if autocomplete_options.is_empty() {
+        #[cfg(feature = "strsim")]
+        if autocomplete_options.is_empty() && self.uses_strsim() {
             // No autocomplete options were found for the user's last
             // (partial) keyword. Attempt to use fuzzy string search to find
             // other autocomplete options:
-            self.strsim_global_autocomplete(&keyword)
+            return self.strsim_global_autocomplete(&keyword)
                 .into_iter()
+                // Only keep keywords that may be suggested (see `suggestible`):
+                .filter(|(keyword, keys)| self.suggestible(keyword, keys, filter))
                 // Only return `maximum_autocomplete_options` number of
                 // keywords:
                 .take(*maximum_autocomplete_options)
@@ -166,14 +207,10 @@ impl<K: Hash + Ord> SearchIndex<K> {
                 .map(|(keyword, _keys)| keyword.as_str())
                 // Collect all keyword autocompletions into a `Vec`:
                 .collect()
-        } else {
-            // There were some matches. Return the results without processing:
-            autocomplete_options.into_iter().map(|kstring| kstring.as_str()).collect()
         } // if
 
-        // If fuzzy string searching disabled, return the resulting
-        // auto-complete options without further processing:
-        #[cfg(not(any(feature = "strsim", feature = "eddie")))]
+        // There were some matches, or fuzzy string searching is disabled.
+        // Return the results without processing:
         autocomplete_options.into_iter().map(|kstring| kstring.as_str()).collect()
 
     } // fn
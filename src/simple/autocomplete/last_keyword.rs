@@ -0,0 +1,62 @@
+use crate::simple::SearchIndex;
+use std::{cmp::Ord, hash::Hash};
+
+// -----------------------------------------------------------------------------
+
+impl<K: Hash + Ord> SearchIndex<K> {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns only the completions for the last (partial) keyword of the
+    /// search string, using the index settings. The preceding keywords aren't
+    /// repeated in the results. This is useful for rendering inline "ghost
+    /// text" after the user's original, untouched input.
+    ///
+    /// Results are in the same order as [`autocomplete`].
+    ///
+    /// [`autocomplete`]: struct.SearchIndex.html#method.autocomplete
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # use indicium::simple::SearchIndex;
+    /// # use pretty_assertions::assert_eq;
+    /// #
+    /// let mut search_index: SearchIndex<usize> = SearchIndex::default();
+    /// search_index.insert(&0, &"Last male member of the royal house of Cerdic");
+    ///
+    /// assert_eq!(
+    ///     search_index.autocomplete("Royal house c"),
+    ///     vec!["royal house cerdic".to_string()]
+    /// );
+    ///
+    /// assert_eq!(
+    ///     search_index.autocomplete_last_keyword("Royal house c"),
+    ///     vec!["cerdic".to_string()]
+    /// );
+    /// ```
+
+    #[tracing::instrument(level = "trace", name = "last keyword autocomplete", skip(self))]
+    pub fn autocomplete_last_keyword(&self, string: &str) -> Vec<String> {
+
+        // Autocompleted search strings start with the preceding keywords
+        // joined by spaces, or the user's original text if the
+        // `preserve_query_prefix` setting is enabled:
+        let prefix: String = match self.preserve_query_prefix {
+            true => self.original_query_prefix(string).to_string(),
+            false => self.normalized_query_prefix(string),
+        }; // match
+
+        // Strip the preceding keywords from each autocompleted search string.
+        // `Keyword` autocompletions don't repeat them, so they are kept as-is:
+        self.autocomplete(string)
+            .into_iter()
+            .map(|autocompletion| match autocompletion.strip_prefix(&prefix) {
+                Some(last_keyword) => last_keyword.to_string(),
+                None => autocompletion,
+            }) // map
+            .collect()
+
+    } // fn
+
+} // impl
\ No newline at end of file
@@ -1,6 +1,10 @@
+mod at_cursor;
 mod context;
 mod global;
+mod hybrid;
 mod keyword;
+mod last_keyword;
+mod query_prefix;
 
 // -----------------------------------------------------------------------------
 
@@ -92,11 +96,27 @@ impl<K: Hash + Ord> SearchIndex<K> {
     #[tracing::instrument(level = "trace", name = "autocomplete", skip(self))]
     pub fn autocomplete(&self, string: &str) -> Vec<String> {
 
+        // Start timing the operation for metrics:
+        #[cfg(feature = "metrics")]
+        let start = std::time::Instant::now();
+
+        // Return the precomputed autocompletion options for hot prefixes. They
+        // still count as an autocompletion:
+        let warm_cache_key: String = self.warm_cache_key(string);
+        if let Some(autocomplete_options) = self.warm_cache.get(&warm_cache_key) {
+            #[cfg(feature = "metrics")]
+            crate::simple::internal::metrics::record_autocomplete(&self.autocomplete_type, start);
+            self.runtime_counters.record_autocomplete();
+            return self.splice_query_prefix(string, autocomplete_options)
+        } // if
+
         let autocomplete_options: Vec<String> = match &self.autocomplete_type {
             AutocompleteType::Context =>
                 self.autocomplete_context(&self.maximum_autocomplete_options, string),
             AutocompleteType::Global =>
                 self.autocomplete_global(&self.maximum_autocomplete_options, string),
+            AutocompleteType::Hybrid =>
+                self.autocomplete_hybrid(&self.maximum_autocomplete_options, string).1,
             AutocompleteType::Keyword =>
                 self.autocomplete_keyword(&self.maximum_autocomplete_options, string)
                     .into_iter()
@@ -104,6 +124,13 @@ impl<K: Hash + Ord> SearchIndex<K> {
                     .collect(),
         }; // match
 
+        // Record the operation for metrics:
+        #[cfg(feature = "metrics")]
+        crate::simple::internal::metrics::record_autocomplete(&self.autocomplete_type, start);
+
+        // Count the autocompletion (see `runtime_stats`):
+        self.runtime_counters.record_autocomplete();
+
         // For debug builds:
         #[cfg(debug_assertions)]
         tracing::debug!(
@@ -112,7 +139,17 @@ impl<K: Hash + Ord> SearchIndex<K> {
             string,
         ); // debug!
 
-        autocomplete_options
+        // Place any pinned suggestions first:
+        let autocomplete_options: Vec<String> = self.merge_pinned_suggestions(
+            &self.maximum_autocomplete_options,
+            string,
+            autocomplete_options,
+        );
+
+        // Remember the autocompletion options if this is a hot prefix:
+        self.warm_cache.insert(&warm_cache_key, &autocomplete_options);
+
+        self.splice_query_prefix(string, autocomplete_options)
 
     } // fn
 
@@ -208,11 +245,17 @@ impl<K: Hash + Ord> SearchIndex<K> {
         string: &str,
     ) -> Vec<String> {
 
+        // Start timing the operation for metrics:
+        #[cfg(feature = "metrics")]
+        let start = std::time::Instant::now();
+
         let autocomplete_options: Vec<String> = match autocomplete_type {
             AutocompleteType::Context =>
                 self.autocomplete_context(&self.maximum_autocomplete_options, string),
             AutocompleteType::Global =>
                 self.autocomplete_global(&self.maximum_autocomplete_options, string),
+            AutocompleteType::Hybrid =>
+                self.autocomplete_hybrid(&self.maximum_autocomplete_options, string).1,
             AutocompleteType::Keyword =>
                 self.autocomplete_keyword(&self.maximum_autocomplete_options, string)
                     .into_iter()
@@ -220,6 +263,13 @@ impl<K: Hash + Ord> SearchIndex<K> {
                     .collect(),
         }; // match
 
+        // Record the operation for metrics:
+        #[cfg(feature = "metrics")]
+        crate::simple::internal::metrics::record_autocomplete(autocomplete_type, start);
+
+        // Count the autocompletion (see `runtime_stats`):
+        self.runtime_counters.record_autocomplete();
+
         // For debug builds:
         #[cfg(debug_assertions)]
         tracing::debug!(
@@ -228,7 +278,14 @@ impl<K: Hash + Ord> SearchIndex<K> {
             string,
         ); // debug!
 
-        autocomplete_options
+        // Place any pinned suggestions first:
+        let autocomplete_options: Vec<String> = self.merge_pinned_suggestions(
+            &self.maximum_autocomplete_options,
+            string,
+            autocomplete_options,
+        );
+
+        self.splice_query_prefix(string, autocomplete_options)
 
     } // fn
 
@@ -320,6 +377,32 @@ impl<K: Hash + Ord> SearchIndex<K> {
     ///     ]
     /// );
     /// ```
+    ///
+    /// Different surfaces can request a different number of options from the
+    /// same search index, such as a short mobile dropdown and a longer desktop
+    /// dropdown. Pass the index's own `AutocompleteType` to only override the
+    /// limit:
+    ///
+    /// ```rust
+    /// # use indicium::simple::SearchIndex;
+    /// # use pretty_assertions::assert_eq;
+    /// #
+    /// let mut search_index: SearchIndex<usize> = SearchIndex::default();
+    /// search_index.insert(&0, &"Harold Godwinson");
+    /// search_index.insert(&1, &"Harold Hardrada");
+    /// search_index.insert(&2, &"Harthacnut");
+    ///
+    /// let autocomplete_type = search_index.query_config().autocomplete_type;
+    ///
+    /// // Mobile:
+    /// assert_eq!(
+    ///     search_index.autocomplete_with(&autocomplete_type, &1, "har"),
+    ///     vec!["hardrada".to_string()]
+    /// );
+    ///
+    /// // Desktop:
+    /// assert_eq!(search_index.autocomplete_with(&autocomplete_type, &10, "har").len(), 5);
+    /// ```
 
     #[tracing::instrument(level = "trace", name = "autocomplete", skip(self))]
     pub fn autocomplete_with(
@@ -329,11 +412,17 @@ impl<K: Hash + Ord> SearchIndex<K> {
         string: &str,
     ) -> Vec<String> {
 
+        // Start timing the operation for metrics:
+        #[cfg(feature = "metrics")]
+        let start = std::time::Instant::now();
+
         let autocomplete_options: Vec<String> = match autocomplete_type {
             AutocompleteType::Context =>
                 self.autocomplete_context(maximum_autocomplete_options, string),
             AutocompleteType::Global =>
                 self.autocomplete_global(maximum_autocomplete_options, string),
+            AutocompleteType::Hybrid =>
+                self.autocomplete_hybrid(maximum_autocomplete_options, string).1,
             AutocompleteType::Keyword =>
                 self.autocomplete_keyword(maximum_autocomplete_options, string)
                     .into_iter()
@@ -341,6 +430,13 @@ impl<K: Hash + Ord> SearchIndex<K> {
                     .collect(),
         }; // match
 
+        // Record the operation for metrics:
+        #[cfg(feature = "metrics")]
+        crate::simple::internal::metrics::record_autocomplete(autocomplete_type, start);
+
+        // Count the autocompletion (see `runtime_stats`):
+        self.runtime_counters.record_autocomplete();
+
         // For debug builds:
         #[cfg(debug_assertions)]
         tracing::debug!(
@@ -349,7 +445,96 @@ impl<K: Hash + Ord> SearchIndex<K> {
             string,
         ); // debug!
 
-        autocomplete_options
+        // Place any pinned suggestions first:
+        let autocomplete_options: Vec<String> = self.merge_pinned_suggestions(
+            maximum_autocomplete_options,
+            string,
+            autocomplete_options,
+        );
+
+        self.splice_query_prefix(string, autocomplete_options)
+
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns autocompletion options using the index settings, only
+    /// suggesting keywords that have at least one key passing the filter. This
+    /// can be used to restrict suggestions to records that the current user
+    /// may see, so that the existence of hidden records isn't revealed through
+    /// autocompletion.
+    ///
+    /// Filtered autocompletions aren't stored in the warm cache, since they
+    /// depend on the filter.
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # use indicium::simple::SearchIndex;
+    /// # use pretty_assertions::assert_eq;
+    /// #
+    /// let mut search_index: SearchIndex<usize> = SearchIndex::default();
+    /// search_index.insert(&0, &"Public roadmap");
+    /// search_index.insert(&1, &"Private reorganization");
+    ///
+    /// let visible = |key: &usize| *key != 1;
+    ///
+    /// assert_eq!(
+    ///     search_index.autocomplete_filtered("r", visible),
+    ///     vec!["roadmap".to_string()]
+    /// );
+    /// ```
+
+    #[tracing::instrument(level = "trace", name = "filtered autocomplete", skip(self, filter))]
+    pub fn autocomplete_filtered(
+        &self,
+        string: &str,
+        filter: impl Fn(&K) -> bool,
+    ) -> Vec<String> {
+
+        // Start timing the operation for metrics:
+        #[cfg(feature = "metrics")]
+        let start = std::time::Instant::now();
+
+        let maximum_autocomplete_options = &self.maximum_autocomplete_options;
+
+        let autocomplete_options: Vec<String> = match &self.autocomplete_type {
+            AutocompleteType::Context =>
+                self.autocomplete_context_filtered(maximum_autocomplete_options, string, &filter),
+            AutocompleteType::Global =>
+                self.autocomplete_global_filtered(maximum_autocomplete_options, string, &filter),
+            AutocompleteType::Hybrid =>
+                self.autocomplete_hybrid_filtered(maximum_autocomplete_options, string, &filter).1,
+            AutocompleteType::Keyword =>
+                self.autocomplete_keyword_filtered(maximum_autocomplete_options, string, &filter)
+                    .into_iter()
+                    .map(|str| str.to_string())
+                    .collect(),
+        }; // match
+
+        // Record the operation for metrics:
+        #[cfg(feature = "metrics")]
+        crate::simple::internal::metrics::record_autocomplete(&self.autocomplete_type, start);
+
+        // Count the autocompletion (see `runtime_stats`):
+        self.runtime_counters.record_autocomplete();
+
+        // For debug builds:
+        #[cfg(debug_assertions)]
+        tracing::debug!(
+            "{} filtered autocomplete options for \"{}\".",
+            autocomplete_options.len(),
+            string,
+        ); // debug!
+
+        // Place any pinned suggestions first:
+        let autocomplete_options: Vec<String> = self.merge_pinned_suggestions(
+            maximum_autocomplete_options,
+            string,
+            autocomplete_options,
+        );
+
+        self.splice_query_prefix(string, autocomplete_options)
 
     } // fn
 
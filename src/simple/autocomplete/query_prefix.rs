@@ -0,0 +1,92 @@
+use crate::simple::internal::string_keywords::SplitContext;
+use crate::simple::SearchIndex;
+use kstring::KString;
+use std::{cmp::Ord, hash::Hash};
+
+// -----------------------------------------------------------------------------
+
+impl<K: Hash + Ord> SearchIndex<K> {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns the normalized keywords that precede the last (partial) keyword
+    /// of the search string, joined by spaces. This is how autocompleted search
+    /// strings begin.
+
+    pub(crate) fn normalized_query_prefix(&self, string: &str) -> String {
+
+        // Split the search string into keywords the same way autocompletion
+        // does, and drop the last (partial) keyword:
+        let mut keywords: Vec<KString> = self.string_keywords(
+            string,
+            SplitContext::Searching,
+        );
+        keywords.pop();
+
+        match keywords.is_empty() {
+            true => String::new(),
+            false => keywords.join(" ") + " ",
+        } // match
+
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns the user's original, untouched text that precedes the last
+    /// (partial) keyword of the search string.
+
+    pub(crate) fn original_query_prefix<'s>(&self, string: &'s str) -> &'s str {
+
+        // Without a split pattern, the whole string is the last keyword:
+        let Some(split_pattern) = &self.split_pattern else { return "" };
+
+        // Trailing separators don't start a new keyword, so ignore them when
+        // looking for where the last keyword begins:
+        let string_without_separators: &str =
+            string.trim_end_matches(split_pattern.as_slice());
+
+        match string_without_separators.rfind(split_pattern.as_slice()) {
+            Some(index) => {
+                // Include the separator in the prefix:
+                let separator_length: usize = string_without_separators[index..]
+                    .chars()
+                    .next()
+                    .map_or(0, char::len_utf8);
+                &string[..index + separator_length]
+            },
+            None => "",
+        } // match
+
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// If the `preserve_query_prefix` setting is enabled, replaces the
+    /// normalized keywords in front of each autocompleted search string with
+    /// the user's original text. Autocompletions that don't begin with the
+    /// normalized keywords (such as `Keyword` autocompletions) are unchanged.
+
+    pub(crate) fn splice_query_prefix(
+        &self,
+        string: &str,
+        autocomplete_options: Vec<String>,
+    ) -> Vec<String> {
+
+        if !self.preserve_query_prefix {
+            return autocomplete_options
+        } // if
+
+        let normalized_prefix: String = self.normalized_query_prefix(string);
+        let original_prefix: &str = self.original_query_prefix(string);
+
+        autocomplete_options
+            .into_iter()
+            .map(|autocompletion| match autocompletion.strip_prefix(&normalized_prefix) {
+                Some(last_keyword) => original_prefix.to_string() + last_keyword,
+                None => autocompletion,
+            }) // map
+            .collect()
+
+    } // fn
+
+} // impl
\ No newline at end of file
@@ -16,6 +16,7 @@
 /// [`SearchIndex::new()`]: struct.SearchIndex.html#method.new
 
 #[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub enum AutocompleteType {
     /// The search string may contain multiple keywords and the last (partial)
     /// keyword will be autocompleted. The last keyword in the search string
@@ -32,6 +33,13 @@ pub enum AutocompleteType {
     ///
     /// [`profile`]: struct.SearchIndex.html#method.profile
     Global,
+    /// Uses `Context` autocompletion, but falls back to `Global`
+    /// autocompletion when the preceding keywords are too restrictive and no
+    /// options are found. See also: the [`autocomplete_hybrid`] method, which
+    /// reports which type produced the options.
+    ///
+    /// [`autocomplete_hybrid`]: struct.SearchIndex.html#method.autocomplete_hybrid
+    Hybrid,
     /// The search string is expected to only contain a single keyword. This is
     /// the lightest and fastest autocompletion type. It is good for compact
     /// interfaces or where records are very simple.
@@ -0,0 +1,140 @@
+use crate::simple::{indexable::Indexable, search_index::SearchIndex, CancelToken, Cancelled};
+use std::time::{Duration, Instant};
+use std::{clone::Clone, cmp::Ord};
+
+// -----------------------------------------------------------------------------
+//
+/// Progress of a long-running index build. Reported by
+/// [`SearchIndex::build_from_iter`] after every record.
+///
+/// [`SearchIndex::build_from_iter`]: struct.SearchIndex.html#method.build_from_iter
+
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct BuildProgress {
+    /// The number of records inserted so far.
+    pub records: usize,
+    /// The number of keywords added to the search index so far.
+    pub keywords: usize,
+    /// The time elapsed since the build started.
+    pub elapsed: Duration,
+} // BuildProgress
+
+// -----------------------------------------------------------------------------
+
+impl<K: Clone + Ord> SearchIndex<K> {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Inserts every key-value pair from an iterator into the search index,
+    /// reporting the build's progress after every record. This is useful for
+    /// rendering progress bars, or enforcing timeouts, during long index
+    /// builds.
+    ///
+    /// Records are streamed from the iterator, so the whole collection doesn't
+    /// need to be in memory at once. Start from `SearchIndex::default()` or a
+    /// `SearchIndexBuilder` to choose the search index's settings.
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # use indicium::simple::{BuildProgress, SearchIndex};
+    /// # use pretty_assertions::assert_eq;
+    /// #
+    /// let kings = vec!["William the Conqueror", "William Rufus", "Henry Beauclerc"];
+    ///
+    /// let mut search_index: SearchIndex<usize> = SearchIndex::default();
+    /// let mut last_progress = BuildProgress::default();
+    ///
+    /// search_index.build_from_iter(
+    ///     kings.into_iter().enumerate(),
+    ///     |progress| last_progress = progress,
+    /// );
+    ///
+    /// assert_eq!(last_progress.records, 3);
+    /// assert_eq!(search_index.search("william"), vec![&0, &1]);
+    /// ```
+
+    pub fn build_from_iter<I, V>(
+        &mut self,
+        records: I,
+        progress: impl FnMut(BuildProgress),
+    )
+    where
+        I: IntoIterator<Item = (K, V)>,
+        V: Indexable,
+    {
+        // A token that is never cancelled can't fail:
+        let _ = self.build_from_iter_cancellable(records, progress, &CancelToken::new());
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// This is `build_from_iter` with a [`CancelToken`]. The token is checked
+    /// before every record. If it has been cancelled, the build stops and
+    /// `Err(Cancelled)` is returned. The records inserted before the
+    /// cancellation remain in the search index.
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # use indicium::simple::{CancelToken, Cancelled, SearchIndex};
+    /// # use pretty_assertions::assert_eq;
+    /// #
+    /// let kings = vec!["William the Conqueror", "William Rufus", "Henry Beauclerc"];
+    ///
+    /// let mut search_index: SearchIndex<usize> = SearchIndex::default();
+    /// let token = CancelToken::new();
+    ///
+    /// let result = search_index.build_from_iter_cancellable(
+    ///     kings.into_iter().enumerate(),
+    ///     |progress| if progress.records == 2 { token.cancel() },
+    ///     &token,
+    /// );
+    ///
+    /// assert_eq!(result, Err(Cancelled));
+    /// assert_eq!(search_index.search("william"), vec![&0, &1]);
+    /// assert_eq!(search_index.search("henry"), Vec::<&usize>::new());
+    /// ```
+    ///
+    /// [`CancelToken`]: struct.CancelToken.html
+
+    #[tracing::instrument(level = "trace", name = "build from iterator", skip(self, records, progress, cancel_token))]
+    pub fn build_from_iter_cancellable<I, V>(
+        &mut self,
+        records: I,
+        mut progress: impl FnMut(BuildProgress),
+        cancel_token: &CancelToken,
+    ) -> Result<(), Cancelled>
+    where
+        I: IntoIterator<Item = (K, V)>,
+        V: Indexable,
+    {
+
+        let start = Instant::now();
+        let initial_keywords: usize = self.b_tree_map.len();
+
+        for (index, (key, value)) in records.into_iter().enumerate() {
+            // Stop if the caller has cancelled the build:
+            if cancel_token.is_cancelled() { return Err(Cancelled) }
+
+            self.insert(&key, &value);
+
+            progress(BuildProgress {
+                records: index + 1,
+                keywords: self.b_tree_map.len().saturating_sub(initial_keywords),
+                elapsed: start.elapsed(),
+            }); // progress
+        } // for
+
+        // Record when the search index was built (see `runtime_stats`):
+        self.runtime_counters.record_rebuild(self.clock.now());
+
+        // Benchmark the fuzzy matching crates on the new keywords, if
+        // requested (see `fuzzy_backend`):
+        self.choose_fuzzy_backend();
+
+        Ok(())
+
+    } // fn
+
+} // impl
\ No newline at end of file
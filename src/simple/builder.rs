@@ -1,6 +1,15 @@
-use crate::simple::{AutocompleteType, EddieMetric, SearchIndex, SearchType, StrsimMetric};
+#[cfg(feature = "geo")]
+use crate::simple::internal::GeoIndex;
+use crate::simple::changelog::Changelog;
+use crate::simple::synonyms::SynonymMap;
+use crate::simple::internal::{Clock, FieldKeywords, FrontMap, InteriorTokens, KeywordCache, KeywordEnricher, KeywordUsage, Permuterm, RuntimeCounters, SaturationHook, SortedPostings, SuggestionFilter, WarmCache};
+use crate::simple::{AutocompleteType, CharFilter, DuplicateKeyPolicy, EddieMetric, Elision, Evictions, ExcludeKeywords, FuzzyBackendChoice, KeywordCapPolicy, Preset, QueryLimitPolicy, SearchIndex, SearchIndexConfig, SearchType, StrsimMetric, Synonyms, Transliteration};
+use crate::Error;
 use kstring::KString;
 use std::collections::{BTreeMap, BTreeSet};
+use std::path::Path;
+use std::sync::Arc;
+use std::time::SystemTime;
 use std::{clone::Clone, cmp::Ord};
 
 // -----------------------------------------------------------------------------
@@ -12,13 +21,42 @@ use std::{clone::Clone, cmp::Ord};
 ///
 /// If you're in a hurry, you can instantiate your search index with
 /// `SearchIndex::default()` instead.
+///
+/// Each setter takes the builder by value and returns it, so the builder can
+/// be configured in a single expression. A partially configured builder can be
+/// cloned and reused as a template for several search indexes:
+///
+/// ```rust
+/// # use indicium::simple::{SearchIndex, SearchIndexBuilder, SearchType};
+/// #
+/// let template: SearchIndexBuilder<usize> = SearchIndexBuilder::default()
+///     .case_sensitive(false)
+///     .max_search_results(50);
+///
+/// let and_index: SearchIndex<usize> = template.clone()
+///     .search_type(SearchType::And)
+///     .build();
+///
+/// let or_index: SearchIndex<usize> = template
+///     .search_type(SearchType::Or)
+///     .build();
+///
+/// assert_eq!(and_index.config().maximum_search_results, 50);
+/// assert_eq!(or_index.config().search_type, SearchType::Or);
+/// ```
 
+#[derive(Clone)]
 pub struct SearchIndexBuilder<K> {
     b_tree_map: BTreeMap<KString, BTreeSet<K>>,
+    whole_strings: BTreeMap<KString, K>,
+    whole_string_keywords: BTreeSet<KString>,
+    field_keywords: BTreeMap<K, FieldKeywords>,
+    pinned_suggestions: BTreeMap<KString, Vec<KString>>,
     search_type: SearchType,
     autocomplete_type: AutocompleteType,
     strsim_metric: Option<StrsimMetric>,
     eddie_metric: Option<EddieMetric>,
+    fuzzy_backend: FuzzyBackendChoice,
     fuzzy_length: usize,
     fuzzy_minimum_score: f64,
     split_pattern: Option<Vec<char>>,
@@ -31,6 +69,42 @@ pub struct SearchIndexBuilder<K> {
     maximum_search_results: usize,
     maximum_keys_per_keyword: usize,
     dump_keyword: Option<KString>,
+    query_cache_size: usize,
+    reverse_map: bool,
+    maximum_keywords: Option<usize>,
+    interior_autocomplete: bool,
+    posting_timestamps: bool,
+    changelog: bool,
+    applied_sequence: u64,
+    grapheme_lengths: bool,
+    front_map: bool,
+    permuterm: bool,
+    sorted_postings: bool,
+    minimum_keys_for_autocomplete: usize,
+    maximum_keywords_per_record: Option<usize>,
+    keyword_cap_policy: KeywordCapPolicy,
+    maximum_query_length: Option<usize>,
+    maximum_query_keywords: Option<usize>,
+    maximum_fuzzy_candidates: Option<usize>,
+    adaptive_fuzzy_length: Option<usize>,
+    fuzzy_first_letter_fallback: bool,
+    query_limit_policy: QueryLimitPolicy,
+    duplicate_key_policy: DuplicateKeyPolicy,
+    context_window: Option<usize>,
+    preserve_query_prefix: bool,
+    suggestion_blocklist: Option<Vec<KString>>,
+    shingles: bool,
+    char_filter: Option<CharFilter>,
+    elision: Option<Elision>,
+    plural_variants: bool,
+    synonyms: Option<Synonyms>,
+    transliteration: Option<Transliteration>,
+    clock: Clock,
+    keyword_enricher: KeywordEnricher,
+    saturation_hook: SaturationHook,
+    suggestion_filter: SuggestionFilter,
+    #[cfg(feature = "geo")]
+    geo_index: GeoIndex<K>,
 } // SearchIndexBuilder
 
 // -----------------------------------------------------------------------------
@@ -39,11 +113,16 @@ impl<K: Clone + Ord> From<SearchIndex<K>> for SearchIndexBuilder<K> {
     /// Convert to `SearchIndexBuilder<K>` struct from `SearchIndex<K>` struct.
     fn from(search_index: SearchIndex<K>) -> Self {
         SearchIndexBuilder {
-            b_tree_map: search_index.b_tree_map,
+            b_tree_map: Arc::unwrap_or_clone(search_index.b_tree_map),
+            whole_strings: Arc::unwrap_or_clone(search_index.whole_strings),
+            whole_string_keywords: search_index.whole_string_keywords,
+            field_keywords: search_index.field_keywords,
+            pinned_suggestions: search_index.pinned_suggestions,
             search_type: search_index.search_type,
             autocomplete_type: search_index.autocomplete_type,
             strsim_metric: search_index.strsim_metric,
             eddie_metric: search_index.eddie_metric,
+            fuzzy_backend: search_index.fuzzy_backend,
             fuzzy_length: search_index.fuzzy_length,
             fuzzy_minimum_score: search_index.fuzzy_minimum_score,
             split_pattern: search_index.split_pattern,
@@ -56,6 +135,42 @@ impl<K: Clone + Ord> From<SearchIndex<K>> for SearchIndexBuilder<K> {
             maximum_search_results: search_index.maximum_search_results,
             maximum_keys_per_keyword: search_index.maximum_keys_per_keyword,
             dump_keyword: search_index.dump_keyword,
+            query_cache_size: search_index.keyword_cache.capacity(),
+            reverse_map: search_index.reverse_map.is_some(),
+            maximum_keywords: search_index.maximum_keywords,
+            interior_autocomplete: search_index.interior_autocomplete,
+            posting_timestamps: search_index.posting_times.is_some(),
+            changelog: search_index.changelog.is_some(),
+            applied_sequence: search_index.applied_sequence,
+            grapheme_lengths: search_index.grapheme_lengths,
+            front_map: search_index.front_map.is_some(),
+            permuterm: search_index.permuterm.is_some(),
+            sorted_postings: search_index.sorted_postings.is_some(),
+            minimum_keys_for_autocomplete: search_index.minimum_keys_for_autocomplete,
+            maximum_keywords_per_record: search_index.maximum_keywords_per_record,
+            keyword_cap_policy: search_index.keyword_cap_policy,
+            maximum_query_length: search_index.maximum_query_length,
+            maximum_query_keywords: search_index.maximum_query_keywords,
+            maximum_fuzzy_candidates: search_index.maximum_fuzzy_candidates,
+            adaptive_fuzzy_length: search_index.adaptive_fuzzy_length,
+            fuzzy_first_letter_fallback: search_index.fuzzy_first_letter_fallback,
+            query_limit_policy: search_index.query_limit_policy,
+            duplicate_key_policy: search_index.duplicate_key_policy,
+            context_window: search_index.context_window,
+            preserve_query_prefix: search_index.preserve_query_prefix,
+            suggestion_blocklist: search_index.suggestion_blocklist,
+            shingles: search_index.shingles,
+            char_filter: search_index.char_filter,
+            elision: search_index.elision,
+            plural_variants: search_index.plural_variants,
+            synonyms: search_index.synonyms,
+            transliteration: search_index.transliteration,
+            clock: search_index.clock,
+            keyword_enricher: search_index.keyword_enricher,
+            saturation_hook: search_index.saturation_hook,
+            suggestion_filter: search_index.suggestion_filter,
+            #[cfg(feature = "geo")]
+            geo_index: search_index.geo_index,
         } // SearchIndexBuilder
     } // fn
 } // impl
@@ -65,12 +180,88 @@ impl<K: Clone + Ord> From<SearchIndex<K>> for SearchIndexBuilder<K> {
 impl<K: Clone + Ord> From<SearchIndexBuilder<K>> for SearchIndex<K> {
     /// Convert to `SearchIndex<K>` struct from `SearchIndexBuilder<K>` struct.
     fn from(search_index: SearchIndexBuilder<K>) -> Self {
-        SearchIndex {
-            b_tree_map: search_index.b_tree_map,
+        // Every keyword and its keys, including compacted whole-string
+        // keywords but not the `dump_keyword`:
+        let postings = || search_index.b_tree_map
+            .iter()
+            .filter(|(keyword, _keys)| search_index.dump_keyword.as_ref() != Some(keyword))
+            .flat_map(|(keyword, keys)| keys.iter().map(move |key| (keyword, key)))
+            .chain(search_index.whole_strings.iter());
+
+        // If requested, build the reverse map from any existing keywords. The
+        // `Upsert` duplicate key policy always requires it:
+        let reverse_map = (
+            search_index.reverse_map ||
+                search_index.duplicate_key_policy == DuplicateKeyPolicy::Upsert
+        ).then(|| {
+            let mut reverse_map: BTreeMap<K, BTreeSet<KString>> = BTreeMap::new();
+            postings().for_each(|(keyword, key)| {
+                reverse_map.entry(key.clone()).or_default().insert(keyword.clone());
+            }); // for_each
+            reverse_map
+        }); // then
+
+        // If requested, timestamp any existing postings with the current time:
+        let posting_times = search_index.posting_timestamps.then(|| {
+            let now = search_index.clock.now();
+            let mut posting_times: BTreeMap<K, BTreeMap<KString, SystemTime>> = BTreeMap::new();
+            postings().for_each(|(keyword, key)| {
+                posting_times.entry(key.clone()).or_default().insert(keyword.clone(), now);
+            }); // for_each
+            posting_times
+        }); // then
+
+        // If requested, build the front map from any existing keywords:
+        let front_map = search_index.front_map
+            .then(|| FrontMap::from_b_tree_map(&search_index.b_tree_map, search_index.dump_keyword.as_ref()));
+
+        // If requested, build the permuterm index from any existing keywords:
+        let permuterm = search_index.permuterm
+            .then(|| Permuterm::from_b_tree_map(&search_index.b_tree_map));
+
+        // If requested, build the sorted postings from any existing keywords:
+        let sorted_postings = search_index.sorted_postings
+            .then(|| SortedPostings::from_b_tree_map(&search_index.b_tree_map, search_index.dump_keyword.as_ref()));
+
+        // If interior autocompletion is enabled, build the interior tokens
+        // from any existing keywords, including compacted whole strings:
+        let interior_tokens = match (search_index.interior_autocomplete, &search_index.split_pattern) {
+            (true, Some(split_pattern)) if !split_pattern.is_empty() => Some(InteriorTokens::from_keywords(
+                split_pattern,
+                search_index.b_tree_map.keys().chain(search_index.whole_strings.keys()),
+            )), // Some
+            _ => None,
+        }; // match
+
+        // Normalize the synonym rules once, so that applying them to a keyword
+        // is a single lookup:
+        let synonym_map = search_index.synonyms
+            .as_ref()
+            .map(|synonyms| SynonymMap::new(synonyms, search_index.case_sensitive))
+            .unwrap_or_default();
+
+        // Keywords are lowercased unless the search index is case sensitive,
+        // so excluded keywords (such as those read from a stop words file) are
+        // lowercased to match:
+        let exclude_keywords = match search_index.case_sensitive {
+            true => search_index.exclude_keywords,
+            false => search_index.exclude_keywords.map(|exclude_keywords| {
+                let mut lowercased: Vec<KString> = Vec::with_capacity(exclude_keywords.len());
+                exclude_keywords
+                    .iter()
+                    .map(|keyword| KString::from(keyword.to_lowercase()))
+                    .for_each(|keyword| if !lowercased.contains(&keyword) { lowercased.push(keyword) });
+                lowercased
+            }), // map
+        }; // match
+
+        let mut built = SearchIndex {
+            b_tree_map: Arc::new(search_index.b_tree_map),
             search_type: search_index.search_type,
             autocomplete_type: search_index.autocomplete_type,
             strsim_metric: search_index.strsim_metric,
             eddie_metric: search_index.eddie_metric,
+            fuzzy_backend: search_index.fuzzy_backend,
             fuzzy_length: search_index.fuzzy_length,
             fuzzy_minimum_score: search_index.fuzzy_minimum_score,
             split_pattern: search_index.split_pattern,
@@ -78,11 +269,142 @@ impl<K: Clone + Ord> From<SearchIndexBuilder<K>> for SearchIndex<K> {
             minimum_keyword_length: search_index.minimum_keyword_length,
             maximum_keyword_length: search_index.maximum_keyword_length,
             maximum_string_length: search_index.maximum_string_length,
-            exclude_keywords: search_index.exclude_keywords,
+            exclude_keywords,
             maximum_autocomplete_options: search_index.maximum_autocomplete_options,
             maximum_search_results: search_index.maximum_search_results,
             maximum_keys_per_keyword: search_index.maximum_keys_per_keyword,
             dump_keyword: search_index.dump_keyword,
+            keyword_cache: KeywordCache::with_capacity(search_index.query_cache_size),
+            reverse_map,
+            warm_cache: WarmCache::default(),
+            pinned_suggestions: search_index.pinned_suggestions,
+            whole_strings: Arc::new(search_index.whole_strings),
+            whole_string_keywords: search_index.whole_string_keywords,
+            field_keywords: search_index.field_keywords,
+            maximum_keywords: search_index.maximum_keywords,
+            keyword_usage: KeywordUsage::default(),
+            evictions: Evictions::default(),
+            saturated_keywords: BTreeSet::new(),
+            saturation_hook: search_index.saturation_hook,
+            runtime_counters: RuntimeCounters::new(search_index.clock.now()),
+            suggestion_filter: search_index.suggestion_filter,
+            clock: search_index.clock,
+            keyword_enricher: search_index.keyword_enricher,
+            active_fuzzy_backend: FuzzyBackendChoice::default(),
+            interior_autocomplete: search_index.interior_autocomplete,
+            interior_tokens,
+            posting_times,
+            changelog: search_index.changelog.then(Changelog::default),
+            applied_sequence: search_index.applied_sequence,
+            grapheme_lengths: search_index.grapheme_lengths,
+            front_map,
+            permuterm,
+            sorted_postings,
+            minimum_keys_for_autocomplete: search_index.minimum_keys_for_autocomplete,
+            maximum_keywords_per_record: search_index.maximum_keywords_per_record,
+            keyword_cap_policy: search_index.keyword_cap_policy,
+            maximum_query_length: search_index.maximum_query_length,
+            maximum_query_keywords: search_index.maximum_query_keywords,
+            maximum_fuzzy_candidates: search_index.maximum_fuzzy_candidates,
+            adaptive_fuzzy_length: search_index.adaptive_fuzzy_length,
+            fuzzy_first_letter_fallback: search_index.fuzzy_first_letter_fallback,
+            query_limit_policy: search_index.query_limit_policy,
+            duplicate_key_policy: search_index.duplicate_key_policy,
+            context_window: search_index.context_window,
+            preserve_query_prefix: search_index.preserve_query_prefix,
+            suggestion_blocklist: search_index.suggestion_blocklist,
+            shingles: search_index.shingles,
+            char_filter: search_index.char_filter,
+            elision: search_index.elision,
+            plural_variants: search_index.plural_variants,
+            synonym_map,
+            synonyms: search_index.synonyms,
+            transliteration: search_index.transliteration,
+            #[cfg(feature = "geo")]
+            geo_index: search_index.geo_index,
+        }; // SearchIndex
+
+        // Choose the fuzzy matching crate, from any existing keywords if
+        // requested:
+        built.choose_fuzzy_backend();
+
+        built
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl<K: Clone + Ord> From<SearchIndexConfig> for SearchIndexBuilder<K> {
+    /// Convert to `SearchIndexBuilder<K>` struct from `SearchIndexConfig`
+    /// struct. The resulting builder has no keywords or keys.
+    fn from(config: SearchIndexConfig) -> Self {
+        SearchIndexBuilder {
+            b_tree_map: BTreeMap::new(),
+            whole_strings: BTreeMap::new(),
+            whole_string_keywords: BTreeSet::new(),
+            field_keywords: BTreeMap::new(),
+            pinned_suggestions: BTreeMap::new(),
+            search_type: config.search_type,
+            autocomplete_type: config.autocomplete_type,
+            strsim_metric: config.strsim_metric,
+            eddie_metric: config.eddie_metric,
+            fuzzy_backend: config.fuzzy_backend,
+            fuzzy_length: config.fuzzy_length,
+            fuzzy_minimum_score: config.fuzzy_minimum_score,
+            split_pattern: config.split_pattern,
+            case_sensitive: config.case_sensitive,
+            minimum_keyword_length: config.minimum_keyword_length,
+            maximum_keyword_length: config.maximum_keyword_length,
+            maximum_string_length: config.maximum_string_length,
+            exclude_keywords: config.exclude_keywords.map(|exclude_keywords| exclude_keywords
+                .into_iter()
+                .map(KString::from)
+                .collect()
+            ), // map
+            maximum_autocomplete_options: config.maximum_autocomplete_options,
+            maximum_search_results: config.maximum_search_results,
+            maximum_keys_per_keyword: config.maximum_keys_per_keyword,
+            dump_keyword: config.dump_keyword.map(KString::from),
+            query_cache_size: config.query_cache_size,
+            reverse_map: config.reverse_map,
+            maximum_keywords: config.maximum_keywords,
+            interior_autocomplete: config.interior_autocomplete,
+            posting_timestamps: config.posting_timestamps,
+            changelog: config.changelog,
+            applied_sequence: 0,
+            grapheme_lengths: config.grapheme_lengths,
+            front_map: config.front_map,
+            permuterm: config.permuterm,
+            sorted_postings: config.sorted_postings,
+            minimum_keys_for_autocomplete: config.minimum_keys_for_autocomplete,
+            maximum_keywords_per_record: config.maximum_keywords_per_record,
+            keyword_cap_policy: config.keyword_cap_policy,
+            maximum_query_length: config.maximum_query_length,
+            maximum_query_keywords: config.maximum_query_keywords,
+            maximum_fuzzy_candidates: config.maximum_fuzzy_candidates,
+            adaptive_fuzzy_length: config.adaptive_fuzzy_length,
+            fuzzy_first_letter_fallback: config.fuzzy_first_letter_fallback,
+            query_limit_policy: config.query_limit_policy,
+            duplicate_key_policy: config.duplicate_key_policy,
+            context_window: config.context_window,
+            preserve_query_prefix: config.preserve_query_prefix,
+            suggestion_blocklist: config.suggestion_blocklist.map(|suggestion_blocklist| suggestion_blocklist
+                .into_iter()
+                .map(KString::from)
+                .collect()
+            ), // map
+            shingles: config.shingles,
+            char_filter: config.char_filter,
+            elision: config.elision,
+            plural_variants: config.plural_variants,
+            synonyms: config.synonyms,
+            transliteration: config.transliteration,
+            clock: Clock::default(),
+            keyword_enricher: KeywordEnricher::default(),
+            saturation_hook: SaturationHook::default(),
+            suggestion_filter: SuggestionFilter::default(),
+            #[cfg(feature = "geo")]
+            geo_index: GeoIndex::default(),
         } // SearchIndexBuilder
     } // fn
 } // impl
@@ -154,6 +476,55 @@ impl<K: Clone + Ord> SearchIndexBuilder<K> {
         self
     } // fn
 
+    /// The crate used for fuzzy matching when both the `eddie` and `strsim`
+    /// features are enabled. `FuzzyBackendChoice::Auto` chooses a crate from
+    /// the number and length of the keywords when the search index is built
+    /// or loaded. See [`FuzzyBackendChoice`] for more information.
+    ///
+    /// **Default:** `FuzzyBackendChoice::Eddie`, or `FuzzyBackendChoice::Strsim`
+    /// if only the `strsim` feature is enabled.
+    ///
+    /// [`FuzzyBackendChoice`]: enum.FuzzyBackendChoice.html
+    #[cfg(any(feature = "eddie", feature = "strsim"))]
+    pub fn fuzzy_backend(mut self, fuzzy_backend: FuzzyBackendChoice) -> Self {
+        self.fuzzy_backend = fuzzy_backend;
+        self
+    } // fn
+
+    /// All of the fuzzy matching settings at once: `strsim_metric`,
+    /// `eddie_metric`, `fuzzy_length`, `fuzzy_minimum_score`, `fuzzy_backend`,
+    /// `adaptive_fuzzy_length` and `fuzzy_first_letter_fallback`. See
+    /// [`FuzzyConfig`] for more information.
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # use indicium::simple::{FuzzyConfig, SearchIndex, SearchIndexBuilder};
+    /// # use pretty_assertions::assert_eq;
+    /// #
+    /// let search_index: SearchIndex<usize> = SearchIndexBuilder::default()
+    ///     .fuzzy_config(FuzzyConfig { length: 2, minimum_score: 0.5, ..Default::default() })
+    ///     .build();
+    ///
+    /// assert_eq!(search_index.fuzzy_config().length, 2);
+    /// assert_eq!(search_index.fuzzy_config().minimum_score, 0.5);
+    /// ```
+    ///
+    /// **Default:** `FuzzyConfig::default()`
+    ///
+    /// [`FuzzyConfig`]: struct.FuzzyConfig.html
+    #[cfg(any(feature = "eddie", feature = "strsim"))]
+    pub fn fuzzy_config(mut self, fuzzy_config: crate::simple::FuzzyConfig) -> Self {
+        self.strsim_metric = fuzzy_config.strsim_metric;
+        self.eddie_metric = fuzzy_config.eddie_metric;
+        self.fuzzy_length = fuzzy_config.length;
+        self.fuzzy_minimum_score = fuzzy_config.minimum_score;
+        self.fuzzy_backend = fuzzy_config.backend;
+        self.adaptive_fuzzy_length = fuzzy_config.adaptive_length;
+        self.fuzzy_first_letter_fallback = fuzzy_config.first_letter_fallback;
+        self
+    } // fn
+
     /// String's minimum length (in chars or codepoints) to use "approximate
     /// string matching" or "fuzzy matching."
     ///
@@ -264,6 +635,61 @@ impl<K: Clone + Ord> SearchIndexBuilder<K> {
         self
     } // fn
 
+    /// Adds a ready-made list of keywords to the keyword exclusion list. Any
+    /// keywords already in the list (for example, from `exclude_keywords`) are
+    /// kept, so a preset can be combined with your own keywords. See
+    /// [`ExcludeKeywords`] for the available lists.
+    ///
+    /// ```rust
+    /// # use indicium::simple::{Preset, SearchIndex, SearchIndexBuilder};
+    /// #
+    /// let mut search_index: SearchIndex<usize> = SearchIndexBuilder::default()
+    ///     .exclude_keywords(Some(vec!["ltd".to_string()]))
+    ///     .exclude_preset(Preset::EnglishMinimal)
+    ///     .build();
+    ///
+    /// search_index.insert(&0, &"The Bank of England Ltd");
+    ///
+    /// assert_eq!(search_index.search("bank"), vec![&0]);
+    /// assert!(search_index.search("the").is_empty());
+    /// assert!(search_index.search("ltd").is_empty());
+    /// ```
+    ///
+    /// [`ExcludeKeywords`]: struct.ExcludeKeywords.html
+    pub fn exclude_preset(mut self, preset: Preset) -> Self {
+        let exclude_keywords = self.exclude_keywords.get_or_insert_with(Vec::new);
+        preset
+            .keywords()
+            .iter()
+            // Skip keywords that are already excluded:
+            .for_each(|keyword| if !exclude_keywords.iter().any(|excluded| excluded == keyword) {
+                exclude_keywords.push(KString::from_static(keyword));
+            }); // for_each
+        self
+    } // fn
+
+    /// Adds the stop words from an Apache Solr & Lucene `stopwords.txt` file
+    /// to the keyword exclusion list. Like `exclude_preset`, any keywords
+    /// already in the list are kept. See [`ExcludeKeywords::parse_stop_words`]
+    /// for the format.
+    ///
+    /// [`ExcludeKeywords::parse_stop_words`]: struct.ExcludeKeywords.html#method.parse_stop_words
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error::Io` error if the file can't be read.
+    pub fn stop_words_file(mut self, path: impl AsRef<Path>) -> Result<Self, Error> {
+        let stop_words = ExcludeKeywords::parse_stop_words(&std::fs::read_to_string(path)?);
+        let exclude_keywords = self.exclude_keywords.get_or_insert_with(Vec::new);
+        stop_words
+            .into_iter()
+            // Skip keywords that are already excluded:
+            .for_each(|keyword| if !exclude_keywords.iter().any(|excluded| *excluded == keyword) {
+                exclude_keywords.push(KString::from(keyword));
+            }); // for_each
+        Ok(self)
+    } // fn
+
     /// Maximum number of auto-complete options to return. This setting can be
     /// overidden by some function arguments.
     ///
@@ -294,8 +720,9 @@ impl<K: Clone + Ord> SearchIndexBuilder<K> {
     } // fn
 
     /// A special keyword that will return or "dump" all keys (or records) in
-    /// the search index. This is helpful for the `Select2` module, where it
-    /// should be returning all records if the search string is empty.
+    /// the search index when searched for. It may be disabled: the `Select2`
+    /// module and `SearchIndex::all_keys` return all records either way, but
+    /// they're faster with a dump keyword or a reverse map.
     ///
     /// **Default:** `Some("\0".to_string())`
     pub fn dump_keyword(mut self, dump_keyword: Option<String>) -> Self {
@@ -303,9 +730,865 @@ impl<K: Clone + Ord> SearchIndexBuilder<K> {
         self
     } // fn
 
+    /// Number of recent search strings for which the split & normalized
+    /// keywords are remembered. `Live` search and autocompletion are often
+    /// called repeatedly with the same search string, for example while the
+    /// user pauses typing. Set to `0` to turn the cache off.
+    ///
+    /// **Default:** `16`
+    pub fn query_cache_size(mut self, query_cache_size: usize) -> Self {
+        self.query_cache_size = query_cache_size;
+        self
+    } // fn
+
+    /// Maintain a reverse map of the keywords attached to each key. This is
+    /// required to efficiently find records that are similar to a record (see
+    /// `more_like_this`) but increases the memory used by the search index.
+    ///
+    /// The `Upsert` duplicate key policy (the default) needs the reverse map to
+    /// find a key's stale keywords, so it's always maintained for `Upsert`:
+    /// `build` ignores `false`, and `try_build` reports it as an
+    /// `Error::InvalidSetting` error. Use the `Accumulate` policy to save the
+    /// memory.
+    ///
+    /// ```rust
+    /// # use indicium::simple::{DuplicateKeyPolicy, SearchIndex, SearchIndexBuilder};
+    /// #
+    /// let result: Result<SearchIndex<usize>, indicium::Error> = SearchIndexBuilder::default()
+    ///     .reverse_map(false)
+    ///     .try_build();
+    /// assert!(result.is_err());
+    ///
+    /// let result: Result<SearchIndex<usize>, indicium::Error> = SearchIndexBuilder::default()
+    ///     .duplicate_key_policy(DuplicateKeyPolicy::Accumulate)
+    ///     .reverse_map(false)
+    ///     .try_build();
+    /// assert!(result.is_ok());
+    /// ```
+    ///
+    /// **Default:** `true`
+    pub fn reverse_map(mut self, reverse_map: bool) -> Self {
+        self.reverse_map = reverse_map;
+        self
+    } // fn
+
+    /// Maximum number of keywords in the search index, for memory-constrained
+    /// deployments. When an insert exceeds the cap, the least recently
+    /// searched (and rarest) keywords are evicted. This degrades recall
+    /// gracefully rather than growing without bound. See also: the
+    /// `evictions` method.
+    ///
+    /// **Default:** `None`
+    pub fn maximum_keywords(mut self, maximum_keywords: Option<usize>) -> Self {
+        self.maximum_keywords = maximum_keywords;
+        self
+    } // fn
+
+    /// Also suggest whole-string keywords (see `max_string_len`) that contain
+    /// the last (partial) keyword as an interior token, rather than only those
+    /// that start with it. For example, typing "conqueror" can then suggest
+    /// "william the conqueror". Applies to `Global` and `Keyword`
+    /// autocompletion. The interior tokens of the whole-string keywords are
+    /// indexed as records are inserted and removed, which takes more memory
+    /// but avoids scanning every keyword on each autocompletion.
+    ///
+    /// ```rust
+    /// # use indicium::simple::{AutocompleteType, SearchIndex, SearchIndexBuilder};
+    /// #
+    /// let mut search_index: SearchIndex<usize> = SearchIndexBuilder::default()
+    ///     .autocomplete_type(AutocompleteType::Global)
+    ///     .interior_autocomplete(true)
+    ///     .build();
+    ///
+    /// search_index.insert(&0, &"William the Conqueror");
+    ///
+    /// assert_eq!(
+    ///     search_index.autocomplete("conq"),
+    ///     vec!["conqueror".to_string(), "william the conqueror".to_string()]
+    /// );
+    /// ```
+    ///
+    /// **Default:** `false`
+    pub fn interior_autocomplete(mut self, interior_autocomplete: bool) -> Self {
+        self.interior_autocomplete = interior_autocomplete;
+        self
+    } // fn
+
+    /// Remember when each key was attached to each of its keywords. This is
+    /// required to drop old postings with the `decay` method, but increases
+    /// the memory used by the search index.
+    ///
+    /// **Default:** `false`
+    pub fn posting_timestamps(mut self, posting_timestamps: bool) -> Self {
+        self.posting_timestamps = posting_timestamps;
+        self
+    } // fn
+
+    /// The source of the current time for posting timestamps and the last
+    /// rebuild time reported by `runtime_stats`. A fixed or stepped clock lets
+    /// tests and replays run deterministically. The clock isn't part of the
+    /// `SearchIndexConfig`. See also `SearchIndex::set_clock`.
+    ///
+    /// ```rust
+    /// # use indicium::simple::{SearchIndex, SearchIndexBuilder};
+    /// # use pretty_assertions::assert_eq;
+    /// # use std::time::{Duration, SystemTime};
+    /// #
+    /// let epoch = SystemTime::UNIX_EPOCH;
+    ///
+    /// let search_index: SearchIndex<usize> = SearchIndexBuilder::default()
+    ///     .clock(move || epoch + Duration::from_secs(60))
+    ///     .build();
+    ///
+    /// assert_eq!(search_index.runtime_stats().last_rebuild, epoch + Duration::from_secs(60));
+    /// ```
+    ///
+    /// **Default:** the system clock
+    pub fn clock(mut self, now: impl Fn() -> SystemTime + Send + Sync + 'static) -> Self {
+        self.clock = Clock::new(now);
+        self
+    } // fn
+
+    /// Derives extra keywords from each keyword of a record when it's indexed,
+    /// for example transliterations or SKU checksum variants. The callback is
+    /// given each keyword after it's been split from the record's strings and
+    /// normalized, and returns the keywords to index alongside it. The derived
+    /// keywords are held to the same case sensitivity, length limits and
+    /// exclusion list as other keywords. Search strings aren't enriched.
+    ///
+    /// The callback isn't part of the `SearchIndexConfig`. See also
+    /// `SearchIndex::set_keyword_enricher`.
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # use indicium::simple::{SearchIndex, SearchIndexBuilder};
+    /// # use pretty_assertions::assert_eq;
+    /// #
+    /// let mut search_index: SearchIndex<usize> = SearchIndexBuilder::default()
+    ///     .keyword_enricher(|keyword| match keyword {
+    ///         "colour" => vec!["color".to_string()],
+    ///         _ => vec![],
+    ///     })
+    ///     .build();
+    ///
+    /// search_index.insert(&0, &"Colour Chart");
+    ///
+    /// assert_eq!(search_index.search("color"), vec![&0]);
+    /// assert_eq!(search_index.search("colour"), vec![&0]);
+    /// ```
+    ///
+    /// **Default:** no enrichment
+    pub fn keyword_enricher(
+        mut self,
+        enrich: impl Fn(&str) -> Vec<String> + Send + Sync + 'static,
+    ) -> Self {
+        self.keyword_enricher = KeywordEnricher::new(enrich);
+        self
+    } // fn
+
+    /// Record every insert, remove and clear in a changelog, so that the
+    /// changes can be shipped to replicas. See also: the `drain_ops` method.
+    ///
+    /// **Default:** `false`
+    pub fn changelog(mut self, changelog: bool) -> Self {
+        self.changelog = changelog;
+        self
+    } // fn
+
+    /// Count keyword lengths (see `min_keyword_len`, `max_keyword_len` and
+    /// `max_string_len`) in grapheme clusters rather than in chars (or
+    /// codepoints.) A letter with combining accents, or an emoji built from a
+    /// zero-width joiner sequence, then counts as a single character.
+    ///
+    /// ```rust
+    /// # use indicium::simple::{SearchIndex, SearchIndexBuilder};
+    /// #
+    /// let mut search_index: SearchIndex<usize> = SearchIndexBuilder::default()
+    ///     .min_keyword_len(2)
+    ///     .max_keyword_len(3)
+    ///     .grapheme_lengths(true)
+    ///     .build();
+    ///
+    /// // Four chars, but two grapheme clusters:
+    /// search_index.insert(&0, &"e\u{301}e\u{301}");
+    ///
+    /// assert_eq!(search_index.search("e\u{301}e\u{301}"), vec![&0]);
+    /// ```
+    ///
+    /// **Default:** `false`
+    #[cfg(feature = "graphemes")]
+    pub fn grapheme_lengths(mut self, grapheme_lengths: bool) -> Self {
+        self.grapheme_lengths = grapheme_lengths;
+        self
+    } // fn
+
+    /// Keep a hash map copy of the keywords & keys alongside the `BTreeMap`.
+    /// Exact keyword lookups (used by `And`, `Or` and `Keyword` searches, and
+    /// by the preceding keywords of `Live` searches) then avoid the
+    /// `BTreeMap`'s string comparisons, while prefix scans still use the
+    /// `BTreeMap`. This roughly doubles the memory used by the search index
+    /// (the dump keyword isn't copied), so it's meant for query-heavy
+    /// deployments.
+    ///
+    /// ```rust
+    /// # use indicium::simple::{SearchIndex, SearchIndexBuilder, SearchType};
+    /// #
+    /// let mut search_index: SearchIndex<usize> = SearchIndexBuilder::default()
+    ///     .search_type(SearchType::And)
+    ///     .front_map(true)
+    ///     .build();
+    ///
+    /// search_index.insert(&0, &"Harold Godwinson");
+    /// search_index.insert(&1, &"Harold Hardrada");
+    /// assert_eq!(search_index.search("harold"), vec![&0, &1]);
+    ///
+    /// search_index.remove(&0, &"Harold Godwinson");
+    /// assert_eq!(search_index.search("harold"), vec![&1]);
+    /// ```
+    ///
+    /// **Default:** `false`
+    pub fn front_map(mut self, front_map: bool) -> Self {
+        self.front_map = front_map;
+        self
+    } // fn
+
+    /// Keep a permuterm index of every rotation of every keyword, so that
+    /// `search_contains` can find keywords containing a fragment anywhere
+    /// (such as `*config*`) with a single prefix scan. This is useful for
+    /// identifier-heavy data, but a keyword of `n` characters takes `n + 1`
+    /// rotations of `n + 1` characters, so it's behind the `permuterm`
+    /// feature.
+    ///
+    /// ```rust
+    /// # use indicium::simple::{SearchIndex, SearchIndexBuilder};
+    /// #
+    /// let mut search_index: SearchIndex<usize> = SearchIndexBuilder::default()
+    ///     .permuterm(true)
+    ///     .build();
+    ///
+    /// search_index.insert(&0, &"TcpListener");
+    /// assert_eq!(search_index.search_contains("listen"), vec![&0]);
+    /// ```
+    ///
+    /// **Default:** `false`
+    #[cfg(feature = "permuterm")]
+    pub fn permuterm(mut self, permuterm: bool) -> Self {
+        self.permuterm = permuterm;
+        self
+    } // fn
+
+    /// Keep a copy of every keyword's keys in a sorted `Vec`, so that
+    /// `search_and_simd` can intersect them over contiguous memory for `u32`
+    /// and `u64` keys. Attaching or detaching a key shifts the keys after it
+    /// in each of its keywords' vectors, and the copy roughly doubles the
+    /// memory used by the search index (the dump keyword isn't copied), so
+    /// it's meant for query-heavy deployments. Requires the `simd` feature.
+    ///
+    /// ```rust
+    /// # use indicium::simple::{SearchIndex, SearchIndexBuilder};
+    /// #
+    /// let mut search_index: SearchIndex<u64> = SearchIndexBuilder::default()
+    ///     .sorted_postings(true)
+    ///     .build();
+    ///
+    /// search_index.insert(&0, &"Harold Godwinson");
+    /// search_index.insert(&1, &"Harold Hardrada");
+    /// assert_eq!(search_index.search_and_simd(&10, "harold"), vec![&0, &1]);
+    ///
+    /// search_index.remove(&0, &"Harold Godwinson");
+    /// assert_eq!(search_index.search_and_simd(&10, "harold"), vec![&1]);
+    /// ```
+    ///
+    /// **Default:** `false`
+    #[cfg(feature = "simd")]
+    pub fn sorted_postings(mut self, sorted_postings: bool) -> Self {
+        self.sorted_postings = sorted_postings;
+        self
+    } // fn
+
+    /// Keywords attached to fewer than this many keys (or records) aren't
+    /// suggested by autocompletion. This suppresses typo-ridden, one-off
+    /// keywords from user-generated content in the suggestions, while still
+    /// allowing them to be searched for explicitly.
+    ///
+    /// ```rust
+    /// # use indicium::simple::{SearchIndex, SearchIndexBuilder, SearchType};
+    /// # use pretty_assertions::assert_eq;
+    /// #
+    /// let mut search_index: SearchIndex<usize> = SearchIndexBuilder::default()
+    ///     .minimum_keys_for_autocomplete(2)
+    ///     .build();
+    ///
+    /// search_index.insert(&0, &"Great service");
+    /// search_index.insert(&1, &"Great food");
+    /// search_index.insert(&2, &"Graet prices");
+    ///
+    /// assert_eq!(search_index.autocomplete("gr"), vec!["great".to_string()]);
+    /// assert_eq!(search_index.search_type(&SearchType::Keyword, "graet"), vec![&2]);
+    /// ```
+    ///
+    /// **Default:** `0`
+    pub fn minimum_keys_for_autocomplete(mut self, minimum_keys_for_autocomplete: usize) -> Self {
+        self.minimum_keys_for_autocomplete = minimum_keys_for_autocomplete;
+        self
+    } // fn
+
+    /// Maximum number of keywords a single record may be attached to. This
+    /// protects the search index from keyword stuffing: a malicious or
+    /// pathological record (such as a huge body of text) could otherwise add
+    /// tens of thousands of keywords. What happens to records over the cap is
+    /// set with `keyword_cap_policy`. See also: the `try_insert` method.
+    ///
+    /// ```rust
+    /// # use indicium::simple::{KeywordCapPolicy, SearchIndex, SearchIndexBuilder};
+    /// # use pretty_assertions::assert_eq;
+    /// #
+    /// let mut search_index: SearchIndex<usize> = SearchIndexBuilder::default()
+    ///     .maximum_keywords_per_record(Some(3))
+    ///     .keyword_cap_policy(KeywordCapPolicy::Reject)
+    ///     .build();
+    ///
+    /// // Short strings are also indexed whole, so "one two" has 3 keywords:
+    /// assert!(search_index.try_insert(&0, &"one two three").is_err());
+    /// assert!(search_index.try_insert(&1, &"one two").is_ok());
+    /// assert_eq!(search_index.search("one"), vec![&1]);
+    /// ```
+    ///
+    /// **Default:** `None`
+    pub fn maximum_keywords_per_record(mut self, maximum_keywords_per_record: Option<usize>) -> Self {
+        self.maximum_keywords_per_record = maximum_keywords_per_record;
+        self
+    } // fn
+
+    /// What to do with records that have more keywords than the
+    /// `maximum_keywords_per_record` cap allows. See [`KeywordCapPolicy`] for
+    /// more information.
+    ///
+    /// [`KeywordCapPolicy`]: enum.KeywordCapPolicy.html
+    ///
+    /// **Default:** `KeywordCapPolicy::Truncate`
+    pub fn keyword_cap_policy(mut self, keyword_cap_policy: KeywordCapPolicy) -> Self {
+        self.keyword_cap_policy = keyword_cap_policy;
+        self
+    } // fn
+
+    /// Maximum length of a search string, in chars (or codepoints.) This
+    /// guards public endpoints against adversarial inputs. What happens to
+    /// longer search strings is set with `query_limit_policy`. See also: the
+    /// `try_search` and `try_autocomplete` methods.
+    ///
+    /// ```rust
+    /// # use indicium::simple::{SearchIndex, SearchIndexBuilder};
+    /// # use pretty_assertions::assert_eq;
+    /// #
+    /// let mut search_index: SearchIndex<usize> = SearchIndexBuilder::default()
+    ///     .maximum_query_length(Some(10))
+    ///     .build();
+    ///
+    /// search_index.insert(&0, &"Harold Godwinson");
+    ///
+    /// // The search string is clamped to "harold god":
+    /// assert_eq!(search_index.autocomplete("harold godwinson"), vec!["harold godwinson".to_string()]);
+    /// ```
+    ///
+    /// **Default:** `None`
+    pub fn maximum_query_length(mut self, maximum_query_length: Option<usize>) -> Self {
+        self.maximum_query_length = maximum_query_length;
+        self
+    } // fn
+
+    /// Maximum number of keywords in a search string. What happens to search
+    /// strings with more keywords is set with `query_limit_policy`.
+    ///
+    /// **Default:** `None`
+    pub fn maximum_query_keywords(mut self, maximum_query_keywords: Option<usize>) -> Self {
+        self.maximum_query_keywords = maximum_query_keywords;
+        self
+    } // fn
+
+    /// Maximum number of keywords evaluated by fuzzy matching for each
+    /// keyword. This bounds the work done by each search, especially when
+    /// `fuzzy_length` is short. The number of candidates is always clamped;
+    /// `query_limit_policy` doesn't apply to it.
+    ///
+    /// **Default:** `None`
+    pub fn maximum_fuzzy_candidates(mut self, maximum_fuzzy_candidates: Option<usize>) -> Self {
+        self.maximum_fuzzy_candidates = maximum_fuzzy_candidates;
+        self
+    } // fn
+
+    /// Chooses the fuzzy matching range for each keyword, rather than always
+    /// using the first `fuzzy_length` characters. The shortest prefix of the
+    /// user's keyword (starting with no prefix at all) that has at most this
+    /// many search index keywords in its range is used. This lets typos in the
+    /// first characters be corrected when the dictionary is sparse, while
+    /// dense dictionaries still use the `fuzzy_length` range.
+    ///
+    /// `fuzzy_length` is the longest prefix that's used, so it should be set
+    /// to a value that's fast enough for the densest ranges.
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # use indicium::simple::{SearchIndex, SearchIndexBuilder};
+    /// # use pretty_assertions::assert_eq;
+    /// #
+    /// let mut search_index: SearchIndex<usize> = SearchIndexBuilder::default()
+    ///     .adaptive_fuzzy_length(Some(100))
+    ///     .build();
+    ///
+    /// search_index.insert(&0, &"William");
+    ///
+    /// // The dictionary is small enough to compare against every keyword, so
+    /// // the typo in the first letter is corrected:
+    /// assert_eq!(search_index.search("gilliam"), vec![&0]);
+    /// ```
+    ///
+    /// **Default:** `None`
+    pub fn adaptive_fuzzy_length(mut self, adaptive_fuzzy_length: Option<usize>) -> Self {
+        self.adaptive_fuzzy_length = adaptive_fuzzy_length;
+        self
+    } // fn
+
+    /// Retries fuzzy matching when it finds nothing, so that typos in the
+    /// first characters of a keyword can be corrected. Normally, only search
+    /// index keywords that start with the first `fuzzy_length` characters of
+    /// the user's keyword are considered, so "gilliam" is never corrected to
+    /// "william".
+    ///
+    /// If enabled, the fuzzy match is retried with the first two characters
+    /// of the user's keyword transposed (so "iwlliam" considers keywords
+    /// starting with "wil") and then, if there's still no match, against every
+    /// keyword in the search index. The last pass may be slow for large search
+    /// indexes. Consider also setting `maximum_fuzzy_candidates`.
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # use indicium::simple::{SearchIndex, SearchIndexBuilder};
+    /// # use pretty_assertions::assert_eq;
+    /// #
+    /// let mut search_index: SearchIndex<usize> = SearchIndexBuilder::default()
+    ///     .fuzzy_first_letter_fallback(true)
+    ///     .build();
+    ///
+    /// search_index.insert(&0, &"William");
+    ///
+    /// assert_eq!(search_index.search("gilliam"), vec![&0]);
+    /// ```
+    ///
+    /// **Default:** `false`
+    pub fn fuzzy_first_letter_fallback(mut self, fuzzy_first_letter_fallback: bool) -> Self {
+        self.fuzzy_first_letter_fallback = fuzzy_first_letter_fallback;
+        self
+    } // fn
+
+    /// What to do with search strings that exceed `maximum_query_length` or
+    /// `maximum_query_keywords`. See [`QueryLimitPolicy`] for more
+    /// information.
+    ///
+    /// [`QueryLimitPolicy`]: enum.QueryLimitPolicy.html
+    ///
+    /// **Default:** `QueryLimitPolicy::Clamp`
+    pub fn query_limit_policy(mut self, query_limit_policy: QueryLimitPolicy) -> Self {
+        self.query_limit_policy = query_limit_policy;
+        self
+    } // fn
+
+    /// What `insert` does when the key is already in the search index. With
+    /// `Upsert`, the new value replaces the old one and stale keywords are
+    /// removed. With `Accumulate`, the key stays attached to the keywords of
+    /// every value inserted for it, and the reverse map is only maintained if
+    /// requested. See [`DuplicateKeyPolicy`] for more information.
+    ///
+    /// [`DuplicateKeyPolicy`]: enum.DuplicateKeyPolicy.html
+    ///
+    /// ```rust
+    /// # use indicium::simple::{DuplicateKeyPolicy, SearchIndex, SearchIndexBuilder};
+    /// # use pretty_assertions::assert_eq;
+    /// #
+    /// let mut search_index: SearchIndex<usize> = SearchIndexBuilder::default()
+    ///     .duplicate_key_policy(DuplicateKeyPolicy::Accumulate)
+    ///     .reverse_map(false)
+    ///     .build();
+    ///
+    /// search_index.insert(&0, &"Harold Godwinson");
+    /// search_index.insert(&0, &"Harold Hardrada");
+    ///
+    /// assert_eq!(search_index.search("godwinson"), vec![&0]);
+    /// assert_eq!(search_index.search("hardrada"), vec![&0]);
+    /// ```
+    ///
+    /// **Default:** `DuplicateKeyPolicy::Upsert`
+    pub fn duplicate_key_policy(mut self, duplicate_key_policy: DuplicateKeyPolicy) -> Self {
+        self.duplicate_key_policy = duplicate_key_policy;
+        self
+    } // fn
+
+    /// The number of preceding keywords used to constrain `Context`
+    /// autocompletion. Requiring every preceding keyword is too strict for
+    /// long, verbose queries. With a context window, only the last few
+    /// preceding keywords filter the suggestions.
+    ///
+    /// ```rust
+    /// # use indicium::simple::{AutocompleteType, SearchIndex, SearchIndexBuilder};
+    /// # use pretty_assertions::assert_eq;
+    /// #
+    /// let mut search_index: SearchIndex<usize> = SearchIndexBuilder::default()
+    ///     .autocomplete_type(AutocompleteType::Context)
+    ///     .context_window(Some(1))
+    ///     .build();
+    ///
+    /// search_index.insert(&0, &"Norman Conquest of England");
+    ///
+    /// // "battle" isn't in the record, but only "conquest" is used as context:
+    /// assert_eq!(
+    ///     search_index.autocomplete("battle conquest e"),
+    ///     vec!["battle conquest england".to_string()]
+    /// );
+    /// ```
+    ///
+    /// **Default:** `None`
+    pub fn context_window(mut self, context_window: Option<usize>) -> Self {
+        self.context_window = context_window;
+        self
+    } // fn
+
+    /// Autocompletion normally rebuilds the search string by joining the
+    /// normalized keywords with single spaces, which loses the user's original
+    /// separators and casing. When this setting is enabled, the completed last
+    /// keyword is spliced onto the original, untouched prefix instead.
+    ///
+    /// ```rust
+    /// # use indicium::simple::{SearchIndex, SearchIndexBuilder};
+    /// # use pretty_assertions::assert_eq;
+    /// #
+    /// let mut search_index: SearchIndex<usize> = SearchIndexBuilder::default()
+    ///     .preserve_query_prefix(true)
+    ///     .build();
+    ///
+    /// search_index.insert(&0, &"A very-big bird");
+    ///
+    /// assert_eq!(
+    ///     search_index.autocomplete("A  Very-Big bi"),
+    ///     vec!["A  Very-Big bird".to_string()]
+    /// );
+    /// ```
+    ///
+    /// **Default:** `false`
+    pub fn preserve_query_prefix(mut self, preserve_query_prefix: bool) -> Self {
+        self.preserve_query_prefix = preserve_query_prefix;
+        self
+    } // fn
+
+    /// List of keywords that are searchable, but are never suggested by
+    /// autocompletion. Unlike `exclude_keywords`, which keeps keywords out of
+    /// the search index, blocked keywords still return search results when
+    /// typed in full. This is useful for sites with user-generated content
+    /// that must not suggest offensive terms.
+    ///
+    /// Whole-string keywords containing a blocked word aren't suggested either.
+    /// Keywords should be given in lower case, unless the search index is case
+    /// sensitive.
+    ///
+    /// ```rust
+    /// # use indicium::simple::{SearchIndex, SearchIndexBuilder};
+    /// # use pretty_assertions::assert_eq;
+    /// #
+    /// let mut search_index: SearchIndex<usize> = SearchIndexBuilder::default()
+    ///     .suggestion_blocklist(Some(vec!["darn".to_string()]))
+    ///     .build();
+    ///
+    /// search_index.insert(&0, &"Darn socks");
+    /// search_index.insert(&1, &"Dark chocolate");
+    ///
+    /// assert_eq!(search_index.autocomplete("dar"), vec!["dark".to_string(), "dark chocolate".to_string()]);
+    /// assert_eq!(search_index.search("darn"), vec![&0]);
+    /// ```
+    ///
+    /// **Default:** `None`
+    pub fn suggestion_blocklist(mut self, suggestion_blocklist: Option<Vec<String>>) -> Self {
+        self.suggestion_blocklist = suggestion_blocklist
+            .map(|vec| vec.into_iter().map(|string| string.into()).collect());
+        self
+    } // fn
+
+    /// Indexes each pair of adjacent keywords in a string as a two-word keyword
+    /// (a "shingle".) For example, `Harold Godwinson of Wessex` is also indexed
+    /// as `harold godwinson` and `godwinson wessex`, since excluded keywords
+    /// aren't part of shingles. Only the words of the string are paired, not
+    /// any synonyms or other variants of them. Shingles are suggested by
+    /// autocompletion, and phrase-like queries can be answered with the
+    /// `Keyword` search type, without a full positional index.
+    ///
+    /// Shingles are opt-in because they roughly double the number of keywords
+    /// stored for each record, and most of them are unique to their record.
+    ///
+    /// ```rust
+    /// # use indicium::simple::{SearchIndex, SearchIndexBuilder, SearchType};
+    /// # use pretty_assertions::assert_eq;
+    /// #
+    /// let mut search_index: SearchIndex<usize> = SearchIndexBuilder::default()
+    ///     .shingles(true)
+    ///     .max_string_len(None)
+    ///     .build();
+    ///
+    /// search_index.insert(&0, &"Harold Godwinson");
+    /// search_index.insert(&1, &"Earl Harold Godwinson of Wessex");
+    /// search_index.insert(&2, &"Godwinson, Harold");
+    ///
+    /// assert_eq!(
+    ///     search_index.search_type(&SearchType::Keyword, "harold godwinson"),
+    ///     vec![&0, &1]
+    /// );
+    ///
+    /// assert_eq!(
+    ///     search_index.autocomplete("earl h"),
+    ///     vec!["earl harold".to_string(), "earl harold godwinson".to_string()]
+    /// );
+    /// ```
+    ///
+    /// **Default:** `false`
+    pub fn shingles(mut self, shingles: bool) -> Self {
+        self.shingles = shingles;
+        self
+    } // fn
+
+    /// Characters that are removed from inside keywords after a string has
+    /// been split. The filter is applied in the same way when indexing and
+    /// when searching, so that `don't` and `dont` match. For whole-string
+    /// keywords (see `max_string_len`) the split pattern characters are kept.
+    /// See [`CharFilter`] for more information.
+    ///
+    /// [`CharFilter`]: enum.CharFilter.html
+    ///
+    /// ```rust
+    /// # use indicium::simple::{CharFilter, SearchIndex, SearchIndexBuilder};
+    /// # use pretty_assertions::assert_eq;
+    /// #
+    /// let mut search_index: SearchIndex<usize> = SearchIndexBuilder::default()
+    ///     .char_filter(Some(CharFilter::Deny(vec!['\''])))
+    ///     .build();
+    ///
+    /// search_index.insert(&0, &"Don't Stop Me Now");
+    ///
+    /// assert_eq!(search_index.search("dont"), vec![&0]);
+    /// assert_eq!(search_index.search("don't"), vec![&0]);
+    /// ```
+    ///
+    /// **Default:** `None`
+    pub fn char_filter(mut self, char_filter: Option<CharFilter>) -> Self {
+        self.char_filter = char_filter;
+        self
+    } // fn
+
+    /// Removes elided articles, such as the French `l'` and `qu'` or the
+    /// Italian `un'`, from the start of words before a string is split. The
+    /// filter is applied in the same way when indexing and when searching, so
+    /// that `l'homme` and `homme` match. See [`Elision`] for the supported
+    /// languages.
+    ///
+    /// [`Elision`]: enum.Elision.html
+    ///
+    /// ```rust
+    /// # use indicium::simple::{Elision, SearchIndex, SearchIndexBuilder};
+    /// # use pretty_assertions::assert_eq;
+    /// #
+    /// let mut search_index: SearchIndex<usize> = SearchIndexBuilder::default()
+    ///     .elision(Some(Elision::French))
+    ///     .build();
+    ///
+    /// search_index.insert(&0, &"L'Homme qui rit");
+    /// search_index.insert(&1, &"Le Rouge et le Noir");
+    ///
+    /// assert_eq!(search_index.search("homme"), vec![&0]);
+    /// assert_eq!(search_index.search("l'homme"), vec![&0]);
+    /// assert!(!search_index.contains_keyword("l'homme"));
+    /// ```
+    ///
+    /// **Default:** `None`
+    pub fn elision(mut self, elision: Option<Elision>) -> Self {
+        self.elision = elision;
+        self
+    } // fn
+
+    /// Indexes the singular and plural variants of each keyword, using a
+    /// lightweight set of English rules (`s`, `es` and `ies`.) This lets
+    /// `pony` find `ponies`, and `boxes` find `box`, without full stemming.
+    /// Only the keywords split from a string get variants, and only at index
+    /// time.
+    ///
+    /// ```rust
+    /// # use indicium::simple::{SearchIndex, SearchIndexBuilder};
+    /// # use pretty_assertions::assert_eq;
+    /// #
+    /// let mut search_index: SearchIndex<usize> = SearchIndexBuilder::default()
+    ///     .plural_variants(true)
+    ///     .build();
+    ///
+    /// search_index.insert(&0, &"Pony Express");
+    /// search_index.insert(&1, &"Boxes of Kings");
+    ///
+    /// assert_eq!(search_index.search("ponies"), vec![&0]);
+    /// assert_eq!(search_index.search("box"), vec![&1]);
+    /// assert_eq!(search_index.search("king"), vec![&1]);
+    /// ```
+    ///
+    /// **Default:** `false`
+    pub fn plural_variants(mut self, plural_variants: bool) -> Self {
+        self.plural_variants = plural_variants;
+        self
+    } // fn
+
+    /// Synonym rules that are applied to keywords after a string has been
+    /// split. Equivalent synonyms are expanded when indexing, and explicit
+    /// mappings are applied both when indexing and when searching. See
+    /// [`Synonyms`] for more information, and `synonyms_file` to load the
+    /// rules from a Solr `synonyms.txt` file.
+    ///
+    /// [`Synonyms`]: struct.Synonyms.html
+    ///
+    /// ```rust
+    /// # use indicium::simple::{SearchIndex, SearchIndexBuilder, Synonyms};
+    /// # use pretty_assertions::assert_eq;
+    /// #
+    /// let mut search_index: SearchIndex<usize> = SearchIndexBuilder::default()
+    ///     .synonyms(Some(Synonyms::parse("couch, sofa\ncolor => colour")))
+    ///     .build();
+    ///
+    /// search_index.insert(&0, &"Leather Couch");
+    /// search_index.insert(&1, &"Color Television");
+    ///
+    /// assert_eq!(search_index.search("sofa"), vec![&0]);
+    /// assert_eq!(search_index.search("colour"), vec![&1]);
+    /// assert_eq!(search_index.search("color"), vec![&1]);
+    /// ```
+    ///
+    /// **Default:** `None`
+    pub fn synonyms(mut self, synonyms: Option<Synonyms>) -> Self {
+        self.synonyms = synonyms;
+        self
+    } // fn
+
+    /// Loads the synonym rules from an Apache Solr & Lucene `synonyms.txt`
+    /// file. Any rules already set with `synonyms` are kept, and the file's
+    /// rules are added after them. See [`Synonyms`].
+    ///
+    /// [`Synonyms`]: struct.Synonyms.html
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error::Io` error if the file can't be read.
+    pub fn synonyms_file(mut self, path: impl AsRef<Path>) -> Result<Self, Error> {
+        let rules = Synonyms::from_file(path)?.rules;
+        self.synonyms.get_or_insert_with(Synonyms::default).rules.extend(rules);
+        Ok(self)
+    } // fn
+
+    /// Transliterates keywords from other scripts, such as Cyrillic, Greek or
+    /// Arabic, into Latin. By default the Latin variant of each keyword is
+    /// indexed alongside the original, so that `Москва` may be found by
+    /// typing `moskva`. With `TransliterationDirection::Both`, search keywords
+    /// are also transliterated, so that `Moskva` may be found by typing
+    /// `Москва`. Whole strings (see `max_string_len`) aren't transliterated.
+    /// See [`Transliteration`].
+    ///
+    /// [`Transliteration`]: struct.Transliteration.html
+    ///
+    /// ```rust
+    /// # use indicium::simple::{SearchIndex, SearchIndexBuilder, Transliteration, TransliterationDirection};
+    /// # use pretty_assertions::assert_eq;
+    /// #
+    /// let mut search_index: SearchIndex<usize> = SearchIndexBuilder::default()
+    ///     .transliteration(Some(Transliteration {
+    ///         direction: TransliterationDirection::Both,
+    ///         keep_originals: true,
+    ///     }))
+    ///     .build();
+    ///
+    /// search_index.insert(&0, &"Москва");
+    /// search_index.insert(&1, &"Moskva River");
+    ///
+    /// assert_eq!(search_index.search("moskva"), vec![&0, &1]);
+    /// assert_eq!(search_index.search("Москва"), vec![&0, &1]);
+    /// assert!(search_index.contains_keyword("москва"));
+    /// ```
+    ///
+    /// **Default:** `None`
+    #[cfg(feature = "transliteration")]
+    pub fn transliteration(mut self, transliteration: Option<Transliteration>) -> Self {
+        self.transliteration = transliteration;
+        self
+    } // fn
+
     /// Build `SearchIndex` from the settings given to the `SearchIndexBuilder`.
     pub fn build(self) -> SearchIndex<K> {
         SearchIndex::from(self)
     } // fn
 
+    /// Build `SearchIndex` from the settings given to the `SearchIndexBuilder`,
+    /// after checking that the settings are usable. Unlike `build`, settings
+    /// that would silently break searching (such as a `NaN` fuzzy minimum
+    /// score) are reported as an error.
+    ///
+    /// ```rust
+    /// # use indicium::simple::{SearchIndex, SearchIndexBuilder};
+    /// #
+    /// let result: Result<SearchIndex<usize>, indicium::Error> = SearchIndexBuilder::default()
+    ///     .min_keyword_len(8)
+    ///     .max_keyword_len(4)
+    ///     .try_build();
+    ///
+    /// assert!(result.is_err());
+    /// ```
+    pub fn try_build(self) -> Result<SearchIndex<K>, Error> {
+        self.validate()?;
+        Ok(SearchIndex::from(self))
+    } // fn
+
+    /// Checks that the settings given to the `SearchIndexBuilder` are usable.
+    fn validate(&self) -> Result<(), Error> {
+        if !self.fuzzy_minimum_score.is_finite() {
+            return Err(Error::InvalidSetting {
+                setting: "fuzzy_minimum_score",
+                reason: format!("{} is not a finite number", self.fuzzy_minimum_score),
+            }) // Err
+        } // if
+
+        if self.minimum_keyword_length > self.maximum_keyword_length {
+            return Err(Error::InvalidSetting {
+                setting: "min_keyword_len",
+                reason: format!(
+                    "{} is greater than the maximum keyword length of {}",
+                    self.minimum_keyword_length,
+                    self.maximum_keyword_length,
+                ), // format!
+            }) // Err
+        } // if
+
+        if self.maximum_keywords_per_record == Some(0) {
+            return Err(Error::InvalidSetting {
+                setting: "maximum_keywords_per_record",
+                reason: "records could not be attached to any keyword".to_string(),
+            }) // Err
+        } // if
+
+        if self.maximum_keys_per_keyword == 0 {
+            return Err(Error::InvalidSetting {
+                setting: "max_keys_per_keyword",
+                reason: "no keys could be attached to any keyword".to_string(),
+            }) // Err
+        } // if
+
+        if !self.reverse_map && self.duplicate_key_policy == DuplicateKeyPolicy::Upsert {
+            return Err(Error::InvalidSetting {
+                setting: "reverse_map",
+                reason: "the `Upsert` duplicate key policy requires the reverse map".to_string(),
+            }) // Err
+        } // if
+
+        Ok(())
+    } // fn
+
 } // impl
\ No newline at end of file
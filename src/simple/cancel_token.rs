@@ -0,0 +1,76 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+// -----------------------------------------------------------------------------
+//
+/// A token used to cancel long-running operations, such as batch inserts and
+/// searches, from another thread. For example, a shutting-down server or an
+/// abandoned HTTP request may cancel the work it no longer needs.
+///
+/// Clones of a token share the same cancellation state.
+///
+/// Basic usage:
+///
+/// ```rust
+/// # use indicium::simple::CancelToken;
+/// #
+/// let token = CancelToken::new();
+/// let handle = token.clone();
+///
+/// assert!(!token.is_cancelled());
+/// handle.cancel();
+/// assert!(token.is_cancelled());
+/// ```
+
+#[derive(Clone, Debug, Default)]
+pub struct CancelToken(Arc<AtomicBool>);
+
+// -----------------------------------------------------------------------------
+
+impl CancelToken {
+
+    /// Creates a new token that hasn't been cancelled.
+    pub fn new() -> Self {
+        Self::default()
+    } // fn
+
+    /// Cancels every operation observing this token (or any of its clones.)
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::Relaxed);
+    } // fn
+
+    /// Returns whether the token has been cancelled.
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    } // fn
+
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl From<Arc<AtomicBool>> for CancelToken {
+    /// Convert to `CancelToken` from an existing `Arc<AtomicBool>` flag.
+    fn from(flag: Arc<AtomicBool>) -> Self {
+        CancelToken(flag)
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+//
+/// Returned by an operation that was cancelled using a [`CancelToken`] before
+/// it could complete.
+///
+/// [`CancelToken`]: struct.CancelToken.html
+
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Cancelled;
+
+// -----------------------------------------------------------------------------
+
+impl std::fmt::Display for Cancelled {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "operation was cancelled")
+    } // fn
+} // impl
+
+impl std::error::Error for Cancelled {}
\ No newline at end of file
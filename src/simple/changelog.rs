@@ -0,0 +1,139 @@
+use crate::simple::{search_index::SearchIndex, InsertOptions};
+use std::cmp::Ord;
+
+// -----------------------------------------------------------------------------
+//
+/// A change made to a search index. See [`IndexOp`].
+///
+/// [`IndexOp`]: struct.IndexOp.html
+
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum IndexChange<K> {
+    /// A record was inserted. `strings` are the strings returned by the
+    /// record's `Indexable` implementation.
+    Insert { key: K, strings: Vec<String> },
+    /// A record was removed. `strings` are the strings returned by the
+    /// record's `Indexable` implementation.
+    Remove { key: K, strings: Vec<String> },
+    /// A record was inserted with `insert_with`. `strings` are the strings
+    /// returned by the record's `Indexable` implementation.
+    InsertWith { key: K, strings: Vec<String>, options: InsertOptions },
+    /// A record that names its fields (see `Indexable::field_names`) was
+    /// inserted. `fields` are the record's field names and strings.
+    InsertFields { key: K, fields: Vec<(String, String)>, options: InsertOptions },
+    /// A single field of a record was re-indexed with `update_field`.
+    UpdateField { key: K, field: String, string: String },
+    /// The search index was cleared.
+    Clear,
+    /// The keys of the `from` keyword were attached to the `to` keyword with
+    /// `alias_keyword`.
+    Alias { from: String, to: String },
+    /// The keys of the `from` keyword were moved to the `to` keyword with
+    /// `rename_keyword`.
+    Rename { from: String, to: String },
+} // IndexChange
+
+// -----------------------------------------------------------------------------
+//
+/// An entry in a search index's changelog. Every mutation of a search index
+/// built with the `changelog` setting is recorded as an `IndexOp`, which may be
+/// retrieved with `drain_ops`. Shipping these ops to another process or
+/// machine allows a search index to be replicated without sending full
+/// snapshots.
+///
+/// Sequence numbers start at `1` and increase by one with each op.
+///
+/// Evictions (see `maximum_keywords`) and `decay` are not recorded, since they
+/// depend on the local state of each search index. Changes made directly to
+/// the underlying `BTreeMap` through `DerefMut` aren't recorded either.
+
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct IndexOp<K> {
+    /// The op's position in the changelog.
+    pub sequence: u64,
+    /// The change made to the search index.
+    pub change: IndexChange<K>,
+} // IndexOp
+
+// -----------------------------------------------------------------------------
+//
+/// The ops recorded since the changelog was last drained.
+
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub(crate) struct Changelog<K> {
+    /// The sequence number of the latest op.
+    pub(crate) sequence: u64,
+    /// Ops that haven't been drained yet.
+    pub(crate) ops: Vec<IndexOp<K>>,
+} // Changelog
+
+impl<K> Default for Changelog<K> {
+    fn default() -> Self {
+        Changelog { sequence: 0, ops: Vec::new() }
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl<K: Ord> SearchIndex<K> {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns the ops recorded since the last call, and removes them from the
+    /// changelog. Returns an empty `Vec` if the search index wasn't built with
+    /// the `changelog` setting. See [`IndexOp`] for more information.
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # use indicium::simple::{IndexChange, IndexOp, SearchIndex, SearchIndexBuilder};
+    /// #
+    /// let mut search_index: SearchIndex<usize> = SearchIndexBuilder::default()
+    ///     .changelog(true)
+    ///     .build();
+    ///
+    /// search_index.insert(&0, &"Harold Godwinson");
+    /// search_index.remove(&0, &"Harold Godwinson");
+    ///
+    /// assert_eq!(
+    ///     search_index.drain_ops(),
+    ///     vec![
+    ///         IndexOp {
+    ///             sequence: 1,
+    ///             change: IndexChange::Insert { key: 0, strings: vec!["Harold Godwinson".to_string()] },
+    ///         },
+    ///         IndexOp {
+    ///             sequence: 2,
+    ///             change: IndexChange::Remove { key: 0, strings: vec!["Harold Godwinson".to_string()] },
+    ///         },
+    ///     ]
+    /// );
+    ///
+    /// assert!(search_index.drain_ops().is_empty());
+    /// ```
+    ///
+    /// [`IndexOp`]: struct.IndexOp.html
+
+    #[tracing::instrument(level = "trace", name = "drain ops", skip(self))]
+    pub fn drain_ops(&mut self) -> Vec<IndexOp<K>> {
+        self.changelog
+            .as_mut()
+            .map(|changelog| std::mem::take(&mut changelog.ops))
+            .unwrap_or_default()
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Appends a change to the changelog, if it's enabled. The change is only
+    /// built when it will be recorded.
+
+    pub(crate) fn record_op(&mut self, change: impl FnOnce() -> IndexChange<K>) {
+        if let Some(changelog) = &mut self.changelog {
+            changelog.sequence += 1;
+            changelog.ops.push(IndexOp { sequence: changelog.sequence, change: change() });
+        } // if
+    } // fn
+
+} // impl
\ No newline at end of file
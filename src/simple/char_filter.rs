@@ -0,0 +1,37 @@
+// -----------------------------------------------------------------------------
+//
+/// Characters that are removed from inside keywords after a string has been
+/// split. Tokens such as `don't` or `co-op` may otherwise keep their interior
+/// punctuation, which makes them hard to match. The filter is applied the same
+/// way when indexing and when searching. See
+/// [`SearchIndexBuilder::char_filter`].
+///
+/// [`SearchIndexBuilder::char_filter`]: struct.SearchIndexBuilder.html#method.char_filter
+
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum CharFilter {
+    /// Removes the listed characters (a denylist.) For example, removing `'`
+    /// turns `don't` into `dont`.
+    Deny(Vec<char>),
+    /// Removes every character that isn't alphanumeric or listed (an
+    /// allowlist.)
+    Allow(Vec<char>),
+} // CharFilter
+
+// -----------------------------------------------------------------------------
+
+impl CharFilter {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns `true` if the character is removed by this filter.
+
+    pub(crate) fn removes(&self, char: char) -> bool {
+        match self {
+            CharFilter::Deny(chars) => chars.contains(&char),
+            CharFilter::Allow(chars) => !char.is_alphanumeric() && !chars.contains(&char),
+        } // match
+    } // fn
+
+} // impl
\ No newline at end of file
@@ -1,5 +1,6 @@
-use crate::simple::SearchIndex;
+use crate::simple::{IndexChange, SearchIndex};
 use std::cmp::Ord;
+use std::sync::Arc;
 
 // -----------------------------------------------------------------------------
 
@@ -10,7 +11,47 @@ impl<K: Ord> SearchIndex<K> {
     /// Clears the search index, removing all elements.
 
     pub fn clear(&mut self) {
-        self.b_tree_map.clear()
+        // Any snapshots keep the previous keywords:
+        self.b_tree_map = Arc::default();
+        self.invalidate_autocompletions();
+        self.keyword_usage.clear();
+        self.whole_strings = Arc::default();
+        self.whole_string_keywords.clear();
+        self.field_keywords.clear();
+        self.saturated_keywords.clear();
+
+        if let Some(reverse_map) = &mut self.reverse_map {
+            reverse_map.clear();
+        } // if
+
+        if let Some(posting_times) = &mut self.posting_times {
+            posting_times.clear();
+        } // if
+
+        if let Some(front_map) = &mut self.front_map {
+            front_map.clear();
+        } // if
+
+        if let Some(permuterm) = &mut self.permuterm {
+            permuterm.clear();
+        } // if
+
+        if let Some(sorted_postings) = &mut self.sorted_postings {
+            sorted_postings.clear();
+        } // if
+
+        if let Some(interior_tokens) = &mut self.interior_tokens {
+            interior_tokens.clear();
+        } // if
+
+        self.record_op(|| IndexChange::Clear);
+
+        #[cfg(feature = "geo")]
+        self.geo_index.clear();
+
+        // Record the size of the search index for metrics:
+        #[cfg(feature = "metrics")]
+        crate::simple::internal::metrics::record_index_size(0);
     } // fn
 
 } // impl
\ No newline at end of file
@@ -0,0 +1,46 @@
+use crate::simple::internal::Clock;
+use crate::simple::search_index::SearchIndex;
+use std::cmp::Ord;
+use std::time::SystemTime;
+
+// -----------------------------------------------------------------------------
+
+impl<K: Ord> SearchIndex<K> {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Replaces the clock used for time-dependent features: posting
+    /// timestamps (see `SearchIndexBuilder::posting_timestamps` and `decay`)
+    /// and the last rebuild time reported by `runtime_stats`. By default, the
+    /// system clock is used. A fixed or stepped clock lets tests and replays
+    /// run deterministically. See also `SearchIndexBuilder::clock`.
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # use indicium::simple::{SearchIndex, SearchIndexBuilder};
+    /// # use pretty_assertions::assert_eq;
+    /// # use std::time::{Duration, SystemTime};
+    /// #
+    /// let mut search_index: SearchIndex<usize> = SearchIndexBuilder::default()
+    ///     .posting_timestamps(true)
+    ///     .build();
+    ///
+    /// let epoch = SystemTime::UNIX_EPOCH;
+    ///
+    /// search_index.set_clock(move || epoch + Duration::from_secs(60));
+    /// search_index.insert(&0, &"Harold");
+    ///
+    /// search_index.set_clock(move || epoch + Duration::from_secs(120));
+    /// search_index.insert(&1, &"William");
+    ///
+    /// assert_eq!(search_index.decay(epoch + Duration::from_secs(90)), 1);
+    /// assert_eq!(search_index.search("harold"), Vec::<&usize>::new());
+    /// assert_eq!(search_index.search("william"), vec![&1]);
+    /// ```
+
+    pub fn set_clock(&mut self, now: impl Fn() -> SystemTime + Send + Sync + 'static) {
+        self.clock = Clock::new(now);
+    } // fn
+
+} // impl
\ No newline at end of file
@@ -0,0 +1,191 @@
+use crate::simple::search_index::SearchIndex;
+use kstring::KString;
+use std::sync::Arc;
+use std::{clone::Clone, cmp::Ord};
+
+// -----------------------------------------------------------------------------
+
+impl<K: Clone + Ord> SearchIndex<K> {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Moves whole-string keywords (see `maximum_string_length`) that are only
+    /// attached to a single key out of the main keyword dictionary, into a more
+    /// compact keyword-to-key map. Returns the number of keywords moved.
+    ///
+    /// Whole-string keywords mostly belong to a single record, and mostly exist
+    /// for autocompletion. Compacting them shrinks the dictionary used for
+    /// fuzzy matching and autocompletion scans. Compacted keywords are still
+    /// found by searches, autocompletion, `evict`, `decay`, snapshots and
+    /// serialization.
+    ///
+    /// Only keywords that were indexed as whole strings are compacted. Shingles
+    /// (see `SearchIndexBuilder::shingles`) and whole strings without a split
+    /// pattern character, which are also ordinary keywords, are left in place.
+    /// If a compacted keyword is inserted again, it's moved back into the main
+    /// dictionary.
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # use indicium::simple::SearchIndex;
+    /// # use pretty_assertions::assert_eq;
+    /// #
+    /// let mut search_index: SearchIndex<usize> = SearchIndex::default();
+    /// search_index.insert(&0, &"Harold Godwinson");
+    /// search_index.insert(&1, &"Harold Hardrada");
+    ///
+    /// // The dictionary also holds the dump keyword:
+    /// assert_eq!(search_index.len(), 6);
+    /// assert_eq!(search_index.compact_whole_strings(), 2);
+    /// assert_eq!(search_index.len(), 4);
+    /// assert_eq!(search_index.keyword_count(), 5);
+    ///
+    /// // Compacted keywords are still suggested:
+    /// assert_eq!(
+    ///     search_index.autocomplete("har"),
+    ///     vec![
+    ///         "hardrada".to_string(),
+    ///         "harold".to_string(),
+    ///         "harold godwinson".to_string(),
+    ///         "harold hardrada".to_string(),
+    ///     ]
+    /// );
+    ///
+    /// search_index.remove(&1, &"Harold Hardrada");
+    /// assert_eq!(
+    ///     search_index.autocomplete("har"),
+    ///     vec!["harold".to_string(), "harold godwinson".to_string()]
+    /// );
+    /// ```
+
+    #[tracing::instrument(level = "trace", name = "compact whole strings", skip(self))]
+    pub fn compact_whole_strings(&mut self) -> usize {
+
+        // Without a split pattern, whole strings can't be told apart from
+        // other keywords:
+        let Some(split_pattern) = &self.split_pattern else { return 0 };
+
+        // Forget the whole strings that have since left the dictionary:
+        let b_tree_map = &self.b_tree_map;
+        self.whole_string_keywords.retain(|keyword| b_tree_map.contains_key(keyword));
+
+        // Find the whole-string keywords that only have a single key. A whole
+        // string without a split pattern character is also an ordinary
+        // keyword, so it stays:
+        let compactable: Vec<KString> = self.whole_string_keywords
+            .iter()
+            .filter(|keyword| self.dump_keyword.as_ref() != Some(*keyword))
+            .filter(|keyword| keyword.contains(split_pattern.as_slice()))
+            .filter(|keyword| self.b_tree_map.get(*keyword).is_some_and(|keys| keys.len() == 1))
+            .cloned()
+            .collect();
+
+        // If a snapshot shares the keywords, they're copied before being
+        // changed:
+        let b_tree_map = Arc::make_mut(&mut self.b_tree_map);
+        let whole_strings = Arc::make_mut(&mut self.whole_strings);
+
+        compactable
+            .iter()
+            .for_each(|keyword| {
+                if let Some(key) = b_tree_map
+                    .remove(keyword)
+                    .and_then(|keys| keys.into_iter().next()) {
+                    whole_strings.insert(keyword.clone(), key);
+                } // if
+                self.whole_string_keywords.remove(keyword);
+            }); // for_each
+
+        self.sync_mirrors(&compactable);
+
+        // Record the size of the search index for metrics:
+        #[cfg(feature = "metrics")]
+        crate::simple::internal::metrics::record_index_size(self.b_tree_map.len());
+
+        // For debug builds:
+        #[cfg(debug_assertions)]
+        tracing::debug!("{} whole-string keywords compacted.", compactable.len());
+
+        compactable.len()
+
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Moves the given keywords back into the main dictionary if they were
+    /// compacted, so that more keys can be attached to them. They're only
+    /// compacted again if they're indexed as whole strings again, since the
+    /// new keys may have them as ordinary keywords or shingles.
+
+    pub(crate) fn expand_whole_strings<'k>(&mut self, keywords: impl Iterator<Item = &'k KString>) {
+        if self.whole_strings.is_empty() { return }
+
+        keywords.for_each(|keyword| {
+            // Only copy the compacted keywords if a snapshot shares them and
+            // they're changing:
+            if !self.whole_strings.contains_key(keyword) { return }
+            if let Some(key) = Arc::make_mut(&mut self.whole_strings).remove(keyword) {
+                Arc::make_mut(&mut self.b_tree_map)
+                    .entry(keyword.clone())
+                    .or_default()
+                    .insert(key);
+            } // if
+        }); // for_each
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Forgets the given compacted keywords, if they're attached to the key.
+
+    pub(crate) fn detach_whole_strings<'k>(&mut self, key: &K, keywords: impl Iterator<Item = &'k KString>) {
+        if self.whole_strings.is_empty() { return }
+
+        keywords.for_each(|keyword| {
+            if self.whole_strings.get(keyword) == Some(key) {
+                Arc::make_mut(&mut self.whole_strings).remove(keyword);
+            } // if
+        }); // for_each
+    } // fn
+
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl<K: Ord> SearchIndex<K> {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Adds the compacted keywords that start with the (partial) keyword to the
+    /// autocompletion options, keeping them in lexographic order and limited to
+    /// `maximum_autocomplete_options`. The filter is applied to each compacted
+    /// keyword's key.
+
+    pub(crate) fn merge_whole_strings<'a>(
+        &'a self,
+        autocompletions: &mut Vec<&'a KString>,
+        keyword: &str,
+        maximum_autocomplete_options: &usize,
+        filter: &dyn Fn(&KString, &K) -> bool,
+    ) {
+        // Compacted keywords only have a single key:
+        if self.whole_strings.is_empty() || self.minimum_keys_for_autocomplete > 1 { return }
+
+        let whole_strings: Vec<&KString> = self.whole_strings
+            .range(KString::from_ref(keyword)..)
+            .take_while(|(whole_string, _key)| whole_string.starts_with(keyword))
+            .filter(|(whole_string, _key)| !self.suggestion_blocked(whole_string))
+            .filter(|(whole_string, key)| filter(whole_string, key))
+            .map(|(whole_string, _key)| whole_string)
+            .take(*maximum_autocomplete_options)
+            .collect();
+
+        if whole_strings.is_empty() { return }
+
+        autocompletions.extend(whole_strings);
+        autocompletions.sort_unstable();
+        autocompletions.dedup();
+        autocompletions.truncate(*maximum_autocomplete_options);
+    } // fn
+
+} // impl
\ No newline at end of file
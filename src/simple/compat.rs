@@ -0,0 +1,83 @@
+use crate::simple::{SearchIndex, SearchIndexBuilder};
+use kstring::KString;
+use std::collections::{BTreeMap, BTreeSet};
+use std::sync::Arc;
+use std::{clone::Clone, cmp::Ord};
+
+// -----------------------------------------------------------------------------
+//
+/// The keywords and keys of a search index, in the layout that indicium 0.4.x
+/// serialized them in: a `BTreeMap<String, BTreeSet<K>>`. Persisted 0.4.x
+/// search indexes can be deserialized into a `LegacyKeywords` and then loaded
+/// with [`SearchIndex::from_legacy_keywords`], so that they aren't orphaned by
+/// changes to the internal representation.
+///
+/// [`SearchIndex::from_legacy_keywords`]: struct.SearchIndex.html#method.from_legacy_keywords
+
+#[derive(Clone, Debug, Default, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(transparent)]
+pub struct LegacyKeywords<K: Ord>(pub BTreeMap<String, BTreeSet<K>>);
+
+// -----------------------------------------------------------------------------
+
+impl<K: Clone + Ord> SearchIndex<K> {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Builds a search index with the given settings from keywords in the
+    /// indicium 0.4.x layout. The keywords are converted to the current layout,
+    /// and any enabled settings that depend on them (such as the reverse map or
+    /// front map) are rebuilt.
+    ///
+    /// The keywords are loaded as they were indexed. If the settings have
+    /// changed since (case sensitivity or split pattern, for example), the
+    /// records should be re-inserted instead.
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # use indicium::simple::{LegacyKeywords, SearchIndex, SearchIndexBuilder};
+    /// # use pretty_assertions::assert_eq;
+    /// # use std::collections::{BTreeMap, BTreeSet};
+    /// #
+    /// // Typically deserialized from a persisted 0.4.x search index:
+    /// let legacy_keywords: LegacyKeywords<usize> = LegacyKeywords(BTreeMap::from([
+    ///     ("harold".to_string(), BTreeSet::from([0])),
+    ///     ("godwinson".to_string(), BTreeSet::from([0])),
+    ///     ("william".to_string(), BTreeSet::from([1])),
+    /// ]));
+    ///
+    /// let search_index: SearchIndex<usize> = SearchIndex::from_legacy_keywords(
+    ///     SearchIndexBuilder::default(),
+    ///     legacy_keywords,
+    /// );
+    ///
+    /// assert_eq!(search_index.search("harold"), vec![&0]);
+    /// assert_eq!(search_index.autocomplete("wil"), vec!["william".to_string()]);
+    /// ```
+
+    #[tracing::instrument(level = "trace", name = "search index from legacy keywords", skip(search_index_builder, legacy_keywords))]
+    pub fn from_legacy_keywords(
+        search_index_builder: SearchIndexBuilder<K>,
+        legacy_keywords: LegacyKeywords<K>,
+    ) -> Self {
+
+        // Convert the `String` keywords into `KString` keywords:
+        let b_tree_map: BTreeMap<KString, BTreeSet<K>> = legacy_keywords.0
+            .into_iter()
+            .map(|(keyword, keys)| (KString::from(keyword), keys))
+            .collect();
+
+        // For debug builds:
+        #[cfg(debug_assertions)]
+        tracing::debug!("{} legacy keywords loaded.", b_tree_map.len());
+
+        // Replace the keywords, and rebuild the search index so that any
+        // settings that depend on the keywords are rebuilt too:
+        let mut search_index: SearchIndex<K> = search_index_builder.build();
+        search_index.b_tree_map = Arc::new(b_tree_map);
+        SearchIndexBuilder::from(search_index).build()
+
+    } // fn
+
+} // impl
\ No newline at end of file
@@ -0,0 +1,22 @@
+// -----------------------------------------------------------------------------
+//
+/// How a persisted search index is compressed by [`SearchIndex::write_to`].
+/// Compressed search indexes start with a header that records the
+/// compression, so [`SearchIndex::read_from`] detects it automatically.
+///
+/// [`SearchIndex::write_to`]: struct.SearchIndex.html#method.write_to
+/// [`SearchIndex::read_from`]: struct.SearchIndex.html#method.read_from
+
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum Compression {
+    /// The search index isn't compressed. This is the same format that
+    /// `to_bytes` returns.
+    #[default]
+    None,
+    /// The search index is compressed with [zstd](https://facebook.github.io/zstd/)
+    /// at the given level (`1` to `22`, or `0` for zstd's default level.)
+    /// Requires the `zstd` feature.
+    #[cfg(feature = "zstd")]
+    Zstd(i32),
+} // Compression
\ No newline at end of file
@@ -0,0 +1,77 @@
+use crate::simple::search_index::SearchIndex;
+use kstring::KString;
+use std::cmp::Ord;
+
+// -----------------------------------------------------------------------------
+
+impl<K: Ord> SearchIndex<K> {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns `true` if the keyword is in the search index, using an exact
+    /// match. The keyword is normalized according to the search index's case
+    /// sensitivity. This allows checks such as "is this tag used anywhere?" to
+    /// reuse the search index.
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # use indicium::simple::SearchIndex;
+    /// #
+    /// let mut search_index: SearchIndex<usize> = SearchIndex::default();
+    /// search_index.insert(&0, &"Rust, Search");
+    ///
+    /// assert!(search_index.contains_keyword("Rust"));
+    /// assert!(!search_index.contains_keyword("rus"));
+    /// ```
+
+    pub fn contains_keyword(&self, keyword: &str) -> bool {
+        let keyword: KString = self.normalize_keyword(keyword);
+        self.dump_keyword.as_ref() != Some(&keyword) &&
+            self.keyword_entry(&keyword).is_some()
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns the keywords in the search index that start with the prefix, in
+    /// lexographic order. The prefix is normalized according to the search
+    /// index's case sensitivity. Compacted whole strings (see
+    /// `compact_whole_strings`) are included. The `dump_keyword` is never
+    /// returned.
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # use indicium::simple::SearchIndex;
+    /// # use pretty_assertions::assert_eq;
+    /// #
+    /// let mut search_index: SearchIndex<usize> = SearchIndex::default();
+    /// search_index.insert(&0, &"Rust, Ruby");
+    /// search_index.insert(&1, &"Python");
+    ///
+    /// assert_eq!(
+    ///     search_index.keywords_with_prefix("RU").collect::<Vec<&str>>(),
+    ///     vec!["ruby", "rust", "rust, ruby"]
+    /// );
+    /// ```
+
+    pub fn keywords_with_prefix(&self, prefix: &str) -> impl Iterator<Item = &str> + '_ {
+        let prefix: KString = self.normalize_keyword(prefix);
+        self.keyword_entries(&prefix)
+            .map(|(keyword, _keys)| keyword.as_str())
+            .take_while(move |keyword| keyword.starts_with(prefix.as_str()))
+            .filter(|keyword| self.dump_keyword.as_deref() != Some(*keyword))
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Normalizes a keyword according to the search index's case sensitivity.
+
+    pub(crate) fn normalize_keyword(&self, keyword: &str) -> KString {
+        match self.case_sensitive {
+            true => KString::from_ref(keyword),
+            false => KString::from(keyword.to_lowercase()),
+        } // match
+    } // fn
+
+} // impl
\ No newline at end of file
@@ -0,0 +1,158 @@
+use crate::simple::search_index::SearchIndex;
+use kstring::KString;
+use std::{cmp::Ord, collections::BTreeSet};
+
+// -----------------------------------------------------------------------------
+
+impl<K: Ord> SearchIndex<K> {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns the number of distinct records (keys) in the search index.
+    ///
+    /// This is cheap if the search index has a `dump_keyword` (the default) or
+    /// a reverse map. Otherwise, the keys of every keyword are gathered.
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # use indicium::simple::SearchIndex;
+    /// # use pretty_assertions::assert_eq;
+    /// #
+    /// let mut search_index: SearchIndex<usize> = SearchIndex::default();
+    /// assert!(search_index.is_empty());
+    ///
+    /// search_index.insert(&0, &"Harold Godwinson");
+    /// search_index.insert(&1, &"Harold Hardrada");
+    ///
+    /// assert!(!search_index.is_empty());
+    /// assert_eq!(search_index.record_count(), 2);
+    /// // "harold", "godwinson", "hardrada", and the two whole strings:
+    /// assert_eq!(search_index.keyword_count(), 5);
+    /// assert!(search_index.contains_key(&1));
+    /// assert!(!search_index.contains_key(&2));
+    /// ```
+
+    pub fn record_count(&self) -> usize {
+        match (&self.dump_keyword, &self.reverse_map) {
+            (Some(dump_keyword), _) => self.b_tree_map
+                .get(dump_keyword)
+                .map_or(0, |keys| keys.len()),
+            (None, Some(reverse_map)) => reverse_map.len(),
+            (None, None) => self.b_tree_map
+                .values()
+                .flatten()
+                .chain(self.whole_strings.values())
+                .collect::<BTreeSet<&K>>()
+                .len(),
+        } // match
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns the number of keywords in the search index, including compacted
+    /// whole strings (see `compact_whole_strings`). The `dump_keyword` isn't
+    /// counted.
+
+    pub fn keyword_count(&self) -> usize {
+        let keywords: usize = self.b_tree_map.len() + self.whole_strings.len();
+        match &self.dump_keyword {
+            Some(dump_keyword) if self.b_tree_map.contains_key(dump_keyword) =>
+                keywords - 1,
+            _ => keywords,
+        } // match
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns the number of keys (or records) attached to a keyword, using an
+    /// exact match, without running a search. The keyword is normalized
+    /// according to the search index's case sensitivity. The `dump_keyword`
+    /// isn't counted.
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # use indicium::simple::SearchIndex;
+    /// # use pretty_assertions::assert_eq;
+    /// #
+    /// let mut search_index: SearchIndex<usize> = SearchIndex::default();
+    ///
+    /// search_index.insert(&0, &"Harold Godwinson");
+    /// search_index.insert(&1, &"Harold Hardrada");
+    /// search_index.insert(&2, &"Edgar Ætheling");
+    ///
+    /// assert_eq!(search_index.count_keyword("HAROLD"), 2);
+    /// assert_eq!(search_index.count_keyword("harol"), 0);
+    /// // "edgar" and the "edgar ætheling" whole string both match:
+    /// assert_eq!(search_index.count_prefix("ed"), 2);
+    /// // Never more than the number of records:
+    /// assert_eq!(search_index.count_prefix("h"), 3);
+    /// assert_eq!(search_index.count_prefix("x"), 0);
+    /// ```
+
+    pub fn count_keyword(&self, keyword: &str) -> usize {
+        let keyword: KString = self.normalize_keyword(keyword);
+        match self.dump_keyword.as_ref() == Some(&keyword) {
+            true => 0,
+            false => self.keyword_entry(&keyword).map_or(0, |keys| keys.len()),
+        } // match
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns an estimate of the number of records that have a keyword
+    /// starting with the prefix, without running a search. This is intended
+    /// for showing "about _n_ results" while the user is typing.
+    ///
+    /// The sizes of the matching keywords' key sets are added together, so a
+    /// record with several matching keywords is counted more than once. The
+    /// estimate is never more than the `record_count`. The prefix is
+    /// normalized according to the search index's case sensitivity.
+
+    pub fn count_prefix(&self, prefix: &str) -> usize {
+        let record_count: usize = self.record_count();
+
+        if prefix.is_empty() {
+            return record_count
+        } // if
+
+        let prefix: KString = self.normalize_keyword(prefix);
+        let mut count: usize = 0;
+
+        for (keyword, keys) in self.keyword_entries(&prefix) {
+            if !keyword.starts_with(prefix.as_str()) || count >= record_count {
+                break
+            } // if
+            if self.dump_keyword.as_ref() != Some(keyword) {
+                count += keys.len();
+            } // if
+        } // for
+
+        count.min(record_count)
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns `true` if the search index contains no records.
+
+    pub fn is_empty(&self) -> bool {
+        self.b_tree_map.is_empty() && self.whole_strings.is_empty()
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns `true` if the key is attached to any keyword in the search
+    /// index, without running a search. Uses the `dump_keyword` or the reverse
+    /// map if available, otherwise every keyword is scanned.
+
+    pub fn contains_key(&self, key: &K) -> bool {
+        match &self.dump_keyword {
+            Some(dump_keyword) => self.b_tree_map
+                .get(dump_keyword)
+                .is_some_and(|keys| keys.contains(key)),
+            None => self.existing_key(key).is_some(),
+        } // match
+    } // fn
+
+} // impl
\ No newline at end of file
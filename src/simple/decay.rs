@@ -0,0 +1,196 @@
+use crate::simple::{indexable::Indexable, search_index::SearchIndex};
+use kstring::KString;
+use std::collections::BTreeMap;
+use std::sync::Arc;
+use std::time::SystemTime;
+use std::{clone::Clone, cmp::Ord};
+
+// -----------------------------------------------------------------------------
+
+impl<K: Clone + Ord> SearchIndex<K> {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Inserts a record into the search index, like `insert`, but timestamps
+    /// its keywords with the given time rather than the current time. This is
+    /// useful when indexing content with a known creation time.
+    ///
+    /// Timestamps are only kept if the search index was built with
+    /// `posting_timestamps` enabled. See also: the `decay` method.
+
+    #[tracing::instrument(level = "trace", name = "search index insert at", skip(self, key, value))]
+    pub fn insert_at(&mut self, key: &K, value: &dyn Indexable, timestamp: SystemTime) {
+        self.insert(key, value);
+
+        if self.posting_times.is_some() {
+            // Only the postings that were actually attached are re-stamped:
+            let keywords: Vec<KString> = self
+                .indexable_keywords(value)
+                .into_iter()
+                .filter(|keyword| self.b_tree_map
+                    .get(keyword)
+                    .is_some_and(|keys| keys.contains(key))
+                ) // filter
+                .collect();
+            self.stamp_postings(key, &keywords, timestamp);
+        } // if
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Drops every posting (the attachment of a key to a keyword) that was
+    /// inserted before the cutoff. Keywords left without any keys are removed
+    /// from the search index. Returns the number of postings dropped.
+    ///
+    /// For search indexes that are continuously fed with user-generated
+    /// content, calling `decay` periodically keeps the index representative of
+    /// recent content without rebuilding it.
+    ///
+    /// This only has an effect if the search index was built with
+    /// `posting_timestamps` enabled.
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # use indicium::simple::{SearchIndex, SearchIndexBuilder};
+    /// # use std::time::{Duration, SystemTime};
+    /// #
+    /// let mut search_index: SearchIndex<usize> = SearchIndexBuilder::default()
+    ///     .posting_timestamps(true)
+    ///     .build();
+    ///
+    /// let now = SystemTime::now();
+    /// let last_week = now - Duration::from_secs(7 * 24 * 60 * 60);
+    ///
+    /// search_index.insert_at(&0, &"Old news", last_week);
+    /// search_index.insert_at(&1, &"Breaking news", now);
+    ///
+    /// assert_eq!(search_index.search("news"), vec![&0, &1]);
+    ///
+    /// let dropped = search_index.decay(now - Duration::from_secs(24 * 60 * 60));
+    /// // The "old", "news" and "old news" keywords were dropped for record 0:
+    /// assert_eq!(dropped, 3);
+    ///
+    /// assert_eq!(search_index.search("news"), vec![&1]);
+    /// assert!(search_index.search("old").is_empty());
+    /// ```
+
+    #[tracing::instrument(level = "trace", name = "search index decay", skip(self))]
+    pub fn decay(&mut self, before: SystemTime) -> usize {
+
+        let Some(posting_times) = &mut self.posting_times else { return 0 };
+
+        let mut dropped: usize = 0;
+
+        // If a snapshot shares the keywords, they're copied before being
+        // changed:
+        let b_tree_map = Arc::make_mut(&mut self.b_tree_map);
+
+        // Examine the postings of every key:
+        posting_times.retain(|key, keyword_times| {
+            // Drop this key's postings that are older than the cutoff:
+            keyword_times.retain(|keyword, timestamp| {
+                if *timestamp >= before { return true }
+
+                // Detach the key from the keyword, removing the keyword from
+                // the search index if it no longer has any keys:
+                if let Some(keys) = b_tree_map.get_mut(keyword) {
+                    if keys.remove(key) { dropped += 1 }
+                    if keys.is_empty() {
+                        b_tree_map.remove(keyword);
+                        self.keyword_usage.forget(keyword);
+                        self.whole_string_keywords.remove(keyword);
+                    } // if
+                } else if self.whole_strings.get(keyword) == Some(key) {
+                    // The keyword is a compacted whole string (see
+                    // `compact_whole_strings`), and this is its only key:
+                    Arc::make_mut(&mut self.whole_strings).remove(keyword);
+                    self.keyword_usage.forget(keyword);
+                    dropped += 1;
+                } // if
+
+                // Copy the keyword's remaining keys into the front map, and
+                // forget its rotations and interior tokens if it was removed:
+                if let Some(front_map) = &mut self.front_map {
+                    front_map.sync(b_tree_map, std::slice::from_ref(keyword));
+                } // if
+                if let Some(sorted_postings) = &mut self.sorted_postings {
+                    sorted_postings.sync(b_tree_map, std::slice::from_ref(keyword));
+                } // if
+                if let Some(permuterm) = &mut self.permuterm {
+                    permuterm.sync(b_tree_map, std::slice::from_ref(keyword));
+                } // if
+                if let Some(interior_tokens) = &mut self.interior_tokens {
+                    interior_tokens.sync(b_tree_map, &self.whole_strings, std::slice::from_ref(keyword));
+                } // if
+
+                // Detach the keyword from the key in the reverse map:
+                if let Some(keywords) = self.reverse_map.as_mut().and_then(|reverse_map| reverse_map.get_mut(key)) {
+                    keywords.remove(keyword);
+                } // if
+
+                false
+            }); // retain
+
+            // If the key has no postings left, detach it from the dump keyword
+            // and forget it:
+            if keyword_times.is_empty() {
+                if let Some(dump_keyword) = &self.dump_keyword {
+                    if let Some(keys) = b_tree_map.get_mut(dump_keyword) {
+                        keys.remove(key);
+                        if keys.is_empty() { b_tree_map.remove(dump_keyword); }
+                    } // if
+                    if let Some(front_map) = &mut self.front_map {
+                        front_map.sync(b_tree_map, std::slice::from_ref(dump_keyword));
+                    } // if
+                    if let Some(sorted_postings) = &mut self.sorted_postings {
+                        sorted_postings.sync(b_tree_map, std::slice::from_ref(dump_keyword));
+                    } // if
+                    if let Some(permuterm) = &mut self.permuterm {
+                        permuterm.sync(b_tree_map, std::slice::from_ref(dump_keyword));
+                    } // if
+                } // if
+                if let Some(reverse_map) = &mut self.reverse_map {
+                    reverse_map.remove(key);
+                } // if
+            } // if
+
+            !keyword_times.is_empty()
+        }); // retain
+
+        // The indexed data may have changed, so precomputed autocompletions are
+        // no longer current:
+        if dropped > 0 { self.invalidate_autocompletions() }
+
+        // Record the size of the search index for metrics:
+        #[cfg(feature = "metrics")]
+        crate::simple::internal::metrics::record_index_size(self.b_tree_map.len());
+
+        // For debug builds:
+        #[cfg(debug_assertions)]
+        tracing::debug!("Decay dropped {} postings.", dropped);
+
+        dropped
+
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Records the time that the key was attached to each of the keywords, if
+    /// posting timestamps are enabled. The `dump_keyword` isn't timestamped.
+
+    pub(crate) fn stamp_postings(&mut self, key: &K, keywords: &[KString], timestamp: SystemTime) {
+        let mut keywords = keywords
+            .iter()
+            .filter(|keyword| self.dump_keyword.as_ref() != Some(*keyword))
+            .peekable();
+        if keywords.peek().is_none() { return }
+
+        if let Some(posting_times) = &mut self.posting_times {
+            let keyword_times: &mut BTreeMap<KString, SystemTime> =
+                posting_times.entry(key.clone()).or_default();
+            keywords.for_each(|keyword| { keyword_times.insert(keyword.clone(), timestamp); });
+        } // if
+    } // fn
+
+} // impl
\ No newline at end of file
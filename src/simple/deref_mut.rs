@@ -1,12 +1,23 @@
 use crate::simple::search_index::SearchIndex;
-use std::{cmp::Ord, ops::DerefMut};
+use std::sync::Arc;
+use std::{clone::Clone, cmp::Ord, ops::DerefMut};
 
 // -----------------------------------------------------------------------------
 //
 /// Dereferencing a `SearchIndex<K>` will give access the underlying `BTreeMap`.
 
-impl<K: Ord> DerefMut for SearchIndex<K> {
+impl<K: Clone + Ord> DerefMut for SearchIndex<K> {
     fn deref_mut(&mut self) -> &mut Self::Target {
-        &mut self.b_tree_map
+        // The caller may change the indexed data, so precomputed
+        // autocompletions can no longer be trusted:
+        self.invalidate_autocompletions();
+        // The front map, sorted postings, permuterm index and interior tokens
+        // can't follow the caller's changes:
+        if let Some(front_map) = &mut self.front_map { front_map.mark_stale() }
+        if let Some(sorted_postings) = &mut self.sorted_postings { sorted_postings.mark_stale() }
+        if let Some(permuterm) = &mut self.permuterm { permuterm.mark_stale() }
+        if let Some(interior_tokens) = &mut self.interior_tokens { interior_tokens.mark_stale() }
+        // If a snapshot shares the keywords, they're copied first:
+        Arc::make_mut(&mut self.b_tree_map)
     } // fn
 } // impl
\ No newline at end of file
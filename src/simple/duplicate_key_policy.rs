@@ -0,0 +1,23 @@
+// -----------------------------------------------------------------------------
+//
+/// What `insert` does when the key is already in the search index, for
+/// example when a record is inserted again after it was edited. See
+/// [`SearchIndexBuilder::duplicate_key_policy`].
+///
+/// [`SearchIndexBuilder::duplicate_key_policy`]: struct.SearchIndexBuilder.html#method.duplicate_key_policy
+
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum DuplicateKeyPolicy {
+    /// The new value replaces the old one: the key is detached from any
+    /// keywords that the new value no longer has. This requires tracking the
+    /// keywords attached to each key, so the reverse map (see
+    /// `SearchIndexBuilder::reverse_map`) is always maintained. It holds every
+    /// key once per attached keyword, roughly doubling the memory used by the
+    /// postings.
+    #[default]
+    Upsert,
+    /// The key's keywords accumulate: the key stays attached to the keywords
+    /// of every value inserted for it, until they're removed with `remove`.
+    Accumulate,
+} // DuplicateKeyPolicy
\ No newline at end of file
@@ -4,6 +4,7 @@
 /// Schelokov's [eddie](https://crates.io/crates/eddie) crate.
 
 #[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub enum EddieMetric {
     /// See [the detailed description](https://en.wikipedia.org/wiki/Levenshtein_distance).
     #[default] Levenshtein,
@@ -0,0 +1,100 @@
+// -----------------------------------------------------------------------------
+//
+/// Articles and other short words that are elided onto the following word with
+/// an apostrophe in Romance languages, such as `l'homme` or `un'amica`. When an
+/// elision filter is set, the elided word and its apostrophe are removed before
+/// a string is split, so that `l'homme` is indexed and searched as `homme`. See
+/// [`SearchIndexBuilder::elision`].
+///
+/// [`SearchIndexBuilder::elision`]: struct.SearchIndexBuilder.html#method.elision
+
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum Elision {
+    /// French elisions: `l'`, `d'`, `qu'`, `jusqu'`, etc.
+    French,
+    /// Italian elisions: `l'`, `dell'`, `un'`, `quest'`, etc.
+    Italian,
+    /// Catalan elisions: `l'`, `d'`, `s'`, etc.
+    Catalan,
+    /// A custom list of elided words, without the apostrophe.
+    Custom(Vec<String>),
+} // Elision
+
+// -----------------------------------------------------------------------------
+
+impl Elision {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns the elided words for this language.
+
+    pub(crate) fn articles(&self) -> Vec<&str> {
+        match self {
+            Elision::French => vec![
+                "l", "m", "t", "qu", "n", "s", "j", "d", "c", "jusqu",
+                "quoiqu", "lorsqu", "puisqu",
+            ], // vec!
+            Elision::Italian => vec![
+                "c", "l", "all", "dall", "dell", "nell", "sull", "coll", "pell",
+                "gl", "agl", "dagl", "degl", "negl", "sugl", "un", "m", "t",
+                "s", "v", "d", "quest", "quell",
+            ], // vec!
+            Elision::Catalan => vec!["d", "l", "m", "n", "s", "t"],
+            Elision::Custom(articles) => articles.iter().map(String::as_str).collect(),
+        } // match
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Removes the elided words (and their apostrophes) from the start of
+    /// every word in the string. Both the straight `'` and the typographic `’`
+    /// apostrophes are recognized. Elided words are matched without regard to
+    /// case.
+
+    pub(crate) fn strip(&self, string: &str) -> String {
+        let articles = self.articles();
+        let mut stripped = String::with_capacity(string.len());
+        let mut rest = string;
+        let mut word_start = true;
+
+        while let Some(char) = rest.chars().next() {
+            // At the start of a word, look for an elided word followed by an
+            // apostrophe and a letter:
+            if word_start {
+                let elided = articles.iter().find_map(|article| {
+                    let prefix = rest.get(..article.len())?;
+                    if !prefix.eq_ignore_ascii_case(article) { return None }
+                    let after = &rest[article.len()..];
+                    let apostrophe = after.chars().next().filter(|c| *c == '\'' || *c == '’')?;
+                    let after = &after[apostrophe.len_utf8()..];
+                    after.chars().next().filter(|c| c.is_alphanumeric())?;
+                    Some(after)
+                }); // find_map
+                if let Some(after) = elided {
+                    rest = after;
+                    word_start = false;
+                    continue
+                } // if
+            } // if
+
+            stripped.push(char);
+            word_start = !char.is_alphanumeric();
+            rest = &rest[char.len_utf8()..];
+        } // while
+
+        stripped
+    } // fn
+
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[test]
+fn test_elision_strip() {
+    assert_eq!(Elision::French.strip("L'homme et l’arbre"), "homme et arbre");
+    assert_eq!(Elision::French.strip("jusqu'à aujourd'hui"), "à aujourd'hui");
+    assert_eq!(Elision::Italian.strip("un'amica dell'anno"), "amica anno");
+    assert_eq!(Elision::French.strip("l' homme"), "l' homme");
+    assert_eq!(Elision::Custom(vec!["o".to_string()]).strip("o'clock"), "clock");
+}
\ No newline at end of file
@@ -0,0 +1,184 @@
+use crate::simple::search_index::SearchIndex;
+use kstring::KString;
+use std::collections::BTreeSet;
+use std::sync::Arc;
+use std::{clone::Clone, cmp::Ord};
+
+// -----------------------------------------------------------------------------
+//
+/// Reports the keywords that a search index with a `maximum_keywords` cap has
+/// evicted to stay within its memory budget. See
+/// [`SearchIndexBuilder::maximum_keywords`] and [`SearchIndex::evictions`].
+///
+/// [`SearchIndexBuilder::maximum_keywords`]: struct.SearchIndexBuilder.html#method.maximum_keywords
+/// [`SearchIndex::evictions`]: struct.SearchIndex.html#method.evictions
+
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Evictions {
+    /// The number of keywords that have been evicted.
+    pub keywords: usize,
+    /// The number of keys (or records) that were detached from the evicted
+    /// keywords.
+    pub keys: usize,
+} // Evictions
+
+// -----------------------------------------------------------------------------
+
+impl<K: Ord> SearchIndex<K> {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns the number of keywords (and keys) that have been evicted since
+    /// the search index was created. Evictions only occur if a
+    /// `maximum_keywords` cap has been set with
+    /// [`SearchIndexBuilder::maximum_keywords`].
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # use indicium::simple::{SearchIndex, SearchIndexBuilder};
+    /// # use pretty_assertions::assert_eq;
+    /// #
+    /// let mut search_index: SearchIndex<usize> = SearchIndexBuilder::default()
+    ///     .maximum_keywords(Some(2))
+    ///     .dump_keyword(None)
+    ///     .build();
+    ///
+    /// search_index.insert(&0, &"Harold");
+    /// search_index.insert(&1, &"William");
+    ///
+    /// // Searching for Harold makes it more recently hit than William:
+    /// search_index.search("harold");
+    ///
+    /// search_index.insert(&2, &"Edgar");
+    ///
+    /// assert_eq!(search_index.evictions().keywords, 1);
+    /// assert_eq!(search_index.search("william"), Vec::<&usize>::new());
+    /// assert_eq!(search_index.search("harold"), vec![&0]);
+    /// ```
+    ///
+    /// [`SearchIndexBuilder::maximum_keywords`]: struct.SearchIndexBuilder.html#method.maximum_keywords
+
+    pub fn evictions(&self) -> Evictions {
+        self.evictions
+    } // fn
+
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl<K: Clone + Ord> SearchIndex<K> {
+
+    // -------------------------------------------------------------------------
+    //
+    /// If the search index holds more keywords than its `maximum_keywords` cap,
+    /// evicts the least recently hit (or inserted) keywords. Of the keywords
+    /// last hit at the same time, the rarest are evicted first. Compacted whole
+    /// strings (see `compact_whole_strings`) count towards the cap and may be
+    /// evicted. The `dump_keyword` is never evicted.
+    ///
+    /// To avoid examining every keyword on every insert, the search index is
+    /// trimmed to slightly below the cap.
+
+    pub(crate) fn evict(&mut self) {
+
+        let Some(maximum_keywords) = self.maximum_keywords else { return };
+        let keywords: usize = self.b_tree_map.len() + self.whole_strings.len();
+        if keywords <= maximum_keywords { return }
+
+        // Trim to 1/16th below the cap so that the next few inserts don't
+        // trigger another eviction:
+        let target: usize = maximum_keywords.saturating_sub(maximum_keywords / 16);
+        let excess: usize = keywords - target;
+
+        // Rank the keywords by their last hit, and then by their number of
+        // keys:
+        let mut candidates: Vec<(u64, usize, KString)> = self
+            .keyword_entries("")
+            .filter(|(keyword, _keys)| self.dump_keyword.as_ref() != Some(keyword))
+            .map(|(keyword, keys)| (0, keys.len(), keyword.clone()))
+            .collect();
+
+        candidates
+            .iter_mut()
+            .for_each(|(last_hit, _keys, keyword)| *last_hit = self.keyword_usage.last_hit(keyword));
+
+        candidates.sort_unstable();
+
+        // The indexed data is changing, so precomputed autocompletions are no
+        // longer current:
+        self.invalidate_autocompletions();
+
+        // Evict the lowest ranked keywords:
+        let b_tree_map = Arc::make_mut(&mut self.b_tree_map);
+        candidates
+            .into_iter()
+            .take(excess)
+            .for_each(|(_last_hit, _keys, keyword)| {
+                // The keyword is either in the dictionary, or is a compacted
+                // whole string:
+                let keys: Option<BTreeSet<K>> = match b_tree_map.remove(&keyword) {
+                    Some(keys) => Some(keys),
+                    None if self.whole_strings.contains_key(&keyword) => Arc::make_mut(&mut self.whole_strings)
+                        .remove(&keyword)
+                        .map(|key| BTreeSet::from([key])),
+                    None => None,
+                }; // match
+
+                if let Some(keys) = keys {
+                    // Detach the keyword from its keys in the reverse map:
+                    if let Some(reverse_map) = &mut self.reverse_map {
+                        keys.iter().for_each(|key| {
+                            if let Some(keywords) = reverse_map.get_mut(key) {
+                                keywords.remove(&keyword);
+                            } // if
+                        }); // for_each
+                    } // if
+
+                    // Forget the timestamps of the evicted postings:
+                    if let Some(posting_times) = &mut self.posting_times {
+                        keys.iter().for_each(|key| {
+                            if let Some(keyword_times) = posting_times.get_mut(key) {
+                                keyword_times.remove(&keyword);
+                            } // if
+                        }); // for_each
+                    } // if
+
+                    // Forget the evicted keyword in the front map, sorted
+                    // postings, permuterm index and interior tokens:
+                    if let Some(front_map) = &mut self.front_map {
+                        front_map.sync(b_tree_map, std::slice::from_ref(&keyword));
+                    } // if
+                    if let Some(sorted_postings) = &mut self.sorted_postings {
+                        sorted_postings.sync(b_tree_map, std::slice::from_ref(&keyword));
+                    } // if
+                    if let Some(permuterm) = &mut self.permuterm {
+                        permuterm.sync(b_tree_map, std::slice::from_ref(&keyword));
+                    } // if
+                    if let Some(interior_tokens) = &mut self.interior_tokens {
+                        interior_tokens.sync(b_tree_map, &self.whole_strings, std::slice::from_ref(&keyword));
+                    } // if
+
+                    self.evictions.keywords += 1;
+                    self.evictions.keys += keys.len();
+                } // if
+
+                self.keyword_usage.forget(&keyword);
+                self.whole_string_keywords.remove(&keyword);
+            }); // for_each
+
+        // Record the size of the search index for metrics:
+        #[cfg(feature = "metrics")]
+        crate::simple::internal::metrics::record_index_size(self.b_tree_map.len());
+
+        // For debug builds:
+        #[cfg(debug_assertions)]
+        tracing::debug!(
+            "Evicted keywords. {} keywords remain. {} keywords evicted in total.",
+            self.b_tree_map.len(),
+            self.evictions.keywords,
+        ); // debug!
+
+    } // fn
+
+} // impl
\ No newline at end of file
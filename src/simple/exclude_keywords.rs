@@ -0,0 +1,126 @@
+// -----------------------------------------------------------------------------
+//
+/// Ready-made keyword exclusion lists (also known as "stop words"). Keywords
+/// in these lists appear in so many records that they add little value to
+/// search results, while slowing down the search index.
+///
+/// These lists may be passed to the `exclude_keywords` builder method, or
+/// applied with `exclude_preset` which also keeps any user-supplied keywords.
+///
+/// All keywords are lowercase. If the search index is case sensitive, only the
+/// lowercase forms will be excluded.
+
+pub struct ExcludeKeywords;
+
+// -----------------------------------------------------------------------------
+
+impl ExcludeKeywords {
+
+    // -------------------------------------------------------------------------
+    //
+    /// A short list of the most common English articles, conjunctions,
+    /// prepositions and pronouns. Suitable for most search indexes.
+
+    pub const fn english_minimal() -> &'static [&'static str] {
+        &[
+            "a", "an", "and", "are", "as", "at", "be", "but", "by", "for", "if",
+            "in", "into", "is", "it", "no", "not", "of", "on", "or", "such",
+            "that", "the", "their", "then", "there", "these", "they", "this",
+            "to", "was", "will", "with",
+        ]
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// A longer list of common English function words. This excludes more
+    /// noise, but may also exclude words that users search for on purpose
+    /// (for example, "who" or "about").
+
+    pub const fn english_full() -> &'static [&'static str] {
+        &[
+            "a", "about", "above", "after", "again", "against", "all", "am",
+            "an", "and", "any", "are", "as", "at", "be", "because", "been",
+            "before", "being", "below", "between", "both", "but", "by", "can",
+            "could", "did", "do", "does", "doing", "down", "during", "each",
+            "few", "for", "from", "further", "had", "has", "have", "having",
+            "he", "her", "here", "hers", "herself", "him", "himself", "his",
+            "how", "i", "if", "in", "into", "is", "it", "its", "itself", "just",
+            "me", "more", "most", "my", "myself", "no", "nor", "not", "now",
+            "of", "off", "on", "once", "only", "or", "other", "our", "ours",
+            "ourselves", "out", "over", "own", "same", "she", "should", "so",
+            "some", "such", "than", "that", "the", "their", "theirs", "them",
+            "themselves", "then", "there", "these", "they", "this", "those",
+            "through", "to", "too", "under", "until", "up", "very", "was", "we",
+            "were", "what", "when", "where", "which", "while", "who", "whom",
+            "why", "will", "with", "would", "you", "your", "yours", "yourself",
+            "yourselves",
+        ]
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Parses a stop word list in the Apache Solr & Lucene `stopwords.txt`
+    /// format, so that existing search configurations can be reused. Each line
+    /// holds one or more whitespace-separated stop words. Comments start with
+    /// `#`, or with `|` in the Snowball variant of the format.
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # use indicium::simple::ExcludeKeywords;
+    /// # use pretty_assertions::assert_eq;
+    /// #
+    /// let stop_words = ExcludeKeywords::parse_stop_words(
+    ///     "# English\nthe\nand | conjunction\n"
+    /// );
+    ///
+    /// assert_eq!(stop_words, vec!["the".to_string(), "and".to_string()]);
+    /// ```
+
+    pub fn parse_stop_words(text: &str) -> Vec<String> {
+        text
+            .lines()
+            .map(|line| line.split(['#', '|']).next().unwrap_or_default())
+            .flat_map(str::split_whitespace)
+            .map(str::to_string)
+            .collect()
+    } // fn
+
+} // impl
+
+// -----------------------------------------------------------------------------
+//
+/// Selects one of the [`ExcludeKeywords`] lists for the `exclude_preset`
+/// builder method.
+///
+/// [`ExcludeKeywords`]: struct.ExcludeKeywords.html
+
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum Preset {
+    /// See [`ExcludeKeywords::english_minimal`].
+    ///
+    /// [`ExcludeKeywords::english_minimal`]: struct.ExcludeKeywords.html#method.english_minimal
+    EnglishMinimal,
+    /// See [`ExcludeKeywords::english_full`].
+    ///
+    /// [`ExcludeKeywords::english_full`]: struct.ExcludeKeywords.html#method.english_full
+    EnglishFull,
+} // Preset
+
+// -----------------------------------------------------------------------------
+
+impl Preset {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns the keywords in this preset.
+
+    pub const fn keywords(&self) -> &'static [&'static str] {
+        match self {
+            Preset::EnglishMinimal => ExcludeKeywords::english_minimal(),
+            Preset::EnglishFull => ExcludeKeywords::english_full(),
+        } // match
+    } // fn
+
+} // impl
\ No newline at end of file
@@ -0,0 +1,56 @@
+use crate::simple::search_index::SearchIndex;
+use std::cmp::Ord;
+
+// -----------------------------------------------------------------------------
+
+impl<K: Ord> SearchIndex<K> {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns every keyword in the search index with its frequency (the
+    /// number of keys attached to it), in lexographic order. Keywords attached
+    /// to fewer than `minimum_keys` keys are left out. The `dump_keyword` is
+    /// never returned.
+    ///
+    /// This is suitable for feeding external spell checkers, or for building a
+    /// custom fuzzy matching dictionary.
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # use indicium::simple::{SearchIndex, SearchIndexBuilder};
+    /// # use pretty_assertions::assert_eq;
+    /// #
+    /// let mut search_index: SearchIndex<usize> = SearchIndexBuilder::default()
+    ///     .max_string_len(None)
+    ///     .build();
+    ///
+    /// search_index.insert(&0, &"Harold Godwinson");
+    /// search_index.insert(&1, &"Harold Hardrada");
+    ///
+    /// assert_eq!(
+    ///     search_index.export_dictionary(1),
+    ///     vec![
+    ///         ("godwinson".to_string(), 1),
+    ///         ("hardrada".to_string(), 1),
+    ///         ("harold".to_string(), 2),
+    ///     ]
+    /// );
+    ///
+    /// assert_eq!(search_index.export_dictionary(2), vec![("harold".to_string(), 2)]);
+    /// ```
+
+    #[tracing::instrument(level = "trace", name = "export dictionary", skip(self))]
+    pub fn export_dictionary(&self, minimum_keys: usize) -> Vec<(String, usize)> {
+        self
+            // Compacted whole strings are exported too:
+            .keyword_entries("")
+            // The `dump_keyword` isn't a word:
+            .filter(|(keyword, _keys)| self.dump_keyword.as_ref() != Some(*keyword))
+            // Only keep keywords that are frequent enough:
+            .filter(|(_keyword, keys)| keys.len() >= minimum_keys)
+            .map(|(keyword, keys)| (keyword.to_string(), keys.len()))
+            .collect()
+    } // fn
+
+} // impl
\ No newline at end of file
@@ -0,0 +1,61 @@
+use crate::simple::search_index::SearchIndex;
+use std::cmp::Ord;
+
+// -----------------------------------------------------------------------------
+
+impl<K: Ord> SearchIndex<K> {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns the keyword exclusion list in the Apache Solr & Lucene
+    /// `stopwords.txt` format (one keyword per line), so that it can be shared
+    /// with other search tools. It can be loaded again with the
+    /// `stop_words_file` builder method.
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # use indicium::simple::{SearchIndex, SearchIndexBuilder};
+    /// # use pretty_assertions::assert_eq;
+    /// #
+    /// let search_index: SearchIndex<usize> = SearchIndexBuilder::default()
+    ///     .exclude_keywords(Some(vec!["the".to_string(), "and".to_string()]))
+    ///     .build();
+    ///
+    /// assert_eq!(search_index.export_stop_words(), "the\nand\n");
+    /// ```
+
+    pub fn export_stop_words(&self) -> String {
+        self.exclude_keywords
+            .iter()
+            .flatten()
+            .map(|keyword| format!("{keyword}\n"))
+            .collect()
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns the synonym rules in the Apache Solr & Lucene `synonyms.txt`
+    /// format. It can be loaded again with the `synonyms_file` builder method.
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # use indicium::simple::{SearchIndex, SearchIndexBuilder, Synonyms};
+    /// # use pretty_assertions::assert_eq;
+    /// #
+    /// let search_index: SearchIndex<usize> = SearchIndexBuilder::default()
+    ///     .synonyms(Some(Synonyms::parse("couch, sofa")))
+    ///     .build();
+    ///
+    /// assert_eq!(search_index.export_synonyms(), "couch, sofa\n");
+    /// ```
+
+    pub fn export_synonyms(&self) -> String {
+        self.synonyms
+            .as_ref()
+            .map(|synonyms| synonyms.to_string())
+            .unwrap_or_default()
+    } // fn
+
+} // impl
\ No newline at end of file
@@ -0,0 +1,140 @@
+use crate::simple::search_index::SearchIndex;
+use std::hash::{Hash, Hasher};
+use std::cmp::Ord;
+
+// -----------------------------------------------------------------------------
+//
+/// A 64-bit [FNV-1a](http://www.isthe.com/chongo/tech/comp/fnv/) hasher. Unlike
+/// the standard library's `DefaultHasher`, its output is specified and won't
+/// change between Rust releases.
+
+struct Fnv1a(u64);
+
+impl Default for Fnv1a {
+    fn default() -> Self {
+        Fnv1a(0xcbf2_9ce4_8422_2325)
+    } // fn
+} // impl
+
+impl Hasher for Fnv1a {
+    fn write(&mut self, bytes: &[u8]) {
+        bytes.iter().for_each(|byte| {
+            self.0 ^= u64::from(*byte);
+            self.0 = self.0.wrapping_mul(0x0100_0000_01b3);
+        }); // for_each
+    } // fn
+
+    fn finish(&self) -> u64 {
+        self.0
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl<K: Hash + Ord> SearchIndex<K> {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns a stable hash of the search index's content: its keywords, the
+    /// keys attached to each keyword, and its settings. Two search indexes
+    /// built independently from the same records & settings have the same
+    /// fingerprint. This may be used to verify deployments, or as a cache key.
+    ///
+    /// The fingerprint is computed with FNV-1a, so it's stable between Rust
+    /// releases. Keys and settings are hashed with their `Hash`
+    /// implementations, so fingerprints may differ between platforms of
+    /// different endianness or pointer width. Caches, such as the keyword
+    /// cache, are not included.
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # use indicium::simple::SearchIndex;
+    /// #
+    /// let mut first: SearchIndex<usize> = SearchIndex::default();
+    /// first.insert(&0, &"William the Conqueror");
+    /// first.insert(&1, &"William Rufus");
+    ///
+    /// let mut second: SearchIndex<usize> = SearchIndex::default();
+    /// second.insert(&1, &"William Rufus");
+    /// second.insert(&0, &"William the Conqueror");
+    ///
+    /// assert_eq!(first.fingerprint(), second.fingerprint());
+    ///
+    /// second.insert(&2, &"Henry Beauclerc");
+    ///
+    /// assert_ne!(first.fingerprint(), second.fingerprint());
+    /// ```
+
+    #[tracing::instrument(level = "trace", name = "fingerprint", skip(self))]
+    pub fn fingerprint(&self) -> u64 {
+
+        let mut hasher = Fnv1a::default();
+
+        // Keywords and their keys, including compacted whole strings (see
+        // `compact_whole_strings`). `BTreeMap` & `BTreeSet` iterate in order,
+        // so the insertion order and compaction don't matter:
+        (self.b_tree_map.len() + self.whole_strings.len()).hash(&mut hasher);
+        self.keyword_entries("").for_each(|(keyword, keys)| {
+            keyword.as_str().hash(&mut hasher);
+            keys.len().hash(&mut hasher);
+            keys.iter().for_each(|key| key.hash(&mut hasher));
+        }); // for_each
+
+        // Settings:
+        self.search_type.hash(&mut hasher);
+        self.autocomplete_type.hash(&mut hasher);
+        self.strsim_metric.hash(&mut hasher);
+        self.eddie_metric.hash(&mut hasher);
+        self.fuzzy_backend.hash(&mut hasher);
+        self.fuzzy_length.hash(&mut hasher);
+        self.fuzzy_minimum_score.to_bits().hash(&mut hasher);
+        self.split_pattern.hash(&mut hasher);
+        self.case_sensitive.hash(&mut hasher);
+        self.minimum_keyword_length.hash(&mut hasher);
+        self.maximum_keyword_length.hash(&mut hasher);
+        self.maximum_string_length.hash(&mut hasher);
+        self.exclude_keywords
+            .as_ref()
+            .map(|keywords| keywords.iter().map(|keyword| keyword.as_str()).collect::<Vec<&str>>())
+            .hash(&mut hasher);
+        self.maximum_autocomplete_options.hash(&mut hasher);
+        self.maximum_search_results.hash(&mut hasher);
+        self.maximum_keys_per_keyword.hash(&mut hasher);
+        self.dump_keyword.as_ref().map(|keyword| keyword.as_str()).hash(&mut hasher);
+        self.maximum_keywords.hash(&mut hasher);
+        self.interior_autocomplete.hash(&mut hasher);
+        self.posting_times.is_some().hash(&mut hasher);
+        self.changelog.is_some().hash(&mut hasher);
+        self.grapheme_lengths.hash(&mut hasher);
+        self.front_map.is_some().hash(&mut hasher);
+        self.permuterm.is_some().hash(&mut hasher);
+        self.sorted_postings.is_some().hash(&mut hasher);
+        self.minimum_keys_for_autocomplete.hash(&mut hasher);
+        self.maximum_keywords_per_record.hash(&mut hasher);
+        self.keyword_cap_policy.hash(&mut hasher);
+        self.maximum_query_length.hash(&mut hasher);
+        self.maximum_query_keywords.hash(&mut hasher);
+        self.maximum_fuzzy_candidates.hash(&mut hasher);
+        self.adaptive_fuzzy_length.hash(&mut hasher);
+        self.fuzzy_first_letter_fallback.hash(&mut hasher);
+        self.query_limit_policy.hash(&mut hasher);
+        self.duplicate_key_policy.hash(&mut hasher);
+        self.context_window.hash(&mut hasher);
+        self.preserve_query_prefix.hash(&mut hasher);
+        self.suggestion_blocklist
+            .as_ref()
+            .map(|keywords| keywords.iter().map(|keyword| keyword.as_str()).collect::<Vec<&str>>())
+            .hash(&mut hasher);
+        self.shingles.hash(&mut hasher);
+        self.char_filter.hash(&mut hasher);
+        self.elision.hash(&mut hasher);
+        self.plural_variants.hash(&mut hasher);
+        self.synonyms.hash(&mut hasher);
+        self.transliteration.hash(&mut hasher);
+
+        hasher.finish()
+
+    } // fn
+
+} // impl
\ No newline at end of file
@@ -0,0 +1,60 @@
+use crate::simple::{indexable::Indexable, search_index::SearchIndex};
+use std::{clone::Clone, cmp::Ord, iter::FromIterator};
+
+// -----------------------------------------------------------------------------
+//
+/// Builds a search index with the default settings from an iterator of
+/// key-value pairs. This allows a caller to `.collect()` records into a
+/// `SearchIndex`. Use `SearchIndexBuilder` and `build_from_iter` to choose the
+/// search index's settings.
+///
+/// Basic usage:
+///
+/// ```rust
+/// # use indicium::simple::SearchIndex;
+/// # use pretty_assertions::assert_eq;
+/// #
+/// let search_index: SearchIndex<usize> = vec!["William the Conqueror", "William Rufus"]
+///     .into_iter()
+///     .enumerate()
+///     .collect();
+///
+/// assert_eq!(search_index.search("william"), vec![&0, &1]);
+/// ```
+
+impl<K: Clone + Ord, V: Indexable> FromIterator<(K, V)> for SearchIndex<K> {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(records: I) -> Self {
+        let mut search_index = SearchIndex::default();
+        search_index.build_from_iter(records, |_progress| {});
+        search_index
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+//
+/// Builds a search index with the default settings from a slice of records.
+/// Each record is keyed by its position in the slice, so the search results
+/// can be used to index back into the slice.
+///
+/// Basic usage:
+///
+/// ```rust
+/// # use indicium::simple::SearchIndex;
+/// # use pretty_assertions::assert_eq;
+/// #
+/// let kings = ["William the Conqueror", "William Rufus", "Henry Beauclerc"];
+/// let search_index = SearchIndex::from(&kings[..]);
+///
+/// assert_eq!(search_index.search("henry"), vec![&2]);
+/// ```
+
+impl<T: Indexable> From<&[T]> for SearchIndex<usize> {
+    fn from(records: &[T]) -> Self {
+        let mut search_index = SearchIndex::default();
+        records
+            .iter()
+            .enumerate()
+            .for_each(|(key, record)| search_index.insert(&key, record));
+        search_index
+    } // fn
+} // impl
\ No newline at end of file
@@ -0,0 +1,275 @@
+use crate::simple::search_index::SearchIndex;
+use std::{clone::Clone, cmp::Ord, cmp::Ordering};
+
+// -----------------------------------------------------------------------------
+//
+/// The number of keywords in each front-coded block. The first keyword of each
+/// block is stored in full, so that lookups can binary search the blocks.
+
+const BLOCK_SIZE: usize = 16;
+
+// -----------------------------------------------------------------------------
+//
+/// A read-only copy of a search index, created with `SearchIndex::freeze`.
+///
+/// Keywords are stored with front coding (prefix compression): in each block
+/// of 16 sorted keywords, every keyword after the first only stores the bytes
+/// that differ from the preceding keyword. Keywords in corpora such as URLs or
+/// product codes share long prefixes, so this uses much less memory than the
+/// `BTreeMap` while still supporting exact lookups and prefix scans.
+///
+/// Basic usage:
+///
+/// ```rust
+/// # use indicium::simple::SearchIndex;
+/// #
+/// let mut search_index: SearchIndex<usize> = SearchIndex::default();
+/// search_index.insert(&0, &"International Internship");
+/// search_index.insert(&1, &"Internal Interface");
+///
+/// let frozen = search_index.freeze();
+///
+/// assert_eq!(frozen.keys("internship"), &[0]);
+/// assert_eq!(frozen.keys("Interface"), &[1]);
+/// assert_eq!(frozen.keys("inter"), &[] as &[usize]);
+/// assert_eq!(
+///     frozen.keywords_with_prefix("internat", &10),
+///     vec!["international".to_string(), "international internship".to_string()]
+/// );
+/// ```
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct FrozenIndex<K> {
+    /// Front-coded keywords. Each entry is the length of the prefix shared
+    /// with the preceding keyword, the length of the remaining suffix, and the
+    /// suffix bytes. The lengths are LEB128 varints.
+    bytes: Vec<u8>,
+    /// The offset in `bytes` of the start of each block.
+    blocks: Vec<usize>,
+    /// The keys attached to each keyword, in keyword order.
+    postings: Vec<Box<[K]>>,
+    /// Whether keywords are case sensitive. If not, lookups are lowercased.
+    case_sensitive: bool,
+} // FrozenIndex
+
+// -----------------------------------------------------------------------------
+
+impl<K: Clone + Ord> SearchIndex<K> {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns a read-only, prefix-compressed copy of the search index. See
+    /// [`FrozenIndex`] for more information.
+    ///
+    /// [`FrozenIndex`]: struct.FrozenIndex.html
+
+    #[tracing::instrument(level = "trace", name = "search index freeze", skip(self))]
+    pub fn freeze(&self) -> FrozenIndex<K> {
+        let mut bytes: Vec<u8> = Vec::new();
+        let mut blocks: Vec<usize> = Vec::new();
+        let mut postings: Vec<Box<[K]>> = Vec::with_capacity(self.b_tree_map.len() + self.whole_strings.len());
+        let mut previous: &str = "";
+
+        // Compacted whole strings (see `compact_whole_strings`) are frozen
+        // with the other keywords:
+        self.keyword_entries("")
+            .enumerate()
+            .for_each(|(ordinal, (keyword, keys))| {
+                // Start a new block every `BLOCK_SIZE` keywords. The first
+                // keyword of a block doesn't share a prefix:
+                let shared: usize = if ordinal % BLOCK_SIZE == 0 {
+                    blocks.push(bytes.len());
+                    0
+                } else {
+                    shared_prefix(previous, keyword)
+                }; // if
+
+                let suffix: &[u8] = &keyword.as_bytes()[shared..];
+                write_varint(&mut bytes, shared);
+                write_varint(&mut bytes, suffix.len());
+                bytes.extend_from_slice(suffix);
+
+                postings.push(keys.iter().cloned().collect());
+                previous = keyword;
+            }); // for_each
+
+        bytes.shrink_to_fit();
+
+        FrozenIndex {
+            bytes,
+            blocks,
+            postings,
+            case_sensitive: self.case_sensitive,
+        } // FrozenIndex
+    } // fn
+
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl<K> FrozenIndex<K> {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns the number of keywords.
+
+    pub fn len(&self) -> usize {
+        self.postings.len()
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns `true` if there are no keywords.
+
+    pub fn is_empty(&self) -> bool {
+        self.postings.is_empty()
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns the number of bytes used to store the keywords.
+
+    pub fn keyword_bytes(&self) -> usize {
+        self.bytes.len()
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns the keys attached to the keyword, using an exact match. Returns
+    /// an empty slice if the keyword isn't in the index.
+
+    pub fn keys(&self, keyword: &str) -> &[K] {
+        let keyword: String = self.normalize(keyword);
+
+        // Scan the block that could contain the keyword:
+        let Some(block) = self.find_block(&keyword) else { return &[] };
+
+        self.decode_from(block)
+            .take(BLOCK_SIZE)
+            .find(|(_ordinal, index_keyword)| index_keyword.as_str() >= keyword.as_str())
+            .filter(|(_ordinal, index_keyword)| *index_keyword == keyword)
+            .map_or(&[], |(ordinal, _index_keyword)| &self.postings[ordinal])
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns up to `maximum_keywords` keywords that start with the prefix, in
+    /// lexographic order.
+
+    pub fn keywords_with_prefix(&self, prefix: &str, maximum_keywords: &usize) -> Vec<String> {
+        let prefix: String = self.normalize(prefix);
+
+        // Start scanning at the block that could contain the first match. If
+        // the prefix sorts before every keyword, start at the first block:
+        let block: usize = self.find_block(&prefix).unwrap_or(0);
+
+        self.decode_from(block)
+            .map(|(_ordinal, keyword)| keyword)
+            .skip_while(|keyword| keyword.as_str() < prefix.as_str())
+            .take_while(|keyword| keyword.starts_with(&prefix))
+            .take(*maximum_keywords)
+            .collect()
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Normalizes a keyword according to the index's case sensitivity.
+
+    fn normalize(&self, keyword: &str) -> String {
+        match self.case_sensitive {
+            true => keyword.to_string(),
+            false => keyword.to_lowercase(),
+        } // match
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns the last block whose first keyword sorts at or before the given
+    /// keyword, or `None` if the keyword sorts before every block.
+
+    fn find_block(&self, keyword: &str) -> Option<usize> {
+        // `partition_point` returns the first block whose first keyword sorts
+        // after the given keyword:
+        let after: usize = self.blocks.partition_point(|offset| {
+            let (_shared, first, _next) = read_entry(&self.bytes, *offset);
+            first.cmp(keyword.as_bytes()) != Ordering::Greater
+        }); // partition_point
+
+        after.checked_sub(1)
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Decodes keywords (and their ordinals) starting at the given block, and
+    /// continuing to the end of the index.
+
+    fn decode_from(&self, block: usize) -> impl Iterator<Item = (usize, String)> + '_ {
+        let mut offset: usize = self.blocks.get(block).copied().unwrap_or(self.bytes.len());
+        let mut ordinal: usize = block * BLOCK_SIZE;
+        let mut keyword: Vec<u8> = Vec::new();
+
+        std::iter::from_fn(move || {
+            if offset >= self.bytes.len() { return None }
+            let (shared, suffix, next) = read_entry(&self.bytes, offset);
+            keyword.truncate(shared);
+            keyword.extend_from_slice(suffix);
+            offset = next;
+            ordinal += 1;
+            // Keywords are split on `char` boundaries when encoded, so the
+            // decoded bytes are valid UTF-8:
+            Some((ordinal - 1, String::from_utf8_lossy(&keyword).into_owned()))
+        }) // from_fn
+    } // fn
+
+} // impl
+
+// -----------------------------------------------------------------------------
+//
+/// Returns the length in bytes of the prefix shared by two strings, rounded
+/// down to a `char` boundary.
+
+fn shared_prefix(a: &str, b: &str) -> usize {
+    a.char_indices()
+        .zip(b.chars())
+        .find(|((_index, a_char), b_char)| a_char != b_char)
+        .map_or_else(|| a.len().min(b.len()), |((index, _a_char), _b_char)| index)
+} // fn
+
+// -----------------------------------------------------------------------------
+//
+/// Appends a LEB128 varint.
+
+fn write_varint(bytes: &mut Vec<u8>, mut value: usize) {
+    while value >= 0x80 {
+        bytes.push((value as u8 & 0x7f) | 0x80);
+        value >>= 7;
+    } // while
+    bytes.push(value as u8);
+} // fn
+
+// -----------------------------------------------------------------------------
+//
+/// Reads a LEB128 varint. Returns the value and the offset after it.
+
+fn read_varint(bytes: &[u8], mut offset: usize) -> (usize, usize) {
+    let mut value: usize = 0;
+    let mut shift: u32 = 0;
+    loop {
+        let byte: u8 = bytes[offset];
+        offset += 1;
+        value |= usize::from(byte & 0x7f) << shift;
+        if byte & 0x80 == 0 { return (value, offset) }
+        shift += 7;
+    } // loop
+} // fn
+
+// -----------------------------------------------------------------------------
+//
+/// Reads a front-coded entry. Returns the shared prefix length, the suffix
+/// bytes, and the offset of the next entry.
+
+fn read_entry(bytes: &[u8], offset: usize) -> (usize, &[u8], usize) {
+    let (shared, offset) = read_varint(bytes, offset);
+    let (length, offset) = read_varint(bytes, offset);
+    (shared, &bytes[offset..offset + length], offset + length)
+} // fn
\ No newline at end of file
@@ -0,0 +1,97 @@
+use crate::simple::{FuzzyBackendChoice, SearchIndex};
+use std::cmp::Ord;
+
+// -----------------------------------------------------------------------------
+
+impl<K: Ord> SearchIndex<K> {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns the crate that's used for fuzzy matching. If the
+    /// `fuzzy_backend` setting is `FuzzyBackendChoice::Auto`, this is the
+    /// crate that was chosen for the search index's keywords, so it's never
+    /// `Auto`.
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # use indicium::simple::{FuzzyBackendChoice, SearchIndex, SearchIndexBuilder};
+    /// #
+    /// let search_index: SearchIndex<usize> = SearchIndexBuilder::default()
+    ///     .fuzzy_backend(FuzzyBackendChoice::Auto)
+    ///     .build();
+    ///
+    /// assert_ne!(search_index.active_fuzzy_backend(), FuzzyBackendChoice::Auto);
+    /// assert_eq!(search_index.active_fuzzy_backend(), search_index.runtime_stats().fuzzy_backend);
+    /// ```
+
+    pub fn active_fuzzy_backend(&self) -> FuzzyBackendChoice {
+        self.active_fuzzy_backend
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Chooses the crate that's used for fuzzy matching from the
+    /// `fuzzy_backend` setting, and returns it. If the setting is
+    /// `FuzzyBackendChoice::Auto` and both the `eddie` and `strsim` features
+    /// are enabled, the crate is chosen from the number of keywords in the
+    /// search index and their average length. See [`FuzzyBackendChoice`].
+    ///
+    /// This is done when the search index is built, loaded, or rebuilt with
+    /// `build_from_iter`. It may be called again after many records have been
+    /// inserted, so that the choice reflects the current keywords.
+    ///
+    /// [`FuzzyBackendChoice`]: enum.FuzzyBackendChoice.html
+    ///
+    /// If the setting names a crate whose feature isn't enabled, the default
+    /// crate is used instead.
+
+    #[tracing::instrument(level = "trace", name = "choose fuzzy backend", skip(self))]
+    pub fn choose_fuzzy_backend(&mut self) -> FuzzyBackendChoice {
+
+        self.active_fuzzy_backend = match self.fuzzy_backend {
+            #[cfg(all(feature = "eddie", feature = "strsim"))]
+            FuzzyBackendChoice::Auto => crate::simple::internal::auto_fuzzy_backend(
+                &self.b_tree_map,
+                self.eddie_metric.as_ref(),
+                self.strsim_metric.as_ref(),
+            ), // auto_fuzzy_backend
+            FuzzyBackendChoice::Eddie if cfg!(feature = "eddie") => FuzzyBackendChoice::Eddie,
+            FuzzyBackendChoice::Strsim if cfg!(feature = "strsim") => FuzzyBackendChoice::Strsim,
+            // Only one crate is available, or the chosen crate's feature isn't
+            // enabled:
+            _ => FuzzyBackendChoice::default(),
+        }; // match
+
+        // For debug builds:
+        #[cfg(debug_assertions)]
+        tracing::debug!("{:?} chosen for fuzzy matching.", self.active_fuzzy_backend);
+
+        self.active_fuzzy_backend
+
+    } // fn
+
+} // impl
+// -----------------------------------------------------------------------------
+
+impl<K: Ord> SearchIndex<K> {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns `true` if the `eddie` crate is used for fuzzy matching.
+
+    #[cfg(feature = "eddie")]
+    pub(crate) fn uses_eddie(&self) -> bool {
+        self.active_fuzzy_backend == FuzzyBackendChoice::Eddie
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns `true` if the `strsim` crate is used for fuzzy matching.
+
+    #[cfg(feature = "strsim")]
+    pub(crate) fn uses_strsim(&self) -> bool {
+        self.active_fuzzy_backend == FuzzyBackendChoice::Strsim
+    } // fn
+
+} // impl
\ No newline at end of file
@@ -0,0 +1,23 @@
+// -----------------------------------------------------------------------------
+//
+/// This is used to select the crate that's used for fuzzy matching when both
+/// the `eddie` and `strsim` features are enabled.
+
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum FuzzyBackendChoice {
+    /// Ilia Schelokov's [eddie](https://crates.io/crates/eddie) crate. This is
+    /// the default if the `eddie` feature is enabled.
+    #[cfg_attr(feature = "eddie", default)]
+    Eddie,
+    /// Danny Guo's [strsim](https://crates.io/crates/strsim) crate. This is
+    /// the default if only the `strsim` feature is enabled.
+    #[cfg_attr(not(feature = "eddie"), default)]
+    Strsim,
+    /// The crate is chosen from the search index's keywords when the search
+    /// index is built (or loaded): `eddie` for dictionaries of 10,000 or more
+    /// keywords, or whose keywords average more than 12 characters, and
+    /// `strsim` otherwise. The choice is reported by `active_fuzzy_backend`
+    /// and `runtime_stats`.
+    Auto,
+} // FuzzyBackendChoice
\ No newline at end of file
@@ -0,0 +1,114 @@
+use crate::simple::{EddieMetric, FuzzyBackendChoice, SearchIndex, StrsimMetric};
+use crate::Error;
+use std::cmp::Ord;
+
+// -----------------------------------------------------------------------------
+//
+/// The fuzzy matching settings of a `SearchIndex`, gathered in one place: the
+/// string similarity metric of each crate, the length of the prefix that
+/// candidates must share with the user's keyword (or how it's adapted to the
+/// dictionary), whether that prefix is relaxed when nothing matches, the
+/// minimum score, and the crate that's used (see [`FuzzyBackendChoice`]). These settings are applied
+/// to both the `eddie` and `strsim` crates.
+///
+/// The settings may be given to `SearchIndexBuilder::fuzzy_config`, or changed
+/// on an existing search index with `SearchIndex::set_fuzzy_config` without
+/// rebuilding it.
+///
+/// [`FuzzyBackendChoice`]: enum.FuzzyBackendChoice.html
+
+#[derive(Clone, Debug, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct FuzzyConfig {
+    pub strsim_metric: Option<StrsimMetric>,
+    pub eddie_metric: Option<EddieMetric>,
+    pub length: usize,
+    pub minimum_score: f64,
+    pub backend: FuzzyBackendChoice,
+    pub adaptive_length: Option<usize>,
+    pub first_letter_fallback: bool,
+} // FuzzyConfig
+
+// -----------------------------------------------------------------------------
+
+impl Default for FuzzyConfig {
+    fn default() -> Self {
+        SearchIndex::<usize>::default().fuzzy_config()
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl<K: Ord> SearchIndex<K> {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns the search index's fuzzy matching settings. See [`FuzzyConfig`]
+    /// for more information.
+    ///
+    /// [`FuzzyConfig`]: struct.FuzzyConfig.html
+
+    #[tracing::instrument(level = "trace", name = "fuzzy config", skip(self))]
+    pub fn fuzzy_config(&self) -> FuzzyConfig {
+        FuzzyConfig {
+            strsim_metric: self.strsim_metric.clone(),
+            eddie_metric: self.eddie_metric.clone(),
+            length: self.fuzzy_length,
+            minimum_score: self.fuzzy_minimum_score,
+            backend: self.fuzzy_backend,
+            adaptive_length: self.adaptive_fuzzy_length,
+            first_letter_fallback: self.fuzzy_first_letter_fallback,
+        } // FuzzyConfig
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Updates the search index's fuzzy matching settings in place. The
+    /// keywords & keys are left untouched, the fuzzy matching crate is chosen
+    /// again (see `choose_fuzzy_backend`), and cached autocompletion options
+    /// are discarded since they may no longer apply.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error::InvalidSetting` error if the minimum score is `NaN`.
+    /// The settings are left unchanged. Minimum scores outside of `0.0` to
+    /// `1.0` are saturated to that range.
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # use indicium::simple::{EddieMetric, SearchIndex};
+    /// # use pretty_assertions::assert_eq;
+    /// #
+    /// let mut search_index: SearchIndex<usize> = SearchIndex::default();
+    /// search_index.insert(&0, &"Harold Godwinson");
+    ///
+    /// // Fuzzy matching finds "harold" from the first character:
+    /// let mut fuzzy_config = search_index.fuzzy_config();
+    /// fuzzy_config.eddie_metric = Some(EddieMetric::JaroWinkler);
+    /// fuzzy_config.length = 1;
+    /// search_index.set_fuzzy_config(fuzzy_config).unwrap();
+    ///
+    /// assert_eq!(
+    ///     search_index.autocomplete("hsrold"),
+    ///     vec!["harold".to_string(), "harold godwinson".to_string()]
+    /// );
+    /// assert_eq!(search_index.fuzzy_config().length, 1);
+    /// ```
+
+    #[tracing::instrument(level = "trace", name = "set fuzzy config", skip(self))]
+    pub fn set_fuzzy_config(&mut self, fuzzy_config: FuzzyConfig) -> Result<(), Error> {
+        self.set_fuzzy_minimum_score(fuzzy_config.minimum_score)?;
+        self.strsim_metric = fuzzy_config.strsim_metric;
+        self.eddie_metric = fuzzy_config.eddie_metric;
+        self.fuzzy_length = fuzzy_config.length;
+        self.fuzzy_backend = fuzzy_config.backend;
+        self.adaptive_fuzzy_length = fuzzy_config.adaptive_length;
+        self.fuzzy_first_letter_fallback = fuzzy_config.first_letter_fallback;
+        self.choose_fuzzy_backend();
+        // Fuzzy matching changes which keywords are suggested:
+        self.invalidate_autocompletions();
+        Ok(())
+    } // fn
+
+} // impl
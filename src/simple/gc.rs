@@ -0,0 +1,121 @@
+use crate::simple::search_index::SearchIndex;
+use kstring::KString;
+use std::sync::Arc;
+use std::{clone::Clone, cmp::Ord};
+
+// -----------------------------------------------------------------------------
+//
+/// Describes what was reclaimed by [`SearchIndex::gc`].
+///
+/// [`SearchIndex::gc`]: struct.SearchIndex.html#method.gc
+
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct GcReport {
+    /// The keywords that were removed because they had no keys left, in
+    /// lexographic order.
+    pub keywords: Vec<String>,
+    /// The number of keys whose empty entries were removed from the reverse
+    /// map or the posting timestamps.
+    pub keys: usize,
+} // GcReport
+
+// -----------------------------------------------------------------------------
+
+impl GcReport {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns `true` if nothing was reclaimed.
+
+    pub fn is_empty(&self) -> bool {
+        self.keywords.is_empty() && self.keys == 0
+    } // fn
+
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl<K: Clone + Ord> SearchIndex<K> {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Removes keywords that no longer have any keys attached, and the empty
+    /// per-key entries of the reverse map and posting timestamps, and reports
+    /// what was reclaimed.
+    ///
+    /// `insert`, `remove`, `replace` and `decay` already remove keywords as
+    /// soon as their last key is detached, so this is only needed after the
+    /// keywords have been changed directly through `DerefMut`.
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # use indicium::simple::{GcReport, SearchIndex};
+    /// # use pretty_assertions::assert_eq;
+    /// #
+    /// let mut search_index: SearchIndex<usize> = SearchIndex::default();
+    /// search_index.insert(&0, &"Harold");
+    /// search_index.insert(&1, &"Edgar");
+    ///
+    /// // Detach a key directly, leaving its keyword without keys:
+    /// search_index.get_mut("edgar").unwrap().clear();
+    ///
+    /// assert_eq!(
+    ///     search_index.gc(),
+    ///     GcReport { keywords: vec!["edgar".to_string()], keys: 0 }
+    /// );
+    ///
+    /// assert!(search_index.gc().is_empty());
+    /// ```
+
+    #[tracing::instrument(level = "trace", name = "garbage collect", skip(self))]
+    pub fn gc(&mut self) -> GcReport {
+
+        let empty: Vec<KString> = self.b_tree_map
+            .iter()
+            .filter(|(_keyword, keys)| keys.is_empty())
+            .map(|(keyword, _keys)| keyword.clone())
+            .collect();
+
+        // Only copy keywords shared with a snapshot if there's something to
+        // remove:
+        if !empty.is_empty() {
+            let b_tree_map = Arc::make_mut(&mut self.b_tree_map);
+            empty.iter().for_each(|keyword| {
+                b_tree_map.remove(keyword);
+                self.keyword_usage.forget(keyword);
+                self.whole_string_keywords.remove(keyword);
+            }); // for_each
+            self.sync_mirrors(&empty);
+        } // if
+
+        let mut keys: usize = 0;
+
+        if let Some(reverse_map) = &mut self.reverse_map {
+            let before = reverse_map.len();
+            reverse_map.retain(|_key, keywords| !keywords.is_empty());
+            keys += before - reverse_map.len();
+        } // if
+
+        if let Some(posting_times) = &mut self.posting_times {
+            let before = posting_times.len();
+            posting_times.retain(|_key, keyword_times| !keyword_times.is_empty());
+            keys += before - posting_times.len();
+        } // if
+
+        // Record the size of the search index for metrics:
+        #[cfg(feature = "metrics")]
+        crate::simple::internal::metrics::record_index_size(self.b_tree_map.len());
+
+        // For debug builds:
+        #[cfg(debug_assertions)]
+        tracing::debug!("{} empty keywords and {} empty key entries reclaimed.", empty.len(), keys);
+
+        GcReport {
+            keywords: empty.into_iter().map(|keyword| keyword.to_string()).collect(),
+            keys,
+        } // GcReport
+
+    } // fn
+
+} // impl
\ No newline at end of file
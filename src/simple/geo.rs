@@ -0,0 +1,132 @@
+use crate::simple::{indexable::Indexable, search_index::SearchIndex};
+use std::collections::BTreeMap;
+use std::{clone::Clone, cmp::Ord, hash::Hash};
+
+// -----------------------------------------------------------------------------
+
+impl<K: Clone + Ord> SearchIndex<K> {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Inserts a key-value pair into the search index, along with the record's
+    /// location (latitude & longitude in degrees.) Records with a location may
+    /// be found using [`search_near`].
+    ///
+    /// If the record already has a location, it is replaced. Coordinates that
+    /// aren't finite numbers are not stored.
+    ///
+    /// The record's location is removed along with the record by `remove`, and
+    /// kept by `replace`. Use [`remove_location`] to only remove the location.
+    ///
+    /// [`search_near`]: struct.SearchIndex.html#method.search_near
+    /// [`remove_location`]: struct.SearchIndex.html#method.remove_location
+
+    #[tracing::instrument(level = "trace", name = "search index insert with location", skip(self, key, value))]
+    pub fn insert_with_location(
+        &mut self,
+        key: &K,
+        value: &dyn Indexable,
+        latitude: f64,
+        longitude: f64,
+    ) {
+        self.insert(key, value);
+
+        if latitude.is_finite() && longitude.is_finite() {
+            self.geo_index.insert(key, latitude, longitude);
+        } else {
+            // For debug builds:
+            #[cfg(debug_assertions)]
+            tracing::warn!("Location ({}, {}) is not valid and was not stored.", latitude, longitude);
+        } // if
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Removes a record's location from the search index, if it has one. The
+    /// record's keywords are not removed.
+
+    pub fn remove_location(&mut self, key: &K) {
+        self.geo_index.remove(key);
+    } // fn
+
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl<'a, K: 'a + Hash + Ord> SearchIndex<K> {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns a record's location (latitude & longitude in degrees), if it has
+    /// one.
+
+    pub fn location(&self, key: &K) -> Option<(f64, f64)> {
+        self.geo_index.get(key).copied()
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Searches for records that match the search string and are located
+    /// within `radius_km` kilometres of a point (latitude & longitude in
+    /// degrees.) This is useful for "store locator" style searches.
+    ///
+    /// The search string is matched according to the search index's
+    /// `SearchType`. Records without a location are never returned. The
+    /// results are sorted nearest first and are returned with their distance
+    /// from the point, in kilometres. The `maximum_search_results` setting is
+    /// observed.
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # use indicium::simple::SearchIndex;
+    /// # use pretty_assertions::assert_eq;
+    /// #
+    /// let mut search_index: SearchIndex<usize> = SearchIndex::default();
+    ///
+    /// search_index.insert_with_location(&0, &"Coffee Shop Vancouver", 49.2827, -123.1207);
+    /// search_index.insert_with_location(&1, &"Coffee Shop Burnaby", 49.2488, -122.9805);
+    /// search_index.insert_with_location(&2, &"Coffee Shop Toronto", 43.6532, -79.3832);
+    ///
+    /// let search_results: Vec<usize> = search_index
+    ///     .search_near("coffee", 49.2500, -123.0000, 25.0)
+    ///     .into_iter()
+    ///     .map(|(key, _distance)| *key)
+    ///     .collect();
+    ///
+    /// assert_eq!(search_results, vec![1, 0]);
+    /// ```
+
+    #[tracing::instrument(level = "trace", name = "search near", skip(self))]
+    pub fn search_near(
+        &'a self,
+        string: &'a str,
+        latitude: f64,
+        longitude: f64,
+        radius_km: f64,
+    ) -> Vec<(&'a K, f64)> {
+
+        // Find every record within the search radius first, so that records
+        // outside of it don't use up room in the text search results:
+        let nearby: BTreeMap<&K, f64> = self.geo_index.near(latitude, longitude, radius_km);
+
+        let mut search_results: Vec<(&'a K, f64)> = self
+            .search_with_filtered(
+                &self.search_type,
+                &nearby.len(),
+                string,
+                &|key| nearby.contains_key(key),
+            ) // search_with_filtered
+            .into_iter()
+            .filter_map(|key| nearby.get(key).map(|distance| (key, *distance)))
+            .collect();
+
+        // Sort the search results nearest first:
+        search_results.sort_by(|(_, a), (_, b)| a.total_cmp(b));
+        search_results.truncate(self.maximum_search_results);
+
+        search_results
+
+    } // fn
+
+} // impl
\ No newline at end of file
@@ -0,0 +1,209 @@
+use crate::simple::{indexable::Indexable, search_index::SearchIndex, KeywordCapPolicy};
+use crate::Error;
+use std::{clone::Clone, cmp::Ord};
+
+// -----------------------------------------------------------------------------
+//
+/// A record's strings and field names (see `Indexable::field_names`),
+/// captured when the record was added to a batch. This lets the batch outlive
+/// the record.
+
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub(crate) struct Strings(pub(crate) Vec<String>, pub(crate) Vec<String>);
+
+impl Strings {
+    /// Captures a record's strings and field names.
+    pub(crate) fn of(value: &dyn Indexable) -> Self {
+        Strings(value.strings(), value.field_names())
+    } // fn
+} // impl
+
+impl Indexable for Strings {
+    fn strings(&self) -> Vec<String> {
+        self.0.clone()
+    } // fn
+
+    fn field_names(&self) -> Vec<String> {
+        self.1.clone()
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+//
+/// A single change collected in an `IndexBatch`.
+
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+enum BatchOp<K> {
+    Insert(K, Strings),
+    Remove(K, Strings),
+    Replace(K, Strings, Strings),
+} // BatchOp
+
+// -----------------------------------------------------------------------------
+//
+/// A batch of inserts, removes and replaces that are applied to a search index
+/// together with `SearchIndex::apply`.
+///
+/// Each record's strings are captured when it's added to the batch, so the
+/// batch doesn't borrow the records. This allows a batch to be built from a
+/// change-stream message before locking the search index.
+///
+/// Since `apply` takes the search index mutably, readers sharing the search
+/// index through a lock (such as `RwLock<SearchIndex<K>>`) either see all of
+/// the batch's changes or none of them. If any change is rejected, none of
+/// them are applied.
+
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct IndexBatch<K> {
+    ops: Vec<BatchOp<K>>,
+} // IndexBatch
+
+// -----------------------------------------------------------------------------
+
+impl<K> Default for IndexBatch<K> {
+    fn default() -> Self {
+        IndexBatch { ops: Vec::new() }
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl<K> IndexBatch<K> {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Instantiates a new, empty batch.
+
+    pub fn new() -> Self {
+        Self::default()
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Adds an insert to the batch. See `SearchIndex::insert`.
+
+    pub fn insert(&mut self, key: K, value: &dyn Indexable) {
+        self.ops.push(BatchOp::Insert(key, Strings::of(value)));
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Adds a removal to the batch. See `SearchIndex::remove`.
+
+    pub fn remove(&mut self, key: K, value: &dyn Indexable) {
+        self.ops.push(BatchOp::Remove(key, Strings::of(value)));
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Adds a replacement to the batch. See `SearchIndex::replace`.
+
+    pub fn replace(&mut self, key: K, before: &dyn Indexable, after: &dyn Indexable) {
+        self.ops.push(BatchOp::Replace(key, Strings::of(before), Strings::of(after)));
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns the number of changes in the batch.
+
+    pub fn len(&self) -> usize {
+        self.ops.len()
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns `true` if the batch contains no changes.
+
+    pub fn is_empty(&self) -> bool {
+        self.ops.is_empty()
+    } // fn
+
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl<K: Clone + Ord> SearchIndex<K> {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Applies every change in the batch to the search index, in the order
+    /// they were added to the batch. See [`IndexBatch`] for more information.
+    ///
+    /// # Errors
+    ///
+    /// If the `keyword_cap_policy` is `Reject` and any inserted or replacing
+    /// record exceeds the `maximum_keywords_per_record` cap, an
+    /// `Error::KeywordCapExceeded` error is returned. Every record is checked
+    /// before any change is made, so the search index is left untouched.
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # use indicium::simple::{IndexBatch, SearchIndex};
+    /// # use std::sync::RwLock;
+    /// #
+    /// let search_index: RwLock<SearchIndex<usize>> = RwLock::new(SearchIndex::default());
+    /// search_index.write().unwrap().insert(&0, &"Harold Godwinson");
+    ///
+    /// // Collect a change-stream message's changes:
+    /// let mut batch = IndexBatch::new();
+    /// batch.replace(0, &"Harold Godwinson", &"Harold II");
+    /// batch.insert(1, &"Edgar Ætheling");
+    ///
+    /// // Readers see either none or all of the changes:
+    /// search_index.write().unwrap().apply(batch).unwrap();
+    ///
+    /// let search_index = search_index.read().unwrap();
+    /// assert!(search_index.search("godwinson").is_empty());
+    /// assert_eq!(search_index.search("harold"), vec![&0]);
+    /// assert_eq!(search_index.search("edgar"), vec![&1]);
+    /// ```
+    ///
+    /// A rejected record rejects the whole batch:
+    ///
+    /// ```rust
+    /// # use indicium::simple::{IndexBatch, KeywordCapPolicy, SearchIndex, SearchIndexBuilder};
+    /// #
+    /// let mut search_index: SearchIndex<usize> = SearchIndexBuilder::default()
+    ///     .maximum_keywords_per_record(Some(2))
+    ///     .keyword_cap_policy(KeywordCapPolicy::Reject)
+    ///     .build();
+    ///
+    /// let mut batch = IndexBatch::new();
+    /// batch.insert(0, &"Harold");
+    /// batch.insert(1, &"Edgar Ætheling of Wessex");
+    ///
+    /// assert!(search_index.apply(batch).is_err());
+    /// assert!(search_index.search("harold").is_empty());
+    /// ```
+    ///
+    /// [`IndexBatch`]: struct.IndexBatch.html
+
+    #[tracing::instrument(level = "trace", name = "search index apply", skip(self, batch))]
+    pub fn apply(&mut self, batch: IndexBatch<K>) -> Result<(), Error> {
+
+        // Check every record against the keyword cap before changing anything,
+        // so that a rejected record doesn't leave the batch half applied:
+        if let (Some(maximum), KeywordCapPolicy::Reject) = (self.maximum_keywords_per_record, self.keyword_cap_policy) {
+            for op in &batch.ops {
+                let (BatchOp::Insert(_key, value) | BatchOp::Replace(_key, _, value)) = op else { continue };
+                let keywords = self.indexable_keywords(value).len();
+                if keywords > maximum {
+                    return Err(Error::KeywordCapExceeded { keywords, maximum })
+                } // if
+            } // for
+        } // if
+
+        for op in batch.ops {
+            match op {
+                BatchOp::Insert(key, value) => { self.try_insert(&key, &value)?; },
+                BatchOp::Remove(key, value) => self.remove(&key, &value),
+                BatchOp::Replace(key, before, after) => { self.try_replace(&key, &before, &after)?; },
+            } // match
+        } // for
+
+        Ok(())
+
+    } // fn
+
+} // impl
\ No newline at end of file
@@ -35,9 +35,18 @@
 /// `Vec<String>`.
 ///
 /// * Note: Any type that implements `ToString` (and consequently any type that
-/// implements `Display`) to, in turn, gets the `Indexable` implementation for
-/// free. This includes Rust primitives like `String`, `u8`, `u16`, `u32`,
-/// `u64`, `i8`, `i16`, `i32`, `i64` and so on...
+///   implements `Display`) to, in turn, gets the `Indexable` implementation for
+///   free. This includes Rust primitives like `String`, `u8`, `u16`, `u32`,
+///   `u64`, `i8`, `i16`, `i32`, `i64` and so on... It also includes ecosystem
+///   types like `chrono::NaiveDate` and `uuid::Uuid`, which implement
+///   `Display`, so no feature-gated implementations are needed for them.
+///
+/// * Note: Composite types such as `Vec<String>`, `HashMap<String, String>`,
+///   tuples and `Option<T>` can't be given `Indexable` implementations by this
+///   crate. Because of the `ToString` implementation below, the compiler
+///   rejects them as conflicting (E0119): the standard library could implement
+///   `Display` for these types in the future. Implement `Indexable` on the
+///   record that contains them, returning each element as a `String`.
 ///
 /// #### Pro-Tip: Hash Tags
 ///
@@ -57,6 +66,14 @@ pub trait Indexable {
     /// Returns a string for every field for a record that is to be indexed by
     /// Indicium Search.
     fn strings(&self) -> Vec<String>;
+
+    /// Returns the name of every field returned by `strings`, in the same
+    /// order. Records that name their fields can have a single field
+    /// re-indexed with `SearchIndex::update_field`. By default, fields aren't
+    /// named.
+    fn field_names(&self) -> Vec<String> {
+        Vec::new()
+    } // fn field_names
 } // Indexable
 
 // -----------------------------------------------------------------------------
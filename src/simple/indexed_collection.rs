@@ -0,0 +1,415 @@
+use crate::simple::{indexable::Indexable, search_index::SearchIndex};
+use std::collections::HashMap;
+use std::{clone::Clone, cmp::Ord, hash::Hash};
+
+// -----------------------------------------------------------------------------
+//
+/// A collection that owns both its records and a `SearchIndex` over them, and
+/// keeps the two synchronized as records are added, replaced and removed. See
+/// [`IndexedVec`] and [`IndexedHashMap`].
+///
+/// The provided `search` method returns the matching records rather than
+/// their keys.
+///
+/// [`IndexedVec`]: struct.IndexedVec.html
+/// [`IndexedHashMap`]: struct.IndexedHashMap.html
+
+pub trait IndexedCollection {
+    /// The key type used to look up records, such as a `Vec` index.
+    type Key: Hash + Ord;
+    /// The record type held by the collection.
+    type Value: Indexable;
+
+    /// Returns the search index over the collection's records.
+    fn search_index(&self) -> &SearchIndex<Self::Key>;
+
+    /// Returns the record for a key, if any.
+    fn get(&self, key: &Self::Key) -> Option<&Self::Value>;
+
+    /// Searches the collection and returns the matching records, using the
+    /// search index's `search` method.
+    fn search(&self, string: &str) -> Vec<&Self::Value> {
+        self.search_index()
+            .search(string)
+            .into_iter()
+            .filter_map(|key| self.get(key))
+            .collect()
+    } // fn
+} // IndexedCollection
+
+// -----------------------------------------------------------------------------
+//
+/// A `Vec` that indexes its records as they're pushed. Each record is keyed by
+/// its position in the `Vec`.
+///
+/// Basic usage:
+///
+/// ```rust
+/// # use indicium::simple::{IndexedCollection, IndexedVec};
+/// # use pretty_assertions::assert_eq;
+/// #
+/// let mut kings: IndexedVec<&str> = IndexedVec::default();
+/// kings.push("William the Conqueror");
+/// kings.push("William Rufus");
+/// kings.push("Henry Beauclerc");
+///
+/// assert_eq!(kings.search("william"), vec![&"William the Conqueror", &"William Rufus"]);
+///
+/// // Removing a record shifts the records after it, and their keys:
+/// kings.remove(0);
+/// assert_eq!(kings.search("william"), vec![&"William Rufus"]);
+/// assert_eq!(kings.search_index().search("henry"), vec![&1]);
+/// ```
+
+#[derive(Clone, Debug)]
+pub struct IndexedVec<T: Indexable> {
+    /// The records.
+    records: Vec<T>,
+    /// The search index over the records, keyed by position.
+    search_index: SearchIndex<usize>,
+} // IndexedVec
+
+// -----------------------------------------------------------------------------
+
+impl<T: Indexable> Default for IndexedVec<T> {
+    fn default() -> Self {
+        Self::with_search_index(SearchIndex::default())
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl<T: Indexable> From<Vec<T>> for IndexedVec<T> {
+    /// Indexes every record of the `Vec`, using the default search index
+    /// settings.
+    fn from(records: Vec<T>) -> Self {
+        let search_index = SearchIndex::from(records.as_slice());
+        IndexedVec { records, search_index }
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl<T: Indexable> IndexedVec<T> {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Makes an empty collection that indexes its records with the given
+    /// search index. Use this to choose the search index's settings with a
+    /// `SearchIndexBuilder`. The search index should be empty.
+
+    pub fn with_search_index(search_index: SearchIndex<usize>) -> Self {
+        IndexedVec { records: Vec::new(), search_index }
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Appends a record to the collection and indexes it. Returns the record's
+    /// key (its position.)
+
+    pub fn push(&mut self, record: T) -> usize {
+        let key = self.records.len();
+        self.search_index.insert(&key, &record);
+        self.records.push(record);
+        key
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Replaces the record at a position, updating the search index. Returns
+    /// the old record, or `None` if the position is out of bounds (in which
+    /// case the collection is unchanged.)
+
+    pub fn replace(&mut self, key: usize, record: T) -> Option<T> {
+        let slot = self.records.get_mut(key)?;
+        self.search_index.replace(&key, slot, &record);
+        Some(std::mem::replace(slot, record))
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Removes and returns the record at a position, shifting the records
+    /// after it to the left like `Vec::remove`. The shifted records are
+    /// re-keyed in the search index.
+    ///
+    /// This is O(n): every record after the position is removed from, and
+    /// inserted again into, the search index. If the order of the records
+    /// doesn't matter, `swap_remove` only re-keys one record.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the position is out of bounds.
+
+    pub fn remove(&mut self, key: usize) -> T {
+        // Detach every record from the removed one onwards, since their keys
+        // are about to change:
+        self.records
+            .iter()
+            .enumerate()
+            .skip(key)
+            .for_each(|(position, record)| self.search_index.remove(&position, record));
+
+        let record = self.records.remove(key);
+
+        // Re-attach the shifted records under their new keys:
+        self.records
+            .iter()
+            .enumerate()
+            .skip(key)
+            .for_each(|(position, record)| self.search_index.insert(&position, record));
+
+        record
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Removes and returns the record at a position, replacing it with the
+    /// last record like `Vec::swap_remove`. Only the moved record is re-keyed
+    /// in the search index, so this is O(1) in the number of records.
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # use indicium::simple::{IndexedCollection, IndexedVec};
+    /// # use pretty_assertions::assert_eq;
+    /// #
+    /// let mut kings: IndexedVec<&str> = IndexedVec::default();
+    /// kings.push("William the Conqueror");
+    /// kings.push("William Rufus");
+    /// kings.push("Henry Beauclerc");
+    ///
+    /// // The last record takes the removed record's place, and its key:
+    /// assert_eq!(kings.swap_remove(0), "William the Conqueror");
+    /// assert_eq!(kings.search_index().search("henry"), vec![&0]);
+    /// assert_eq!(kings.search_index().search("william"), vec![&1]);
+    /// assert!(kings.search_index().search("conqueror").is_empty());
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the position is out of bounds.
+
+    pub fn swap_remove(&mut self, key: usize) -> T {
+        let last: usize = self.records.len().saturating_sub(1);
+
+        // Detach the removed record, and the last record since its key is
+        // about to change:
+        if let Some(record) = self.records.get(key) {
+            self.search_index.remove(&key, record);
+        } // if
+        if key < last {
+            self.search_index.remove(&last, &self.records[last]);
+        } // if
+
+        let record = self.records.swap_remove(key);
+
+        // Re-attach the moved record under its new key:
+        if key < last {
+            self.search_index.insert(&key, &self.records[key]);
+        } // if
+
+        record
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Removes and returns the last record, if any.
+
+    pub fn pop(&mut self) -> Option<T> {
+        let record = self.records.pop()?;
+        self.search_index.remove(&self.records.len(), &record);
+        Some(record)
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns the records as a slice.
+
+    pub fn as_slice(&self) -> &[T] {
+        &self.records
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns the number of records in the collection.
+
+    pub fn len(&self) -> usize {
+        self.records.len()
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns `true` if the collection has no records.
+
+    pub fn is_empty(&self) -> bool {
+        self.records.is_empty()
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns the records and the search index, consuming the collection.
+
+    pub fn into_parts(self) -> (Vec<T>, SearchIndex<usize>) {
+        (self.records, self.search_index)
+    } // fn
+
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl<T: Indexable> IndexedCollection for IndexedVec<T> {
+    type Key = usize;
+    type Value = T;
+
+    fn search_index(&self) -> &SearchIndex<usize> {
+        &self.search_index
+    } // fn
+
+    fn get(&self, key: &usize) -> Option<&T> {
+        self.records.get(*key)
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+//
+/// A `HashMap` that indexes its records as they're inserted, using the hash
+/// map's keys as the search index keys.
+///
+/// Basic usage:
+///
+/// ```rust
+/// # use indicium::simple::{IndexedCollection, IndexedHashMap};
+/// # use pretty_assertions::assert_eq;
+/// #
+/// let mut kings: IndexedHashMap<u16, &str> = IndexedHashMap::default();
+/// kings.insert(1066, "William the Conqueror");
+/// kings.insert(1087, "William Rufus");
+///
+/// assert_eq!(kings.search("rufus"), vec![&"William Rufus"]);
+///
+/// // Inserting an existing key replaces its record:
+/// kings.insert(1087, "Henry Beauclerc");
+/// assert_eq!(kings.search("rufus"), Vec::<&&str>::new());
+/// assert_eq!(kings.search("henry"), vec![&"Henry Beauclerc"]);
+///
+/// kings.remove(&1066);
+/// assert_eq!(kings.search("william"), Vec::<&&str>::new());
+/// ```
+
+#[derive(Clone, Debug)]
+pub struct IndexedHashMap<K: Clone + Eq + Hash + Ord, V: Indexable> {
+    /// The records.
+    records: HashMap<K, V>,
+    /// The search index over the records, keyed by the hash map's keys.
+    search_index: SearchIndex<K>,
+} // IndexedHashMap
+
+// -----------------------------------------------------------------------------
+
+impl<K: Clone + Eq + Hash + Ord, V: Indexable> Default for IndexedHashMap<K, V> {
+    fn default() -> Self {
+        Self::with_search_index(SearchIndex::default())
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl<K: Clone + Eq + Hash + Ord, V: Indexable> From<HashMap<K, V>> for IndexedHashMap<K, V> {
+    /// Indexes every record of the `HashMap`, using the default search index
+    /// settings.
+    fn from(records: HashMap<K, V>) -> Self {
+        let mut search_index = SearchIndex::default();
+        records
+            .iter()
+            .for_each(|(key, record)| search_index.insert(key, record));
+        IndexedHashMap { records, search_index }
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl<K: Clone + Eq + Hash + Ord, V: Indexable> IndexedHashMap<K, V> {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Makes an empty collection that indexes its records with the given
+    /// search index. Use this to choose the search index's settings with a
+    /// `SearchIndexBuilder`. The search index should be empty.
+
+    pub fn with_search_index(search_index: SearchIndex<K>) -> Self {
+        IndexedHashMap { records: HashMap::new(), search_index }
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Inserts a record into the collection and indexes it. If the key was
+    /// already present, its record is replaced (in the search index too) and
+    /// the old record is returned.
+
+    pub fn insert(&mut self, key: K, record: V) -> Option<V> {
+        match self.records.get(&key) {
+            Some(before) => self.search_index.replace(&key, before, &record),
+            None => self.search_index.insert(&key, &record),
+        } // match
+        self.records.insert(key, record)
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Removes a record from the collection and the search index. Returns the
+    /// removed record, if any.
+
+    pub fn remove(&mut self, key: &K) -> Option<V> {
+        let record = self.records.remove(key)?;
+        self.search_index.remove(key, &record);
+        Some(record)
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns the records as a `HashMap`.
+
+    pub fn as_hash_map(&self) -> &HashMap<K, V> {
+        &self.records
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns the number of records in the collection.
+
+    pub fn len(&self) -> usize {
+        self.records.len()
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns `true` if the collection has no records.
+
+    pub fn is_empty(&self) -> bool {
+        self.records.is_empty()
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns the records and the search index, consuming the collection.
+
+    pub fn into_parts(self) -> (HashMap<K, V>, SearchIndex<K>) {
+        (self.records, self.search_index)
+    } // fn
+
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl<K: Clone + Eq + Hash + Ord, V: Indexable> IndexedCollection for IndexedHashMap<K, V> {
+    type Key = K;
+    type Value = V;
+
+    fn search_index(&self) -> &SearchIndex<K> {
+        &self.search_index
+    } // fn
+
+    fn get(&self, key: &K) -> Option<&V> {
+        self.records.get(key)
+    } // fn
+} // impl
\ No newline at end of file
@@ -7,9 +7,12 @@ use ahash::HashSet;
 use std::collections::HashSet;
 
 // Static dependencies:
-use crate::simple::{indexable::Indexable, search_index::SearchIndex};
+use crate::simple::internal::FieldKeywords;
+use crate::simple::{indexable::Indexable, search_index::SearchIndex, DuplicateKeyPolicy, IndexChange, InsertOptions, InsertReport, KeywordCapPolicy};
+use crate::Error;
 use kstring::KString;
 use std::collections::BTreeSet;
+use std::sync::Arc;
 use std::{clone::Clone, cmp::Ord};
 
 // -----------------------------------------------------------------------------
@@ -136,17 +139,197 @@ impl<K: Clone + Ord> SearchIndex<K> {
     /// You can use the enum's variants to represent your different collections.
     /// Each variant's associated data can hold the `key` for your record.
     ///
-    /// Note that I couldn't implement the `FromIterator` trait which would
-    /// allow a caller to `.collect();` into the `SearchIndex`. `FromIterator`
-    /// works with an owned iterator (`IntoIterator`) and uses owned values
-    /// only. If there were a similar trait that worked with borrowed values,
-    /// it would be do-able.
+    /// To build a search index from owned key-value pairs in one call, a
+    /// caller can `.collect();` into the `SearchIndex`. A slice of records can
+    /// also be converted with `SearchIndex::from`, which keys each record by
+    /// its position in the slice.
 
     #[tracing::instrument(level = "trace", name = "search index insert", skip(self, key, value))]
     pub fn insert(&mut self, key: &K, value: &dyn Indexable) {
+        // Records rejected by the `maximum_keywords_per_record` cap are
+        // skipped. Use `try_insert` to observe this:
+        if let Err(_error) = self.try_insert(key, value) {
+            // For debug builds:
+            #[cfg(debug_assertions)]
+            tracing::warn!("Record was not inserted: {}", _error);
+        } // if
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Inserts a key-value pair into the search index, like `insert`, with
+    /// options that override the search index settings for this record. See
+    /// [`InsertOptions`].
+    ///
+    /// [`InsertOptions`]: struct.InsertOptions.html
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # use indicium::simple::{InsertOptions, SearchIndex};
+    /// # use pretty_assertions::assert_eq;
+    /// #
+    /// let mut search_index: SearchIndex<usize> = SearchIndex::default();
+    ///
+    /// // Short titles are indexed as whole strings, but long descriptions
+    /// // aren't:
+    /// search_index.insert(&0, &"Harold Godwinson");
+    /// search_index.insert_with(
+    ///     &1,
+    ///     &"Last crowned Anglo-Saxon king of England",
+    ///     InsertOptions { index_whole_strings: false, ..Default::default() },
+    /// );
+    ///
+    /// assert!(search_index.contains_keyword("harold godwinson"));
+    /// assert!(!search_index.contains_keyword("last crowned anglo-saxon king of england"));
+    /// assert_eq!(search_index.search("crowned"), vec![&1]);
+    /// ```
+
+    #[tracing::instrument(level = "trace", name = "search index insert with", skip(self, key, value))]
+    pub fn insert_with(&mut self, key: &K, value: &dyn Indexable, options: InsertOptions) {
+        // Records rejected by the `maximum_keywords_per_record` cap are
+        // skipped. Use `try_insert_with` to observe this:
+        if let Err(_error) = self.try_insert_with(key, value, options) {
+            // For debug builds:
+            #[cfg(debug_assertions)]
+            tracing::warn!("Record was not inserted: {}", _error);
+        } // if
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Inserts a key-value pair into the search index, like `insert`, and
+    /// reports how the record was indexed.
+    ///
+    /// If the search index has a `maximum_keywords_per_record` cap, records
+    /// with too many keywords are either truncated (reported in the
+    /// `InsertReport`) or rejected with an `Error::KeywordCapExceeded` error,
+    /// depending on the `keyword_cap_policy` setting.
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # use indicium::simple::{InsertReport, SearchIndex, SearchIndexBuilder};
+    /// # use pretty_assertions::assert_eq;
+    /// #
+    /// let mut search_index: SearchIndex<usize> = SearchIndexBuilder::default()
+    ///     .maximum_keywords_per_record(Some(2))
+    ///     .build();
+    ///
+    /// let report = search_index.try_insert(&0, &"gamma alpha beta").unwrap();
+    /// assert_eq!(report, InsertReport { keywords: 2, truncated_keywords: 2 });
+    ///
+    /// // Only the first keywords in lexographic order are kept:
+    /// assert_eq!(search_index.search("alpha"), vec![&0]);
+    /// assert_eq!(search_index.search("gamma"), Vec::<&usize>::new());
+    /// ```
+    ///
+    /// Inserting a key that's already in the search index replaces its value,
+    /// unless the `duplicate_key_policy` is `Accumulate`:
+    ///
+    /// ```rust
+    /// # use indicium::simple::SearchIndex;
+    /// # use pretty_assertions::assert_eq;
+    /// #
+    /// let mut search_index: SearchIndex<usize> = SearchIndex::default();
+    /// search_index.insert(&0, &"Harold Godwinson");
+    /// search_index.insert(&0, &"Harold Hardrada");
+    ///
+    /// assert_eq!(search_index.search("hardrada"), vec![&0]);
+    /// assert_eq!(search_index.search("godwinson"), Vec::<&usize>::new());
+    /// ```
 
-        // Get all keywords for the `Indexable` record:
-        let mut keywords: HashSet<KString> = self.indexable_keywords(value);
+    #[tracing::instrument(level = "trace", name = "search index try insert", skip(self, key, value))]
+    pub fn try_insert(&mut self, key: &K, value: &dyn Indexable) -> Result<InsertReport, Error> {
+        self.try_insert_with(key, value, InsertOptions::default())
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Inserts a key-value pair into the search index with options that
+    /// override the search index settings for this record, like
+    /// `insert_with`, and reports how the record was indexed, like
+    /// `try_insert`.
+
+    #[tracing::instrument(level = "trace", name = "search index try insert with", skip(self, key, value))]
+    pub fn try_insert_with(
+        &mut self,
+        key: &K,
+        value: &dyn Indexable,
+        options: InsertOptions,
+    ) -> Result<InsertReport, Error> {
+
+        // Get all keywords for the `Indexable` record, which of them are whole
+        // strings, and which fields they came from if the fields are named:
+        let (mut keywords, mut whole_strings, mut fields): (HashSet<KString>, Vec<KString>, Option<FieldKeywords>) =
+            self.indexable_keyword_parts(value, &options);
+
+        // If there's a cap on the number of keywords per record, enforce it
+        // according to the policy:
+        let mut truncated_keywords: usize = 0;
+        if let Some(maximum) = self.maximum_keywords_per_record {
+            if keywords.len() > maximum {
+                match self.keyword_cap_policy {
+                    KeywordCapPolicy::Reject => return Err(Error::KeywordCapExceeded {
+                        keywords: keywords.len(),
+                        maximum,
+                    }), // Err
+                    KeywordCapPolicy::Truncate => {
+                        // Keep the first keywords in lexographic order, so
+                        // that the result doesn't depend on hashing order:
+                        let mut sorted: Vec<KString> = keywords.into_iter().collect();
+                        sorted.sort_unstable();
+                        truncated_keywords = sorted.len() - maximum;
+                        sorted.truncate(maximum);
+                        keywords = sorted.into_iter().collect();
+                    }, // Truncate
+                } // match
+            } // if
+        } // if
+
+        // Only the keywords that survived the cap are indexed:
+        whole_strings.retain(|whole_string| keywords.contains(whole_string));
+        if let Some(fields) = &mut fields {
+            fields
+                .values_mut()
+                .for_each(|field_keywords| field_keywords.retain(|keyword| keywords.contains(keyword)));
+        } // if
+
+        // The indexed data is changing, so precomputed autocompletions are no
+        // longer current:
+        self.invalidate_autocompletions();
+
+        // If the changelog is enabled, record this insert. Inserts with the
+        // default options are recorded as plain inserts, and records with
+        // named fields keep their field names:
+        self.record_op(|| match (fields.is_some(), options == InsertOptions::default()) {
+            (true, _) => IndexChange::InsertFields {
+                key: key.clone(),
+                fields: value.field_names().into_iter().zip(value.strings()).collect(),
+                options,
+            }, // InsertFields
+            (false, true) => IndexChange::Insert { key: key.clone(), strings: value.strings() },
+            (false, false) => IndexChange::InsertWith { key: key.clone(), strings: value.strings(), options },
+        }); // record_op
+
+        // If this key is already in the search index and the new value
+        // replaces the old one, detach the key from keywords that the new
+        // value no longer has:
+        if self.duplicate_key_policy == DuplicateKeyPolicy::Upsert {
+            let stale_keywords: Vec<KString> = self.reverse_map
+                .as_ref()
+                .and_then(|reverse_map| reverse_map.get(key))
+                .map(|key_keywords| key_keywords
+                    .iter()
+                    .filter(|keyword| !keywords.contains(*keyword))
+                    .cloned()
+                    .collect()
+                ) // map
+                .unwrap_or_default();
+            self.detach_keywords(key, &stale_keywords);
+        } // if
+
+        let report = InsertReport { keywords: keywords.len(), truncated_keywords };
 
         // If `dump_keyword` feature is turned on, ensure that all records are
         // attached to this special keyword:
@@ -154,6 +337,72 @@ impl<K: Clone + Ord> SearchIndex<K> {
             keywords.insert(dump_keyword.as_ref().into());
         } // if
 
+        self.attach_keywords(key, keywords, whole_strings);
+
+        // Remember which keywords each named field produced, so that a single
+        // field can be updated (see `update_field`). Only a value that
+        // replaces the key's earlier one can be tagged:
+        match (fields, self.duplicate_key_policy) {
+            (Some(fields), DuplicateKeyPolicy::Upsert) => { self.field_keywords.insert(key.clone(), fields); },
+            _ => { self.field_keywords.remove(key); },
+        } // match
+
+        Ok(report)
+
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Attaches a key to the given keywords, which may include the
+    /// `dump_keyword`, and remembers which of them are whole strings (see
+    /// `compact_whole_strings`). Used by `insert` and `update_field`.
+
+    pub(crate) fn attach_keywords(
+        &mut self,
+        key: &K,
+        keywords: HashSet<KString>,
+        whole_strings: Vec<KString>,
+    ) {
+
+        // Newly inserted keywords count as recently hit, so that they aren't
+        // the first to be evicted if the search index has a keyword cap:
+        if self.maximum_keywords.is_some() {
+            self.keyword_usage.touch(keywords.iter());
+        } // if
+
+        // If the reverse map is maintained, record this key's keywords. The
+        // `dump_keyword` is attached to every key so it isn't recorded:
+        if let Some(reverse_map) = &mut self.reverse_map {
+            reverse_map
+                .entry(key.clone())
+                .or_default()
+                .extend(
+                    keywords
+                        .iter()
+                        .filter(|keyword| self.dump_keyword.as_ref() != Some(keyword))
+                        .cloned()
+                ); // extend
+        } // if
+
+        // If the search index keeps copies of its keywords, remember which
+        // keywords will have to be copied into them:
+        let touched: Vec<KString> = match self.has_mirrors() {
+            true => keywords.iter().cloned().collect(),
+            false => Vec::new(),
+        }; // match
+
+        // Move any compacted whole-string keywords back into the keywords, so
+        // that this key can be attached to them:
+        self.expand_whole_strings(keywords.iter());
+
+        // If a snapshot shares the keywords, they're copied before being
+        // changed:
+        let b_tree_map = Arc::make_mut(&mut self.b_tree_map);
+
+        // The keywords this key was actually attached to. Keywords at the
+        // per-keyword cap reject the key:
+        let mut attached: Vec<KString> = Vec::new();
+
         // Iterate over the keywords:
         keywords
             .into_iter()
@@ -161,7 +410,7 @@ impl<K: Clone + Ord> SearchIndex<K> {
             .for_each(|keyword|
                 // Attempt to get mutuable reference to the _keyword entry_ in
                 // the search index:
-                match self.b_tree_map.get_mut(&keyword) {
+                match b_tree_map.get_mut(&keyword) {
                     // If keyword was found in search index, add _key reference_
                     // for this record to _keyword entry_:
                     Some(keys) => {
@@ -174,8 +423,14 @@ impl<K: Clone + Ord> SearchIndex<K> {
                             // If it hasn't, insert the key (record) into the
                             // list:
                             keys.insert(key.clone());
+                            attached.push(keyword.clone());
                         } else {
                             // If the limit has been reached, do not insert.
+                            // Record the saturated keyword, and report it the
+                            // first time:
+                            if self.saturated_keywords.insert(keyword.clone()) {
+                                self.saturation_hook.call(&keyword);
+                            } // if
                             // Display warning for debug builds.
                             #[cfg(debug_assertions)]
                             tracing::warn!(
@@ -193,10 +448,31 @@ impl<K: Clone + Ord> SearchIndex<K> {
                     None => {
                         let mut b_tree_set = BTreeSet::new();
                         b_tree_set.insert(key.clone());
-                        self.b_tree_map.insert(keyword.as_ref().into(), b_tree_set);
+                        b_tree_map.insert(keyword.as_ref().into(), b_tree_set);
+                        attached.push(keyword.clone());
                     }, // None
                 } // match
-            ) // for_each
+            ); // for_each
+
+        // If posting timestamps are kept, record when this key was attached to
+        // its keywords. Rejected postings aren't stamped, so that `decay`
+        // doesn't count them:
+        if self.posting_times.is_some() {
+            self.stamp_postings(key, &attached, self.clock.now());
+        } // if
+
+        // Remember which keywords are whole strings, so that they can be
+        // compacted (see `compact_whole_strings`):
+        self.whole_string_keywords.extend(whole_strings);
+
+        self.sync_mirrors_for_key(&touched, key);
+
+        // If the search index is over its keyword cap, evict keywords:
+        self.evict();
+
+        // Record the size of the search index for metrics:
+        #[cfg(feature = "metrics")]
+        crate::simple::internal::metrics::record_index_size(self.b_tree_map.len());
 
     } // fn
 
@@ -0,0 +1,36 @@
+// -----------------------------------------------------------------------------
+//
+/// Options that override the search index settings for a single record. Used
+/// with [`SearchIndex::insert_with`] and [`SearchIndex::try_insert_with`].
+///
+/// Options are usually given with struct update syntax, so that any options
+/// added in the future keep their defaults:
+///
+/// ```rust
+/// # use indicium::simple::InsertOptions;
+/// #
+/// let options = InsertOptions { index_whole_strings: false, ..Default::default() };
+/// ```
+///
+/// [`SearchIndex::insert_with`]: struct.SearchIndex.html#method.insert_with
+/// [`SearchIndex::try_insert_with`]: struct.SearchIndex.html#method.try_insert_with
+
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct InsertOptions {
+    /// Whether the record's whole strings are indexed as keywords, if the
+    /// search index's `maximum_string_length` setting allows it. This is
+    /// useful for records with long fields, such as descriptions, that
+    /// shouldn't be autocompleted as a whole.
+    ///
+    /// **Default:** `true`
+    pub index_whole_strings: bool,
+} // InsertOptions
+
+// -----------------------------------------------------------------------------
+
+impl Default for InsertOptions {
+    fn default() -> Self {
+        InsertOptions { index_whole_strings: true }
+    } // fn
+} // impl
\ No newline at end of file
@@ -0,0 +1,16 @@
+// -----------------------------------------------------------------------------
+//
+/// Describes how a record was indexed. Returned by
+/// [`SearchIndex::try_insert`].
+///
+/// [`SearchIndex::try_insert`]: struct.SearchIndex.html#method.try_insert
+
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct InsertReport {
+    /// The number of keywords the record was attached to, not counting the
+    /// `dump_keyword`.
+    pub keywords: usize,
+    /// The number of the record's keywords that were dropped because of the
+    /// `maximum_keywords_per_record` cap.
+    pub truncated_keywords: usize,
+} // InsertReport
\ No newline at end of file
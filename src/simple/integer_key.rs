@@ -0,0 +1,24 @@
+// -----------------------------------------------------------------------------
+//
+/// Integer key types that may use the sorted-vector posting intersection
+/// provided by the `simd` feature. See [`SearchIndex::search_and_simd`] for
+/// more information.
+///
+/// This trait is sealed and is implemented for `u32` and `u64` only. These
+/// types are `Copy`, so sorted postings are contiguous and may be compared
+/// several lanes at a time.
+///
+/// [`SearchIndex::search_and_simd`]: struct.SearchIndex.html#method.search_and_simd
+
+pub trait IntegerKey: Copy + Ord + sealed::Sealed {}
+
+impl IntegerKey for u32 {}
+impl IntegerKey for u64 {}
+
+// -----------------------------------------------------------------------------
+
+mod sealed {
+    pub trait Sealed {}
+    impl Sealed for u32 {}
+    impl Sealed for u64 {}
+} // mod sealed
\ No newline at end of file
@@ -0,0 +1,108 @@
+//! Chooses between the `eddie` and `strsim` crates from the size of a search
+//! index's dictionary and the length of its keywords, for the
+//! `FuzzyBackendChoice::Auto` setting.
+
+use crate::simple::{EddieMetric, FuzzyBackendChoice, StrsimMetric};
+use kstring::KString;
+use std::collections::{BTreeMap, BTreeSet};
+
+// -----------------------------------------------------------------------------
+//
+/// The number of keywords sampled from the search index to find the average
+/// keyword length.
+
+const SAMPLES: usize = 48;
+
+// -----------------------------------------------------------------------------
+//
+/// Dictionaries with at least this many keywords use `eddie`.
+
+const LARGE_DICTIONARY: usize = 10_000;
+
+// -----------------------------------------------------------------------------
+//
+/// Dictionaries whose sampled keywords are longer than this many characters on
+/// average use `eddie`.
+
+const LONG_KEYWORDS: usize = 12;
+
+// -----------------------------------------------------------------------------
+//
+/// Returns the crate to use for fuzzy matching. If only one crate has a
+/// string similarity metric, it's returned. Otherwise, `eddie` is chosen for
+/// large dictionaries or long keywords, since it reuses its buffers between
+/// comparisons, and `strsim` is chosen for small dictionaries of short
+/// keywords (including an empty dictionary), where its simpler per-comparison
+/// setup is cheaper. The choice only depends on the keywords, so it's the same
+/// every time for the same search index.
+
+pub(crate) fn auto_fuzzy_backend<K>(
+    b_tree_map: &BTreeMap<KString, BTreeSet<K>>,
+    eddie_metric: Option<&EddieMetric>,
+    strsim_metric: Option<&StrsimMetric>,
+) -> FuzzyBackendChoice {
+
+    match (eddie_metric, strsim_metric) {
+        (Some(_eddie_metric), Some(_strsim_metric)) => {},
+        (None, Some(_strsim_metric)) => return FuzzyBackendChoice::Strsim,
+        _ => return FuzzyBackendChoice::Eddie,
+    }; // match
+
+    if b_tree_map.len() >= LARGE_DICTIONARY { return FuzzyBackendChoice::Eddie }
+
+    // Sample keywords evenly from across the dictionary:
+    let step: usize = (b_tree_map.len() / SAMPLES).max(1);
+    let lengths: Vec<usize> = b_tree_map
+        .keys()
+        .step_by(step)
+        .take(SAMPLES)
+        .map(|keyword| keyword.chars().count())
+        .collect();
+
+    let average_length: usize = match lengths.len() {
+        0 => 0,
+        samples => lengths.iter().sum::<usize>() / samples,
+    }; // match
+
+    // For debug builds:
+    #[cfg(debug_assertions)]
+    tracing::debug!(
+        "{} keywords with an average length of {} for fuzzy matching.",
+        b_tree_map.len(),
+        average_length,
+    ); // debug!
+
+    match average_length > LONG_KEYWORDS {
+        true => FuzzyBackendChoice::Eddie,
+        false => FuzzyBackendChoice::Strsim,
+    } // match
+
+} // fn
+
+// -----------------------------------------------------------------------------
+
+#[test]
+fn test_auto_fuzzy_backend() {
+    let dictionary = |keywords: &[&str]| -> BTreeMap<KString, BTreeSet<usize>> {
+        keywords
+            .iter()
+            .map(|keyword| (KString::from_ref(keyword), BTreeSet::new()))
+            .collect()
+    }; // dictionary
+
+    let short = dictionary(&["harold", "william", "edgar", "henry", "conqueror"]);
+    let long = dictionary(&["antidisestablishmentarianism", "internationalization"]);
+    let large: BTreeMap<KString, BTreeSet<usize>> = (0..LARGE_DICTIONARY)
+        .map(|keyword| (KString::from(keyword.to_string()), BTreeSet::new()))
+        .collect();
+
+    let choose = |b_tree_map| auto_fuzzy_backend(b_tree_map, Some(&EddieMetric::Levenshtein), Some(&StrsimMetric::Levenshtein));
+    assert_eq!(choose(&short), FuzzyBackendChoice::Strsim);
+    assert_eq!(choose(&short), choose(&short));
+    assert_eq!(choose(&long), FuzzyBackendChoice::Eddie);
+    assert_eq!(choose(&large), FuzzyBackendChoice::Eddie);
+    assert_eq!(choose(&BTreeMap::new()), FuzzyBackendChoice::Strsim);
+
+    assert_eq!(auto_fuzzy_backend(&long, None, Some(&StrsimMetric::Jaro)), FuzzyBackendChoice::Strsim);
+    assert_eq!(auto_fuzzy_backend(&short, Some(&EddieMetric::Jaro), None), FuzzyBackendChoice::Eddie);
+} // fn
\ No newline at end of file
@@ -0,0 +1,15 @@
+// -----------------------------------------------------------------------------
+//
+/// Returns the first `chars` characters (or codepoints) of the string, or
+/// `None` if the string is shorter than that. Unlike slicing by byte index,
+/// this never splits a multi-byte UTF-8 character.
+
+pub(crate) fn char_prefix(string: &str, chars: usize) -> Option<&str> {
+    match string.char_indices().nth(chars) {
+        // The string is longer than the prefix. Slice at the byte index of
+        // the first character after the prefix:
+        Some((index, _char)) => Some(&string[..index]),
+        // The string is exactly as long as the prefix, or shorter:
+        None => (string.chars().count() == chars).then_some(string),
+    } // match
+} // fn
\ No newline at end of file
@@ -0,0 +1,74 @@
+//! The source of the current time for time-dependent features (posting
+//! timestamps, query log decay and the last rebuild time), which can be
+//! replaced so that tests and replays run deterministically.
+
+use std::cmp::Ordering;
+use std::fmt::{Debug, Formatter, Result};
+use std::sync::Arc;
+use std::time::SystemTime;
+
+// -----------------------------------------------------------------------------
+//
+/// A callback that returns the current time.
+
+type Now = Arc<dyn Fn() -> SystemTime + Send + Sync>;
+
+// -----------------------------------------------------------------------------
+//
+/// Holds the callback given to `clock`, if any. Without a callback, the
+/// system clock is used. The callback is shared with clones.
+
+#[derive(Clone, Default)]
+pub(crate) struct Clock(Option<Now>);
+
+// -----------------------------------------------------------------------------
+
+impl Clock {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Wraps a callback.
+
+    pub(crate) fn new(now: impl Fn() -> SystemTime + Send + Sync + 'static) -> Self {
+        Clock(Some(Arc::new(now)))
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns the current time, from the callback if there is one.
+
+    pub(crate) fn now(&self) -> SystemTime {
+        match &self.0 {
+            Some(now) => now(),
+            None => SystemTime::now(),
+        } // match
+    } // fn
+
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl Debug for Clock {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> Result {
+        match self.0 {
+            Some(_) => formatter.write_str("Clock(Some(..))"),
+            None => formatter.write_str("Clock(None)"),
+        } // match
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+//
+/// The clock is an implementation detail and is ignored when comparing.
+
+impl PartialEq for Clock {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    } // fn
+} // impl
+
+impl PartialOrd for Clock {
+    fn partial_cmp(&self, _other: &Self) -> Option<Ordering> {
+        Some(Ordering::Equal)
+    } // fn
+} // impl
\ No newline at end of file
@@ -1,4 +1,4 @@
-use crate::simple::internal::FuzzyTopScores;
+use crate::simple::internal::{FuzzyTopScores, Keys};
 use kstring::KString;
 use std::collections::BTreeSet;
 
@@ -34,7 +34,7 @@ impl<K: std::hash::Hash + std::cmp::Ord> crate::simple::search_index::SearchInde
     pub(crate) fn eddie_autocomplete_context_damerau_levenshtein(
         &self,
         index_range: &str,
-        key_set: &BTreeSet<&K>,
+        key_set: &Keys<K>,
         user_keyword: &str,
     ) -> impl Iterator<Item = (&KString, &BTreeSet<K>)> {
 
@@ -55,6 +55,8 @@ impl<K: std::hash::Hash + std::cmp::Ord> crate::simple::search_index::SearchInde
             // iteration when we reach a keyword that does not start with our
             // supplied (partial) keyword.
             .take_while(|(index_keyword, _keys)| index_keyword.starts_with(index_range))
+            // Only evaluate `maximum_fuzzy_candidates` number of keywords:
+            .take(self.maximum_fuzzy_candidates.unwrap_or(usize::MAX))
             // Only examine search index keywords that intersect with the caller
             // provided key-set. This ensures contextual fuzzy matching. This
             // will filter out search index keywords that don't contain any keys
@@ -48,6 +48,8 @@ impl<K: std::hash::Hash + std::cmp::Ord> crate::simple::search_index::SearchInde
             // iteration when we reach a keyword that does not start with our
             // supplied (partial) keyword.
             .take_while(|(index_keyword, _keys)| index_keyword.starts_with(index_range))
+            // Only evaluate `maximum_fuzzy_candidates` number of keywords:
+            .take(self.maximum_fuzzy_candidates.unwrap_or(usize::MAX))
             // For each keyword in the search index:
             .for_each(|(index_keyword, index_keys)| {
                 // Using this keyword from the search index, calculate its
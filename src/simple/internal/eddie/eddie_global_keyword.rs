@@ -22,6 +22,13 @@ impl<K: Hash + Ord> SearchIndex<K> {
         user_keyword: &str,
     ) -> Option<&KString> {
 
+        // Record that fuzzy matching is being used as a fall-back:
+        #[cfg(feature = "metrics")]
+        crate::simple::internal::metrics::record_fuzzy_fallback();
+
+        // Count the fuzzy fall-back (see `runtime_stats`):
+        self.runtime_counters.record_fuzzy_fallback();
+
         // Build an index keyword range to fuzzy match against.
         //
         // | Example | User Keyword                       | Length | Index Keyword Must Start With... |
@@ -42,52 +49,47 @@ impl<K: Hash + Ord> SearchIndex<K> {
         // will be fuzzy matched against every keyword in the index. This is OK
         // (or even desirable) if the search index isn't large, however, this
         // will be crippling slow on very large search indicies.
-        let index_range: &str = if self.fuzzy_length > 0 {
-            // The user keyword must be longer than the match length to be
-            // evaluated for fuzzy-matches:
-            if user_keyword.len() >= self.fuzzy_length {
-                // Use the first _n_ characters of the user's keyword to find
-                // search index keywords to compare against:
-                &user_keyword[0..self.fuzzy_length]
-            } else {
-                // The user's keyword is too short. Do not perform any fuzzy
-                // matching:
-                return None
-            } // if
-        } else {
-            // The match length is 0, compare user's keyword against all search
-            // index keywords:
-            ""
-        }; // if
+        //
+        // If `adaptive_fuzzy_length` is set, a shorter prefix may be used when
+        // few search index keywords start with it.
+        let Some(index_range) = self.fuzzy_index_range(user_keyword) else {
+            // The user's keyword is too short. Do not perform any fuzzy
+            // matching:
+            return None
+        }; // else
 
         // Attempt to find the closest match for the user's keyword using the
         // selected string similarity metric defined in the `SearchIndex`:
-        if let Some(eddie_metric) = &self.eddie_metric {
+        self.fuzzy_match_with_fallback(index_range, Option::is_none, |index_range| {
 
-            match eddie_metric {
+            if let Some(eddie_metric) = &self.eddie_metric {
 
-                EddieMetric::DamerauLevenshtein =>
-                    self.eddie_keyword_global_damerau_levenshtein(index_range, user_keyword),
+                match eddie_metric {
 
-                EddieMetric::Jaro =>
-                    self.eddie_keyword_global_jaro(index_range, user_keyword),
+                    EddieMetric::DamerauLevenshtein =>
+                        self.eddie_keyword_global_damerau_levenshtein(index_range, user_keyword),
 
-                EddieMetric::JaroWinkler =>
-                    self.eddie_keyword_global_jaro_winkler(index_range, user_keyword),
+                    EddieMetric::Jaro =>
+                        self.eddie_keyword_global_jaro(index_range, user_keyword),
 
-                EddieMetric::Levenshtein =>
-                    self.eddie_keyword_global_levenshtein(index_range, user_keyword),
+                    EddieMetric::JaroWinkler =>
+                        self.eddie_keyword_global_jaro_winkler(index_range, user_keyword),
 
-            } // match
+                    EddieMetric::Levenshtein =>
+                        self.eddie_keyword_global_levenshtein(index_range, user_keyword),
 
-        } else {
+                } // match
 
-            // No string similarity metric was defined in the `SearchIndex`
-            // settings. Fuzzy string matching effectively turned off.
-            // Return a `None` to the caller:
-            None
+            } else {
+
+                // No string similarity metric was defined in the `SearchIndex`
+                // settings. Fuzzy string matching effectively turned off.
+                // Return a `None` to the caller:
+                None
+
+            } // if
 
-        } // if
+        }) // fuzzy_match_with_fallback
 
     } // fn
 
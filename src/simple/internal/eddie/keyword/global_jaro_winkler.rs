@@ -43,6 +43,8 @@ impl<K: std::cmp::Ord> crate::simple::search_index::SearchIndex<K> {
             // iteration when we reach a keyword that does not start with our
             // supplied (partial) keyword.
             .take_while(|(index_keyword, _keys)| index_keyword.starts_with(index_range))
+            // Only evaluate `maximum_fuzzy_candidates` number of keywords:
+            .take(self.maximum_fuzzy_candidates.unwrap_or(usize::MAX))
             // For each keyword in the search index, calculate its similarity
             // to the user's keyword. Map the `(keyword, keys)` tuple into
             // a `(keyword, score)` tuple:
@@ -54,7 +56,7 @@ impl<K: std::cmp::Ord> crate::simple::search_index::SearchIndex<K> {
             .filter(|(_keyword, score)| score >= &self.fuzzy_minimum_score)
             // Find the `(keyword, score)` tuple with the highest score:
             .max_by(|(_a_keyword, a_score), (_b_keyword, b_score)|
-                a_score.partial_cmp(b_score).unwrap()
+                a_score.total_cmp(b_score)
             ) // max_by
             // Return the `keyword` portion of the `(keyword, score)` tuple
             // to the caller:
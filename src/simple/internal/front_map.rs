@@ -0,0 +1,165 @@
+//! An optional hash map that mirrors the search index's `BTreeMap`, so that
+//! exact keyword lookups don't pay for the `BTreeMap`'s string comparisons.
+//! Range & prefix scans still use the `BTreeMap`.
+
+// Conditionally select hash map type based on feature flags:
+#[cfg(feature = "gxhash")]
+type HashMap<K, V> = std::collections::HashMap<K, V, gxhash::GxBuildHasher>;
+#[cfg(all(feature = "ahash", not(feature = "gxhash")))]
+use ahash::HashMap;
+#[cfg(all(not(feature = "ahash"), not(feature = "gxhash")))]
+use std::collections::HashMap;
+
+// Static dependencies:
+use kstring::KString;
+use std::cmp::Ordering;
+use std::collections::{BTreeMap, BTreeSet};
+use std::{clone::Clone, cmp::Ord};
+
+// -----------------------------------------------------------------------------
+//
+/// A copy of the keywords & keys in a hash map. After the `BTreeMap` has been
+/// changed directly (through `DerefMut`) the copy is marked as stale, and
+/// lookups fall back to the `BTreeMap` until the copy is rebuilt.
+///
+/// The dump keyword, which is attached to every key, isn't copied. Lookups of
+/// it always fall back to the `BTreeMap`, so that inserting a record doesn't
+/// have to copy every key.
+
+#[derive(Clone, Debug)]
+pub(crate) struct FrontMap<K> {
+    map: HashMap<KString, BTreeSet<K>>,
+    dump_keyword: Option<KString>,
+    stale: bool,
+} // FrontMap
+
+// -----------------------------------------------------------------------------
+
+impl<K: Clone + Ord> FrontMap<K> {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Builds a front map that mirrors the given `BTreeMap`, except for the
+    /// dump keyword.
+
+    pub(crate) fn from_b_tree_map(
+        b_tree_map: &BTreeMap<KString, BTreeSet<K>>,
+        dump_keyword: Option<&KString>,
+    ) -> Self {
+        FrontMap {
+            map: b_tree_map
+                .iter()
+                .filter(|(keyword, _keys)| dump_keyword != Some(*keyword))
+                .map(|(keyword, keys)| (keyword.clone(), keys.clone()))
+                .collect(),
+            dump_keyword: dump_keyword.cloned(),
+            stale: false,
+        } // FrontMap
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Copies the current keys of the given keywords from the `BTreeMap`. If
+    /// the front map is stale, it's rebuilt entirely.
+
+    pub(crate) fn sync(
+        &mut self,
+        b_tree_map: &BTreeMap<KString, BTreeSet<K>>,
+        keywords: &[KString],
+    ) {
+        if self.stale {
+            *self = Self::from_b_tree_map(b_tree_map, self.dump_keyword.as_ref());
+            return
+        } // if
+
+        keywords
+            .iter()
+            .filter(|keyword| self.dump_keyword.as_ref() != Some(*keyword))
+            .for_each(|keyword| match b_tree_map.get(keyword) {
+                Some(keys) => { self.map.insert(keyword.clone(), keys.clone()); },
+                None => { self.map.remove(keyword); },
+            }); // for_each
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Attaches or detaches one key for the given keywords, to match the
+    /// `BTreeMap`. Unlike `sync`, this doesn't copy the other keys of the
+    /// keywords, so it's used when a single record is inserted or removed.
+
+    pub(crate) fn sync_key(
+        &mut self,
+        b_tree_map: &BTreeMap<KString, BTreeSet<K>>,
+        keywords: &[KString],
+        key: &K,
+    ) {
+        if self.stale {
+            *self = Self::from_b_tree_map(b_tree_map, self.dump_keyword.as_ref());
+            return
+        } // if
+
+        keywords
+            .iter()
+            .filter(|keyword| self.dump_keyword.as_ref() != Some(*keyword))
+            .for_each(|keyword| match b_tree_map.get(keyword).map(|keys| keys.contains(key)) {
+                Some(true) => { self.map.entry(keyword.clone()).or_default().insert(key.clone()); },
+                Some(false) => if let Some(keys) = self.map.get_mut(keyword) { keys.remove(key); },
+                None => { self.map.remove(keyword); },
+            }); // for_each
+    } // fn
+
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl<K> FrontMap<K> {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Looks up the keys for a keyword. Returns `None` if the front map is
+    /// stale or the keyword is the dump keyword, and the `BTreeMap` should be
+    /// used instead.
+
+    pub(crate) fn get(&self, keyword: &str) -> Option<Option<&BTreeSet<K>>> {
+        let fall_back = self.stale ||
+            self.dump_keyword.as_ref().is_some_and(|dump_keyword| dump_keyword == keyword);
+        (!fall_back).then(|| self.map.get(keyword))
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Marks the front map as stale, after the `BTreeMap` was changed in a way
+    /// that can't be tracked.
+
+    pub(crate) fn mark_stale(&mut self) {
+        self.map.clear();
+        self.stale = true;
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Removes all keywords.
+
+    pub(crate) fn clear(&mut self) {
+        self.map.clear();
+        self.stale = false;
+    } // fn
+
+} // impl
+
+// -----------------------------------------------------------------------------
+//
+/// The front map is a copy of the `BTreeMap` and is ignored when comparing
+/// search indexes.
+
+impl<K> PartialEq for FrontMap<K> {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    } // fn
+} // impl
+
+impl<K> PartialOrd for FrontMap<K> {
+    fn partial_cmp(&self, _other: &Self) -> Option<Ordering> {
+        Some(Ordering::Equal)
+    } // fn
+} // impl
\ No newline at end of file
@@ -0,0 +1,101 @@
+use crate::simple::internal::char_prefix::char_prefix;
+use crate::simple::search_index::SearchIndex;
+use kstring::KString;
+use std::cmp::Ord;
+
+// -----------------------------------------------------------------------------
+
+impl<K: Ord> SearchIndex<K> {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns the prefix that search index keywords must start with to be
+    /// fuzzy matched against the user's keyword, or `None` if the user's
+    /// keyword is too short to be fuzzy matched.
+    ///
+    /// Normally, this is the first `fuzzy_length` characters of the user's
+    /// keyword. If `adaptive_fuzzy_length` is set, shorter prefixes are tried
+    /// first (starting with the empty prefix, which matches every keyword) and
+    /// the first one with few enough keywords in its range is used.
+
+    pub(crate) fn fuzzy_index_range<'u>(&self, user_keyword: &'u str) -> Option<&'u str> {
+
+        if let Some(maximum_candidates) = self.adaptive_fuzzy_length {
+            for length in 0..self.fuzzy_length {
+                // Every shorter prefix is also too short:
+                let index_range: &str = char_prefix(user_keyword, length)?;
+
+                // Only count up to one more than the maximum, so that dense
+                // ranges aren't scanned in full:
+                let candidates: usize = match length {
+                    0 => self.b_tree_map.len(),
+                    _ => self.b_tree_map
+                        .range(KString::from_ref(index_range)..)
+                        .take_while(|(keyword, _keys)| keyword.starts_with(index_range))
+                        .take(maximum_candidates + 1)
+                        .count(),
+                }; // match
+
+                if candidates <= maximum_candidates {
+                    return Some(index_range)
+                } // if
+            } // for
+        } // if
+
+        match self.fuzzy_length {
+            // The match length is 0, compare user's keyword against all search
+            // index keywords:
+            0 => Some(""),
+            // The user keyword must be at least as long as the match length to
+            // be evaluated for fuzzy-matches:
+            fuzzy_length => char_prefix(user_keyword, fuzzy_length),
+        } // match
+
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Fuzzy matches the user's keyword against the search index keywords
+    /// starting with `index_range`, using the `fuzzy_match` closure. If
+    /// nothing is found and `fuzzy_first_letter_fallback` is enabled, the
+    /// fuzzy match is retried with the first two characters of the range
+    /// transposed, and then against every keyword.
+
+    pub(crate) fn fuzzy_match_with_fallback<T>(
+        &self,
+        index_range: &str,
+        is_empty: impl Fn(&T) -> bool,
+        fuzzy_match: impl Fn(&str) -> T,
+    ) -> T {
+
+        let fuzzy_matches: T = fuzzy_match(index_range);
+
+        if !self.fuzzy_first_letter_fallback || !is_empty(&fuzzy_matches) {
+            return fuzzy_matches
+        } // if
+
+        // Transpose the first two characters, for typos such as "iwlliam":
+        let mut chars = index_range.chars();
+        if let (Some(first), Some(second)) = (chars.next(), chars.next()) {
+            if first != second {
+                let transposed_range: String = [second, first]
+                    .into_iter()
+                    .chain(chars)
+                    .collect();
+                let fuzzy_matches: T = fuzzy_match(&transposed_range);
+                if !is_empty(&fuzzy_matches) {
+                    return fuzzy_matches
+                } // if
+            } // if
+        } // if
+
+        // Compare the user's keyword against every keyword, for typos such as
+        // "gilliam":
+        match index_range.is_empty() {
+            true => fuzzy_matches,
+            false => fuzzy_match(""),
+        } // match
+
+    } // fn
+
+} // impl
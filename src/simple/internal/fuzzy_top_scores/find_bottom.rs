@@ -1,5 +1,5 @@
 use crate::simple::internal::FuzzyTopScores;
-use std::{clone::Clone, cmp::Ord, cmp::PartialOrd, hash::Hash};
+use std::{clone::Clone, cmp::Ord, cmp::Ordering, cmp::PartialOrd, hash::Hash};
 
 // -----------------------------------------------------------------------------
 
@@ -22,7 +22,7 @@ impl<'a, K: Hash + Ord, S: Clone + PartialOrd> FuzzyTopScores<'a, K, S> {
             // efficient but it requires `Ord` to be implemented for the `S`
             // score type which could be a floating-point number.
             .min_by(|(_a_keyword, (_a_keys, a_score)), (_b_keyword, (_b_keys, b_score))|
-                a_score.partial_cmp(b_score).unwrap()
+                a_score.partial_cmp(b_score).unwrap_or(Ordering::Equal)
             ) // min_by
             // Remove the `keys` for the lowest score (or bottom) field since we
             // don't need them for comparisons or look-ups:
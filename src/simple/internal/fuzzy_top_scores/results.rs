@@ -1,6 +1,6 @@
 use crate::simple::internal::FuzzyTopScores;
 use kstring::KString;
-use std::{cmp::Ord, cmp::PartialOrd, collections::BTreeSet, hash::Hash};
+use std::{cmp::Ord, cmp::Ordering, cmp::PartialOrd, collections::BTreeSet, hash::Hash};
 
 // -----------------------------------------------------------------------------
 
@@ -26,7 +26,7 @@ impl<'a, K: Hash + Ord, S: PartialOrd> FuzzyTopScores<'a, K, S> {
             .collect();
 
         // Sort the keywords in order of descending score:
-        vec.sort_unstable_by(|a, b| b.1.1.partial_cmp(&a.1.1).unwrap());
+        vec.sort_unstable_by(|a, b| b.1.1.partial_cmp(&a.1.1).unwrap_or(Ordering::Equal));
 
         // Return the keywords and keys to the caller:
         vec
@@ -0,0 +1,96 @@
+use std::cmp::Ord;
+
+// -----------------------------------------------------------------------------
+//
+/// Number of lanes compared at once. A block of this many elements is compared
+/// against the probe value with a branch-free loop that the compiler is able
+/// to vectorize for integer types.
+
+const LANES: usize = 8;
+
+// -----------------------------------------------------------------------------
+//
+/// Intersects two sorted, deduplicated slices and returns the common elements
+/// in ascending order.
+///
+/// The smaller slice is iterated over. For each of its elements, the larger
+/// slice is searched with a galloping (or exponential) search that starts at
+/// the previous match. Once the galloping search has narrowed the window down
+/// to a single block of `LANES` elements, the block is scanned with a
+/// branch-free comparison.
+
+pub(crate) fn intersect_sorted<T: Copy + Ord>(a: &[T], b: &[T]) -> Vec<T> {
+
+    // Iterate over the smaller slice and gallop through the larger slice:
+    let (small, large) = if a.len() <= b.len() { (a, b) } else { (b, a) };
+
+    // This `Vec` is used to contain the intersection:
+    let mut intersection: Vec<T> = Vec::with_capacity(small.len());
+
+    // Position in the larger slice. Every element before this position is
+    // known to be smaller than the current probe value:
+    let mut base: usize = 0;
+
+    for probe in small {
+
+        // Stop early if the larger slice has been exhausted:
+        if base >= large.len() { break }
+
+        // Gallop: double the step until the element at `base + step` is no
+        // longer smaller than the probe value:
+        let mut step: usize = LANES;
+        while base + step < large.len() && large[base + step] < *probe {
+            base += step;
+            step *= 2;
+        } // while
+
+        // The probe value (if present) is somewhere in this window:
+        let end: usize = (base + step + 1).min(large.len());
+
+        // Binary search the window until it fits in a single block:
+        let mut low: usize = base;
+        let mut high: usize = end;
+        while high - low > LANES {
+            let middle: usize = low + (high - low) / 2;
+            if large[middle] < *probe { low = middle } else { high = middle + 1 }
+        } // while
+
+        // Branch-free block scan. Count the elements smaller than the probe
+        // value and check whether the probe value is present:
+        let block: &[T] = &large[low..high];
+        let smaller: usize = block.iter().map(|value| (value < probe) as usize).sum();
+        let found: bool = block.iter().fold(false, |found, value| found | (value == probe));
+
+        // Advance past every element smaller than the probe value:
+        base = low + smaller;
+
+        if found {
+            intersection.push(*probe);
+            base += 1;
+        } // if
+
+    } // for
+
+    intersection
+
+} // fn
+
+// -----------------------------------------------------------------------------
+
+#[test]
+fn test_intersect_sorted() {
+
+    let a: Vec<u32> = (0..1_000).map(|n| n * 3).collect();
+    let b: Vec<u32> = (0..1_000).map(|n| n * 5).collect();
+    let expected: Vec<u32> = (0..200).map(|n| n * 15).collect();
+
+    assert_eq!(intersect_sorted(&a, &b), expected);
+    assert_eq!(intersect_sorted(&b, &a), expected);
+
+    let c: Vec<u64> = vec![7, 999, 4_000];
+    let d: Vec<u64> = (0..5_000).collect();
+    assert_eq!(intersect_sorted(&c, &d), vec![7, 999, 4_000]);
+
+    assert_eq!(intersect_sorted::<u64>(&[], &d), Vec::<u64>::new());
+
+}
\ No newline at end of file
@@ -0,0 +1,180 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::{clone::Clone, cmp::Ord};
+
+// -----------------------------------------------------------------------------
+//
+/// The mean radius of the Earth, in kilometres.
+
+const EARTH_RADIUS_KM: f64 = 6_371.008_8;
+
+// -----------------------------------------------------------------------------
+//
+/// The size of a bucket (or grid cell), in degrees of latitude and longitude.
+
+const BUCKET_SIZE_DEGREES: f64 = 1.0;
+
+// -----------------------------------------------------------------------------
+//
+/// Returns the great-circle distance between two points, in kilometres, using
+/// the haversine formula.
+
+pub(crate) fn haversine_km(from: (f64, f64), to: (f64, f64)) -> f64 {
+    let (from_latitude, from_longitude) = (from.0.to_radians(), from.1.to_radians());
+    let (to_latitude, to_longitude) = (to.0.to_radians(), to.1.to_radians());
+
+    let half_chord = ((to_latitude - from_latitude) / 2.0).sin().powi(2)
+        + from_latitude.cos()
+        * to_latitude.cos()
+        * ((to_longitude - from_longitude) / 2.0).sin().powi(2);
+
+    2.0 * EARTH_RADIUS_KM * half_chord.sqrt().min(1.0).asin()
+} // fn
+
+// -----------------------------------------------------------------------------
+//
+/// Returns the bucket (or grid cell) that contains a point.
+
+fn bucket(latitude: f64, longitude: f64) -> (i64, i64) {
+    (
+        (latitude / BUCKET_SIZE_DEGREES).floor() as i64,
+        (longitude / BUCKET_SIZE_DEGREES).floor() as i64,
+    )
+} // fn
+
+// -----------------------------------------------------------------------------
+//
+/// Stores the location (latitude & longitude in degrees) of records, bucketed
+/// into a coarse grid so that records near a point can be found without
+/// measuring the distance to every record.
+
+#[derive(Clone, Debug, PartialEq, PartialOrd)]
+pub(crate) struct GeoIndex<K> {
+    /// The location of each record.
+    locations: BTreeMap<K, (f64, f64)>,
+    /// The records in each grid cell.
+    buckets: BTreeMap<(i64, i64), BTreeSet<K>>,
+} // GeoIndex
+
+// -----------------------------------------------------------------------------
+
+impl<K> Default for GeoIndex<K> {
+    fn default() -> Self {
+        GeoIndex {
+            locations: BTreeMap::new(),
+            buckets: BTreeMap::new(),
+        } // GeoIndex
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl<K: Clone + Ord> GeoIndex<K> {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Sets the location of a record, replacing any previous location.
+
+    pub(crate) fn insert(&mut self, key: &K, latitude: f64, longitude: f64) {
+        self.remove(key);
+
+        self.buckets
+            .entry(bucket(latitude, longitude))
+            .or_default()
+            .insert(key.clone());
+
+        self.locations.insert(key.clone(), (latitude, longitude));
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Removes the location of a record, if it has one.
+
+    pub(crate) fn remove(&mut self, key: &K) {
+        if let Some((latitude, longitude)) = self.locations.remove(key) {
+            let cell = bucket(latitude, longitude);
+            if let Some(keys) = self.buckets.get_mut(&cell) {
+                keys.remove(key);
+                if keys.is_empty() { self.buckets.remove(&cell); }
+            } // if
+        } // if
+    } // fn
+
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl<K: Ord> GeoIndex<K> {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns the location of a record, if it has one.
+
+    pub(crate) fn get(&self, key: &K) -> Option<&(f64, f64)> {
+        self.locations.get(key)
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Removes all locations.
+
+    pub(crate) fn clear(&mut self) {
+        self.locations.clear();
+        self.buckets.clear();
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns every record within `radius_km` kilometres of a point, along
+    /// with its distance from the point.
+
+    pub(crate) fn near(
+        &self,
+        latitude: f64,
+        longitude: f64,
+        radius_km: f64,
+    ) -> BTreeMap<&K, f64> {
+
+        // The bounding box of the search radius, in degrees. A degree of
+        // latitude is roughly constant, while a degree of longitude shrinks
+        // towards the poles:
+        let latitude_delta = (radius_km / EARTH_RADIUS_KM).to_degrees();
+        let longitude_delta = match latitude.to_radians().cos() {
+            cos if cos > f64::EPSILON => (latitude_delta / cos).min(180.0),
+            _ => 180.0,
+        }; // match
+
+        let (south, west) = bucket(latitude - latitude_delta, longitude - longitude_delta);
+        let (north, east) = bucket(latitude + latitude_delta, longitude + longitude_delta);
+
+        // Visiting every grid cell in a very large bounding box would be
+        // slower than checking every location directly:
+        // Float-to-integer casts saturate, so the bounds are clamped to the
+        // range of `i64`, and the cell count saturates rather than overflowing:
+        let rows: u64 = north.abs_diff(south).saturating_add(1);
+        let columns: u64 = east.abs_diff(west).saturating_add(1);
+        let cells: u64 = rows.saturating_mul(columns);
+
+        // Candidate locations, before their distance is measured:
+        let candidates: Box<dyn Iterator<Item = (&K, &(f64, f64))>> =
+            if cells > self.locations.len() as u64 || columns > 360 {
+                Box::new(self.locations.iter())
+            } else {
+                Box::new(
+                    (south..=north)
+                        .flat_map(|row| (west..=east).map(move |column| (row, column)))
+                        // Wrap longitudes around the antimeridian:
+                        .map(|(row, column)| (row, (column + 180).rem_euclid(360) - 180))
+                        .filter_map(|cell| self.buckets.get(&cell))
+                        .flatten()
+                        .filter_map(|key| self.locations.get_key_value(key))
+                ) // Box
+            }; // if
+
+        candidates
+            .map(|(key, location)| (key, haversine_km((latitude, longitude), *location)))
+            .filter(|(_key, distance)| *distance <= radius_km)
+            .collect()
+
+    } // fn
+
+} // impl
\ No newline at end of file
@@ -8,9 +8,17 @@ use std::collections::HashSet;
 
 // Static dependencies:
 use crate::simple::internal::string_keywords::SplitContext;
-use crate::simple::{Indexable, SearchIndex};
+use crate::simple::{Indexable, InsertOptions, SearchIndex};
 use kstring::KString;
 use std::cmp::Ord;
+use std::collections::{BTreeMap, BTreeSet};
+
+// -----------------------------------------------------------------------------
+//
+/// The keywords produced by each named field of a record (see
+/// `Indexable::field_names`), by field name.
+
+pub(crate) type FieldKeywords = BTreeMap<String, BTreeSet<KString>>;
 
 // -----------------------------------------------------------------------------
 
@@ -26,22 +34,86 @@ impl<K: Ord> SearchIndex<K> {
         &self,
         value: &dyn Indexable,
     ) -> HashSet<KString> {
+        self.indexable_keywords_with(value, &InsertOptions::default())
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns all keywords for the given `Indexable` record, like
+    /// `indexable_keywords`, with the given insert options.
+
+    pub(crate) fn indexable_keywords_with(
+        &self,
+        value: &dyn Indexable,
+        options: &InsertOptions,
+    ) -> HashSet<KString> {
+        self.indexable_keyword_parts(value, options).0
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns the keywords of a single field's string, and the whole string
+    /// keyword among them (see `compact_whole_strings`), like
+    /// `indexable_keyword_parts`.
+
+    pub(crate) fn field_keyword_parts(&self, string: &str) -> (HashSet<KString>, Vec<KString>) {
+        let (mut keywords, whole_string) = self.split_string_parts(string, SplitContext::Indexing, true);
+        keywords.extend(whole_string.clone());
+        (keywords.into_iter().collect(), whole_string.into_iter().collect())
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns all keywords for the given `Indexable` record, like
+    /// `indexable_keywords_with`, the whole string keywords among them (see
+    /// `compact_whole_strings`), and the keywords of each field if the record
+    /// names its fields (see `Indexable::field_names`).
+
+    pub(crate) fn indexable_keyword_parts(
+        &self,
+        value: &dyn Indexable,
+        options: &InsertOptions,
+    ) -> (HashSet<KString>, Vec<KString>, Option<FieldKeywords>) {
+
+        let mut keywords: HashSet<KString> = HashSet::default();
+        let mut whole_strings: Vec<KString> = Vec::new();
 
         // The implemented trait method `strings()` will return the strings from
         // the record that are meant to be indexed:
         let strings = value.strings();
 
-        // Store the individual keywords from these strings:
+        // If the record names its fields, there's a name for every string:
+        let field_names: Vec<String> = value.field_names();
+        let mut fields: Option<FieldKeywords> =
+            (!field_names.is_empty() && field_names.len() == strings.len()).then(BTreeMap::new);
+
+        // Split each `String` field from the record into keywords according
+        // to the `SearchIndex` settings, and store them in the `HashSet`. The
+        // whole string is also kept as a keyword if enabled in user settings,
+        // unless it's left out with the insert options:
         strings
-            // Iterate over each `String` field from the record:
             .into_iter()
-            // Split each `String` into keywords according to the `SearchIndex`
-            // settings. Note that `string_keywords` will allow "use entire
-            // string as a keyword" if enabled in user settings. Flatten the
-            // string's keywords into the `HashSet`:
-            .flat_map(|string| self.string_keywords(&string, SplitContext::Indexing))
-            // Collect all keywords into a `HashSet`:
-            .collect()
+            .enumerate()
+            .for_each(|(index, string)| {
+                let (string_keywords, whole_string) = self.split_string_parts(
+                    &string,
+                    SplitContext::Indexing,
+                    options.index_whole_strings,
+                ); // split_string_parts
+                if let Some(fields) = &mut fields {
+                    fields
+                        .entry(field_names[index].clone())
+                        .or_default()
+                        .extend(string_keywords.iter().chain(&whole_string).cloned());
+                } // if
+                keywords.extend(string_keywords);
+                if let Some(whole_string) = whole_string {
+                    keywords.insert(whole_string.clone());
+                    whole_strings.push(whole_string);
+                } // if
+            }); // for_each
+
+        (keywords, whole_strings, fields)
 
     } // fn
 
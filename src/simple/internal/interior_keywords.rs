@@ -0,0 +1,63 @@
+use crate::simple::internal::KeywordKeys;
+use crate::simple::search_index::SearchIndex;
+use kstring::KString;
+use std::cmp::Ord;
+
+// -----------------------------------------------------------------------------
+
+impl<K: Ord> SearchIndex<K> {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns the whole-string keywords (see `maximum_string_length`) that
+    /// contain the (partial) keyword as an interior token. For example, the
+    /// partial keyword "conq" matches the whole-string keyword "william the
+    /// conqueror". Keywords that start with the partial keyword aren't
+    /// returned since they're found by the regular prefix search. Compacted
+    /// whole strings (see `compact_whole_strings`) are included.
+    ///
+    /// The interior tokens are indexed as keywords are attached and detached
+    /// when `interior_autocomplete` is enabled, so this is a prefix scan of
+    /// the tokens. If the tokens are stale (after the keywords were changed
+    /// through `DerefMut`), every keyword in the search index is scanned
+    /// instead.
+
+    pub(crate) fn interior_keywords(&self, keyword: &str) -> Vec<(&KString, KeywordKeys<'_, K>)> {
+
+        // Look up the keywords in the interior tokens, if they're current:
+        if let Some(interior_keywords) = self.interior_tokens
+            .as_ref()
+            .and_then(|interior_tokens| interior_tokens.containing(keyword)) {
+                return interior_keywords
+                    .into_iter()
+                    // The dump keyword isn't a real keyword:
+                    .filter(|index_keyword| self.dump_keyword.as_ref() != Some(*index_keyword))
+                    .filter_map(|index_keyword| self
+                        .keyword_entry(index_keyword)
+                        .map(|keys| (index_keyword, keys))
+                    ) // filter_map
+                    .collect()
+        } // if
+
+        // Whole-string keywords are the only keywords that may contain the
+        // split pattern's characters:
+        let split_pattern: &[char] = self.split_pattern.as_deref().unwrap_or_default();
+
+        self
+            // Iterate over every keyword in the search index:
+            .keyword_entries("")
+            // The dump keyword isn't a real keyword:
+            .filter(|(index_keyword, _keys)| self.dump_keyword.as_ref() != Some(*index_keyword))
+            // Only keep whole-string keywords with an interior token that
+            // starts with the partial keyword:
+            .filter(|(index_keyword, _keys)| !split_pattern.is_empty() && index_keyword
+                .split(split_pattern)
+                // Skip the first token, it's covered by the prefix search:
+                .skip(1)
+                .any(|token| token.starts_with(keyword))
+            ) // filter
+            // Collect the matching keywords into a `Vec`:
+            .collect()
+    } // fn
+
+} // impl
\ No newline at end of file
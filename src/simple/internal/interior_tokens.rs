@@ -0,0 +1,161 @@
+//! An optional index of the interior tokens of the keywords that contain the
+//! split pattern (whole-string keywords), so that the keywords with an
+//! interior token starting with a (partial) keyword can be found with a single
+//! prefix scan instead of visiting every keyword. See
+//! `SearchIndex::interior_keywords`.
+
+use kstring::KString;
+use std::cmp::Ordering;
+use std::collections::{BTreeMap, BTreeSet};
+use std::ops::Bound;
+
+// -----------------------------------------------------------------------------
+//
+/// The keywords of each interior token. After the `BTreeMap` has been changed
+/// directly (through `DerefMut`) the tokens are marked as stale, and lookups
+/// fall back to scanning the keywords until they're rebuilt.
+
+#[derive(Clone, Debug, Default)]
+pub(crate) struct InteriorTokens {
+    tokens: BTreeMap<KString, BTreeSet<KString>>,
+    split_pattern: Vec<char>,
+    stale: bool,
+} // InteriorTokens
+
+// -----------------------------------------------------------------------------
+
+impl InteriorTokens {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Builds the interior tokens of the given keywords.
+
+    pub(crate) fn from_keywords<'k>(
+        split_pattern: &[char],
+        keywords: impl Iterator<Item = &'k KString>,
+    ) -> Self {
+        let mut interior_tokens = InteriorTokens {
+            tokens: BTreeMap::new(),
+            split_pattern: split_pattern.to_vec(),
+            stale: false,
+        }; // InteriorTokens
+        keywords.for_each(|keyword| interior_tokens.insert(keyword));
+        interior_tokens
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Adds the interior tokens of the given keywords that are in the
+    /// `BTreeMap` or are compacted whole strings, and removes those of the
+    /// keywords that aren't. If the tokens are stale, they're rebuilt
+    /// entirely.
+
+    pub(crate) fn sync<K>(
+        &mut self,
+        b_tree_map: &BTreeMap<KString, BTreeSet<K>>,
+        whole_strings: &BTreeMap<KString, K>,
+        keywords: &[KString],
+    ) {
+        if self.stale {
+            *self = Self::from_keywords(&self.split_pattern, b_tree_map.keys().chain(whole_strings.keys()));
+            return
+        } // if
+
+        keywords
+            .iter()
+            .for_each(|keyword| match b_tree_map.contains_key(keyword) || whole_strings.contains_key(keyword) {
+                true => self.insert(keyword),
+                false => self.remove(keyword),
+            }); // for_each
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns the keywords with an interior token that starts with the
+    /// (partial) keyword, in lexographic order. Returns `None` if the tokens
+    /// are stale and the keywords should be scanned instead.
+
+    pub(crate) fn containing<'t>(&'t self, keyword: &str) -> Option<BTreeSet<&'t KString>> {
+        (!self.stale).then(|| self.tokens
+            .range::<str, _>((Bound::Included(keyword), Bound::Unbounded))
+            .take_while(|(token, _keywords)| token.starts_with(keyword))
+            .flat_map(|(_token, keywords)| keywords)
+            .collect()
+        ) // then
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Marks the tokens as stale, after the `BTreeMap` was changed in a way
+    /// that can't be tracked.
+
+    pub(crate) fn mark_stale(&mut self) {
+        self.tokens.clear();
+        self.stale = true;
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Removes all tokens.
+
+    pub(crate) fn clear(&mut self) {
+        self.tokens.clear();
+        self.stale = false;
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Adds the interior tokens of a keyword.
+
+    fn insert(&mut self, keyword: &KString) {
+        let tokens: Vec<KString> = interior_tokens(keyword, &self.split_pattern)
+            .map(KString::from_ref)
+            .collect();
+        tokens.into_iter().for_each(|token| {
+            self.tokens.entry(token).or_default().insert(keyword.clone());
+        }); // for_each
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Removes the interior tokens of a keyword.
+
+    fn remove(&mut self, keyword: &KString) {
+        interior_tokens(keyword, &self.split_pattern).for_each(|token| {
+            if let Some(keywords) = self.tokens.get_mut(token) {
+                keywords.remove(keyword);
+                if keywords.is_empty() { self.tokens.remove(token); }
+            } // if
+        }); // for_each
+    } // fn
+
+} // impl
+
+// -----------------------------------------------------------------------------
+//
+/// The interior tokens are derived from the keywords and are ignored when
+/// comparing search indexes.
+
+impl PartialEq for InteriorTokens {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    } // fn
+} // impl
+
+impl PartialOrd for InteriorTokens {
+    fn partial_cmp(&self, _other: &Self) -> Option<Ordering> {
+        Some(Ordering::Equal)
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+//
+/// Returns the tokens of a keyword after the first one. The first token is
+/// found by the regular prefix search.
+
+fn interior_tokens<'k>(keyword: &'k str, split_pattern: &'k [char]) -> impl Iterator<Item = &'k str> {
+    keyword
+        .split(split_pattern)
+        .skip(1)
+        .filter(|token| !token.is_empty())
+} // fn
\ No newline at end of file
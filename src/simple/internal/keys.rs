@@ -0,0 +1,134 @@
+use std::cmp::Ord;
+use std::collections::BTreeSet;
+
+// -----------------------------------------------------------------------------
+//
+/// The intermediate results of a search: references to keys, sorted and
+/// deduplicated in a `Vec`.
+///
+/// Every internal search step returns and accepts this type, so results move
+/// from one step to the next without being rebuilt. Intersections are done in
+/// place, membership is checked with a binary search, and the public search
+/// methods return the `Vec` as-is.
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(crate) struct Keys<'k, K>(Vec<&'k K>);
+
+// -----------------------------------------------------------------------------
+
+impl<K> Default for Keys<'_, K> {
+    fn default() -> Self {
+        Keys(Vec::new())
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl<'k, K: Ord> Keys<'k, K> {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns the keys of a keyword, which are already sorted and
+    /// deduplicated. At most `maximum` keys are kept.
+
+    pub(crate) fn from_set(keys: &'k BTreeSet<K>, maximum: usize) -> Self {
+        Keys(keys.iter().take(maximum).collect())
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns `true` if the results contain the key.
+
+    pub(crate) fn contains(&self, key: &K) -> bool {
+        self.0.binary_search(&key).is_ok()
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Only keeps the results that are also keys of a keyword.
+
+    pub(crate) fn retain_in(&mut self, keys: &BTreeSet<K>) {
+        self.0.retain(|key| keys.contains(key));
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Only keeps the results that pass the filter. The results stay sorted.
+
+    pub(crate) fn retain(&mut self, mut filter: impl FnMut(&K) -> bool) {
+        self.0.retain(|key| filter(key));
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns the results that are in both sets of results.
+
+    pub(crate) fn intersect(&self, other: &Keys<'k, K>) -> Self {
+        Keys(crate::simple::internal::galloping::intersect_sorted(&self.0, &other.0))
+    } // fn
+
+    // -------------------------------------------------------------------------
+
+    pub(crate) fn len(&self) -> usize {
+        self.0.len()
+    } // fn
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns the results, in ascending order.
+
+    pub(crate) fn into_vec(self) -> Vec<&'k K> {
+        self.0
+    } // fn
+
+} // impl
+
+// -----------------------------------------------------------------------------
+//
+/// Collects keys in any order, sorting and deduplicating them.
+
+impl<'k, K: Ord> FromIterator<&'k K> for Keys<'k, K> {
+    fn from_iter<I: IntoIterator<Item = &'k K>>(iter: I) -> Self {
+        let mut keys: Vec<&'k K> = iter.into_iter().collect();
+        keys.sort_unstable();
+        keys.dedup();
+        Keys(keys)
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl<'k, K> IntoIterator for Keys<'k, K> {
+    type Item = &'k K;
+    type IntoIter = std::vec::IntoIter<&'k K>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[test]
+fn test_keys() {
+    let a: BTreeSet<usize> = [1, 3, 5, 7].into_iter().collect();
+    let b: BTreeSet<usize> = [3, 4, 5].into_iter().collect();
+
+    let mut keys: Keys<usize> = Keys::from_set(&a, usize::MAX);
+    assert!(keys.contains(&5));
+    assert!(!keys.contains(&4));
+    assert_eq!(Keys::from_set(&a, 2).into_vec(), vec![&1, &3]);
+
+    assert_eq!(keys.intersect(&Keys::from_set(&b, usize::MAX)).into_vec(), vec![&3, &5]);
+    keys.retain_in(&b);
+    assert_eq!(keys, Keys::from_set(&b, usize::MAX).intersect(&keys));
+    keys.retain(|key| *key > 3);
+    assert_eq!(keys.into_vec(), vec![&5]);
+
+    let collected: Keys<usize> = b.iter().chain(a.iter()).collect();
+    assert_eq!(collected.len(), 5);
+    assert_eq!(collected.into_vec(), vec![&1, &3, &4, &5, &7]);
+} // fn
\ No newline at end of file
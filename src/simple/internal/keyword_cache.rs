@@ -0,0 +1,178 @@
+//! A small least-recently-used cache that memoizes the keywords produced by
+//! splitting a search string. `Live` search and autocompletion are often called
+//! repeatedly with the same string while the user pauses typing.
+
+// Conditionally select hash map type based on feature flags:
+#[cfg(feature = "gxhash")]
+type HashMap<K, V> = std::collections::HashMap<K, V, gxhash::GxBuildHasher>;
+#[cfg(all(feature = "ahash", not(feature = "gxhash")))]
+use ahash::HashMap;
+#[cfg(all(not(feature = "ahash"), not(feature = "gxhash")))]
+use std::collections::HashMap;
+
+// Static dependencies:
+use kstring::KString;
+use std::cmp::Ordering;
+use std::sync::atomic::{AtomicU64, Ordering as AtomicOrdering};
+use std::sync::{Arc, RwLock};
+
+// -----------------------------------------------------------------------------
+//
+/// Memoizes the keywords for recently seen search strings. The cache lives
+/// behind a `RwLock` so that it can be updated from `&self` search methods while
+/// keeping the `SearchIndex` `Send` and `Sync`. Cache hits only take the read
+/// lock, so concurrent searches don't wait on each other.
+///
+/// The cache depends on the search index settings (case sensitivity, split
+/// pattern, query limits, etc.) and not on the indexed data, so it doesn't
+/// have to be invalidated when records are inserted or removed. It must be
+/// invalidated whenever a setting that changes how search strings are split
+/// is changed.
+
+#[derive(Debug, Default)]
+pub(crate) struct KeywordCache {
+    /// The keywords for each remembered search string.
+    entries: RwLock<HashMap<KString, CacheEntry>>,
+    /// Incremented on every cache access, and used to find the least recently
+    /// used entry.
+    ticks: AtomicU64,
+    /// Maximum number of search strings to remember. `0` disables the cache.
+    capacity: usize,
+} // KeywordCache
+
+// -----------------------------------------------------------------------------
+//
+/// A remembered search string's keywords, and when they were last used.
+
+#[derive(Debug)]
+struct CacheEntry {
+    keywords: Arc<[KString]>,
+    last_used: AtomicU64,
+} // CacheEntry
+
+// -----------------------------------------------------------------------------
+
+impl KeywordCache {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Instantiates a new, empty cache that will remember up to `capacity`
+    /// search strings.
+
+    pub(crate) fn with_capacity(capacity: usize) -> Self {
+        KeywordCache {
+            entries: RwLock::new(HashMap::default()),
+            ticks: AtomicU64::new(0),
+            capacity,
+        } // KeywordCache
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns the maximum number of search strings the cache will remember.
+
+    pub(crate) fn capacity(&self) -> usize {
+        self.capacity
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns the cached keywords for the search string, if any. A cache hit
+    /// marks the entry as the most recently used, and only shares the keywords.
+
+    pub(crate) fn get(&self, string: &str) -> Option<Arc<[KString]>> {
+
+        if self.capacity == 0 { return None }
+
+        // A poisoned lock is treated as a cache miss:
+        let entries = self.entries.read().ok()?;
+        let entry = entries.get(string)?;
+
+        entry.last_used.store(self.tick(), AtomicOrdering::Relaxed);
+        Some(Arc::clone(&entry.keywords))
+
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Remembers the keywords for the search string, and returns the keywords
+    /// that are cached for it. If another search cached the same string first,
+    /// its keywords are kept. If the cache is full, the least recently used
+    /// entry is evicted.
+
+    pub(crate) fn insert(&self, string: &str, keywords: Arc<[KString]>) -> Arc<[KString]> {
+
+        if self.capacity == 0 { return keywords }
+
+        // A poisoned lock means nothing will be cached:
+        let Ok(mut entries) = self.entries.write() else { return keywords };
+
+        // Make room for the search string, if it isn't already cached:
+        if entries.len() >= self.capacity && !entries.contains_key(string) {
+            let least_recently_used: Option<KString> = entries
+                .iter()
+                .min_by_key(|(_string, entry)| entry.last_used.load(AtomicOrdering::Relaxed))
+                .map(|(string, _entry)| string.clone());
+            if let Some(least_recently_used) = least_recently_used {
+                entries.remove(&least_recently_used);
+            } // if
+        } // if
+
+        let last_used = AtomicU64::new(self.tick());
+        let entry = entries
+            .entry(KString::from_ref(string))
+            .or_insert(CacheEntry { keywords, last_used });
+
+        Arc::clone(&entry.keywords)
+
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Forgets every cached search string. This must be called whenever a
+    /// setting that changes how search strings are split is changed.
+
+    pub(crate) fn invalidate(&mut self) {
+        match self.entries.get_mut() {
+            Ok(entries) => entries.clear(),
+            Err(poisoned) => poisoned.into_inner().clear(),
+        } // match
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns the next value of the access counter.
+
+    fn tick(&self) -> u64 {
+        self.ticks.fetch_add(1, AtomicOrdering::Relaxed)
+    } // fn
+
+} // impl
+
+// -----------------------------------------------------------------------------
+//
+/// Cloning a search index does not copy the cached keywords. The clone starts
+/// with an empty cache of the same capacity.
+
+impl Clone for KeywordCache {
+    fn clone(&self) -> Self {
+        KeywordCache::with_capacity(self.capacity)
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+//
+/// The cache is an implementation detail and is ignored when comparing search
+/// indexes.
+
+impl PartialEq for KeywordCache {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    } // fn
+} // impl
+
+impl PartialOrd for KeywordCache {
+    fn partial_cmp(&self, _other: &Self) -> Option<Ordering> {
+        Some(Ordering::Equal)
+    } // fn
+} // impl
\ No newline at end of file
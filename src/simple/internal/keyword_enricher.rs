@@ -0,0 +1,82 @@
+//! An optional callback that derives extra keywords from each keyword of a
+//! record when it's indexed.
+
+use std::cmp::Ordering;
+use std::fmt::{Debug, Formatter, Result};
+use std::sync::Arc;
+
+// -----------------------------------------------------------------------------
+//
+/// A callback that's given a keyword and returns the keywords derived from it.
+
+type Enrich = Arc<dyn Fn(&str) -> Vec<String> + Send + Sync>;
+
+// -----------------------------------------------------------------------------
+//
+/// Holds the callback given to `keyword_enricher`, if any. The callback is
+/// shared with clones of the search index.
+
+#[derive(Clone, Default)]
+pub(crate) struct KeywordEnricher(Option<Enrich>);
+
+// -----------------------------------------------------------------------------
+
+impl KeywordEnricher {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Wraps a callback.
+
+    pub(crate) fn new(enrich: impl Fn(&str) -> Vec<String> + Send + Sync + 'static) -> Self {
+        KeywordEnricher(Some(Arc::new(enrich)))
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns `true` if there's a callback.
+
+    pub(crate) fn is_some(&self) -> bool {
+        self.0.is_some()
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Invokes the callback, if any, with the keyword. Returns the derived
+    /// keywords.
+
+    pub(crate) fn enrich(&self, keyword: &str) -> Vec<String> {
+        match &self.0 {
+            Some(enrich) => enrich(keyword),
+            None => Vec::new(),
+        } // match
+    } // fn
+
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl Debug for KeywordEnricher {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> Result {
+        match self.0 {
+            Some(_) => formatter.write_str("KeywordEnricher(Some(..))"),
+            None => formatter.write_str("KeywordEnricher(None)"),
+        } // match
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+//
+/// The callback is an implementation detail and is ignored when comparing
+/// search indexes.
+
+impl PartialEq for KeywordEnricher {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    } // fn
+} // impl
+
+impl PartialOrd for KeywordEnricher {
+    fn partial_cmp(&self, _other: &Self) -> Option<Ordering> {
+        Some(Ordering::Equal)
+    } // fn
+} // impl
\ No newline at end of file
@@ -0,0 +1,124 @@
+//! Iterates over the keyword dictionary together with the compacted
+//! whole-string keywords (see `compact_whole_strings`), for the readers that
+//! must see every keyword.
+
+use crate::simple::search_index::SearchIndex;
+use kstring::KString;
+use std::collections::{btree_map, BTreeSet};
+use std::iter::Peekable;
+use std::ops::Bound;
+
+// -----------------------------------------------------------------------------
+//
+/// The keys of a keyword: the set of keys of a keyword in the dictionary, or
+/// the single key of a compacted whole-string keyword.
+
+#[derive(Debug)]
+pub(crate) enum KeywordKeys<'a, K> {
+    /// A keyword in the keyword dictionary.
+    Dictionary(&'a BTreeSet<K>),
+    /// A compacted whole-string keyword.
+    Compacted(&'a K),
+} // KeywordKeys
+
+impl<K> Clone for KeywordKeys<'_, K> {
+    fn clone(&self) -> Self {
+        *self
+    } // fn
+} // impl
+
+impl<K> Copy for KeywordKeys<'_, K> {}
+
+// -----------------------------------------------------------------------------
+
+impl<'a, K> KeywordKeys<'a, K> {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns the number of keys.
+
+    pub(crate) fn len(&self) -> usize {
+        match self {
+            KeywordKeys::Dictionary(keys) => keys.len(),
+            KeywordKeys::Compacted(_key) => 1,
+        } // match
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns the keys, in order.
+
+    pub(crate) fn iter(&self) -> impl Iterator<Item = &'a K> {
+        let (keys, key) = match *self {
+            KeywordKeys::Dictionary(keys) => (Some(keys), None),
+            KeywordKeys::Compacted(key) => (None, Some(key)),
+        }; // match
+        keys.into_iter().flatten().chain(key)
+    } // fn
+
+} // impl
+
+// -----------------------------------------------------------------------------
+//
+/// The keywords of the keyword dictionary and the compacted whole-string
+/// keywords, merged in lexographic order. A keyword is never in both.
+
+pub(crate) struct KeywordEntries<'a, K> {
+    dictionary: Peekable<btree_map::Range<'a, KString, BTreeSet<K>>>,
+    whole_strings: Peekable<btree_map::Range<'a, KString, K>>,
+} // KeywordEntries
+
+// -----------------------------------------------------------------------------
+
+impl<'a, K> Iterator for KeywordEntries<'a, K> {
+    type Item = (&'a KString, KeywordKeys<'a, K>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let dictionary_first = match (self.dictionary.peek(), self.whole_strings.peek()) {
+            (Some((keyword, _keys)), Some((whole_string, _key))) => keyword <= whole_string,
+            (Some(_entry), None) => true,
+            (None, Some(_entry)) => false,
+            (None, None) => return None,
+        }; // match
+
+        match dictionary_first {
+            true => self.dictionary
+                .next()
+                .map(|(keyword, keys)| (keyword, KeywordKeys::Dictionary(keys))),
+            false => self.whole_strings
+                .next()
+                .map(|(whole_string, key)| (whole_string, KeywordKeys::Compacted(key))),
+        } // match
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl<K: Ord> SearchIndex<K> {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns every keyword from `start` onwards, including the compacted
+    /// whole-string keywords and the `dump_keyword`, in lexographic order.
+
+    pub(crate) fn keyword_entries(&self, start: &str) -> KeywordEntries<'_, K> {
+        let range: (Bound<&str>, Bound<&str>) = (Bound::Included(start), Bound::Unbounded);
+        KeywordEntries {
+            dictionary: self.b_tree_map.range::<str, _>(range).peekable(),
+            whole_strings: self.whole_strings.range::<str, _>(range).peekable(),
+        } // KeywordEntries
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns the keys of a keyword using an exact match, whether it's in the
+    /// keyword dictionary or is a compacted whole-string keyword.
+
+    pub(crate) fn keyword_entry(&self, keyword: &str) -> Option<KeywordKeys<'_, K>> {
+        match self.keyword_keys(keyword) {
+            Some(keys) => Some(KeywordKeys::Dictionary(keys)),
+            None => self.whole_strings.get(keyword).map(KeywordKeys::Compacted),
+        } // match
+    } // fn
+
+} // impl
\ No newline at end of file
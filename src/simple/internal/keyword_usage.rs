@@ -0,0 +1,143 @@
+//! Tracks when each keyword was last hit by a search, so that a search index
+//! with a `maximum_keywords` cap can evict the least recently hit keywords
+//! first.
+
+use kstring::KString;
+use std::cmp::Ordering;
+use std::collections::BTreeMap;
+use std::sync::RwLock;
+use std::sync::atomic::{AtomicU64, Ordering as AtomicOrdering};
+
+// -----------------------------------------------------------------------------
+//
+/// Remembers the logical time at which each keyword was last searched for. The
+/// record lives behind a `RwLock` so that it can be updated from `&self` search
+/// methods while keeping the `SearchIndex` `Send` and `Sync`. Each keyword's
+/// time is atomic, so a search that hits already-tracked keywords only takes
+/// the read lock.
+///
+/// A logical clock (a counter incremented on every search) is used rather than
+/// the wall clock, since only the relative order of hits matters. Only the
+/// keywords in the search index are tracked, and they're forgotten when
+/// they're removed from it, so the record can't grow past the search index.
+
+#[derive(Debug, Default)]
+pub(crate) struct KeywordUsage {
+    /// The logical time of the latest search.
+    clock: AtomicU64,
+    /// The logical time at which each keyword was last hit. Keywords that have
+    /// never been hit are absent.
+    last_hit: RwLock<BTreeMap<KString, AtomicU64>>,
+} // KeywordUsage
+
+// -----------------------------------------------------------------------------
+
+impl KeywordUsage {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Records that a search hit these keywords. The caller must only pass
+    /// keywords that are in the search index.
+
+    pub(crate) fn touch<'k>(&self, keywords: impl Iterator<Item = &'k KString>) {
+        let now = self.clock.fetch_add(1, AtomicOrdering::Relaxed) + 1;
+
+        // A poisoned lock means the hits aren't recorded. Keywords that are
+        // already tracked are updated in place:
+        let Ok(last_hit) = self.last_hit.read() else { return };
+        let untracked: Vec<&KString> = keywords
+            .filter(|keyword| match last_hit.get(*keyword) {
+                Some(time) => { time.fetch_max(now, AtomicOrdering::Relaxed); false },
+                None => true,
+            }) // filter
+            .collect();
+        drop(last_hit);
+
+        // Keywords that haven't been hit before take the write lock:
+        if untracked.is_empty() { return }
+        if let Ok(mut last_hit) = self.last_hit.write() {
+            untracked.into_iter().for_each(|keyword| {
+                last_hit
+                    .entry(keyword.clone())
+                    .or_default()
+                    .fetch_max(now, AtomicOrdering::Relaxed);
+            }); // for_each
+        } // if
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns the logical time at which a keyword was last hit, or `0` if it
+    /// has never been hit.
+
+    pub(crate) fn last_hit(&mut self, keyword: &KString) -> u64 {
+        let last_hit = match self.last_hit.get_mut() {
+            Ok(last_hit) => last_hit,
+            Err(poisoned) => poisoned.into_inner(),
+        }; // match
+        last_hit.get_mut(keyword).map(|time| *time.get_mut()).unwrap_or_default()
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Forgets a keyword, for example when it has been evicted.
+
+    pub(crate) fn forget(&mut self, keyword: &KString) {
+        match self.last_hit.get_mut() {
+            Ok(last_hit) => last_hit.remove(keyword),
+            Err(poisoned) => poisoned.into_inner().remove(keyword),
+        }; // match
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Forgets every keyword.
+
+    pub(crate) fn clear(&mut self) {
+        match self.last_hit.get_mut() {
+            Ok(last_hit) => last_hit.clear(),
+            Err(poisoned) => poisoned.into_inner().clear(),
+        } // match
+    } // fn
+
+} // impl
+
+// -----------------------------------------------------------------------------
+//
+/// Cloning a search index copies the keyword usage.
+
+impl Clone for KeywordUsage {
+    fn clone(&self) -> Self {
+        let copy = |last_hit: &BTreeMap<KString, AtomicU64>| last_hit
+            .iter()
+            .map(|(keyword, time)| (keyword.clone(), AtomicU64::new(time.load(AtomicOrdering::Relaxed))))
+            .collect();
+
+        let last_hit: BTreeMap<KString, AtomicU64> = match self.last_hit.read() {
+            Ok(last_hit) => copy(&last_hit),
+            Err(poisoned) => copy(&poisoned.into_inner()),
+        }; // match
+
+        KeywordUsage {
+            clock: AtomicU64::new(self.clock.load(AtomicOrdering::Relaxed)),
+            last_hit: RwLock::new(last_hit),
+        } // KeywordUsage
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+//
+/// The keyword usage is an implementation detail and is ignored when comparing
+/// search indexes.
+
+impl PartialEq for KeywordUsage {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    } // fn
+} // impl
+
+impl PartialOrd for KeywordUsage {
+    fn partial_cmp(&self, _other: &Self) -> Option<Ordering> {
+        Some(Ordering::Equal)
+    } // fn
+} // impl
\ No newline at end of file
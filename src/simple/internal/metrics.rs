@@ -0,0 +1,83 @@
+//! Integration with the [metrics](https://crates.io/crates/metrics) facade
+//! crate. When the `metrics` feature is enabled, the search index will emit
+//! counters, gauges and histograms to whichever metrics recorder (Prometheus,
+//! StatsD, etc.) has been installed by the application.
+//!
+//! | Metric                                   | Type      | Labels |
+//! |------------------------------------------|-----------|--------|
+//! | `indicium_searches_total`                | Counter   | `type` |
+//! | `indicium_search_duration_seconds`       | Histogram | `type` |
+//! | `indicium_autocompletes_total`           | Counter   | `type` |
+//! | `indicium_autocomplete_duration_seconds` | Histogram | `type` |
+//! | `indicium_fuzzy_fallbacks_total`         | Counter   |        |
+//! | `indicium_keywords`                      | Gauge     |        |
+
+use crate::simple::{AutocompleteType, SearchType};
+use std::time::Instant;
+
+// -----------------------------------------------------------------------------
+//
+/// Returns the label value used for the `type` label of search metrics.
+
+fn search_type_label(search_type: &SearchType) -> &'static str {
+    match search_type {
+        SearchType::And => "and",
+        SearchType::Keyword => "keyword",
+        SearchType::Live => "live",
+        SearchType::Or => "or",
+    } // match
+} // fn
+
+// -----------------------------------------------------------------------------
+//
+/// Returns the label value used for the `type` label of autocomplete metrics.
+
+fn autocomplete_type_label(autocomplete_type: &AutocompleteType) -> &'static str {
+    match autocomplete_type {
+        AutocompleteType::Context => "context",
+        AutocompleteType::Global => "global",
+        AutocompleteType::Hybrid => "hybrid",
+        AutocompleteType::Keyword => "keyword",
+    } // match
+} // fn
+
+// -----------------------------------------------------------------------------
+//
+/// Records a search: increments the search counter and records the time
+/// elapsed since `start`.
+
+pub(crate) fn record_search(search_type: &SearchType, start: Instant) {
+    let label = search_type_label(search_type);
+    metrics::counter!("indicium_searches_total", "type" => label).increment(1);
+    metrics::histogram!("indicium_search_duration_seconds", "type" => label)
+        .record(start.elapsed().as_secs_f64());
+} // fn
+
+// -----------------------------------------------------------------------------
+//
+/// Records an autocompletion: increments the autocomplete counter and records
+/// the time elapsed since `start`.
+
+pub(crate) fn record_autocomplete(autocomplete_type: &AutocompleteType, start: Instant) {
+    let label = autocomplete_type_label(autocomplete_type);
+    metrics::counter!("indicium_autocompletes_total", "type" => label).increment(1);
+    metrics::histogram!("indicium_autocomplete_duration_seconds", "type" => label)
+        .record(start.elapsed().as_secs_f64());
+} // fn
+
+// -----------------------------------------------------------------------------
+//
+/// Records that a keyword had no exact matches and fuzzy matching was used as
+/// a fall-back.
+
+pub(crate) fn record_fuzzy_fallback() {
+    metrics::counter!("indicium_fuzzy_fallbacks_total").increment(1);
+} // fn
+
+// -----------------------------------------------------------------------------
+//
+/// Records the number of keywords in the search index.
+
+pub(crate) fn record_index_size(keywords: usize) {
+    metrics::gauge!("indicium_keywords").set(keywords as f64);
+} // fn
\ No newline at end of file
@@ -1,8 +1,33 @@
+pub(crate) mod char_prefix;
+pub(crate) mod clock;
+pub(crate) mod front_map;
 mod indexable_keywords;
+mod interior_keywords;
+pub(crate) mod interior_tokens;
+pub(crate) mod keys;
+pub(crate) mod keyword_cache;
+pub(crate) mod keyword_enricher;
+pub(crate) mod keyword_entries;
+pub(crate) mod keyword_usage;
+pub(crate) mod permuterm;
+pub(crate) mod plurals;
+pub(crate) mod runtime_counters;
+pub(crate) mod sorted_postings;
+
+#[cfg(feature = "metrics")]
+pub(crate) mod metrics;
 mod search;
 mod search_and;
-pub(crate) mod search_top_scores;
+pub(crate) mod saturation_hook;
+mod suggestible;
+pub(crate) mod suggestion_filter;
 pub(crate) mod string_keywords;
+pub(crate) mod warm_cache;
+
+pub(crate) mod galloping;
+
+#[cfg(feature = "geo")]
+pub(crate) mod geo_index;
 
 #[cfg(feature = "strsim")]
 mod strsim;
@@ -10,12 +35,37 @@ mod strsim;
 #[cfg(feature = "eddie")]
 mod eddie;
 
+#[cfg(any(feature = "strsim", feature = "eddie"))]
+mod fuzzy_range;
+
 #[cfg(any(feature = "strsim", feature = "eddie"))]
 pub(crate) mod fuzzy_top_scores;
 
+#[cfg(all(feature = "strsim", feature = "eddie"))]
+mod auto_fuzzy_backend;
+
 // -----------------------------------------------------------------------------
 
-pub(crate) use crate::simple::internal::search_top_scores::SearchTopScores;
+#[cfg(feature = "geo")]
+pub(crate) use crate::simple::internal::geo_index::GeoIndex;
+pub(crate) use crate::simple::internal::clock::Clock;
+pub(crate) use crate::simple::internal::front_map::FrontMap;
+pub(crate) use crate::simple::internal::indexable_keywords::FieldKeywords;
+pub(crate) use crate::simple::internal::interior_tokens::InteriorTokens;
+pub(crate) use crate::simple::internal::keys::Keys;
+pub(crate) use crate::simple::internal::keyword_cache::KeywordCache;
+pub(crate) use crate::simple::internal::keyword_enricher::KeywordEnricher;
+pub(crate) use crate::simple::internal::keyword_entries::KeywordKeys;
+pub(crate) use crate::simple::internal::keyword_usage::KeywordUsage;
+pub(crate) use crate::simple::internal::permuterm::Permuterm;
+pub(crate) use crate::simple::internal::runtime_counters::RuntimeCounters;
+pub(crate) use crate::simple::internal::saturation_hook::SaturationHook;
+pub(crate) use crate::simple::internal::sorted_postings::SortedPostings;
+pub(crate) use crate::simple::internal::suggestion_filter::SuggestionFilter;
+pub(crate) use crate::simple::internal::warm_cache::WarmCache;
 
 #[cfg(any(feature = "strsim", feature = "eddie"))]
-pub(crate) use crate::simple::internal::fuzzy_top_scores::FuzzyTopScores;
\ No newline at end of file
+pub(crate) use crate::simple::internal::fuzzy_top_scores::FuzzyTopScores;
+
+#[cfg(all(feature = "strsim", feature = "eddie"))]
+pub(crate) use crate::simple::internal::auto_fuzzy_backend::auto_fuzzy_backend;
\ No newline at end of file
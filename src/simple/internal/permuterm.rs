@@ -0,0 +1,165 @@
+//! An optional permuterm index: every rotation of every keyword (with an end
+//! marker) in a sorted set, so that keywords containing a fragment can be
+//! found with a single prefix scan instead of visiting every keyword. See
+//! `SearchIndex::search_contains`.
+
+use kstring::KString;
+use std::cmp::Ordering;
+use std::collections::{BTreeMap, BTreeSet};
+
+// -----------------------------------------------------------------------------
+//
+/// Marks the end of a keyword in its rotations. Keywords are never expected to
+/// contain this character.
+
+const END: char = '\u{0}';
+
+// -----------------------------------------------------------------------------
+//
+/// The rotations of the keywords. After the `BTreeMap` has been changed
+/// directly (through `DerefMut`) the rotations are marked as stale, and
+/// lookups fall back to scanning the `BTreeMap` until they're rebuilt.
+
+#[derive(Clone, Debug, Default)]
+pub(crate) struct Permuterm {
+    rotations: BTreeSet<KString>,
+    stale: bool,
+} // Permuterm
+
+// -----------------------------------------------------------------------------
+
+impl Permuterm {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Builds the rotations of the keywords in the given `BTreeMap`.
+
+    pub(crate) fn from_b_tree_map<K>(b_tree_map: &BTreeMap<KString, BTreeSet<K>>) -> Self {
+        Permuterm {
+            rotations: b_tree_map.keys().flat_map(|keyword| rotations(keyword)).collect(),
+            stale: false,
+        } // Permuterm
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Adds the rotations of the given keywords that are in the `BTreeMap`,
+    /// and removes those of the keywords that aren't. If the rotations are
+    /// stale, they're rebuilt entirely.
+
+    pub(crate) fn sync<K>(
+        &mut self,
+        b_tree_map: &BTreeMap<KString, BTreeSet<K>>,
+        keywords: &[KString],
+    ) {
+        if self.stale {
+            *self = Self::from_b_tree_map(b_tree_map);
+            return
+        } // if
+
+        keywords
+            .iter()
+            .for_each(|keyword| match b_tree_map.contains_key(keyword) {
+                true => self.rotations.extend(rotations(keyword)),
+                false => rotations(keyword).for_each(|rotation| { self.rotations.remove(&rotation); }),
+            }); // for_each
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns the keywords that contain the fragment, in the order of their
+    /// matching rotations. A keyword that contains the fragment more than once
+    /// is returned more than once. Returns `None` if the rotations are stale
+    /// and the `BTreeMap` should be scanned instead.
+
+    #[cfg(feature = "permuterm")]
+    pub(crate) fn containing<'p>(
+        &'p self,
+        fragment: &'p str,
+    ) -> Option<impl Iterator<Item = KString> + 'p> {
+        (!self.stale).then(|| self.rotations
+            .range::<str, _>((std::ops::Bound::Included(fragment), std::ops::Bound::Unbounded))
+            .take_while(move |rotation| rotation.starts_with(fragment))
+            .filter_map(|rotation| rotation
+                .split_once(END)
+                .map(|(tail, head)| KString::from(format!("{head}{tail}")))
+            ) // filter_map
+        ) // then
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Marks the rotations as stale, after the `BTreeMap` was changed in a way
+    /// that can't be tracked.
+
+    pub(crate) fn mark_stale(&mut self) {
+        self.rotations.clear();
+        self.stale = true;
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Removes all rotations.
+
+    pub(crate) fn clear(&mut self) {
+        self.rotations.clear();
+        self.stale = false;
+    } // fn
+
+} // impl
+
+// -----------------------------------------------------------------------------
+//
+/// Returns every rotation of the keyword followed by the end marker. For
+/// example, `abc` is rotated into `abc$`, `bc$a`, `c$ab` and `$abc`.
+
+fn rotations(keyword: &str) -> impl Iterator<Item = KString> + '_ {
+    keyword
+        .char_indices()
+        .map(|(index, _char)| index)
+        .chain(std::iter::once(keyword.len()))
+        .map(move |index| {
+            let (head, tail) = keyword.split_at(index);
+            KString::from(format!("{tail}{END}{head}"))
+        }) // map
+} // fn
+
+// -----------------------------------------------------------------------------
+//
+/// The rotations are derived from the keywords and are ignored when comparing
+/// search indexes.
+
+impl PartialEq for Permuterm {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    } // fn
+} // impl
+
+impl PartialOrd for Permuterm {
+    fn partial_cmp(&self, _other: &Self) -> Option<Ordering> {
+        Some(Ordering::Equal)
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(feature = "permuterm")]
+#[test]
+fn test_permuterm() {
+    let b_tree_map: BTreeMap<KString, BTreeSet<usize>> = BTreeMap::from([
+        ("banana".into(), BTreeSet::from([0])),
+        ("bandana".into(), BTreeSet::from([1])),
+        ("cabana".into(), BTreeSet::from([2])),
+    ]); // BTreeMap
+
+    let permuterm = Permuterm::from_b_tree_map(&b_tree_map);
+    let containing = |fragment: &str| permuterm
+        .containing(fragment)
+        .unwrap()
+        .collect::<BTreeSet<KString>>();
+
+    assert_eq!(containing("ana"), BTreeSet::from(["banana".into(), "bandana".into(), "cabana".into()]));
+    assert_eq!(containing("nd"), BTreeSet::from(["bandana".into()]));
+    assert_eq!(containing("cab"), BTreeSet::from(["cabana".into()]));
+    assert!(containing("xyz").is_empty());
+}
\ No newline at end of file
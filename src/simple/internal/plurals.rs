@@ -0,0 +1,82 @@
+//! A lightweight set of English pluralization rules, used to index singular
+//! and plural variants of keywords (see `plural_variants`.) This is a cheaper
+//! and more predictable alternative to full stemming.
+
+use kstring::KString;
+
+// -----------------------------------------------------------------------------
+//
+/// Returns the singular or plural variants of an English keyword, using the
+/// `s`, `es` and `ies` rules. Keywords that aren't all letters, or that are
+/// too short to inflect, have no variants.
+
+pub(crate) fn plural_variants(keyword: &str) -> Vec<KString> {
+    if keyword.chars().count() < 3 || !keyword.chars().all(char::is_alphabetic) {
+        return Vec::new()
+    } // if
+
+    let mut variants: Vec<KString> = Vec::with_capacity(2);
+
+    // Plural to singular:
+    if let Some(stem) = keyword.strip_suffix("ies").filter(|stem| stem.len() > 1) {
+        // ponies → pony
+        variants.push(KString::from(format!("{stem}y")));
+    } else if let Some(stem) = keyword.strip_suffix("es").filter(|stem| sibilant(stem)) {
+        // boxes → box
+        variants.push(KString::from_ref(stem));
+    } else if let Some(stem) = keyword.strip_suffix('s').filter(|_stem|
+        !keyword.ends_with("ss") && !keyword.ends_with("us") && !keyword.ends_with("is")
+    ) {
+        // kings → king
+        variants.push(KString::from_ref(stem));
+    } // if
+
+    // Plural keywords aren't pluralized again:
+    if !variants.is_empty() { return variants }
+
+    // Singular to plural:
+    if let Some(stem) = keyword.strip_suffix('y').filter(|stem| stem.ends_with(consonant)) {
+        // pony → ponies
+        variants.push(KString::from(format!("{stem}ies")));
+    } else if sibilant(keyword) {
+        // box → boxes
+        variants.push(KString::from(format!("{keyword}es")));
+    } else if !keyword.ends_with('s') {
+        // king → kings
+        variants.push(KString::from(format!("{keyword}s")));
+    } // if
+
+    variants
+} // fn
+
+// -----------------------------------------------------------------------------
+//
+/// Returns `true` if the word ends with a sibilant that takes `es` in the
+/// plural.
+
+fn sibilant(word: &str) -> bool {
+    ["s", "x", "z", "ch", "sh"].iter().any(|suffix| word.ends_with(suffix))
+} // fn
+
+// -----------------------------------------------------------------------------
+//
+/// Returns `true` if the character is a consonant.
+
+fn consonant(char: char) -> bool {
+    char.is_alphabetic() && !"aeiou".contains(char)
+} // fn
+
+// -----------------------------------------------------------------------------
+
+#[test]
+fn test_plural_variants() {
+    assert_eq!(plural_variants("ponies"), vec![KString::from("pony")]);
+    assert_eq!(plural_variants("pony"), vec![KString::from("ponies")]);
+    assert_eq!(plural_variants("boxes"), vec![KString::from("box")]);
+    assert_eq!(plural_variants("box"), vec![KString::from("boxes")]);
+    assert_eq!(plural_variants("kings"), vec![KString::from("king")]);
+    assert_eq!(plural_variants("king"), vec![KString::from("kings")]);
+    assert_eq!(plural_variants("day"), vec![KString::from("days")]);
+    assert_eq!(plural_variants("glass"), vec![KString::from("glasses")]);
+    assert!(plural_variants("1066").is_empty());
+}
\ No newline at end of file
@@ -0,0 +1,138 @@
+//! Cheap counters of the search index's activity, reported by `runtime_stats`.
+//! Unlike the `metrics` feature, these don't need a metrics recorder.
+
+use crate::simple::{FuzzyBackendChoice, RuntimeStats};
+use std::cmp::Ordering;
+use std::sync::atomic::{AtomicU64, Ordering::Relaxed};
+use std::time::{Duration, SystemTime};
+
+// -----------------------------------------------------------------------------
+//
+/// Counts searches, autocompletions and fuzzy fall-backs. The counters are
+/// atomic so that they can be updated through a shared reference, from any
+/// thread.
+
+#[derive(Debug)]
+pub(crate) struct RuntimeCounters {
+    /// The number of searches.
+    searches: AtomicU64,
+    /// The number of autocompletions.
+    autocompletes: AtomicU64,
+    /// The number of times fuzzy matching was used as a fall-back.
+    fuzzy_fallbacks: AtomicU64,
+    /// When the search index was last built, in milliseconds since the Unix
+    /// epoch.
+    last_rebuild: AtomicU64,
+} // RuntimeCounters
+
+// -----------------------------------------------------------------------------
+
+impl RuntimeCounters {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns zeroed counters, with the given time as the last rebuild.
+
+    pub(crate) fn new(last_rebuild: SystemTime) -> Self {
+        let counters = RuntimeCounters {
+            searches: AtomicU64::new(0),
+            autocompletes: AtomicU64::new(0),
+            fuzzy_fallbacks: AtomicU64::new(0),
+            last_rebuild: AtomicU64::new(0),
+        }; // RuntimeCounters
+        counters.record_rebuild(last_rebuild);
+        counters
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Records a search.
+
+    pub(crate) fn record_search(&self) {
+        self.searches.fetch_add(1, Relaxed);
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Records an autocompletion.
+
+    pub(crate) fn record_autocomplete(&self) {
+        self.autocompletes.fetch_add(1, Relaxed);
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Records that fuzzy matching was used as a fall-back.
+
+    pub(crate) fn record_fuzzy_fallback(&self) {
+        self.fuzzy_fallbacks.fetch_add(1, Relaxed);
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Records that the search index was (re)built at the given time.
+
+    pub(crate) fn record_rebuild(&self, now: SystemTime) {
+        let milliseconds = now
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .map_or(0, |elapsed| elapsed.as_millis() as u64);
+        self.last_rebuild.store(milliseconds, Relaxed);
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns a snapshot of the counters.
+
+    pub(crate) fn stats(&self, fuzzy_backend: FuzzyBackendChoice) -> RuntimeStats {
+        RuntimeStats {
+            searches: self.searches.load(Relaxed),
+            autocompletes: self.autocompletes.load(Relaxed),
+            fuzzy_fallbacks: self.fuzzy_fallbacks.load(Relaxed),
+            last_rebuild: SystemTime::UNIX_EPOCH
+                + Duration::from_millis(self.last_rebuild.load(Relaxed)),
+            fuzzy_backend,
+        } // RuntimeStats
+    } // fn
+
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl Default for RuntimeCounters {
+    fn default() -> Self {
+        Self::new(SystemTime::now())
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+//
+/// Clones of the search index start with a copy of the counters, and count
+/// independently from then on.
+
+impl Clone for RuntimeCounters {
+    fn clone(&self) -> Self {
+        RuntimeCounters {
+            searches: AtomicU64::new(self.searches.load(Relaxed)),
+            autocompletes: AtomicU64::new(self.autocompletes.load(Relaxed)),
+            fuzzy_fallbacks: AtomicU64::new(self.fuzzy_fallbacks.load(Relaxed)),
+            last_rebuild: AtomicU64::new(self.last_rebuild.load(Relaxed)),
+        } // RuntimeCounters
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+//
+/// The counters are an implementation detail and are ignored when comparing
+/// search indexes.
+
+impl PartialEq for RuntimeCounters {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    } // fn
+} // impl
+
+impl PartialOrd for RuntimeCounters {
+    fn partial_cmp(&self, _other: &Self) -> Option<Ordering> {
+        Some(Ordering::Equal)
+    } // fn
+} // impl
\ No newline at end of file
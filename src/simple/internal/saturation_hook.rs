@@ -0,0 +1,70 @@
+//! An optional callback that's invoked when a keyword reaches the
+//! `maximum_keys_per_keyword` limit.
+
+use std::cmp::Ordering;
+use std::fmt::{Debug, Formatter, Result};
+use std::sync::Arc;
+
+// -----------------------------------------------------------------------------
+//
+/// A callback that's given the saturated keyword.
+
+type Hook = Arc<dyn Fn(&str) + Send + Sync>;
+
+// -----------------------------------------------------------------------------
+//
+/// Holds the callback given to `on_saturation`, if any. The callback is shared
+/// with clones of the search index.
+
+#[derive(Clone, Default)]
+pub(crate) struct SaturationHook(Option<Hook>);
+
+// -----------------------------------------------------------------------------
+
+impl SaturationHook {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Wraps a callback.
+
+    pub(crate) fn new(hook: impl Fn(&str) + Send + Sync + 'static) -> Self {
+        SaturationHook(Some(Arc::new(hook)))
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Invokes the callback, if any, with the saturated keyword.
+
+    pub(crate) fn call(&self, keyword: &str) {
+        if let Some(hook) = &self.0 { hook(keyword) }
+    } // fn
+
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl Debug for SaturationHook {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> Result {
+        match self.0 {
+            Some(_) => formatter.write_str("SaturationHook(Some(..))"),
+            None => formatter.write_str("SaturationHook(None)"),
+        } // match
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+//
+/// The callback is an implementation detail and is ignored when comparing
+/// search indexes.
+
+impl PartialEq for SaturationHook {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    } // fn
+} // impl
+
+impl PartialOrd for SaturationHook {
+    fn partial_cmp(&self, _other: &Self) -> Option<Ordering> {
+        Some(Ordering::Equal)
+    } // fn
+} // impl
\ No newline at end of file
@@ -1,6 +1,5 @@
-use crate::simple::search_index::SearchIndex;
+use crate::simple::{internal::Keys, search_index::SearchIndex};
 use std::cmp::Ord;
-use std::collections::BTreeSet;
 use std::hash::Hash;
 
 // -----------------------------------------------------------------------------
@@ -22,7 +21,7 @@ impl<K: Hash + Ord> SearchIndex<K> {
     /// not observe any settings such as _case-sensitivity_ or _maximum
     /// results_. These constraints should be observed at higher levels.
 
-    pub(crate) fn internal_keyword_search(&self, keyword: &str) -> BTreeSet<&K> {
+    pub(crate) fn internal_keyword_search(&self, keyword: &str) -> Keys<'_, K> {
 
         // Uncomment below if I intend to perform full fuzzy matching in this
         // function:
@@ -41,23 +40,17 @@ impl<K: Hash + Ord> SearchIndex<K> {
         }; // if */
 
         // Attempt to get matching keys for the search keyword from BTreeMap:
-        let search_results: BTreeSet<&K> = if let Some(keys) = self.b_tree_map.get(keyword) {
+        let search_results: Keys<K> = if let Some(keys) = self.keyword_keys(keyword) {
 
-            // Attempt to get matching keys for search keyword:
-            keys
-                // Iterate over all matching keys and only return
-                // `maximum_search_results` number of keys:
-                .iter()
-                // Only return `maximum_search_results` number of keys:
-                .take(self.maximum_keys_per_keyword)
-                // Insert a reference to each resulting key into the hash set:
-                .collect()
+            // The keyword's keys are already sorted, so only
+            // `maximum_keys_per_keyword` number of them are copied:
+            Keys::from_set(keys, self.maximum_keys_per_keyword)
 
         } else {
 
-            // The search keyword did not result in any matches. Return an
-            // empty `BTreeSet`:
-            BTreeSet::new()
+            // The search keyword did not result in any matches. Return empty
+            // results:
+            Keys::default()
 
         }; // if
 
@@ -1,6 +1,6 @@
-use crate::simple::search_index::SearchIndex;
+use crate::simple::{internal::Keys, search_index::SearchIndex};
 use kstring::KString;
-use std::{cmp::Ord, collections::BTreeSet, hash::Hash};
+use std::{cmp::Ord, hash::Hash};
 
 // -----------------------------------------------------------------------------
 
@@ -17,10 +17,10 @@ impl<K: Hash + Ord> SearchIndex<K> {
     /// matching. Consider providing the `autocomplete` feature to your users as
     /// an ergonomic alternative to fuzzy matching.
 
-    pub(crate) fn internal_search_and(&self, keywords: &[KString]) -> BTreeSet<&K> {
+    pub(crate) fn internal_search_and(&self, keywords: &[KString]) -> Keys<'_, K> {
 
-        // This `BTreeSet` is used to contain the search results:
-        let mut search_results: Option<BTreeSet<&K>> = None;
+        // This is used to contain the search results:
+        let mut search_results: Option<Keys<K>> = None;
 
         // Get each keyword from our `BTreeMap`, and intersect the resulting
         // keys with our current keys:
@@ -33,47 +33,29 @@ impl<K: Hash + Ord> SearchIndex<K> {
                 // Attempt to retrieve keyword from search index. If keyword
                 // found, intersect keyword records with search results records.
                 // If keyword not found, empty search results:
-                match self.b_tree_map.get(keyword) {
+                match self.keyword_keys(keyword) {
 
                     // Keyword found. Update `search_results` with product of an
                     // intersection with this keyword's records:
-                    Some(keyword_results) => search_results = Some(
-
-                        // Check if `search_results` is already populated:
-                        match &search_results {
-
-                            // If `search_results` is is not empty, intersect
-                            // the current keyword's results with the master
-                            // search results:
-                            Some(search_results) => search_results
-                                // Iterate over each search result record:
-                                .iter()
-                                // Intersect the search result record with the
-                                // keyword results. If the search result record
-                                // doesn't exist in this keyword's results,
-                                // filter it out:
-                                .filter(|key|
-                                    keyword_results.contains(key)
-                                )
-                                // Copy each key from the `Intersection`
-                                // iterator or we'll get a doubly-referenced
-                                // `&&K` key:
-                                .cloned()
-                                // And collect each key into a `BTreeSet` that
-                                // will become the new `search_results`:
-                                .collect(),
-
-                            // If `search_results` is empty, initialize it with
-                            // the first keyword's full search results:
-                            None => self.internal_keyword_search(keyword),
-
-                        } // match
-
-                    ), // Some
+                    Some(keyword_results) => match &mut search_results {
+
+                        // If `search_results` is is not empty, intersect the
+                        // current keyword's results with the master search
+                        // results in place. If a search result record doesn't
+                        // exist in this keyword's results, it's removed:
+                        Some(search_results) =>
+                            search_results.retain_in(keyword_results),
+
+                        // If `search_results` is empty, initialize it with the
+                        // first keyword's full search results:
+                        None => search_results =
+                            Some(self.internal_keyword_search(keyword)),
+
+                    }, // match
 
                     // Any keyword that returns no results will short-circuit
                     // the search results into an empty set:
-                    None => search_results = Some(BTreeSet::new()),
+                    None => search_results = Some(Keys::default()),
 
                 } // match
 
@@ -93,13 +75,9 @@ impl<K: Hash + Ord> SearchIndex<K> {
             } // if
         } // if
 
-        // Return search results:
-        match search_results {
-            // If master `search_results` is not empty, return it:
-            Some(search_results) => search_results,
-            // If master `search_results` is empty, return an empty `BTreeSet`:
-            None => BTreeSet::new(),
-        } // match
+        // Return search results. If master `search_results` is empty, return
+        // empty results:
+        search_results.unwrap_or_default()
 
     } // fn
 
@@ -0,0 +1,178 @@
+//! An optional copy of the search index's postings as sorted vectors, so that
+//! the keys of several keywords can be intersected over contiguous memory
+//! rather than by walking `BTreeSet`s. See `SearchIndex::search_and_simd`.
+
+// Conditionally select hash map type based on feature flags:
+#[cfg(feature = "gxhash")]
+type HashMap<K, V> = std::collections::HashMap<K, V, gxhash::GxBuildHasher>;
+#[cfg(all(feature = "ahash", not(feature = "gxhash")))]
+use ahash::HashMap;
+#[cfg(all(not(feature = "ahash"), not(feature = "gxhash")))]
+use std::collections::HashMap;
+
+// Static dependencies:
+use kstring::KString;
+use std::cmp::Ordering;
+use std::collections::{BTreeMap, BTreeSet};
+use std::{clone::Clone, cmp::Ord};
+
+// -----------------------------------------------------------------------------
+//
+/// The keys of each keyword in a sorted, deduplicated `Vec`. After the
+/// `BTreeMap` has been changed directly (through `DerefMut`) the postings are
+/// marked as stale, and lookups fall back to the `BTreeMap` until they're
+/// rebuilt.
+///
+/// The dump keyword, which is attached to every key, isn't copied. Lookups of
+/// it always fall back to the `BTreeMap`, so that inserting a record doesn't
+/// have to shift every key.
+
+#[derive(Clone, Debug)]
+pub(crate) struct SortedPostings<K> {
+    map: HashMap<KString, Vec<K>>,
+    dump_keyword: Option<KString>,
+    stale: bool,
+} // SortedPostings
+
+// -----------------------------------------------------------------------------
+
+impl<K: Clone + Ord> SortedPostings<K> {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Builds the sorted postings of the given `BTreeMap`, except for the dump
+    /// keyword.
+
+    pub(crate) fn from_b_tree_map(
+        b_tree_map: &BTreeMap<KString, BTreeSet<K>>,
+        dump_keyword: Option<&KString>,
+    ) -> Self {
+        SortedPostings {
+            map: b_tree_map
+                .iter()
+                .filter(|(keyword, _keys)| dump_keyword != Some(*keyword))
+                // A `BTreeSet` iterates in ascending order, so the keys don't
+                // have to be sorted:
+                .map(|(keyword, keys)| (keyword.clone(), keys.iter().cloned().collect()))
+                .collect(),
+            dump_keyword: dump_keyword.cloned(),
+            stale: false,
+        } // SortedPostings
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Copies the current keys of the given keywords from the `BTreeMap`. If
+    /// the postings are stale, they're rebuilt entirely.
+
+    pub(crate) fn sync(
+        &mut self,
+        b_tree_map: &BTreeMap<KString, BTreeSet<K>>,
+        keywords: &[KString],
+    ) {
+        if self.stale {
+            *self = Self::from_b_tree_map(b_tree_map, self.dump_keyword.as_ref());
+            return
+        } // if
+
+        keywords
+            .iter()
+            .filter(|keyword| self.dump_keyword.as_ref() != Some(*keyword))
+            .for_each(|keyword| match b_tree_map.get(keyword) {
+                Some(keys) => { self.map.insert(keyword.clone(), keys.iter().cloned().collect()); },
+                None => { self.map.remove(keyword); },
+            }); // for_each
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Attaches or detaches one key for the given keywords, to match the
+    /// `BTreeMap`. The key is inserted into (or removed from) its sorted
+    /// position, which shifts the keys after it.
+
+    pub(crate) fn sync_key(
+        &mut self,
+        b_tree_map: &BTreeMap<KString, BTreeSet<K>>,
+        keywords: &[KString],
+        key: &K,
+    ) {
+        if self.stale {
+            *self = Self::from_b_tree_map(b_tree_map, self.dump_keyword.as_ref());
+            return
+        } // if
+
+        keywords
+            .iter()
+            .filter(|keyword| self.dump_keyword.as_ref() != Some(*keyword))
+            .for_each(|keyword| match b_tree_map.get(keyword).map(|keys| keys.contains(key)) {
+                Some(true) => {
+                    let keys: &mut Vec<K> = self.map.entry(keyword.clone()).or_default();
+                    if let Err(position) = keys.binary_search(key) {
+                        keys.insert(position, key.clone());
+                    } // if
+                }, // Some
+                Some(false) => if let Some(keys) = self.map.get_mut(keyword) {
+                    if let Ok(position) = keys.binary_search(key) {
+                        keys.remove(position);
+                    } // if
+                }, // Some
+                None => { self.map.remove(keyword); },
+            }); // for_each
+    } // fn
+
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl<K> SortedPostings<K> {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Looks up the sorted keys for a keyword. Returns `None` if the postings
+    /// are stale or the keyword is the dump keyword, and the `BTreeMap` should
+    /// be used instead.
+
+    #[cfg(feature = "simd")]
+    pub(crate) fn get(&self, keyword: &str) -> Option<Option<&[K]>> {
+        let fall_back = self.stale ||
+            self.dump_keyword.as_ref().is_some_and(|dump_keyword| dump_keyword == keyword);
+        (!fall_back).then(|| self.map.get(keyword).map(Vec::as_slice))
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Marks the postings as stale, after the `BTreeMap` was changed in a way
+    /// that can't be tracked.
+
+    pub(crate) fn mark_stale(&mut self) {
+        self.map.clear();
+        self.stale = true;
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Removes all keywords.
+
+    pub(crate) fn clear(&mut self) {
+        self.map.clear();
+        self.stale = false;
+    } // fn
+
+} // impl
+
+// -----------------------------------------------------------------------------
+//
+/// The sorted postings are a copy of the `BTreeMap` and are ignored when
+/// comparing search indexes.
+
+impl<K> PartialEq for SortedPostings<K> {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    } // fn
+} // impl
+
+impl<K> PartialOrd for SortedPostings<K> {
+    fn partial_cmp(&self, _other: &Self) -> Option<Ordering> {
+        Some(Ordering::Equal)
+    } // fn
+} // impl
\ No newline at end of file
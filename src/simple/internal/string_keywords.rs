@@ -1,6 +1,7 @@
+use crate::simple::internal::plurals::plural_variants;
 use crate::simple::search_index::SearchIndex;
 use kstring::KString;
-use std::cmp::Ord;
+use std::{borrow::Cow, cmp::Ord, sync::Arc};
 
 // -----------------------------------------------------------------------------
 //
@@ -86,6 +87,91 @@ impl<K: Ord> SearchIndex<K> {
         context: SplitContext,
     ) -> Vec<KString> {
 
+        match context {
+            SplitContext::Indexing => self.split_string(string, SplitContext::Indexing),
+            SplitContext::Searching => self.query_keywords(string).to_vec(),
+        } // match
+
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Splits a search string into keywords, like `string_keywords` with
+    /// `SplitContext::Searching`. Search strings are often repeated (for
+    /// example, while the user pauses typing in a `Live` search), so the
+    /// keywords are memoized and shared with the keyword cache rather than
+    /// copied. Search strings are also held to the query limits (see
+    /// `maximum_query_length`).
+
+    pub(crate) fn query_keywords(&self, string: &str) -> Arc<[KString]> {
+
+        // Return the memoized keywords if this search string has been seen
+        // recently. Otherwise, split the string and remember its keywords:
+        let keywords: Arc<[KString]> = match self.keyword_cache.get(string) {
+            Some(keywords) => keywords,
+            None => self.keyword_cache.insert(
+                string,
+                Arc::from(self.limited_query_keywords(string)),
+            ), // insert
+        }; // match
+
+        // Record the hit if the search index has a keyword cap. Keywords that
+        // aren't in the search index aren't recorded:
+        if self.maximum_keywords.is_some() {
+            self.keyword_usage.touch(keywords
+                .iter()
+                .filter(|keyword| self.b_tree_map.contains_key(*keyword))
+            ); // touch
+        } // if
+
+        keywords
+
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Splits a string into keywords according to the search index settings.
+    /// Unlike `string_keywords`, search strings aren't memoized or held to the
+    /// query limits.
+
+    pub(crate) fn split_string(
+        &self,
+        string: &str,
+        context: SplitContext,
+    ) -> Vec<KString> {
+        self.split_string_with(string, context, true)
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Splits a string into keywords, like `split_string`. When indexing, the
+    /// whole string is only kept as a keyword if `whole_string` is `true` (see
+    /// `InsertOptions`).
+
+    pub(crate) fn split_string_with(
+        &self,
+        string: &str,
+        context: SplitContext,
+        whole_string: bool,
+    ) -> Vec<KString> {
+        let (mut keywords, whole_string) = self.split_string_parts(string, context, whole_string);
+        keywords.extend(whole_string);
+        keywords
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Splits a string into keywords, like `split_string_with`, but returns
+    /// the whole string keyword separately, so that it can be told apart from
+    /// split keywords and shingles (see `compact_whole_strings`).
+
+    pub(crate) fn split_string_parts(
+        &self,
+        string: &str,
+        context: SplitContext,
+        whole_string: bool,
+    ) -> (Vec<KString>, Option<KString>) {
+
         // If case sensitivity set, leave case intact. Otherwise, normalize the
         // entire string to lower case:
         let string: KString = match self.case_sensitive {
@@ -93,6 +179,12 @@ impl<K: Ord> SearchIndex<K> {
             false => KString::from(string.to_lowercase()),
         }; // match
 
+        // Remove any elided articles from the start of words (see `elision`):
+        let string: KString = match &self.elision {
+            Some(elision) => KString::from(elision.strip(&string)),
+            None => string,
+        }; // match
+
         // Split the the string into keywords:
         let mut keywords: Vec<KString> = if let Some(split_pattern) = &self.split_pattern {
             // Use the split pattern (a `Vec<char>`) to split the `KString` into
@@ -101,10 +193,13 @@ impl<K: Ord> SearchIndex<K> {
                 // Split the `KString` into smaller strings / keywords on
                 // specified characters:
                 .split(split_pattern.as_slice())
+                // Remove the filtered characters from inside the keyword (see
+                // `char_filter`):
+                .map(|keyword| self.filter_chars(keyword, &[]))
                 // Only keep the keyword if it's longer than the minimum length
                 // and shorter than the maximum length:
                 .filter(|keyword| {
-                    let chars = keyword.chars().count();
+                    let chars = self.keyword_length(keyword);
                     chars >= self.minimum_keyword_length
                         && chars <= self.maximum_keyword_length
                 }) // filter
@@ -113,7 +208,7 @@ impl<K: Ord> SearchIndex<K> {
                     !exclude_keyword(keyword, &self.exclude_keywords)
                 ) // filter
                 // Copy string from reference:
-                .map(KString::from_ref)
+                .map(|keyword| KString::from_ref(&keyword))
                 // Collect all keywords into a `Vec`:
                 .collect()
         } else {
@@ -122,6 +217,82 @@ impl<K: Ord> SearchIndex<K> {
             Vec::new()
         };
 
+        // If shingles are enabled, also index each pair of adjacent keywords
+        // as a two-word keyword. They're paired before any synonyms or other
+        // variants are added, so that only words that are next to each other
+        // in the string are paired. They're added after the variants:
+        let shingles: Vec<KString> = match context {
+            SplitContext::Indexing if self.shingles => keywords
+                .windows(2)
+                .map(|pair| KString::from(format!("{} {}", pair[0], pair[1])))
+                .collect(),
+            _ => Vec::new(),
+        }; // match
+
+        // Apply any synonym rules to the keywords:
+        if self.synonyms.is_some() {
+            keywords = keywords
+                .into_iter()
+                .flat_map(|keyword| match self.synonym_map.apply(&keyword, context == SplitContext::Indexing) {
+                    Some(replacements) => replacements.to_vec(),
+                    None => vec![keyword],
+                }) // flat_map
+                .collect();
+        } // if
+
+        // Transliterate the keywords into Latin (see `transliteration`). When
+        // the originals are kept, the Latin variants are added after the
+        // shingles:
+        #[cfg(feature = "transliteration")]
+        let transliterated: Vec<KString> = self.transliterate_keywords(
+            &mut keywords,
+            &context,
+        ); // transliterate_keywords
+
+        // If there's a keyword enricher, also index the keywords it derives
+        // from each keyword. They're added after the shingles:
+        let enriched: Vec<KString> = match context {
+            SplitContext::Indexing if self.keyword_enricher.is_some() => keywords
+                .iter()
+                .flat_map(|keyword| self.keyword_enricher.enrich(keyword))
+                .map(|enriched| match self.case_sensitive {
+                    true => KString::from(enriched),
+                    false => KString::from(enriched.to_lowercase()),
+                }) // map
+                .filter(|enriched| {
+                    let chars = self.keyword_length(enriched);
+                    chars >= self.minimum_keyword_length
+                        && chars <= self.maximum_keyword_length
+                        && !exclude_keyword(enriched, &self.exclude_keywords)
+                        && !keywords.contains(enriched)
+                }) // filter
+                .collect(),
+            _ => Vec::new(),
+        }; // match
+
+        // If plural variants are enabled, also index the singular or plural
+        // form of each keyword. They're added after the shingles, so that a
+        // shingle never pairs a keyword with a variant:
+        if context == SplitContext::Indexing && self.plural_variants {
+            let variants: Vec<KString> = keywords
+                .iter()
+                .flat_map(|keyword| plural_variants(keyword))
+                .filter(|variant| {
+                    let chars = self.keyword_length(variant);
+                    chars >= self.minimum_keyword_length
+                        && chars <= self.maximum_keyword_length
+                        && !exclude_keyword(variant, &self.exclude_keywords)
+                        && !keywords.contains(variant)
+                }) // filter
+                .collect();
+            keywords.extend(variants);
+        } // if
+
+        keywords.extend(shingles);
+        keywords.extend(enriched);
+        #[cfg(feature = "transliteration")]
+        keywords.extend(transliterated);
+
         // Using the whole string as a keyword:
         //
         // * For searching: return the whole string as the search keyword if
@@ -131,7 +302,17 @@ impl<K: Ord> SearchIndex<K> {
         // entire string itself as a keyword. This feature is primarily for
         // autocompletion purposes.
 
-        let chars = string.chars().count();
+        // Remove the filtered characters from the whole string, apart from
+        // the split pattern characters (see `char_filter`):
+        let string: KString = match &self.char_filter {
+            Some(_) => KString::from_ref(&self.filter_chars(
+                &string,
+                self.split_pattern.as_deref().unwrap_or_default(),
+            )),
+            None => string,
+        }; // match
+
+        let chars = self.keyword_length(&string);
 
         // If we're searching, keep the whole string if there is no split
         // pattern defined. We'll search by the whole search string without
@@ -148,19 +329,52 @@ impl<K: Ord> SearchIndex<K> {
         // than the maximum, and 3) the keyword is not in the exclusion list.
         } else if let Some(maximum_string_length) = self.maximum_string_length {
             if  context == SplitContext::Indexing &&
+                whole_string &&
                 chars >= self.minimum_keyword_length &&
                 chars <= maximum_string_length &&
                 !exclude_keyword(&string, &self.exclude_keywords) {
 
-                    // Add field text / entire string to the keyword `Vec`:
-                    keywords.push(string)
+                    // Return field text / entire string alongside the
+                    // keywords:
+                    return (keywords, Some(string))
 
             } // if
         } // if
 
         // Return keywords to caller:
-        keywords
+        (keywords, None)
+
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Removes the characters of the `char_filter` setting from a keyword,
+    /// except for the `keep` characters.
+
+    pub(crate) fn filter_chars<'k>(&self, keyword: &'k str, keep: &[char]) -> Cow<'k, str> {
+        match &self.char_filter {
+            Some(char_filter) => Cow::Owned(keyword
+                .chars()
+                .filter(|char| keep.contains(char) || !char_filter.removes(*char))
+                .collect()
+            ), // Owned
+            None => Cow::Borrowed(keyword),
+        } // match
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns the length of a keyword, as used for the minimum & maximum
+    /// keyword lengths. The length is counted in chars (or codepoints) or, if
+    /// `grapheme_lengths` is enabled, in grapheme clusters.
+
+    pub(crate) fn keyword_length(&self, keyword: &str) -> usize {
+        #[cfg(feature = "graphemes")]
+        if self.grapheme_lengths {
+            return unicode_segmentation::UnicodeSegmentation::graphemes(keyword, true).count()
+        } // if
 
+        keyword.chars().count()
     } // fn
 
 } // impl
\ No newline at end of file
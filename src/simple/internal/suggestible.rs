@@ -0,0 +1,71 @@
+use crate::simple::internal::string_keywords::exclude_keyword;
+use crate::simple::internal::KeywordKeys;
+use crate::simple::search_index::SearchIndex;
+use std::{cmp::Ord, collections::BTreeSet};
+
+// -----------------------------------------------------------------------------
+
+impl<K: Ord> SearchIndex<K> {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns whether a keyword with the given keys may be suggested by
+    /// autocompletion: none of its words may be in the `suggestion_blocklist`,
+    /// it must be attached to at least `minimum_keys_for_autocomplete` keys,
+    /// and at least one of its keys must pass the filter.
+
+    pub(crate) fn suggestible(
+        &self,
+        keyword: &str,
+        keys: &BTreeSet<K>,
+        filter: &dyn Fn(&K) -> bool,
+    ) -> bool {
+        self.suggestible_entry(keyword, KeywordKeys::Dictionary(keys), filter)
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns whether a keyword may be suggested by autocompletion, like
+    /// `suggestible`, for a keyword that may be a compacted whole string (see
+    /// `compact_whole_strings`).
+
+    pub(crate) fn suggestible_entry(
+        &self,
+        keyword: &str,
+        keys: KeywordKeys<'_, K>,
+        filter: &dyn Fn(&K) -> bool,
+    ) -> bool {
+        !self.suggestion_blocked(keyword) &&
+            keys.len() >= self.minimum_keys_for_autocomplete &&
+            keys.iter().any(filter)
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns whether the keyword may not be suggested: it's the
+    /// `dump_keyword`, it's in the `exclude_keywords` list, it's rejected by
+    /// the `set_suggestion_filter` callback, or it (or any of its words, for
+    /// whole-string keywords) is in the `suggestion_blocklist`.
+
+    pub(crate) fn suggestion_blocked(&self, keyword: &str) -> bool {
+        // The dump keyword is attached to every record and is never suggested:
+        if self.dump_keyword.as_deref() == Some(keyword) { return true }
+
+        // Excluded keywords may remain in the search index from before the
+        // `exclude_keywords` list was changed:
+        if exclude_keyword(keyword, &self.exclude_keywords) { return true }
+
+        if self.suggestion_filter.rejects(keyword) { return true }
+
+        match &self.suggestion_blocklist {
+            Some(_) => {
+                let split_pattern: &[char] = self.split_pattern.as_deref().unwrap_or(&[' ']);
+                exclude_keyword(keyword, &self.suggestion_blocklist) || keyword
+                    .split(split_pattern)
+                    .any(|word| exclude_keyword(word, &self.suggestion_blocklist))
+            }, // Some
+            None => false,
+        } // match
+    } // fn
+
+} // impl
\ No newline at end of file
@@ -0,0 +1,71 @@
+//! An optional callback that decides whether a keyword may be suggested by
+//! autocompletion.
+
+use std::cmp::Ordering;
+use std::fmt::{Debug, Formatter, Result};
+use std::sync::Arc;
+
+// -----------------------------------------------------------------------------
+//
+/// A callback that's given a keyword and returns `true` if it may be
+/// suggested.
+
+type Filter = Arc<dyn Fn(&str) -> bool + Send + Sync>;
+
+// -----------------------------------------------------------------------------
+//
+/// Holds the callback given to `set_suggestion_filter`, if any. The callback
+/// is shared with clones of the search index.
+
+#[derive(Clone, Default)]
+pub(crate) struct SuggestionFilter(Option<Filter>);
+
+// -----------------------------------------------------------------------------
+
+impl SuggestionFilter {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Wraps a callback.
+
+    pub(crate) fn new(filter: impl Fn(&str) -> bool + Send + Sync + 'static) -> Self {
+        SuggestionFilter(Some(Arc::new(filter)))
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns `true` if there is a callback and it rejects the keyword.
+
+    pub(crate) fn rejects(&self, keyword: &str) -> bool {
+        self.0.as_ref().is_some_and(|filter| !filter(keyword))
+    } // fn
+
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl Debug for SuggestionFilter {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> Result {
+        match self.0 {
+            Some(_) => formatter.write_str("SuggestionFilter(Some(..))"),
+            None => formatter.write_str("SuggestionFilter(None)"),
+        } // match
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+//
+/// The callback is an implementation detail and is ignored when comparing
+/// search indexes.
+
+impl PartialEq for SuggestionFilter {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    } // fn
+} // impl
+
+impl PartialOrd for SuggestionFilter {
+    fn partial_cmp(&self, _other: &Self) -> Option<Ordering> {
+        Some(Ordering::Equal)
+    } // fn
+} // impl
\ No newline at end of file
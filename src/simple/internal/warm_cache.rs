@@ -0,0 +1,119 @@
+//! Precomputed autocompletion options for "hot" prefixes, such as every single
+//! letter. The first keystrokes of a query match the most keywords, so they're
+//! usually the slowest to autocomplete on large indexes.
+
+use kstring::KString;
+use std::cmp::Ordering;
+use std::collections::{BTreeMap, BTreeSet};
+use std::sync::Mutex;
+
+// -----------------------------------------------------------------------------
+//
+/// Remembers the autocompletion options for the hot prefixes given to
+/// `warm_up`. The options live behind a `Mutex` so that they can be refilled
+/// from `&self` autocomplete methods while keeping the `SearchIndex` `Send`
+/// and `Sync`.
+///
+/// Unlike the keyword cache, the options depend on the indexed data, so they
+/// are invalidated whenever records are inserted or removed. The hot prefixes
+/// themselves are kept, and their options are recomputed the next time they're
+/// autocompleted.
+
+#[derive(Debug, Default)]
+pub(crate) struct WarmCache {
+    /// The hot prefixes given to `warm_up`.
+    prefixes: BTreeSet<KString>,
+    /// The autocompletion options for each hot prefix, if they're current.
+    entries: Mutex<BTreeMap<KString, Vec<String>>>,
+} // WarmCache
+
+// -----------------------------------------------------------------------------
+
+impl WarmCache {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns whether the string is a hot prefix.
+
+    pub(crate) fn is_hot(&self, string: &str) -> bool {
+        self.prefixes.contains(string)
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns the cached autocompletion options for a hot prefix, if they're
+    /// current.
+
+    pub(crate) fn get(&self, string: &str) -> Option<Vec<String>> {
+        if self.prefixes.is_empty() { return None }
+
+        // A poisoned lock is treated as a cache miss:
+        self.entries.lock().ok()?.get(string).cloned()
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Remembers the autocompletion options for a hot prefix. Strings that
+    /// aren't hot prefixes are ignored.
+
+    pub(crate) fn insert(&self, string: &str, autocomplete_options: &[String]) {
+        if !self.is_hot(string) { return }
+
+        // A poisoned lock means nothing will be cached:
+        if let Ok(mut entries) = self.entries.lock() {
+            entries.insert(KString::from_ref(string), autocomplete_options.to_vec());
+        } // if
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Adds a hot prefix.
+
+    pub(crate) fn add_prefix(&mut self, string: &str) {
+        self.prefixes.insert(KString::from_ref(string));
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Forgets the autocompletion options for every hot prefix. This must be
+    /// called whenever the indexed data changes.
+
+    pub(crate) fn invalidate(&mut self) {
+        match self.entries.get_mut() {
+            Ok(entries) => entries.clear(),
+            Err(poisoned) => poisoned.into_inner().clear(),
+        } // match
+    } // fn
+
+} // impl
+
+// -----------------------------------------------------------------------------
+//
+/// Cloning a search index copies the hot prefixes, but not their options. The
+/// options are recomputed the next time they're autocompleted.
+
+impl Clone for WarmCache {
+    fn clone(&self) -> Self {
+        WarmCache {
+            prefixes: self.prefixes.clone(),
+            entries: Mutex::new(BTreeMap::new()),
+        } // WarmCache
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+//
+/// The cache is an implementation detail and is ignored when comparing search
+/// indexes.
+
+impl PartialEq for WarmCache {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    } // fn
+} // impl
+
+impl PartialOrd for WarmCache {
+    fn partial_cmp(&self, _other: &Self) -> Option<Ordering> {
+        Some(Ordering::Equal)
+    } // fn
+} // impl
\ No newline at end of file
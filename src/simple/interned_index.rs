@@ -0,0 +1,244 @@
+use crate::simple::{indexable::Indexable, search_index::SearchIndex};
+use crate::simple::{SearchIndexBuilder, SearchType};
+use std::collections::BTreeMap;
+use std::{clone::Clone, cmp::Ord};
+
+// -----------------------------------------------------------------------------
+//
+/// A search index for large keys, such as `String` paths or UUIDs, that maps
+/// each key to a dense `u32` id and only keeps the ids in its postings. Each
+/// key is stored twice in a translation table, instead of once per keyword it
+/// is attached to, so the postings shrink dramatically and searches intersect
+/// integers instead of comparing keys. Ids are translated back to keys in the
+/// search results.
+///
+/// The ids of removed records are reused once no keyword refers to them. The
+/// inner search index (with its `u32` keys) is available through
+/// `search_index`.
+///
+/// Search results are in the order of the ids rather than the order of the
+/// keys. Ids are assigned in the order that keys are first inserted, and the
+/// ids of removed records are reused, so the order can differ from the order
+/// a `SearchIndex<K>` would return.
+///
+/// Basic usage:
+///
+/// ```rust
+/// # use indicium::simple::{InternedIndex, SearchIndexBuilder, SearchType};
+/// # use pretty_assertions::assert_eq;
+/// #
+/// let mut search_index: InternedIndex<String> = SearchIndexBuilder::default()
+///     .search_type(SearchType::And)
+///     .build_interned();
+///
+/// search_index.insert(&"/kings/harold-godwinson.md".to_string(), &"Harold Godwinson");
+/// search_index.insert(&"/kings/harold-hardrada.md".to_string(), &"Harold Hardrada");
+///
+/// assert_eq!(search_index.search("harold hardrada"), vec!["/kings/harold-hardrada.md"]);
+/// assert_eq!(search_index.key_id(&"/kings/harold-hardrada.md".to_string()), Some(1));
+/// ```
+
+#[derive(Clone, Debug)]
+pub struct InternedIndex<K: Ord> {
+    /// The search index over the ids.
+    search_index: SearchIndex<u32>,
+    /// The key of each id. Ids of removed records are `None` until reused.
+    keys: Vec<Option<K>>,
+    /// The id of each key.
+    ids: BTreeMap<K, u32>,
+    /// Ids of removed records, available for reuse.
+    free: Vec<u32>,
+} // InternedIndex
+
+// -----------------------------------------------------------------------------
+
+impl<K: Clone + Ord> Default for InternedIndex<K> {
+    fn default() -> Self {
+        Self::with_search_index(SearchIndex::default())
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl SearchIndexBuilder<u32> {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Builds an [`InternedIndex`] with these settings, whose keys are mapped
+    /// to dense `u32` ids internally. This costs an extra translation table,
+    /// but makes the postings much smaller for large keys.
+    ///
+    /// [`InternedIndex`]: struct.InternedIndex.html
+
+    pub fn build_interned<K: Clone + Ord>(self) -> InternedIndex<K> {
+        InternedIndex::with_search_index(self.build())
+    } // fn
+
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl<K: Clone + Ord> InternedIndex<K> {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Makes an interned search index over the given search index. The search
+    /// index should be empty.
+
+    pub fn with_search_index(search_index: SearchIndex<u32>) -> Self {
+        InternedIndex { search_index, keys: Vec::new(), ids: BTreeMap::new(), free: Vec::new() }
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Indexes a record under its key, assigning the key an id if it doesn't
+    /// have one. If no keyword was attached to the id (the record has no
+    /// keywords, or it was rejected by the `maximum_keywords_per_record` cap),
+    /// the id is released again.
+    ///
+    /// # Panics
+    ///
+    /// Panics if more than `u32::MAX` keys are interned.
+
+    pub fn insert(&mut self, key: &K, value: &dyn Indexable) {
+        let id = self.intern(key);
+        self.search_index.insert(&id, value);
+        self.release_unused(key, id);
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Removes a record from the search index. Once the key's id isn't
+    /// attached to any keyword, for example when every value accumulated for
+    /// it has been removed, the id is released for reuse. See
+    /// `SearchIndex::remove`.
+
+    pub fn remove(&mut self, key: &K, value: &dyn Indexable) {
+        let Some(id) = self.ids.get(key).copied() else { return };
+        self.search_index.remove(&id, value);
+        self.release_unused(key, id);
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Replaces a record in the search index, keeping its key's id. Like
+    /// `insert`, the id is released if no keyword is attached to it afterwards.
+    /// See `SearchIndex::replace`.
+
+    pub fn replace(&mut self, key: &K, before: &dyn Indexable, after: &dyn Indexable) {
+        let id = self.intern(key);
+        self.search_index.replace(&id, before, after);
+        self.release_unused(key, id);
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Searches with the search index's `search` method, and returns the keys
+    /// of the matching records, in the order of their ids.
+
+    pub fn search(&self, string: &str) -> Vec<&K> {
+        self.resolve(self.search_index.search(string))
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Searches with the given search type, and returns the keys of the
+    /// matching records. See `SearchIndex::search_type`.
+
+    pub fn search_type(&self, search_type: &SearchType, string: &str) -> Vec<&K> {
+        self.resolve(self.search_index.search_type(search_type, string))
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Autocompletes the search string. See `SearchIndex::autocomplete`.
+
+    pub fn autocomplete(&self, string: &str) -> Vec<String> {
+        self.search_index.autocomplete(string)
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns the id of a key, if it's interned.
+
+    pub fn key_id(&self, key: &K) -> Option<u32> {
+        self.ids.get(key).copied()
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns the key of an id, if the id is in use.
+
+    pub fn key(&self, id: u32) -> Option<&K> {
+        self.keys.get(id as usize).and_then(Option::as_ref)
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns the inner search index, whose keys are the ids.
+
+    pub fn search_index(&self) -> &SearchIndex<u32> {
+        &self.search_index
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns the number of interned keys.
+
+    pub fn len(&self) -> usize {
+        self.ids.len()
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns `true` if no keys are interned.
+
+    pub fn is_empty(&self) -> bool {
+        self.ids.is_empty()
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns the id of a key, assigning it a free (or new) id if it doesn't
+    /// have one.
+
+    fn intern(&mut self, key: &K) -> u32 {
+        if let Some(id) = self.ids.get(key) { return *id }
+
+        let id = match self.free.pop() {
+            Some(id) => {
+                self.keys[id as usize] = Some(key.clone());
+                id
+            }, // Some
+            None => {
+                let id = u32::try_from(self.keys.len()).expect("more than `u32::MAX` keys interned");
+                self.keys.push(Some(key.clone()));
+                id
+            }, // None
+        }; // match
+
+        self.ids.insert(key.clone(), id);
+        id
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Releases a key's id for reuse if it isn't attached to any keyword.
+
+    fn release_unused(&mut self, key: &K, id: u32) {
+        if self.search_index.key_keywords(&id).is_empty() {
+            self.ids.remove(key);
+            self.keys[id as usize] = None;
+            self.free.push(id);
+        } // if
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Translates search results from ids back to keys.
+
+    fn resolve(&self, ids: Vec<&u32>) -> Vec<&K> {
+        ids.into_iter().filter_map(|id| self.key(*id)).collect()
+    } // fn
+
+} // impl
\ No newline at end of file
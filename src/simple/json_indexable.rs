@@ -0,0 +1,230 @@
+use crate::simple::json_value::collect_leaves;
+use crate::simple::Indexable;
+use serde_json::Value;
+
+// -----------------------------------------------------------------------------
+//
+/// A reusable description of which parts of a `JSON` document are to be
+/// indexed. Each field is selected by a
+/// [JSON Pointer](https://datatracker.ietf.org/doc/html/rfc6901) and given a
+/// weight. A `*` pointer segment selects every element of an array (or every
+/// value of an object), so `/tags/*` selects all tags.
+///
+/// Only the selected fields are indexed, which scopes searches to those parts
+/// of the document. Weights do not change which records a search returns, but
+/// the [`score`] method may be used to boost the search results whose higher
+/// weighted fields matched the user's query.
+///
+/// Basic usage:
+///
+/// ```rust
+/// # use indicium::simple::{JsonIndexable, SearchIndex};
+/// # use pretty_assertions::assert_eq;
+/// # use serde_json::json;
+/// #
+/// let documents = vec![
+///     json!({ "title": "William the Conqueror", "tags": ["norman", "king"] }),
+///     json!({ "title": "Harold Godwinson", "tags": ["anglo-saxon", "king"], "notes": "norman" }),
+/// ];
+///
+/// let json_indexable = JsonIndexable::new()
+///     .field("/title", 3)
+///     .field("/tags/*", 1);
+///
+/// let mut search_index: SearchIndex<usize> = SearchIndex::default();
+///
+/// documents
+///     .iter()
+///     .enumerate()
+///     .for_each(|(index, document)|
+///         search_index.insert(&index, &json_indexable.document(document))
+///     );
+///
+/// // The `notes` field isn't indexed:
+/// assert_eq!(search_index.search("norman"), vec![&0]);
+///
+/// // Both records are returned, but a title match is weighted higher:
+/// assert_eq!(search_index.search("king"), vec![&0, &1]);
+/// assert_eq!(json_indexable.score(&documents[0], "william king"), 4);
+/// assert_eq!(json_indexable.score(&documents[1], "william king"), 1);
+/// ```
+///
+/// [`score`]: struct.JsonIndexable.html#method.score
+
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct JsonIndexable {
+    /// The JSON Pointers of the fields to be indexed, and their weights.
+    fields: Vec<(String, usize)>,
+    /// The maximum nesting depth of collected leaves, measured from each
+    /// field. If `None`, leaves at any depth are collected.
+    maximum_depth: Option<usize>,
+} // JsonIndexable
+
+// -----------------------------------------------------------------------------
+//
+/// A `JSON` document paired with a [`JsonIndexable`] description, ready to be
+/// inserted into a search index. Returned by [`JsonIndexable::document`].
+///
+/// [`JsonIndexable`]: struct.JsonIndexable.html
+/// [`JsonIndexable::document`]: struct.JsonIndexable.html#method.document
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct JsonDocument<'a> {
+    /// The description of the fields to be indexed.
+    json_indexable: &'a JsonIndexable,
+    /// The `JSON` document to be indexed.
+    value: &'a Value,
+} // JsonDocument
+
+// -----------------------------------------------------------------------------
+//
+/// Decodes a JSON Pointer into its reference tokens, unescaping `~1` as `/`
+/// and `~0` as `~`. The empty pointer refers to the whole document and has no
+/// tokens.
+
+fn reference_tokens(pointer: &str) -> Vec<String> {
+    pointer
+        .split('/')
+        .skip(1)
+        .map(|token| token.replace("~1", "/").replace("~0", "~"))
+        .collect()
+} // fn
+
+// -----------------------------------------------------------------------------
+//
+/// Resolves the remaining reference tokens of a JSON Pointer against a value.
+/// A `*` token fans out to every element of an array or every value of an
+/// object.
+
+fn resolve<'a>(value: &'a Value, tokens: &[String], resolved: &mut Vec<&'a Value>) {
+    let Some((token, remaining)) = tokens.split_first() else {
+        resolved.push(value);
+        return
+    }; // let
+
+    match (value, token.as_str()) {
+        (Value::Array(array), "*") => array
+            .iter()
+            .for_each(|value| resolve(value, remaining, resolved)),
+        (Value::Object(object), "*") => object
+            .values()
+            .for_each(|value| resolve(value, remaining, resolved)),
+        (Value::Array(array), index) => if let Some(value) = index
+            .parse::<usize>()
+            .ok()
+            .and_then(|index| array.get(index))
+        {
+            resolve(value, remaining, resolved)
+        }, // if
+        (Value::Object(object), key) => if let Some(value) = object.get(key) {
+            resolve(value, remaining, resolved)
+        }, // if
+        _ => {},
+    }; // match
+} // fn
+
+// -----------------------------------------------------------------------------
+
+impl JsonIndexable {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns an empty description. Use [`field`] to select the fields to be
+    /// indexed.
+    ///
+    /// [`field`]: struct.JsonIndexable.html#method.field
+
+    pub fn new() -> Self {
+        Self::default()
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Selects a field to be indexed by its JSON Pointer (for example `/title`,
+    /// `/author/name` or `/tags/*`) and assigns it a weight. All leaves beneath
+    /// a selected array or object are collected. Pointers that do not resolve
+    /// for a document are ignored.
+
+    pub fn field(mut self, pointer: &str, weight: usize) -> Self {
+        self.fields.push((pointer.to_string(), weight));
+        self
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Only collects leaves nested at most `maximum_depth` levels beneath each
+    /// selected field.
+
+    pub fn maximum_depth(mut self, maximum_depth: usize) -> Self {
+        self.maximum_depth = Some(maximum_depth);
+        self
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Pairs a `JSON` document with this description so that it may be inserted
+    /// into a search index.
+
+    pub fn document<'a>(&'a self, value: &'a Value) -> JsonDocument<'a> {
+        JsonDocument { json_indexable: self, value }
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns the leaves of the selected field in a document.
+
+    fn field_strings(&self, value: &Value, pointer: &str) -> Vec<String> {
+        let mut resolved: Vec<&Value> = Vec::new();
+        resolve(value, &reference_tokens(pointer), &mut resolved);
+
+        let mut strings: Vec<String> = Vec::new();
+        resolved
+            .into_iter()
+            .for_each(|value| collect_leaves(value, 0, &self.maximum_depth, &mut strings));
+
+        strings
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Sums the weights of the document's fields that contain at least one of
+    /// the query's keywords. Keywords are compared case-insensitively as whole
+    /// alphanumeric words. This may be used to re-rank search results so that
+    /// matches in higher weighted fields come first.
+
+    pub fn score(&self, value: &Value, query: &str) -> usize {
+        let query_keywords: Vec<String> = query
+            .split(|character: char| !character.is_alphanumeric())
+            .filter(|keyword| !keyword.is_empty())
+            .map(str::to_lowercase)
+            .collect();
+
+        self.fields
+            .iter()
+            .filter(|(pointer, _weight)| self
+                .field_strings(value, pointer)
+                .iter()
+                .flat_map(|string| string
+                    .split(|character: char| !character.is_alphanumeric())
+                    .map(str::to_lowercase)
+                    .collect::<Vec<String>>()
+                ) // flat_map
+                .any(|keyword| query_keywords.contains(&keyword))
+            ) // filter
+            .map(|(_pointer, weight)| weight)
+            .sum()
+    } // fn
+
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl Indexable for JsonDocument<'_> {
+    fn strings(&self) -> Vec<String> {
+        self.json_indexable
+            .fields
+            .iter()
+            .flat_map(|(pointer, _weight)| self.json_indexable.field_strings(self.value, pointer))
+            .collect()
+    } // fn strings
+} // impl Indexable
\ No newline at end of file
@@ -0,0 +1,151 @@
+use crate::simple::Indexable;
+use serde_json::Value;
+
+// -----------------------------------------------------------------------------
+//
+/// Makes an arbitrary `serde_json::Value` document indexable, without having
+/// to deserialize it into an intermediate `struct`. The string, number and
+/// boolean leaves of the document are recursively collected and indexed.
+/// Object keys (field names) and `null` values are not indexed.
+///
+/// Note that `serde_json::Value` already implements `Indexable` through its
+/// `Display` implementation. However, that indexes the document's serialized
+/// `JSON` text, including its field names. Wrap the document in a `JsonValue`
+/// to only index its values.
+///
+/// By default the entire document is indexed. Collection may be limited to a
+/// maximum nesting depth using [`maximum_depth`], or to selected parts of the
+/// document using [JSON Pointers](https://datatracker.ietf.org/doc/html/rfc6901)
+/// with [`pointers`].
+///
+/// Basic usage:
+///
+/// ```rust
+/// # use indicium::simple::{JsonValue, SearchIndex};
+/// # use pretty_assertions::assert_eq;
+/// # use serde_json::json;
+/// #
+/// let document = json!({
+///     "name": "William the Conqueror",
+///     "house": { "name": "Normandy", "founded": 911 },
+///     "titles": ["Duke of Normandy", "King of England"],
+/// });
+///
+/// let mut search_index: SearchIndex<usize> = SearchIndex::default();
+///
+/// search_index.insert(&0, &JsonValue::new(&document).pointers(&["/name", "/titles"]));
+///
+/// assert_eq!(search_index.search("england"), vec![&0]);
+/// assert_eq!(search_index.search("911"), Vec::<&usize>::new());
+/// ```
+///
+/// [`maximum_depth`]: struct.JsonValue.html#method.maximum_depth
+/// [`pointers`]: struct.JsonValue.html#method.pointers
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct JsonValue<'a> {
+    /// The `JSON` document to be indexed.
+    value: &'a Value,
+    /// The maximum nesting depth of collected leaves. The document's root is at
+    /// depth `0`. If `None`, leaves at any depth are collected.
+    maximum_depth: Option<usize>,
+    /// JSON Pointers to the parts of the document that are to be indexed. If
+    /// `None`, the entire document is indexed.
+    pointers: Option<Vec<String>>,
+} // JsonValue
+
+// -----------------------------------------------------------------------------
+
+impl<'a> JsonValue<'a> {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Wraps a `JSON` document so that it may be indexed. The entire document
+    /// is indexed by default.
+
+    pub fn new(value: &'a Value) -> Self {
+        JsonValue {
+            value,
+            maximum_depth: None,
+            pointers: None,
+        } // JsonValue
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Only collects leaves nested at most `maximum_depth` levels deep. The
+    /// document's root is at depth `0`, the fields of a root object are at
+    /// depth `1`, and so on. When used with [`pointers`], depth is measured
+    /// from each pointer's target.
+    ///
+    /// [`pointers`]: struct.JsonValue.html#method.pointers
+
+    pub fn maximum_depth(mut self, maximum_depth: usize) -> Self {
+        self.maximum_depth = Some(maximum_depth);
+        self
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Only indexes the parts of the document selected by these
+    /// [JSON Pointers](https://datatracker.ietf.org/doc/html/rfc6901), for
+    /// example `/title` or `/author/name`. All leaves beneath a selected array
+    /// or object are collected. Pointers that do not resolve are ignored.
+
+    pub fn pointers(mut self, pointers: &[&str]) -> Self {
+        self.pointers = Some(pointers.iter().map(ToString::to_string).collect());
+        self
+    } // fn
+
+} // impl
+
+// -----------------------------------------------------------------------------
+//
+/// Recursively collects the string, number and boolean leaves of a `JSON`
+/// value, descending no further than the maximum depth.
+
+pub(crate) fn collect_leaves(
+    value: &Value,
+    depth: usize,
+    maximum_depth: &Option<usize>,
+    strings: &mut Vec<String>,
+) {
+
+    // Stop descending once the maximum depth has been exceeded:
+    if maximum_depth.is_some_and(|maximum_depth| depth > maximum_depth) { return }
+
+    match value {
+        Value::String(string) => strings.push(string.clone()),
+        Value::Number(number) => strings.push(number.to_string()),
+        Value::Bool(boolean) => strings.push(boolean.to_string()),
+        Value::Null => {},
+        Value::Array(array) => array
+            .iter()
+            .for_each(|value| collect_leaves(value, depth + 1, maximum_depth, strings)),
+        Value::Object(object) => object
+            .values()
+            .for_each(|value| collect_leaves(value, depth + 1, maximum_depth, strings)),
+    }; // match
+
+} // fn
+
+// -----------------------------------------------------------------------------
+
+impl Indexable for JsonValue<'_> {
+    fn strings(&self) -> Vec<String> {
+        let mut strings: Vec<String> = Vec::new();
+
+        match &self.pointers {
+            // Only collect the leaves beneath the selected parts of the
+            // document:
+            Some(pointers) => pointers
+                .iter()
+                .filter_map(|pointer| self.value.pointer(pointer))
+                .for_each(|value| collect_leaves(value, 0, &self.maximum_depth, &mut strings)),
+            // Collect the leaves of the entire document:
+            None => collect_leaves(self.value, 0, &self.maximum_depth, &mut strings),
+        }; // match
+
+        strings
+    } // fn strings
+} // impl Indexable
\ No newline at end of file
@@ -0,0 +1,18 @@
+// -----------------------------------------------------------------------------
+//
+/// What to do when a record has more keywords than the
+/// `maximum_keywords_per_record` cap allows. See
+/// [`SearchIndexBuilder::maximum_keywords_per_record`].
+///
+/// [`SearchIndexBuilder::maximum_keywords_per_record`]: struct.SearchIndexBuilder.html#method.maximum_keywords_per_record
+
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum KeywordCapPolicy {
+    /// The record is indexed, but only attached to the first keywords (in
+    /// lexographic order) up to the cap. The rest of its keywords are dropped.
+    #[default]
+    Truncate,
+    /// The record isn't indexed at all. `try_insert` returns an error.
+    Reject,
+} // KeywordCapPolicy
\ No newline at end of file
@@ -0,0 +1,45 @@
+use crate::simple::internal::KeywordEnricher;
+use crate::simple::search_index::SearchIndex;
+use std::cmp::Ord;
+
+// -----------------------------------------------------------------------------
+
+impl<K: Ord> SearchIndex<K> {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Replaces the callback that derives extra keywords from each keyword
+    /// when records are indexed. Records that are already indexed aren't
+    /// changed, so this is normally followed by `build_from_iter` or by
+    /// re-inserting the records. See `SearchIndexBuilder::keyword_enricher`
+    /// for more information.
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # use indicium::simple::SearchIndex;
+    /// # use pretty_assertions::assert_eq;
+    /// #
+    /// let mut search_index: SearchIndex<usize> = SearchIndex::default();
+    ///
+    /// // Also index SKU numbers without their check digit:
+    /// search_index.set_keyword_enricher(|keyword| match keyword.len() {
+    ///     5 if keyword.bytes().all(|byte| byte.is_ascii_digit()) =>
+    ///         vec![keyword[..4].to_string()],
+    ///     _ => vec![],
+    /// });
+    ///
+    /// search_index.insert(&0, &"Widget 10667");
+    ///
+    /// assert_eq!(search_index.search("1066"), vec![&0]);
+    /// assert_eq!(search_index.search("10667"), vec![&0]);
+    /// ```
+
+    pub fn set_keyword_enricher(
+        &mut self,
+        enrich: impl Fn(&str) -> Vec<String> + Send + Sync + 'static,
+    ) {
+        self.keyword_enricher = KeywordEnricher::new(enrich);
+    } // fn
+
+} // impl
\ No newline at end of file
@@ -0,0 +1,92 @@
+use crate::simple::internal::Keys;
+use crate::simple::search_index::SearchIndex;
+use kstring::KString;
+use std::{cmp::Ord, hash::Hash, sync::Arc};
+
+// -----------------------------------------------------------------------------
+//
+/// Reports how a single keyword of an `And` search contributed to the search
+/// results. See [`SearchIndex::diagnose_and`].
+///
+/// [`SearchIndex::diagnose_and`]: struct.SearchIndex.html#method.diagnose_and
+
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct KeywordHits {
+    /// The keyword, normalized according to the search index settings.
+    pub keyword: String,
+    /// The number of keys (or records) attached to this keyword on its own.
+    pub hits: usize,
+    /// The number of search results if this keyword were dropped from the
+    /// search string.
+    pub results_without: usize,
+} // KeywordHits
+
+// -----------------------------------------------------------------------------
+
+impl<K: Hash + Ord> SearchIndex<K> {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Explains an `And` search by reporting, for each keyword in the search
+    /// string, its individual hit count and the number of search results if
+    /// it were dropped. When an `And` search returns nothing, this shows which
+    /// keyword(s) caused the empty intersection, so that the user interface
+    /// can suggest removing the offending term.
+    ///
+    /// Keywords are reported in the order they appear in the search string.
+    /// `maximum_search_results` isn't applied to the counts.
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # use indicium::simple::{KeywordHits, SearchIndex, SearchType};
+    /// # use pretty_assertions::assert_eq;
+    /// #
+    /// let mut search_index: SearchIndex<usize> = SearchIndex::default();
+    /// search_index.insert(&0, &"William the Conqueror");
+    /// search_index.insert(&1, &"William Rufus");
+    ///
+    /// assert!(search_index.search_type(&SearchType::And, "conqueror rufus").is_empty());
+    ///
+    /// assert_eq!(
+    ///     search_index.diagnose_and("conqueror rufus"),
+    ///     vec![
+    ///         KeywordHits { keyword: "conqueror".to_string(), hits: 1, results_without: 1 },
+    ///         KeywordHits { keyword: "rufus".to_string(), hits: 1, results_without: 1 },
+    ///     ]
+    /// );
+    /// ```
+
+    #[tracing::instrument(level = "trace", name = "diagnose and search", skip(self))]
+    pub fn diagnose_and(&self, string: &str) -> Vec<KeywordHits> {
+
+        // Split search `String` into keywords according to the `SearchIndex`
+        // settings:
+        let keywords: Arc<[KString]> = self.query_keywords(string);
+
+        keywords
+            .iter()
+            .enumerate()
+            .map(|(position, keyword)| {
+                // Perform an `And` search with every other keyword:
+                let other_keywords: Vec<KString> = keywords
+                    .iter()
+                    .enumerate()
+                    .filter(|(other_position, _keyword)| *other_position != position)
+                    .map(|(_position, keyword)| keyword.clone())
+                    .collect();
+
+                let results_without: Keys<K> =
+                    self.internal_search_and(other_keywords.as_slice());
+
+                KeywordHits {
+                    keyword: keyword.to_string(),
+                    hits: self.keyword_keys(keyword).map_or(0, |keys| keys.len()),
+                    results_without: results_without.len(),
+                } // KeywordHits
+            }) // map
+            .collect()
+
+    } // fn
+
+} // impl
\ No newline at end of file
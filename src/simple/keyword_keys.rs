@@ -0,0 +1,84 @@
+use crate::simple::search_index::SearchIndex;
+use kstring::KString;
+use std::collections::BTreeSet;
+use std::{clone::Clone, cmp::Ord};
+
+// -----------------------------------------------------------------------------
+
+impl<K: Ord> SearchIndex<K> {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns the keys attached to a keyword, using an exact match. If the
+    /// search index has a front map (see `SearchIndexBuilder::front_map`) it's
+    /// used for the lookup, otherwise the `BTreeMap` is used.
+
+    pub(crate) fn keyword_keys(&self, keyword: &str) -> Option<&BTreeSet<K>> {
+        match self.front_map.as_ref().and_then(|front_map| front_map.get(keyword)) {
+            Some(keys) => keys,
+            None => self.b_tree_map.get(keyword),
+        } // match
+    } // fn
+
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl<K: Clone + Ord> SearchIndex<K> {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns `true` if the search index keeps any copies of its keywords
+    /// (a front map, permuterm index, interior tokens or sorted postings) that
+    /// have to be synchronized when keywords change.
+
+    pub(crate) fn has_mirrors(&self) -> bool {
+        self.front_map.is_some() ||
+            self.permuterm.is_some() ||
+            self.interior_tokens.is_some() ||
+            self.sorted_postings.is_some()
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Copies the current keys of the given keywords into the front map and
+    /// sorted postings, and their rotations into the permuterm index, if the
+    /// search index has them.
+
+    pub(crate) fn sync_mirrors(&mut self, keywords: &[KString]) {
+        if let Some(front_map) = &mut self.front_map {
+            front_map.sync(&self.b_tree_map, keywords);
+        } // if
+        if let Some(sorted_postings) = &mut self.sorted_postings {
+            sorted_postings.sync(&self.b_tree_map, keywords);
+        } // if
+        if let Some(permuterm) = &mut self.permuterm {
+            permuterm.sync(&self.b_tree_map, keywords);
+        } // if
+        if let Some(interior_tokens) = &mut self.interior_tokens {
+            interior_tokens.sync(&self.b_tree_map, &self.whole_strings, keywords);
+        } // if
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Like `sync_mirrors`, but only copies one key of the given keywords into
+    /// the front map and sorted postings. Used after a single record's key is
+    /// attached to or detached from its keywords.
+
+    pub(crate) fn sync_mirrors_for_key(&mut self, keywords: &[KString], key: &K) {
+        if let Some(front_map) = &mut self.front_map {
+            front_map.sync_key(&self.b_tree_map, keywords, key);
+        } // if
+        if let Some(sorted_postings) = &mut self.sorted_postings {
+            sorted_postings.sync_key(&self.b_tree_map, keywords, key);
+        } // if
+        if let Some(permuterm) = &mut self.permuterm {
+            permuterm.sync(&self.b_tree_map, keywords);
+        } // if
+        if let Some(interior_tokens) = &mut self.interior_tokens {
+            interior_tokens.sync(&self.b_tree_map, &self.whole_strings, keywords);
+        } // if
+    } // fn
+
+} // impl
\ No newline at end of file
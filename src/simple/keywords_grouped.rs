@@ -0,0 +1,87 @@
+use crate::simple::search_index::SearchIndex;
+use std::cmp::Ord;
+use std::collections::BTreeMap;
+
+// -----------------------------------------------------------------------------
+
+impl<K: Ord> SearchIndex<K> {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns the keywords in the search index grouped by their first
+    /// character, for building A–Z browse pages from the search index. Groups
+    /// and the keywords in each group are in lexographic order. Keywords keep
+    /// the search index's case normalization, so a case insensitive search
+    /// index groups under lower case characters. The `dump_keyword` is never
+    /// returned.
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # use indicium::simple::SearchIndex;
+    /// # use pretty_assertions::assert_eq;
+    /// # use std::collections::BTreeMap;
+    /// #
+    /// let mut search_index: SearchIndex<usize> = SearchIndex::default();
+    /// search_index.insert(&0, &"Harold");
+    /// search_index.insert(&1, &"Edgar");
+    /// search_index.insert(&2, &"Henry");
+    ///
+    /// assert_eq!(
+    ///     search_index.keywords_grouped_by_initial(),
+    ///     BTreeMap::from([('e', vec!["edgar"]), ('h', vec!["harold", "henry"])])
+    /// );
+    /// ```
+
+    #[tracing::instrument(level = "trace", name = "keywords grouped by initial", skip(self))]
+    pub fn keywords_grouped_by_initial(&self) -> BTreeMap<char, Vec<&str>> {
+        self.keywords_grouped_by_initial_filtered(|_key| true)
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns the keywords grouped by their first character, like
+    /// `keywords_grouped_by_initial`, only including keywords that have at
+    /// least one key passing the filter. This can be used to build browse
+    /// pages for a subset of the records, such as those the current user may
+    /// see.
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # use indicium::simple::SearchIndex;
+    /// # use pretty_assertions::assert_eq;
+    /// # use std::collections::BTreeMap;
+    /// #
+    /// let mut search_index: SearchIndex<usize> = SearchIndex::default();
+    /// search_index.insert(&0, &"Harold");
+    /// search_index.insert(&1, &"Edgar");
+    /// search_index.insert(&2, &"Henry");
+    ///
+    /// assert_eq!(
+    ///     search_index.keywords_grouped_by_initial_filtered(|key| *key != 1),
+    ///     BTreeMap::from([('h', vec!["harold", "henry"])])
+    /// );
+    /// ```
+
+    #[tracing::instrument(level = "trace", name = "keywords grouped by initial filtered", skip(self, filter))]
+    pub fn keywords_grouped_by_initial_filtered(
+        &self,
+        filter: impl Fn(&K) -> bool,
+    ) -> BTreeMap<char, Vec<&str>> {
+
+        let mut groups: BTreeMap<char, Vec<&str>> = BTreeMap::new();
+
+        self.b_tree_map
+            .iter()
+            .filter(|(keyword, _keys)| self.dump_keyword.as_ref() != Some(keyword))
+            .filter(|(_keyword, keys)| keys.iter().any(&filter))
+            .for_each(|(keyword, _keys)| if let Some(initial) = keyword.chars().next() {
+                groups.entry(initial).or_default().push(keyword.as_str());
+            }); // for_each
+
+        groups
+
+    } // fn
+
+} // impl
\ No newline at end of file
@@ -0,0 +1,219 @@
+use crate::simple::persist::{decompress, read_postings, Layout, PersistedKey, PersistedKeywords};
+use crate::simple::{SearchIndex, SearchIndexBuilder};
+use crate::Error;
+use kstring::KString;
+use std::collections::{BTreeMap, BTreeSet};
+use std::io::Read;
+use std::sync::OnceLock;
+use std::{clone::Clone, cmp::Ord};
+
+// -----------------------------------------------------------------------------
+//
+/// A persisted search index (see [`SearchIndex::to_bytes`]) whose keyword
+/// dictionary is loaded eagerly, but whose keys are only decoded the first
+/// time each keyword is looked up. For large search indexes, this makes
+/// opening the index much faster, which helps command-line tools that only run
+/// a few queries.
+///
+/// Basic usage:
+///
+/// ```rust
+/// # use indicium::simple::{LazyIndex, SearchIndex};
+/// # use pretty_assertions::assert_eq;
+/// # use std::collections::BTreeSet;
+/// #
+/// let mut search_index: SearchIndex<usize> = SearchIndex::default();
+/// search_index.insert(&0, &"Harold Godwinson");
+/// search_index.insert(&1, &"Harold Hardrada");
+///
+/// let lazy: LazyIndex<usize> = LazyIndex::from_bytes(search_index.to_bytes()).unwrap();
+/// assert_eq!(lazy.loaded_keywords(), 0);
+///
+/// assert_eq!(lazy.keys("Harold").unwrap(), Some(&BTreeSet::from([0, 1])));
+/// assert_eq!(lazy.keys("tostig").unwrap(), None);
+/// assert_eq!(lazy.loaded_keywords(), 1);
+///
+/// assert_eq!(lazy.keywords_with_prefix("har").collect::<Vec<_>>(), vec![
+///     "hardrada", "harold", "harold godwinson", "harold hardrada",
+/// ]);
+/// ```
+///
+/// [`SearchIndex::to_bytes`]: struct.SearchIndex.html#method.to_bytes
+
+#[derive(Debug)]
+pub struct LazyIndex<K: Ord> {
+    /// The persisted search index.
+    bytes: Vec<u8>,
+    /// The offset of the postings section in `bytes`.
+    postings_start: usize,
+    /// Each keyword, and the offset & length of its keys in the postings
+    /// section, in lexographic order.
+    dictionary: Vec<(KString, usize, usize)>,
+    /// The keys of each keyword, once decoded.
+    postings: Vec<OnceLock<BTreeSet<K>>>,
+    /// Whether keywords are case sensitive. If not, lookups are lowercased.
+    case_sensitive: bool,
+    /// The keywords that were indexed as whole strings, and weren't compacted.
+    whole_string_keywords: BTreeSet<KString>,
+    /// The compacted whole strings.
+    compacted: BTreeSet<KString>,
+} // LazyIndex
+
+// -----------------------------------------------------------------------------
+
+impl<K: Clone + Ord + PersistedKey> LazyIndex<K> {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Opens a persisted search index. Only the keyword dictionary is read.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error::Io` error with the `InvalidData` kind if the bytes
+    /// aren't a persisted search index.
+
+    #[tracing::instrument(level = "trace", name = "lazy index from bytes", skip(bytes))]
+    pub fn from_bytes(bytes: Vec<u8>) -> Result<Self, Error> {
+        let layout = Layout::read(&bytes)?;
+        let Layout { case_sensitive, dictionary, postings_start, whole_string_keywords, compacted, .. } = layout;
+        let postings = dictionary.iter().map(|_entry| OnceLock::new()).collect();
+        Ok(LazyIndex {
+            bytes,
+            postings_start,
+            dictionary,
+            postings,
+            case_sensitive,
+            whole_string_keywords,
+            compacted,
+        })
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Opens a persisted search index from a reader (such as a file) written
+    /// by `SearchIndex::write_to`. Compressed search indexes are decompressed
+    /// into memory, since the keys are read from their offsets later.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error::Io` error if reading fails, or with the `InvalidData`
+    /// kind if the data isn't a persisted search index.
+
+    pub fn read_from<R: Read>(reader: R) -> Result<Self, Error> {
+        let mut bytes: Vec<u8> = Vec::new();
+        decompress(reader)?.read_to_end(&mut bytes)?;
+        Self::from_bytes(bytes)
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns the keys attached to a keyword, decoding them on first access.
+    /// Returns `None` if the keyword isn't in the search index. Unless the
+    /// search index is case sensitive, the keyword is lowercased.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error::Io` error with the `InvalidData` kind if the keys
+    /// can't be decoded.
+
+    pub fn keys(&self, keyword: &str) -> Result<Option<&BTreeSet<K>>, Error> {
+        let keyword: String = match self.case_sensitive {
+            true => keyword.to_string(),
+            false => keyword.to_lowercase(),
+        }; // match
+
+        let Ok(index) = self.dictionary.binary_search_by(|(entry, _, _)| entry.as_str().cmp(&keyword)) else {
+            return Ok(None)
+        }; // let
+
+        self.load(index).map(Some)
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns the keywords that start with the prefix, in lexographic order.
+    /// The prefix is lowercased unless the search index is case sensitive. No
+    /// keys are decoded.
+
+    pub fn keywords_with_prefix(&self, prefix: &str) -> impl Iterator<Item = &str> + '_ {
+        let prefix: String = match self.case_sensitive {
+            true => prefix.to_string(),
+            false => prefix.to_lowercase(),
+        }; // match
+        let start = self.dictionary.partition_point(|(keyword, _, _)| keyword.as_str() < prefix.as_str());
+        self.dictionary[start..]
+            .iter()
+            .map(|(keyword, _, _)| keyword.as_str())
+            .take_while(move |keyword| keyword.starts_with(prefix.as_str()))
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns the number of keywords in the search index.
+
+    pub fn len(&self) -> usize {
+        self.dictionary.len()
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns `true` if the search index has no keywords.
+
+    pub fn is_empty(&self) -> bool {
+        self.dictionary.is_empty()
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns the number of keywords whose keys have been decoded so far.
+
+    pub fn loaded_keywords(&self) -> usize {
+        self.postings.iter().filter(|keys| keys.get().is_some()).count()
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Decodes any remaining keys, and builds a fully loaded search index with
+    /// the given settings. Like `SearchIndex::from_bytes`, the whole strings
+    /// and compacted whole strings are restored as they were indexed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error::Io` error with the `InvalidData` kind if the keys
+    /// can't be decoded.
+
+    #[tracing::instrument(level = "trace", name = "lazy index into search index", skip(self, search_index_builder))]
+    pub fn into_search_index(
+        self,
+        search_index_builder: SearchIndexBuilder<K>,
+    ) -> Result<SearchIndex<K>, Error> {
+        let LazyIndex { bytes, postings_start, dictionary, postings, whole_string_keywords, compacted, .. } = self;
+
+        let mut b_tree_map: BTreeMap<KString, BTreeSet<K>> = BTreeMap::new();
+        for ((keyword, offset, length), keys) in dictionary.into_iter().zip(postings) {
+            let keys = match keys.into_inner() {
+                Some(keys) => keys,
+                None => read_postings(&bytes[postings_start..], offset, length)?,
+            }; // match
+            b_tree_map.insert(keyword, keys);
+        } // for
+
+        Ok(SearchIndex::from_persisted_keywords(
+            search_index_builder,
+            PersistedKeywords { b_tree_map, whole_string_keywords, compacted },
+        ))
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns the keys of the keyword at the dictionary index, decoding them
+    /// if they haven't been already.
+
+    fn load(&self, index: usize) -> Result<&BTreeSet<K>, Error> {
+        if let Some(keys) = self.postings[index].get() { return Ok(keys) }
+        let (_keyword, offset, length) = &self.dictionary[index];
+        let keys = read_postings(&self.bytes[self.postings_start..], *offset, *length)?;
+        Ok(self.postings[index].get_or_init(|| keys))
+    } // fn
+
+} // impl
\ No newline at end of file
@@ -0,0 +1,113 @@
+// -----------------------------------------------------------------------------
+//
+/// How the keys for each keyword position are combined in a
+/// [`SearchIndex::search_live_with`] search.
+///
+/// [`SearchIndex::search_live_with`]: struct.SearchIndex.html#method.search_live_with
+
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum Conjunction {
+    /// Records must match every keyword position.
+    #[default]
+    And,
+    /// Records may match any keyword position. Search results are returned in
+    /// order of descending relevance (the number of positions matched.)
+    Or,
+} // Conjunction
+
+// -----------------------------------------------------------------------------
+//
+/// How a keyword in the search string is matched against the keywords in the
+/// search index, in a [`SearchIndex::search_live_with`] search.
+///
+/// [`SearchIndex::search_live_with`]: struct.SearchIndex.html#method.search_live_with
+
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum KeywordMatch {
+    /// The keyword must match a keyword in the search index exactly.
+    Exact,
+    /// The keyword is treated as a partial keyword, and matches every keyword
+    /// in the search index that starts with it.
+    Prefix,
+} // KeywordMatch
+
+// -----------------------------------------------------------------------------
+//
+/// The strategy for a [`SearchIndex::search_live_with`] search. The `Live`
+/// search type always uses `And` for every keyword, an exact match for all but
+/// the last keyword, and a prefix match for the last keyword. This lets the
+/// caller choose otherwise, for example `Or` for recall-heavy product search.
+///
+/// Basic usage:
+///
+/// ```rust
+/// # use indicium::simple::{Conjunction, KeywordMatch, LiveStrategy};
+/// #
+/// // "Or for all but last" live search:
+/// let strategy = LiveStrategy::new()
+///     .conjunction(Conjunction::Or)
+///     .preceding(KeywordMatch::Exact)
+///     .last(KeywordMatch::Prefix);
+/// ```
+///
+/// [`SearchIndex::search_live_with`]: struct.SearchIndex.html#method.search_live_with
+
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct LiveStrategy {
+    pub(crate) conjunction: Conjunction,
+    pub(crate) preceding: KeywordMatch,
+    pub(crate) last: KeywordMatch,
+} // LiveStrategy
+
+// -----------------------------------------------------------------------------
+
+impl Default for LiveStrategy {
+    /// Initialize `LiveStrategy` with the same behaviour as the `Live` search
+    /// type.
+    fn default() -> Self {
+        LiveStrategy {
+            conjunction: Conjunction::And,
+            preceding: KeywordMatch::Exact,
+            last: KeywordMatch::Prefix,
+        } // LiveStrategy
+    } // fn
+} // impl Default
+
+// -----------------------------------------------------------------------------
+
+impl LiveStrategy {
+
+    /// Start the strategy chain with the same behaviour as the `Live` search
+    /// type.
+    pub fn new() -> Self {
+        Self::default()
+    } // fn
+
+    /// How the keys for each keyword position are combined.
+    ///
+    /// **Default:** `Conjunction::And`
+    pub fn conjunction(mut self, conjunction: Conjunction) -> Self {
+        self.conjunction = conjunction;
+        self
+    } // fn
+
+    /// How every keyword except the last is matched.
+    ///
+    /// **Default:** `KeywordMatch::Exact`
+    pub fn preceding(mut self, preceding: KeywordMatch) -> Self {
+        self.preceding = preceding;
+        self
+    } // fn
+
+    /// How the last (partial) keyword is matched.
+    ///
+    /// **Default:** `KeywordMatch::Prefix`
+    pub fn last(mut self, last: KeywordMatch) -> Self {
+        self.last = last;
+        self
+    } // fn
+
+} // impl
\ No newline at end of file
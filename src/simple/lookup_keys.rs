@@ -0,0 +1,96 @@
+use crate::simple::search_index::SearchIndex;
+use std::{cmp::Ord, collections::BTreeSet, str::FromStr};
+
+// -----------------------------------------------------------------------------
+
+impl<K: Ord> SearchIndex<K> {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns the search index's own copy of the key, or `None` if the key
+    /// isn't attached to any keyword. Uses the reverse map if it's enabled,
+    /// or the `dump_keyword` if there is one. Otherwise every keyword is
+    /// scanned.
+
+    pub(crate) fn existing_key(&self, key: &K) -> Option<&K> {
+        match (&self.reverse_map, &self.dump_keyword) {
+            (Some(reverse_map), _) => reverse_map
+                .get_key_value(key)
+                .map(|(key, _keywords)| key),
+            (None, Some(dump_keyword)) => self.b_tree_map
+                .get(dump_keyword)
+                .and_then(|keys| keys.get(key)),
+            (None, None) => self.b_tree_map
+                .values()
+                .find_map(|keys| keys.get(key))
+                .or_else(|| self.whole_strings.values().find(|compacted| *compacted == key)),
+        } // match
+    } // fn
+
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl<K: FromStr + Ord> SearchIndex<K> {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Treats the query as a list of literal keys (or record ids) separated by
+    /// whitespace, and returns the keys that exist in the search index. This
+    /// lets the same search box accept a pasted list of ids.
+    ///
+    /// Each id is parsed with `FromStr`. Ids that can't be parsed or aren't in
+    /// the search index are ignored. Keys are returned in the order given in
+    /// the query, without duplicates, up to `maximum_search_results` keys.
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # use indicium::simple::SearchIndex;
+    /// # use pretty_assertions::assert_eq;
+    /// #
+    /// let mut search_index: SearchIndex<u32> = SearchIndex::default();
+    /// search_index.insert(&1001, &"Harold Godwinson");
+    /// search_index.insert(&1002, &"Edgar Ætheling");
+    /// search_index.insert(&1003, &"William the Conqueror");
+    ///
+    /// assert_eq!(
+    ///     search_index.lookup_keys("1003 9999 1001\n1003 harold"),
+    ///     vec![&1003, &1001]
+    /// );
+    /// ```
+
+    #[tracing::instrument(level = "trace", name = "lookup keys", skip(self))]
+    pub fn lookup_keys(&self, ids_query: &str) -> Vec<&K> {
+
+        // Used to skip ids that appear more than once in the query:
+        let mut seen: BTreeSet<&K> = BTreeSet::new();
+
+        let search_results: Vec<&K> = ids_query
+            // Ids are only separated by whitespace. No other tokenization is
+            // done:
+            .split_whitespace()
+            // Ignore ids that aren't valid keys:
+            .filter_map(|id| id.parse::<K>().ok())
+            // Ignore keys that aren't in the search index:
+            .filter_map(|key| self.existing_key(&key))
+            // Only keep the first occurrence of each key:
+            .filter(|key| seen.insert(*key))
+            // Only return `maximum_search_results` number of keys:
+            .take(self.maximum_search_results)
+            // Collect the keys into a `Vec`:
+            .collect();
+
+        // For debug builds:
+        #[cfg(debug_assertions)]
+        tracing::debug!(
+            "{} keys found for \"{}\".",
+            search_results.len(),
+            ids_query,
+        ); // debug!
+
+        search_results
+
+    } // fn
+
+} // impl
\ No newline at end of file
@@ -3,8 +3,6 @@
 //!
 //! There might be more search implementations in future versions.
 
-#[cfg(all(feature = "eddie", feature = "strsim"))]
-compile_error!("features `eddie` (preferred) and `strsim` cannot both be enabled");
 
 #[cfg(all(feature = "ahash", feature = "gxhash"))]
 compile_error!("features `ahash` (preferred) and `gxhash` cannot both be enabled");
@@ -15,24 +13,100 @@ mod internal;
 mod search;
 
 // Methods, structs & implementations:
+mod alias_keyword;
+mod all_keys;
+mod apply_ops;
 mod autocomplete_type;
+mod build_from_iter;
 mod builder;
+mod cancel_token;
+mod changelog;
+mod char_filter;
 mod clear;
+mod clock;
+mod compact_whole_strings;
+mod compression;
+mod contains_keyword;
+mod counts;
+#[cfg(feature = "compat")]
+mod compat;
 mod default;
+mod decay;
 mod deref;
 mod deref_mut;
 mod dump_keyword;
+mod duplicate_key_policy;
 mod eddie_metric;
+mod elision;
+mod evict;
+mod exclude_keywords;
+mod export_dictionary;
+mod export_solr;
+mod fingerprint;
+mod fuzzy_backend;
+mod fuzzy_backend_choice;
+mod fuzzy_config;
+mod from_iter;
+mod frozen_index;
+mod gc;
+#[cfg(feature = "geo")]
+mod geo;
+mod index_batch;
 mod indexable;
+mod indexed_collection;
 mod insert;
+mod insert_options;
+mod insert_report;
+mod interned_index;
+#[cfg(feature = "simd")]
+mod integer_key;
+#[cfg(feature = "json")]
+mod json_indexable;
+#[cfg(feature = "json")]
+mod json_value;
+mod keyword_cap_policy;
+mod keyword_enricher;
+mod keyword_hits;
+mod keyword_keys;
+mod keywords_grouped;
+mod lazy_index;
+mod live_strategy;
+mod lookup_keys;
 mod max_keys_per_keyword;
+mod more_like_this;
 mod new;
+mod persist;
+mod pinned_suggestions;
+mod prepared_query;
+mod query_config;
+mod query_limit_policy;
+mod query_limits;
+mod query_log;
+mod raw_index;
+mod related_keywords;
 mod remove;
 mod replace;
+mod runtime_settings;
+mod runtime_stats;
+mod saturated_keywords;
+#[cfg(feature = "permuterm")]
+mod search_contains;
 mod search_index;
+mod search_index_config;
+mod search_options;
 mod search_type;
+mod snapshot;
+mod snippet;
 mod strsim_metric;
+mod suggestion_filter;
+mod synonyms;
 mod tests;
+mod tokenize;
+mod tokenize_mode;
+mod top_scores;
+mod transliteration;
+mod update_field;
+mod warm_up;
 
 // For debug builds only:
 #[cfg(debug_assertions)]
@@ -41,9 +115,53 @@ mod profile;
 // -----------------------------------------------------------------------------
 
 pub use crate::simple::autocomplete_type::AutocompleteType;
+pub use crate::simple::build_from_iter::BuildProgress;
 pub use crate::simple::builder::SearchIndexBuilder;
+pub use crate::simple::cancel_token::{CancelToken, Cancelled};
+pub use crate::simple::changelog::{IndexChange, IndexOp};
+pub use crate::simple::char_filter::CharFilter;
+pub use crate::simple::compression::Compression;
+#[cfg(feature = "compat")]
+pub use crate::simple::compat::LegacyKeywords;
+pub use crate::simple::duplicate_key_policy::DuplicateKeyPolicy;
 pub use crate::simple::eddie_metric::EddieMetric;
+pub use crate::simple::elision::Elision;
+pub use crate::simple::evict::Evictions;
+pub use crate::simple::exclude_keywords::{ExcludeKeywords, Preset};
+pub use crate::simple::frozen_index::FrozenIndex;
+pub use crate::simple::fuzzy_backend_choice::FuzzyBackendChoice;
+pub use crate::simple::fuzzy_config::FuzzyConfig;
+pub use crate::simple::gc::GcReport;
+pub use crate::simple::index_batch::IndexBatch;
 pub use crate::simple::indexable::Indexable;
+pub use crate::simple::indexed_collection::{IndexedCollection, IndexedHashMap, IndexedVec};
+pub use crate::simple::insert_options::InsertOptions;
+pub use crate::simple::insert_report::InsertReport;
+pub use crate::simple::interned_index::InternedIndex;
+#[cfg(feature = "simd")]
+pub use crate::simple::integer_key::IntegerKey;
+#[cfg(feature = "json")]
+pub use crate::simple::json_indexable::{JsonDocument, JsonIndexable};
+#[cfg(feature = "json")]
+pub use crate::simple::json_value::JsonValue;
+pub use crate::simple::keyword_cap_policy::KeywordCapPolicy;
+pub use crate::simple::keyword_hits::KeywordHits;
+pub use crate::simple::lazy_index::LazyIndex;
+pub use crate::simple::live_strategy::{Conjunction, KeywordMatch, LiveStrategy};
+pub use crate::simple::persist::PersistedKey;
+pub use crate::simple::prepared_query::PreparedQuery;
+pub use crate::simple::query_config::QueryConfig;
+pub use crate::simple::query_limit_policy::QueryLimitPolicy;
+pub use crate::simple::query_log::QueryLog;
+pub use crate::simple::raw_index::RawIndex;
+pub use crate::simple::runtime_stats::RuntimeStats;
 pub use crate::simple::search_index::SearchIndex;
+pub use crate::simple::search_index_config::SearchIndexConfig;
+pub use crate::simple::search_options::SearchOptions;
 pub use crate::simple::search_type::SearchType;
-pub use crate::simple::strsim_metric::StrsimMetric;
\ No newline at end of file
+pub use crate::simple::snapshot::IndexSnapshot;
+pub use crate::simple::strsim_metric::StrsimMetric;
+pub use crate::simple::synonyms::{SynonymRule, Synonyms};
+pub use crate::simple::tokenize_mode::TokenizeMode;
+pub use crate::simple::top_scores::TopScores;
+pub use crate::simple::transliteration::{Transliteration, TransliterationDirection};
\ No newline at end of file
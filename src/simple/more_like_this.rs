@@ -0,0 +1,99 @@
+use crate::simple::search_index::SearchIndex;
+use kstring::KString;
+use std::collections::{BTreeMap, BTreeSet};
+use std::{cmp::Ord, cmp::Ordering};
+
+// -----------------------------------------------------------------------------
+
+impl<K: Ord> SearchIndex<K> {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns the keywords attached to a key. If the reverse map is
+    /// maintained, it's used. Otherwise, every keyword in the search index is
+    /// examined.
+
+    pub(crate) fn key_keywords(&self, key: &K) -> BTreeSet<&KString> {
+        match &self.reverse_map {
+            Some(reverse_map) => reverse_map
+                .get(key)
+                .map(|keywords| keywords.iter().collect())
+                .unwrap_or_default(),
+            None => self
+                .keyword_entries("")
+                .filter(|(keyword, keys)|
+                    self.dump_keyword.as_ref() != Some(*keyword) && keys.iter().any(|other| other == key)
+                ) // filter
+                .map(|(keyword, _keys)| keyword)
+                .collect(),
+        } // match
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns records that are similar to the record with the given key
+    /// ("more like this.") Records are scored by the keywords they share with
+    /// the given record, where rarer keywords count for more than common ones.
+    /// The most similar records are returned first. The given record itself is
+    /// never returned.
+    ///
+    /// This is most efficient when the reverse map is maintained. See
+    /// [`SearchIndexBuilder::reverse_map`]. Otherwise, every keyword in the
+    /// search index is examined to find the record's keywords.
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # use indicium::simple::{SearchIndex, SearchIndexBuilder};
+    /// # use pretty_assertions::assert_eq;
+    /// #
+    /// let mut search_index: SearchIndex<usize> = SearchIndexBuilder::default()
+    ///     .reverse_map(true)
+    ///     .build();
+    ///
+    /// search_index.insert(&0, &"William the Conqueror, King of England");
+    /// search_index.insert(&1, &"William Rufus, King of England");
+    /// search_index.insert(&2, &"Robert Curthose, Duke of Normandy");
+    /// search_index.insert(&3, &"William Adelin, Duke of Normandy");
+    ///
+    /// assert_eq!(search_index.more_like_this(&1, &2), vec![&0, &3]);
+    /// ```
+    ///
+    /// [`SearchIndexBuilder::reverse_map`]: struct.SearchIndexBuilder.html#method.reverse_map
+
+    #[tracing::instrument(level = "trace", name = "more like this", skip(self, key))]
+    pub fn more_like_this(&self, key: &K, maximum_search_results: &usize) -> Vec<&K> {
+
+        // Tally a score for every other record sharing a keyword with this
+        // record. Each shared keyword is weighted by its rarity:
+        let mut scores: BTreeMap<&K, f64> = BTreeMap::new();
+
+        self.key_keywords(key)
+            .into_iter()
+            .filter_map(|keyword| self.keyword_keys(keyword))
+            .for_each(|keys| {
+                let weight = 1.0 / keys.len() as f64;
+                keys.iter()
+                    .filter(|other| *other != key)
+                    .for_each(|other| *scores.entry(other).or_default() += weight)
+            }); // for_each
+
+        // Order the records by score (highest first) and then by key:
+        let mut search_results: Vec<(&K, f64)> = scores.into_iter().collect();
+
+        search_results.sort_by(|(a_key, a_score), (b_key, b_score)|
+            match b_score.total_cmp(a_score) {
+                Ordering::Equal => a_key.cmp(b_key),
+                ordering => ordering,
+            } // match
+        ); // sort_by
+
+        search_results
+            .into_iter()
+            .take(*maximum_search_results)
+            .map(|(key, _score)| key)
+            .collect()
+
+    } // fn
+
+} // impl
\ No newline at end of file
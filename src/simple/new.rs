@@ -1,5 +1,11 @@
-use crate::simple::{AutocompleteType, EddieMetric, SearchIndex, SearchType, StrsimMetric};
-use std::{cmp::Ord, collections::BTreeMap};
+#[cfg(feature = "geo")]
+use crate::simple::internal::GeoIndex;
+use crate::simple::internal::{Clock, KeywordCache, KeywordEnricher, KeywordUsage, RuntimeCounters, SaturationHook, SuggestionFilter, WarmCache};
+use crate::simple::synonyms::SynonymMap;
+use crate::simple::{AutocompleteType, DuplicateKeyPolicy, EddieMetric, Evictions, FuzzyBackendChoice, KeywordCapPolicy, QueryLimitPolicy, SearchIndex, SearchType, StrsimMetric};
+use std::collections::{BTreeMap, BTreeSet};
+use std::time::SystemTime;
+use std::{cmp::Ord, sync::Arc};
 
 // -----------------------------------------------------------------------------
 
@@ -57,11 +63,12 @@ impl<K: Ord> SearchIndex<K> {
     ) -> SearchIndex<K> {
 
         SearchIndex {
-            b_tree_map: BTreeMap::new(),
+            b_tree_map: Arc::default(),
             search_type,
             autocomplete_type,
             strsim_metric,
             eddie_metric,
+            fuzzy_backend: FuzzyBackendChoice::default(),
             fuzzy_length,
             fuzzy_minimum_score,
             split_pattern,
@@ -74,6 +81,56 @@ impl<K: Ord> SearchIndex<K> {
             maximum_search_results,
             maximum_keys_per_keyword,
             dump_keyword: dump_keyword.map(|string| string.into()),
+            keyword_cache: KeywordCache::with_capacity(16),
+            // The default `DuplicateKeyPolicy::Upsert` requires the reverse
+            // map:
+            reverse_map: Some(BTreeMap::new()),
+            warm_cache: WarmCache::default(),
+            pinned_suggestions: BTreeMap::new(),
+            whole_strings: Arc::new(BTreeMap::new()),
+            whole_string_keywords: BTreeSet::new(),
+            field_keywords: BTreeMap::new(),
+            maximum_keywords: None,
+            keyword_usage: KeywordUsage::default(),
+            evictions: Evictions::default(),
+            saturated_keywords: BTreeSet::new(),
+            saturation_hook: SaturationHook::default(),
+            runtime_counters: RuntimeCounters::new(SystemTime::now()),
+            suggestion_filter: SuggestionFilter::default(),
+            clock: Clock::default(),
+            keyword_enricher: KeywordEnricher::default(),
+            active_fuzzy_backend: FuzzyBackendChoice::default(),
+            interior_autocomplete: false,
+            interior_tokens: None,
+            posting_times: None,
+            changelog: None,
+            applied_sequence: 0,
+            grapheme_lengths: false,
+            front_map: None,
+            permuterm: None,
+            sorted_postings: None,
+            minimum_keys_for_autocomplete: 0,
+            maximum_keywords_per_record: None,
+            keyword_cap_policy: KeywordCapPolicy::Truncate,
+            maximum_query_length: None,
+            maximum_query_keywords: None,
+            maximum_fuzzy_candidates: None,
+            adaptive_fuzzy_length: None,
+            fuzzy_first_letter_fallback: false,
+            query_limit_policy: QueryLimitPolicy::Clamp,
+            duplicate_key_policy: DuplicateKeyPolicy::Upsert,
+            context_window: None,
+            preserve_query_prefix: false,
+            suggestion_blocklist: None,
+            shingles: false,
+            char_filter: None,
+            elision: None,
+            plural_variants: false,
+            synonyms: None,
+            synonym_map: SynonymMap::default(),
+            transliteration: None,
+            #[cfg(feature = "geo")]
+            geo_index: GeoIndex::default(),
         } // SearchIndex
 
     } // fn
@@ -0,0 +1,666 @@
+use crate::simple::internal::KeywordKeys;
+use crate::simple::{Compression, SearchIndex, SearchIndexBuilder};
+use crate::Error;
+use kstring::KString;
+use std::collections::{BTreeMap, BTreeSet};
+use std::io::{ErrorKind, Read, Write};
+use std::sync::Arc;
+use std::{clone::Clone, cmp::Ord};
+
+// -----------------------------------------------------------------------------
+//
+/// Identifies the persisted search index format.
+
+pub(crate) const MAGIC: &[u8; 4] = b"INDC";
+
+/// The version of the persisted search index format. Version 2 added the
+/// whole strings section, which follows the postings. It lists the ordinals
+/// of the keywords that were indexed as whole strings, and of those that were
+/// compacted (see `SearchIndex::compact_whole_strings`).
+
+pub(crate) const VERSION: u8 = 2;
+
+/// The oldest version of the persisted search index format that can be read.
+
+const OLDEST_VERSION: u8 = 1;
+
+/// Identifies a compressed persisted search index. It's followed by the
+/// compression identifier (see `Compression`) and the compressed data.
+
+pub(crate) const COMPRESSED_MAGIC: &[u8; 4] = b"INDZ";
+
+/// The compression identifier for zstd.
+
+#[cfg(feature = "zstd")]
+const ZSTD: u8 = 1;
+
+// -----------------------------------------------------------------------------
+//
+/// Key types that can be written to, and read from, the persisted search index
+/// format (see [`SearchIndex::to_bytes`].) This is implemented for the
+/// unsigned integer types and `String`. It may be implemented for other key
+/// types.
+///
+/// [`SearchIndex::to_bytes`]: struct.SearchIndex.html#method.to_bytes
+
+pub trait PersistedKey: Sized {
+    /// Appends the encoded key to the bytes.
+    fn write_key(&self, bytes: &mut Vec<u8>);
+
+    /// Decodes a key from the start of the bytes. Returns the key and the
+    /// number of bytes read, or `None` if the bytes aren't a valid key.
+    fn read_key(bytes: &[u8]) -> Option<(Self, usize)>;
+} // PersistedKey
+
+// -----------------------------------------------------------------------------
+
+macro_rules! persisted_integer_key {
+    ($($type:ty),*) => {$(
+        impl PersistedKey for $type {
+            fn write_key(&self, bytes: &mut Vec<u8>) {
+                write_varint(bytes, *self as u64);
+            } // fn
+
+            fn read_key(bytes: &[u8]) -> Option<(Self, usize)> {
+                let (value, length) = read_varint(bytes)?;
+                Some((<$type>::try_from(value).ok()?, length))
+            } // fn
+        } // impl
+    )*};
+} // macro_rules!
+
+persisted_integer_key!(u8, u16, u32, u64, usize);
+
+impl PersistedKey for String {
+    fn write_key(&self, bytes: &mut Vec<u8>) {
+        write_varint(bytes, self.len() as u64);
+        bytes.extend_from_slice(self.as_bytes());
+    } // fn
+
+    fn read_key(bytes: &[u8]) -> Option<(Self, usize)> {
+        let (length, offset) = read_varint(bytes)?;
+        let end = offset.checked_add(usize::try_from(length).ok()?)?;
+        let string = std::str::from_utf8(bytes.get(offset..end)?).ok()?;
+        Some((string.to_string(), end))
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl<K: Clone + Ord + PersistedKey> SearchIndex<K> {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Writes the search index's keywords and keys in a compact binary format,
+    /// so that a large search index can be loaded without re-indexing the
+    /// records. The settings aren't written, and should be persisted
+    /// separately (see `config`.)
+    ///
+    /// The format starts with a dictionary of the keywords and the offset of
+    /// each keyword's keys, followed by the keys. This allows the keys to be
+    /// loaded lazily with [`LazyIndex`]. To write to a file, or to compress the
+    /// search index, use `write_to`.
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # use indicium::simple::{SearchIndex, SearchIndexBuilder};
+    /// # use pretty_assertions::assert_eq;
+    /// #
+    /// let mut search_index: SearchIndex<usize> = SearchIndex::default();
+    /// search_index.insert(&0, &"Harold Godwinson");
+    /// search_index.insert(&1, &"William the Conqueror");
+    ///
+    /// let bytes: Vec<u8> = search_index.to_bytes();
+    ///
+    /// let loaded: SearchIndex<usize> =
+    ///     SearchIndex::from_bytes(SearchIndexBuilder::default(), &bytes).unwrap();
+    ///
+    /// assert_eq!(loaded.search("william"), vec![&1]);
+    /// assert_eq!(loaded.autocomplete("har"), vec!["harold".to_string(), "harold godwinson".to_string()]);
+    /// ```
+    ///
+    /// [`LazyIndex`]: struct.LazyIndex.html
+
+    #[tracing::instrument(level = "trace", name = "search index to bytes", skip(self))]
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes: Vec<u8> = Vec::new();
+        // Writing to a `Vec` doesn't fail:
+        let _ = self.write_uncompressed(&mut bytes);
+        bytes
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Writes the search index to a writer (such as a file) in the same format
+    /// as `to_bytes`, optionally compressed. The keys are encoded and written
+    /// one keyword at a time, so only the keyword dictionary is held in memory
+    /// while writing, not the keys.
+    ///
+    /// Compressed search indexes start with a small header that records the
+    /// compression, and are detected automatically by `read_from`.
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # use indicium::simple::{Compression, SearchIndex, SearchIndexBuilder};
+    /// # use pretty_assertions::assert_eq;
+    /// #
+    /// let mut search_index: SearchIndex<usize> = SearchIndex::default();
+    /// search_index.insert(&0, &"Harold Godwinson");
+    ///
+    /// let mut file: Vec<u8> = Vec::new();
+    /// search_index.write_to(&mut file, Compression::None).unwrap();
+    ///
+    /// let loaded: SearchIndex<usize> =
+    ///     SearchIndex::read_from(SearchIndexBuilder::default(), file.as_slice()).unwrap();
+    ///
+    /// assert_eq!(loaded.search("godwinson"), vec![&0]);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error::Io` error if writing fails.
+
+    #[tracing::instrument(level = "trace", name = "search index write to", skip(self, writer))]
+    pub fn write_to<W: Write>(&self, mut writer: W, compression: Compression) -> Result<(), Error> {
+        match compression {
+            Compression::None => self.write_uncompressed(&mut writer)?,
+            #[cfg(feature = "zstd")]
+            Compression::Zstd(level) => {
+                writer.write_all(COMPRESSED_MAGIC)?;
+                writer.write_all(&[ZSTD])?;
+                let mut encoder = zstd::stream::write::Encoder::new(writer, level)?;
+                self.write_uncompressed(&mut encoder)?;
+                encoder.finish()?;
+            }, // Zstd
+        } // match
+        Ok(())
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Builds a search index with the given settings from bytes written by
+    /// `to_bytes` or `write_to`. Any enabled settings that depend on the
+    /// keywords (such as the reverse map or front map) are rebuilt.
+    ///
+    /// The keywords are loaded as they were indexed, including which keywords
+    /// are whole strings and which were compacted. If the settings have
+    /// changed since (case sensitivity or split pattern, for example), the
+    /// records should be re-inserted instead.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error::Io` error with the `InvalidData` kind if the bytes
+    /// aren't a persisted search index.
+
+    pub fn from_bytes(
+        search_index_builder: SearchIndexBuilder<K>,
+        bytes: &[u8],
+    ) -> Result<Self, Error> {
+        Self::read_from(search_index_builder, bytes)
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Builds a search index with the given settings from a reader (such as a
+    /// file) written by `to_bytes` or `write_to`. Compression is detected from
+    /// the header. The keys are read and decoded one keyword at a time, so the
+    /// whole persisted search index is never held in memory. See `from_bytes`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error::Io` error if reading fails, or with the `InvalidData`
+    /// kind if the data isn't a persisted search index (or it's compressed, but
+    /// the feature for its compression isn't enabled.)
+
+    #[tracing::instrument(level = "trace", name = "search index read from", skip(search_index_builder, reader))]
+    pub fn read_from<R: Read>(
+        search_index_builder: SearchIndexBuilder<K>,
+        reader: R,
+    ) -> Result<Self, Error> {
+
+        let keywords: PersistedKeywords<K> = read_keywords(&mut decompress(reader)?)?;
+
+        // For debug builds:
+        #[cfg(debug_assertions)]
+        tracing::debug!("{} persisted keywords loaded.", keywords.b_tree_map.len());
+
+        Ok(Self::from_persisted_keywords(search_index_builder, keywords))
+
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Builds a search index with the given settings from the persisted
+    /// keywords. This is shared by `read_from` and `LazyIndex`.
+
+    pub(crate) fn from_persisted_keywords(
+        search_index_builder: SearchIndexBuilder<K>,
+        mut keywords: PersistedKeywords<K>,
+    ) -> Self {
+
+        // Move the compacted whole strings back out of the keywords. They only
+        // have a single key:
+        let b_tree_map = &keywords.b_tree_map;
+        keywords.compacted.retain(|keyword| b_tree_map.get(keyword).is_some_and(|keys| keys.len() == 1));
+        let whole_strings: BTreeMap<KString, K> = keywords.compacted
+            .into_iter()
+            .filter_map(|keyword| keywords.b_tree_map
+                .remove(&keyword)
+                .and_then(|keys| keys.into_iter().next())
+                .map(|key| (keyword, key))
+            ) // filter_map
+            .collect();
+
+        // Replace the keywords, and rebuild the search index so that any
+        // settings that depend on the keywords are rebuilt too:
+        let mut search_index: SearchIndex<K> = search_index_builder.build();
+        search_index.b_tree_map = Arc::new(keywords.b_tree_map);
+        search_index.whole_strings = Arc::new(whole_strings);
+        search_index.whole_string_keywords = keywords.whole_string_keywords;
+        SearchIndexBuilder::from(search_index).build()
+
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Writes the uncompressed format. The keys are encoded twice: once to
+    /// find their offsets for the dictionary, and again to write them.
+
+    fn write_uncompressed(&self, writer: &mut dyn Write) -> std::io::Result<()> {
+
+        // Encodes the keys of a keyword:
+        let encode = |keys: KeywordKeys<'_, K>, posting: &mut Vec<u8>| {
+            posting.clear();
+            write_varint(posting, keys.len() as u64);
+            keys.iter().for_each(|key| key.write_key(posting));
+        }; // closure
+
+        // Build the dictionary, with the offset of each keyword's keys. The
+        // keywords, including any compacted whole-string keywords, are visited
+        // in lexographic order. The ordinals of the whole strings, and of the
+        // compacted whole strings, are gathered along the way:
+        let mut posting: Vec<u8> = Vec::new();
+        let mut dictionary: Vec<u8> = Vec::new();
+        let mut offset: usize = 0;
+        let mut count: usize = 0;
+        let mut whole_string_ordinals: Vec<u64> = Vec::new();
+        let mut compacted_ordinals: Vec<u64> = Vec::new();
+        self.keyword_entries("").for_each(|(keyword, keys)| {
+            encode(keys, &mut posting);
+            write_varint(&mut dictionary, keyword.len() as u64);
+            dictionary.extend_from_slice(keyword.as_bytes());
+            write_varint(&mut dictionary, offset as u64);
+            write_varint(&mut dictionary, posting.len() as u64);
+            offset += posting.len();
+            if self.whole_string_keywords.contains(keyword) {
+                whole_string_ordinals.push(count as u64);
+            } // if
+            if let KeywordKeys::Compacted(_key) = keys {
+                compacted_ordinals.push(count as u64);
+            } // if
+            count += 1;
+        }); // for_each
+
+        // Header, dictionary, then postings:
+        let mut header: Vec<u8> = Vec::with_capacity(32);
+        header.extend_from_slice(MAGIC);
+        header.push(VERSION);
+        header.push(u8::from(self.case_sensitive));
+        write_varint(&mut header, count as u64);
+        write_varint(&mut header, dictionary.len() as u64);
+        writer.write_all(&header)?;
+        writer.write_all(&dictionary)?;
+        for (_keyword, keys) in self.keyword_entries("") {
+            encode(keys, &mut posting);
+            writer.write_all(&posting)?;
+        } // for
+
+        // Then the ordinals of the whole strings, and of the compacted whole
+        // strings:
+        let mut whole_strings: Vec<u8> = Vec::new();
+        for ordinals in [whole_string_ordinals, compacted_ordinals] {
+            write_varint(&mut whole_strings, ordinals.len() as u64);
+            ordinals.into_iter().for_each(|ordinal| write_varint(&mut whole_strings, ordinal));
+        } // for
+        writer.write_all(&whole_strings)?;
+
+        Ok(())
+
+    } // fn
+
+} // impl
+
+// -----------------------------------------------------------------------------
+//
+/// Returns a reader of the uncompressed format. If the data starts with the
+/// compressed header, it's decompressed as it's read.
+
+pub(crate) fn decompress<'r, R: Read + 'r>(mut reader: R) -> Result<Box<dyn Read + 'r>, Error> {
+    let mut magic: [u8; 4] = [0; 4];
+    let read = read_up_to(&mut reader, &mut magic)?;
+
+    // Not compressed. Put the magic bytes back in front of the reader:
+    if &magic != COMPRESSED_MAGIC {
+        return Ok(Box::new(std::io::Cursor::new(magic[..read].to_vec()).chain(reader)))
+    } // if
+
+    let mut id: [u8; 1] = [0];
+    reader.read_exact(&mut id)?;
+    match id[0] {
+        #[cfg(feature = "zstd")]
+        ZSTD => Ok(Box::new(zstd::stream::read::Decoder::new(reader)?)),
+        _ => Err(invalid_data("unsupported compression")),
+    } // match
+} // fn
+
+// -----------------------------------------------------------------------------
+//
+/// The keywords and keys read from the uncompressed format.
+
+pub(crate) struct PersistedKeywords<K> {
+    /// Every keyword, including the compacted whole strings.
+    pub(crate) b_tree_map: BTreeMap<KString, BTreeSet<K>>,
+    /// The keywords that were indexed as whole strings, and weren't compacted.
+    pub(crate) whole_string_keywords: BTreeSet<KString>,
+    /// The compacted whole strings.
+    pub(crate) compacted: BTreeSet<KString>,
+} // PersistedKeywords
+
+// -----------------------------------------------------------------------------
+//
+/// Reads the keywords and keys of the uncompressed format, one keyword at a
+/// time.
+
+fn read_keywords<K: Ord + PersistedKey>(reader: &mut dyn Read) -> Result<PersistedKeywords<K>, Error> {
+    let mut header: [u8; 6] = [0; 6];
+    if read_up_to(reader, &mut header)? < header.len() || &header[..MAGIC.len()] != MAGIC {
+        return Err(invalid_data("not a persisted search index"))
+    } // if
+    let version: u8 = header[MAGIC.len()];
+    if !(OLDEST_VERSION..=VERSION).contains(&version) {
+        return Err(invalid_data("unsupported persisted search index version"))
+    } // if
+
+    // Read the dictionary. It's read incrementally rather than allocated up
+    // front, in case the length is corrupt:
+    let count = read_varint_from(reader)?;
+    let dictionary_length = read_varint_from(reader)?;
+    let mut dictionary: Vec<u8> = Vec::new();
+    reader.take(dictionary_length as u64).read_to_end(&mut dictionary)?;
+    if dictionary.len() != dictionary_length {
+        return Err(invalid_data("truncated dictionary"))
+    } // if
+
+    let mut b_tree_map: BTreeMap<KString, BTreeSet<K>> = BTreeMap::new();
+    let mut posting: Vec<u8> = Vec::new();
+    let mut position: usize = 0;
+    let mut expected_offset: usize = 0;
+    for _ in 0..count {
+        let (keyword, offset, length) = read_dictionary_entry(&dictionary, &mut position)?;
+        // Postings are written in dictionary order:
+        if offset != expected_offset {
+            return Err(invalid_data("posting offset out of order"))
+        } // if
+        expected_offset = offset.checked_add(length).ok_or_else(|| invalid_data("invalid length"))?;
+        posting.clear();
+        reader.take(length as u64).read_to_end(&mut posting)?;
+        if posting.len() != length {
+            return Err(invalid_data("truncated postings"))
+        } // if
+        b_tree_map.insert(keyword, read_postings(&posting, 0, length)?);
+    } // for
+
+    if position != dictionary.len() {
+        return Err(invalid_data("dictionary length mismatch"))
+    } // if
+
+    // Read the whole strings section, unless the format predates it. The
+    // ordinals are positions in the dictionary, which is in lexographic order:
+    let mut whole_string_keywords: BTreeSet<KString> = BTreeSet::new();
+    let mut compacted: BTreeSet<KString> = BTreeSet::new();
+    if version > OLDEST_VERSION {
+        let keywords: Vec<&KString> = b_tree_map.keys().collect();
+        for set in [&mut whole_string_keywords, &mut compacted] {
+            for _ in 0..read_varint_from(reader)? {
+                let keyword = keywords
+                    .get(read_varint_from(reader)?)
+                    .ok_or_else(|| invalid_data("whole string out of bounds"))?;
+                set.insert((*keyword).clone());
+            } // for
+        } // for
+    } // if
+
+    Ok(PersistedKeywords { b_tree_map, whole_string_keywords, compacted })
+} // fn
+
+// -----------------------------------------------------------------------------
+//
+/// The sections of a persisted search index.
+
+pub(crate) struct Layout {
+    /// Whether the keywords were indexed case sensitively.
+    pub(crate) case_sensitive: bool,
+    /// Each keyword, and the offset & length of its keys in the postings.
+    pub(crate) dictionary: Vec<(KString, usize, usize)>,
+    /// The offset of the postings (the keys of every keyword) in the persisted
+    /// search index.
+    pub(crate) postings_start: usize,
+    /// The keywords that were indexed as whole strings, and weren't compacted.
+    pub(crate) whole_string_keywords: BTreeSet<KString>,
+    /// The compacted whole strings.
+    pub(crate) compacted: BTreeSet<KString>,
+} // Layout
+
+impl Layout {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Reads the header, dictionary and whole strings section of a persisted
+    /// search index. The keys aren't decoded, but their offsets are checked to
+    /// be in bounds.
+
+    pub(crate) fn read(bytes: &[u8]) -> Result<Self, Error> {
+        if bytes.get(..MAGIC.len()) != Some(MAGIC) {
+            return Err(invalid_data("not a persisted search index"))
+        } // if
+        let version: u8 = match bytes.get(MAGIC.len()) {
+            Some(version) if (OLDEST_VERSION..=VERSION).contains(version) => *version,
+            _ => return Err(invalid_data("unsupported persisted search index version")),
+        }; // match
+        let case_sensitive: bool = bytes.get(MAGIC.len() + 1) == Some(&1);
+
+        let mut offset: usize = MAGIC.len() + 2;
+        let count = read_usize(bytes, &mut offset)?;
+        let dictionary_length = read_usize(bytes, &mut offset)?;
+        let dictionary_end = offset
+            .checked_add(dictionary_length)
+            .filter(|end| *end <= bytes.len())
+            .ok_or_else(|| invalid_data("truncated dictionary"))?;
+        // The postings are followed by the whole strings section, unless the
+        // format predates it:
+        let postings: &[u8] = &bytes[dictionary_end..];
+
+        let dictionary_bytes: &[u8] = &bytes[offset..dictionary_end];
+        let mut position: usize = 0;
+        let mut postings_length: usize = 0;
+        let mut dictionary: Vec<(KString, usize, usize)> = Vec::with_capacity(count.min(dictionary_length));
+        for _ in 0..count {
+            let (keyword, posting_offset, posting_length) = read_dictionary_entry(dictionary_bytes, &mut position)?;
+            let posting_end = posting_offset
+                .checked_add(posting_length)
+                .filter(|end| *end <= postings.len())
+                .ok_or_else(|| invalid_data("posting offset out of bounds"))?;
+            postings_length = postings_length.max(posting_end);
+            dictionary.push((keyword, posting_offset, posting_length));
+        } // for
+
+        if position != dictionary_bytes.len() {
+            return Err(invalid_data("dictionary length mismatch"))
+        } // if
+
+        // Read the whole strings section. The ordinals are positions in the
+        // dictionary, which is in lexographic order:
+        let whole_strings: &[u8] = &postings[postings_length..];
+        let mut whole_string_keywords: BTreeSet<KString> = BTreeSet::new();
+        let mut compacted: BTreeSet<KString> = BTreeSet::new();
+        if version > OLDEST_VERSION {
+            let mut position: usize = 0;
+            for set in [&mut whole_string_keywords, &mut compacted] {
+                for _ in 0..read_usize(whole_strings, &mut position)? {
+                    let (keyword, _offset, _length) = dictionary
+                        .get(read_usize(whole_strings, &mut position)?)
+                        .ok_or_else(|| invalid_data("whole string out of bounds"))?;
+                    set.insert(keyword.clone());
+                } // for
+            } // for
+        } // if
+
+        Ok(Layout {
+            case_sensitive,
+            dictionary,
+            postings_start: dictionary_end,
+            whole_string_keywords,
+            compacted,
+        })
+    } // fn
+
+} // impl
+
+// -----------------------------------------------------------------------------
+//
+/// Reads a dictionary entry: a keyword, and the offset & length of its keys.
+/// Advances the position past the entry.
+
+fn read_dictionary_entry(
+    dictionary: &[u8],
+    position: &mut usize,
+) -> Result<(KString, usize, usize), Error> {
+    let keyword_length = read_usize(dictionary, position)?;
+    let keyword = position
+        .checked_add(keyword_length)
+        .and_then(|end| dictionary.get(*position..end))
+        .and_then(|keyword| std::str::from_utf8(keyword).ok())
+        .ok_or_else(|| invalid_data("invalid keyword"))?;
+    *position += keyword_length;
+    let offset = read_usize(dictionary, position)?;
+    let length = read_usize(dictionary, position)?;
+    Ok((KString::from_ref(keyword), offset, length))
+} // fn
+
+// -----------------------------------------------------------------------------
+//
+/// Decodes the keys of a keyword.
+
+pub(crate) fn read_postings<K: Ord + PersistedKey>(
+    postings: &[u8],
+    offset: usize,
+    length: usize,
+) -> Result<BTreeSet<K>, Error> {
+    let bytes: &[u8] = &postings[offset..offset + length];
+    let mut position: usize = 0;
+    let count = read_usize(bytes, &mut position)?;
+    let mut keys: BTreeSet<K> = BTreeSet::new();
+    for _ in 0..count {
+        let (key, read) = K::read_key(&bytes[position..])
+            .ok_or_else(|| invalid_data("invalid key"))?;
+        position += read;
+        keys.insert(key);
+    } // for
+    Ok(keys)
+} // fn
+
+// -----------------------------------------------------------------------------
+//
+/// Returns an `InvalidData` I/O error.
+
+fn invalid_data(reason: &str) -> Error {
+    Error::Io(std::io::Error::new(ErrorKind::InvalidData, reason.to_string()))
+} // fn
+
+// -----------------------------------------------------------------------------
+//
+/// Appends a LEB128 varint.
+
+fn write_varint(bytes: &mut Vec<u8>, mut value: u64) {
+    while value >= 0x80 {
+        bytes.push((value as u8 & 0x7f) | 0x80);
+        value >>= 7;
+    } // while
+    bytes.push(value as u8);
+} // fn
+
+// -----------------------------------------------------------------------------
+//
+/// Reads a LEB128 varint from the start of the bytes. Returns the value and
+/// the number of bytes read, or `None` if the varint is truncated or
+/// overflows.
+
+fn read_varint(bytes: &[u8]) -> Option<(u64, usize)> {
+    let mut value: u64 = 0;
+    for (index, byte) in bytes.iter().enumerate().take(10) {
+        value |= u64::from(byte & 0x7f).checked_shl(7 * index as u32)?;
+        if byte & 0x80 == 0 { return Some((value, index + 1)) }
+    } // for
+    None
+} // fn
+
+// -----------------------------------------------------------------------------
+//
+/// Reads a LEB128 varint as a `usize`, advancing the offset.
+
+fn read_usize(bytes: &[u8], offset: &mut usize) -> Result<usize, Error> {
+    let (value, read) = bytes
+        .get(*offset..)
+        .and_then(read_varint)
+        .ok_or_else(|| invalid_data("invalid length"))?;
+    *offset += read;
+    usize::try_from(value).map_err(|_error| invalid_data("invalid length"))
+} // fn
+
+// -----------------------------------------------------------------------------
+//
+/// Reads a LEB128 varint as a `usize` from a reader.
+
+fn read_varint_from(reader: &mut dyn Read) -> Result<usize, Error> {
+    let mut bytes: Vec<u8> = Vec::with_capacity(10);
+    let mut byte: [u8; 1] = [0];
+    loop {
+        reader.read_exact(&mut byte)?;
+        bytes.push(byte[0]);
+        if byte[0] & 0x80 == 0 || bytes.len() == 10 { break }
+    } // loop
+    read_usize(&bytes, &mut 0)
+} // fn
+
+// -----------------------------------------------------------------------------
+//
+/// Reads into the buffer until it's full or the reader is exhausted. Returns
+/// the number of bytes read.
+
+fn read_up_to(reader: &mut dyn Read, buffer: &mut [u8]) -> std::io::Result<usize> {
+    let mut read: usize = 0;
+    while read < buffer.len() {
+        match reader.read(&mut buffer[read..]) {
+            Ok(0) => break,
+            Ok(bytes) => read += bytes,
+            Err(error) if error.kind() == ErrorKind::Interrupted => continue,
+            Err(error) => return Err(error),
+        } // match
+    } // while
+    Ok(read)
+} // fn
+
+// -----------------------------------------------------------------------------
+
+#[test]
+fn test_persisted_key() {
+    let mut bytes: Vec<u8> = Vec::new();
+    300_u32.write_key(&mut bytes);
+    "Æthelred".to_string().write_key(&mut bytes);
+    let (number, read) = u32::read_key(&bytes).unwrap();
+    assert_eq!((number, read), (300, 2));
+    assert_eq!(String::read_key(&bytes[read..]).unwrap().0, "Æthelred");
+    assert!(u8::read_key(&bytes).is_none());
+}
\ No newline at end of file
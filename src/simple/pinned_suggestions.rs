@@ -0,0 +1,149 @@
+use crate::simple::internal::string_keywords::SplitContext;
+use crate::simple::SearchIndex;
+use kstring::KString;
+use std::{cmp::Ord, collections::HashSet, hash::Hash};
+
+// -----------------------------------------------------------------------------
+
+impl<K: Hash + Ord> SearchIndex<K> {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Pins a suggestion to a prefix. When the last (partial) keyword of an
+    /// autocompletion starts with the prefix, the pinned suggestion is shown
+    /// before the organic suggestions, which are deduplicated against it. This
+    /// allows editorial control over autocompletion.
+    ///
+    /// A pinned suggestion is only shown while it still starts with the
+    /// user's last keyword, and isn't shown if the suggestion blocklist or
+    /// suggestion filter rejects it. Pins are kept when the search index is
+    /// cleared.
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # use indicium::simple::SearchIndex;
+    /// # use pretty_assertions::assert_eq;
+    /// #
+    /// let mut search_index: SearchIndex<usize> = SearchIndex::default();
+    /// search_index.insert(&0, &"Wild boar");
+    /// search_index.insert(&1, &"William the Conqueror");
+    ///
+    /// search_index.pin_suggestion("wil", "william the conqueror");
+    ///
+    /// assert_eq!(
+    ///     search_index.autocomplete("wil"),
+    ///     vec![
+    ///         "william the conqueror".to_string(),
+    ///         "wild".to_string(),
+    ///         "wild boar".to_string(),
+    ///         "william".to_string(),
+    ///     ]
+    /// );
+    ///
+    /// // The pinned suggestion no longer matches the user's last keyword:
+    /// assert_eq!(search_index.autocomplete("wild"), vec!["wild".to_string(), "wild boar".to_string()]);
+    /// ```
+
+    #[tracing::instrument(level = "trace", name = "pin suggestion", skip(self))]
+    pub fn pin_suggestion(&mut self, prefix: &str, suggestion: &str) {
+
+        let prefix: KString = self.normalize_keyword(prefix);
+        let suggestion: KString = self.normalize_keyword(suggestion);
+
+        let pinned: &mut Vec<KString> = self.pinned_suggestions.entry(prefix).or_default();
+        if !pinned.contains(&suggestion) {
+            pinned.push(suggestion);
+        } // if
+
+        // Cached autocompletions may no longer be current:
+        self.invalidate_autocompletions();
+
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Unpins a suggestion from a prefix. Returns `true` if the suggestion was
+    /// pinned.
+
+    #[tracing::instrument(level = "trace", name = "unpin suggestion", skip(self))]
+    pub fn unpin_suggestion(&mut self, prefix: &str, suggestion: &str) -> bool {
+
+        let prefix: KString = self.normalize_keyword(prefix);
+        let suggestion: KString = self.normalize_keyword(suggestion);
+
+        let Some(pinned) = self.pinned_suggestions.get_mut(&prefix) else { return false };
+        let length: usize = pinned.len();
+        pinned.retain(|pinned_suggestion| *pinned_suggestion != suggestion);
+        let unpinned: bool = pinned.len() < length;
+
+        if pinned.is_empty() {
+            self.pinned_suggestions.remove(&prefix);
+        } // if
+
+        // Cached autocompletions may no longer be current:
+        self.invalidate_autocompletions();
+
+        unpinned
+
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Places the pinned suggestions that match the search string's last
+    /// (partial) keyword in front of the autocompletion options. Organic
+    /// options that duplicate a pinned suggestion are dropped.
+
+    pub(crate) fn merge_pinned_suggestions(
+        &self,
+        maximum_autocomplete_options: &usize,
+        string: &str,
+        autocomplete_options: Vec<String>,
+    ) -> Vec<String> {
+
+        if self.pinned_suggestions.is_empty() {
+            return autocomplete_options
+        } // if
+
+        let Some(last_keyword) = self
+            .string_keywords(string, SplitContext::Searching)
+            .pop() else { return autocomplete_options };
+
+        // Pinned suggestions are placed after the preceding keywords, like the
+        // organic suggestions:
+        let prefix: String = self.normalized_query_prefix(string);
+
+        // A suggestion may be pinned to several prefixes, so suggestions that
+        // have already been seen are skipped. Pins observe the same blocklist
+        // and suggestion filter as organic suggestions:
+        let mut seen: HashSet<&KString> = HashSet::new();
+        let mut pinned: Vec<String> = self.pinned_suggestions
+            .iter()
+            .filter(|(pinned_prefix, _suggestions)| last_keyword.starts_with(pinned_prefix.as_str()))
+            .flat_map(|(_pinned_prefix, suggestions)| suggestions)
+            .filter(|suggestion| suggestion.starts_with(last_keyword.as_str()))
+            .filter(|suggestion| !self.suggestion_blocked(suggestion))
+            .filter(|suggestion| seen.insert(suggestion))
+            .map(|suggestion| prefix.clone() + suggestion)
+            .collect();
+
+        if pinned.is_empty() {
+            return autocomplete_options
+        } // if
+
+        // For debug builds:
+        #[cfg(debug_assertions)]
+        tracing::debug!("{} pinned suggestions for \"{}\".", pinned.len(), string);
+
+        let organic: Vec<String> = autocomplete_options
+            .into_iter()
+            .filter(|autocompletion| !pinned.contains(autocompletion))
+            .collect();
+
+        pinned.extend(organic);
+        pinned.truncate(*maximum_autocomplete_options);
+        pinned
+
+    } // fn
+
+} // impl
\ No newline at end of file
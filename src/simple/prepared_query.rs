@@ -0,0 +1,154 @@
+use crate::simple::internal::string_keywords::SplitContext;
+use crate::simple::internal::Keys;
+use crate::simple::search_index::SearchIndex;
+use kstring::KString;
+use std::collections::BTreeSet;
+use std::{cmp::Ord, hash::Hash};
+
+// -----------------------------------------------------------------------------
+//
+/// An `And` search that has been compiled by [`SearchIndex::compile`], so that
+/// it may be run repeatedly with [`SearchIndex::run`]. This is intended for
+/// saved searches, such as dashboards that poll the same queries.
+///
+/// The search string has already been split into normalized keywords, and the
+/// keywords are ordered so that the keyword with the fewest keys is
+/// intersected first.
+///
+/// [`SearchIndex::compile`]: struct.SearchIndex.html#method.compile
+/// [`SearchIndex::run`]: struct.SearchIndex.html#method.run
+
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct PreparedQuery {
+    /// The normalized & deduplicated keywords, in intersection order.
+    pub(crate) keywords: Vec<KString>,
+} // PreparedQuery
+
+// -----------------------------------------------------------------------------
+
+impl PreparedQuery {
+
+    /// Returns the query's normalized keywords, in the order that they're
+    /// intersected.
+    pub fn keywords(&self) -> impl Iterator<Item = &str> {
+        self.keywords.iter().map(KString::as_str)
+    } // fn
+
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl<K: Hash + Ord> SearchIndex<K> {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Compiles a search string into a `PreparedQuery` for an `And` search.
+    /// The search string is split into keywords according to the search index
+    /// settings, and the keywords are ordered by how many keys are attached to
+    /// them, fewest first.
+    ///
+    /// The `PreparedQuery` doesn't borrow the search index, so the search
+    /// index may be changed between runs. If the settings that affect how
+    /// strings are split into keywords (such as case sensitivity) are changed,
+    /// the query should be compiled again.
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # use indicium::simple::SearchIndex;
+    /// # use pretty_assertions::assert_eq;
+    /// #
+    /// let mut search_index: SearchIndex<usize> = SearchIndex::default();
+    ///
+    /// search_index.insert(&0, &"William the Conqueror");
+    /// search_index.insert(&1, &"William Rufus");
+    /// search_index.insert(&2, &"Henry Beauclerc");
+    ///
+    /// let prepared_query = search_index.compile("William Rufus");
+    /// assert_eq!(prepared_query.keywords().collect::<Vec<&str>>(), vec!["rufus", "william"]);
+    /// assert_eq!(search_index.run(&prepared_query), vec![&1]);
+    ///
+    /// search_index.insert(&3, &"William Rufus the Younger");
+    /// assert_eq!(search_index.run(&prepared_query), vec![&1, &3]);
+    /// ```
+
+    #[tracing::instrument(level = "trace", name = "compile query", skip(self))]
+    pub fn compile(&self, string: &str) -> PreparedQuery {
+
+        // Split search `String` into keywords according to the `SearchIndex`
+        // settings. Repeated keywords don't change an `And` search's results:
+        let mut keywords: Vec<KString> = self.string_keywords(
+            string,
+            SplitContext::Searching,
+        );
+        keywords.sort_unstable();
+        keywords.dedup();
+
+        // Intersect the smallest keyword first. Keywords that aren't in the
+        // search index come first, so that running the query stops early:
+        keywords.sort_by_cached_key(|keyword|
+            self.keyword_keys(keyword).map_or(0, BTreeSet::len)
+        ); // sort_by_cached_key
+
+        PreparedQuery { keywords }
+
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Runs a `PreparedQuery` that was compiled with `compile`, returning the
+    /// keys of the records that contain every keyword, in lexographic order.
+    /// At most `maximum_search_results` keys are returned.
+    ///
+    /// Records may have been inserted or removed since the query was compiled.
+    /// The compiled intersection order is checked against the current number
+    /// of keys per keyword, and is only re-sorted if it's no longer
+    /// smallest-first.
+
+    #[tracing::instrument(level = "trace", name = "run prepared query", skip(self))]
+    pub fn run(&self, prepared_query: &PreparedQuery) -> Vec<&K> {
+
+        // Count the search (see `runtime_stats`):
+        self.runtime_counters.record_search();
+
+        // Look up each keyword's keys. Any keyword that isn't found
+        // short-circuits the search results into an empty set:
+        let mut keyword_keys: Vec<&BTreeSet<K>> =
+            Vec::with_capacity(prepared_query.keywords.len());
+
+        for keyword in &prepared_query.keywords {
+            match self.keyword_keys(keyword) {
+                Some(keys) => keyword_keys.push(keys),
+                None => return Vec::new(),
+            } // match
+        } // for
+
+        // Revalidate the intersection order, in case records were inserted or
+        // removed since the query was compiled:
+        if keyword_keys.windows(2).any(|pair| pair[0].len() > pair[1].len()) {
+            keyword_keys.sort_by_key(|keys| keys.len());
+        } // if
+
+        let Some((first, rest)) = keyword_keys.split_first() else {
+            return Vec::new()
+        }; // else
+
+        // Intersect the keys of every keyword, smallest first:
+        let mut search_results: Keys<K> =
+            Keys::from_set(first, self.maximum_keys_per_keyword);
+
+        for keys in rest {
+            if search_results.is_empty() {
+                break
+            } // if
+            search_results.retain_in(keys);
+        } // for
+
+        search_results
+            .into_iter()
+            .take(self.maximum_search_results)
+            .collect()
+
+    } // fn
+
+} // impl
@@ -0,0 +1,85 @@
+use crate::simple::{AutocompleteType, SearchIndex, SearchType};
+use std::cmp::Ord;
+
+// -----------------------------------------------------------------------------
+//
+/// The query-side settings of a `SearchIndex`. These settings only affect how
+/// searches and autocompletions are performed, and not how records are indexed,
+/// so they can be changed on an existing search index with
+/// `SearchIndex::set_config_queries` without rebuilding it.
+///
+/// Index-side settings (such as the split pattern, case sensitivity, keyword
+/// lengths or excluded keywords) determine which keywords are stored for each
+/// record. Changing them requires re-indexing the records, for example with
+/// `SearchIndex::with_config` and `insert`.
+
+#[derive(Clone, Debug, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct QueryConfig {
+    pub search_type: SearchType,
+    pub autocomplete_type: AutocompleteType,
+    pub fuzzy_minimum_score: f64,
+    pub maximum_autocomplete_options: usize,
+    pub maximum_search_results: usize,
+} // QueryConfig
+
+// -----------------------------------------------------------------------------
+
+impl<K: Ord> SearchIndex<K> {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns the search index's query-side settings. See [`QueryConfig`] for
+    /// more information.
+    ///
+    /// [`QueryConfig`]: struct.QueryConfig.html
+
+    #[tracing::instrument(level = "trace", name = "query config", skip(self))]
+    pub fn query_config(&self) -> QueryConfig {
+        QueryConfig {
+            search_type: self.search_type.clone(),
+            autocomplete_type: self.autocomplete_type.clone(),
+            fuzzy_minimum_score: self.fuzzy_minimum_score,
+            maximum_autocomplete_options: self.maximum_autocomplete_options,
+            maximum_search_results: self.maximum_search_results,
+        } // QueryConfig
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Updates the search index's query-side settings in place. Unlike
+    /// round-tripping through `SearchIndexBuilder`, the keywords & keys are
+    /// left untouched and nothing is re-indexed. Cached search keywords and
+    /// autocompletion options are discarded since they may no longer apply.
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # use indicium::simple::{SearchIndex, SearchType};
+    /// #
+    /// let mut search_index: SearchIndex<usize> = SearchIndex::default();
+    /// search_index.insert(&0, &"Edward the Confessor");
+    /// search_index.insert(&1, &"Edward the Elder");
+    ///
+    /// assert_eq!(search_index.search("confessor elder"), Vec::<&usize>::new());
+    ///
+    /// let mut query_config = search_index.query_config();
+    /// query_config.search_type = SearchType::Or;
+    /// query_config.maximum_search_results = 1;
+    /// search_index.set_config_queries(query_config);
+    ///
+    /// assert_eq!(search_index.search("confessor elder"), vec![&0]);
+    /// ```
+
+    #[tracing::instrument(level = "trace", name = "set config queries", skip(self))]
+    pub fn set_config_queries(&mut self, query_config: QueryConfig) {
+        self.search_type = query_config.search_type;
+        self.autocomplete_type = query_config.autocomplete_type;
+        self.fuzzy_minimum_score = query_config.fuzzy_minimum_score;
+        self.maximum_autocomplete_options = query_config.maximum_autocomplete_options;
+        self.maximum_search_results = query_config.maximum_search_results;
+        self.keyword_cache.invalidate();
+        self.invalidate_autocompletions();
+    } // fn
+
+} // impl
\ No newline at end of file
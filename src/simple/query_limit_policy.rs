@@ -0,0 +1,19 @@
+// -----------------------------------------------------------------------------
+//
+/// What to do when a search string exceeds the `maximum_query_length` or
+/// `maximum_query_keywords` limits. See
+/// [`SearchIndexBuilder::maximum_query_length`].
+///
+/// [`SearchIndexBuilder::maximum_query_length`]: struct.SearchIndexBuilder.html#method.maximum_query_length
+
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum QueryLimitPolicy {
+    /// The search string is silently shortened to the maximum length, and only
+    /// its first keywords (up to the maximum) are used.
+    #[default]
+    Clamp,
+    /// Searches and autocompletions for the search string return nothing.
+    /// `try_search` and `try_autocomplete` return an error.
+    Reject,
+} // QueryLimitPolicy
\ No newline at end of file
@@ -0,0 +1,142 @@
+use crate::simple::internal::string_keywords::SplitContext;
+use crate::simple::{QueryLimitPolicy, SearchIndex};
+use crate::Error;
+use kstring::KString;
+use std::{cmp::Ord, hash::Hash};
+
+// -----------------------------------------------------------------------------
+
+impl<K: Ord> SearchIndex<K> {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Splits a search string into keywords, holding it to the
+    /// `maximum_query_length` and `maximum_query_keywords` limits according to
+    /// the `query_limit_policy`.
+
+    pub(crate) fn limited_query_keywords(&self, string: &str) -> Vec<KString> {
+
+        // Shorten (or reject) search strings that are too long, before any
+        // work is done on them:
+        let string: &str = match self.maximum_query_length {
+            Some(maximum) => match string.char_indices().nth(maximum) {
+                Some((index, _char)) => match self.query_limit_policy {
+                    QueryLimitPolicy::Clamp => &string[..index],
+                    QueryLimitPolicy::Reject => return Vec::new(),
+                }, // Some
+                None => string,
+            }, // Some
+            None => string,
+        }; // match
+
+        let mut keywords: Vec<KString> = self.split_string(string, SplitContext::Searching);
+
+        // Only use the first keywords (or none) if there are too many:
+        if let Some(maximum) = self.maximum_query_keywords {
+            if keywords.len() > maximum {
+                match self.query_limit_policy {
+                    QueryLimitPolicy::Clamp => keywords.truncate(maximum),
+                    QueryLimitPolicy::Reject => keywords.clear(),
+                } // match
+            } // if
+        } // if
+
+        keywords
+
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns an error if the search string exceeds the query limits and the
+    /// `query_limit_policy` is `Reject`.
+
+    pub(crate) fn check_query(&self, string: &str) -> Result<(), Error> {
+        if self.query_limit_policy == QueryLimitPolicy::Clamp { return Ok(()) }
+
+        if let Some(maximum) = self.maximum_query_length {
+            let length: usize = string.chars().count();
+            if length > maximum {
+                return Err(Error::QueryLimitExceeded { limit: "maximum_query_length", value: length, maximum })
+            } // if
+        } // if
+
+        if let Some(maximum) = self.maximum_query_keywords {
+            let keywords: usize = self.split_string(string, SplitContext::Searching).len();
+            if keywords > maximum {
+                return Err(Error::QueryLimitExceeded { limit: "maximum_query_keywords", value: keywords, maximum })
+            } // if
+        } // if
+
+        Ok(())
+    } // fn
+
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl<'a, K: 'a + Hash + Ord> SearchIndex<K> {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Searches like `search`, but returns an `Error::QueryLimitExceeded`
+    /// error if the search string exceeds the `maximum_query_length` or
+    /// `maximum_query_keywords` limits and the `query_limit_policy` is
+    /// `Reject`. This lets public endpoints tell adversarial (or accidental)
+    /// oversized queries apart from queries with no results.
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # use indicium::simple::{QueryLimitPolicy, SearchIndex, SearchIndexBuilder};
+    /// # use pretty_assertions::assert_eq;
+    /// #
+    /// let mut search_index: SearchIndex<usize> = SearchIndexBuilder::default()
+    ///     .maximum_query_keywords(Some(2))
+    ///     .query_limit_policy(QueryLimitPolicy::Reject)
+    ///     .build();
+    /// search_index.insert(&0, &"Harold Godwinson");
+    ///
+    /// assert_eq!(search_index.try_search("harold").unwrap(), vec![&0]);
+    /// assert!(search_index.try_search("harold harold harold").is_err());
+    /// assert_eq!(search_index.search("harold harold harold"), Vec::<&usize>::new());
+    /// ```
+
+    #[tracing::instrument(level = "trace", name = "try search", skip(self))]
+    pub fn try_search(&'a self, string: &'a str) -> Result<Vec<&'a K>, Error> {
+        self.check_query(string)?;
+        Ok(self.search(string))
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Autocompletes like `autocomplete`, but returns an
+    /// `Error::QueryLimitExceeded` error if the search string exceeds the
+    /// `maximum_query_length` or `maximum_query_keywords` limits and the
+    /// `query_limit_policy` is `Reject`.
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # use indicium::simple::{QueryLimitPolicy, SearchIndex, SearchIndexBuilder};
+    /// # use pretty_assertions::assert_eq;
+    /// #
+    /// let mut search_index: SearchIndex<usize> = SearchIndexBuilder::default()
+    ///     .maximum_query_length(Some(8))
+    ///     .query_limit_policy(QueryLimitPolicy::Reject)
+    ///     .build();
+    /// search_index.insert(&0, &"Harold Godwinson");
+    ///
+    /// assert_eq!(
+    ///     search_index.try_autocomplete("har").unwrap(),
+    ///     vec!["harold".to_string(), "harold godwinson".to_string()]
+    /// );
+    /// assert!(search_index.try_autocomplete("harold godw").is_err());
+    /// ```
+
+    #[tracing::instrument(level = "trace", name = "try autocomplete", skip(self))]
+    pub fn try_autocomplete(&self, string: &str) -> Result<Vec<String>, Error> {
+        self.check_query(string)?;
+        Ok(self.autocomplete(string))
+    } // fn
+
+} // impl
\ No newline at end of file
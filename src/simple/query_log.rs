@@ -0,0 +1,274 @@
+use crate::simple::internal::Clock;
+use crate::simple::search_index::SearchIndex;
+use std::collections::BTreeMap;
+use std::time::{Duration, SystemTime};
+use std::{cmp::Ord, cmp::Ordering, hash::Hash};
+
+// -----------------------------------------------------------------------------
+//
+/// Tracks the queries that users submit, so that popular (or trending)
+/// queries can be suggested alongside the completions derived from the search
+/// index.
+///
+/// Each recorded query earns a point of popularity. Popularity decays over
+/// time, halving every `half_life`, so that recent queries outweigh old ones.
+/// When more than `maximum_queries` distinct queries are tracked, the least
+/// popular query is forgotten.
+///
+/// Queries are normalized by trimming whitespace and converting them to lower
+/// case.
+///
+/// Basic usage:
+///
+/// ```rust
+/// # use indicium::simple::QueryLog;
+/// # use pretty_assertions::assert_eq;
+/// #
+/// let mut query_log = QueryLog::default();
+///
+/// query_log.record("William the Conqueror");
+/// query_log.record("William Rufus");
+/// query_log.record("william rufus");
+///
+/// assert_eq!(
+///     query_log.popular_queries(&2),
+///     vec!["william rufus".to_string(), "william the conqueror".to_string()]
+/// );
+///
+/// assert_eq!(
+///     query_log.popular_completions("william t", &5),
+///     vec!["william the conqueror".to_string()]
+/// );
+/// ```
+
+#[derive(Clone, Debug, PartialEq, PartialOrd)]
+pub struct QueryLog {
+    /// The popularity of each query, as of the moment it was last updated.
+    queries: BTreeMap<String, (f64, SystemTime)>,
+    /// The time it takes for a query's popularity to halve.
+    half_life: Duration,
+    /// The maximum number of distinct queries that are tracked.
+    maximum_queries: usize,
+    /// The source of the current time (see `with_clock`.)
+    clock: Clock,
+} // QueryLog
+
+// -----------------------------------------------------------------------------
+
+impl Default for QueryLog {
+    /// Initialize `QueryLog` with a half-life of one day and up to `1_024`
+    /// distinct queries.
+    fn default() -> Self {
+        Self::new(Duration::from_secs(24 * 60 * 60), 1_024)
+    } // fn
+} // impl Default
+
+// -----------------------------------------------------------------------------
+
+impl QueryLog {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Creates an empty query log. Popularity halves every `half_life`, and up
+    /// to `maximum_queries` distinct queries are tracked.
+
+    pub fn new(half_life: Duration, maximum_queries: usize) -> Self {
+        QueryLog {
+            queries: BTreeMap::new(),
+            half_life,
+            maximum_queries,
+            clock: Clock::default(),
+        } // QueryLog
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Replaces the clock used to decay popularity. By default, the system
+    /// clock is used. A fixed or stepped clock makes the decay reproducible,
+    /// for example in tests that run in CI.
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # use indicium::simple::QueryLog;
+    /// # use pretty_assertions::assert_eq;
+    /// # use std::sync::atomic::{AtomicU64, Ordering};
+    /// # use std::sync::Arc;
+    /// # use std::time::{Duration, SystemTime};
+    /// #
+    /// let seconds = Arc::new(AtomicU64::new(0));
+    /// let clock = seconds.clone();
+    ///
+    /// let mut query_log = QueryLog::new(Duration::from_secs(60), 16)
+    ///     .with_clock(move || SystemTime::UNIX_EPOCH + Duration::from_secs(clock.load(Ordering::Relaxed)));
+    ///
+    /// query_log.record("harold");
+    /// query_log.record("harold");
+    ///
+    /// // Two half-lives later, "harold" is worth half a query:
+    /// seconds.store(120, Ordering::Relaxed);
+    /// query_log.record("william");
+    ///
+    /// assert_eq!(query_log.popular_queries(&2), vec!["william".to_string(), "harold".to_string()]);
+    /// ```
+
+    pub fn with_clock(mut self, now: impl Fn() -> SystemTime + Send + Sync + 'static) -> Self {
+        self.clock = Clock::new(now);
+        self
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns a popularity score decayed from the moment it was recorded to
+    /// `now`.
+
+    fn decayed(&self, score: f64, recorded: SystemTime, now: SystemTime) -> f64 {
+        let elapsed = now.duration_since(recorded).unwrap_or_default().as_secs_f64();
+        match self.half_life.as_secs_f64() {
+            half_life if half_life > 0.0 => score * 0.5_f64.powf(elapsed / half_life),
+            _ => score,
+        } // match
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Records that a user submitted a query. Blank queries are ignored.
+
+    pub fn record(&mut self, query: &str) {
+        let query = query.trim().to_lowercase();
+        if query.is_empty() || self.maximum_queries == 0 { return }
+
+        let now = self.clock.now();
+
+        let score = match self.queries.get(&query) {
+            Some((score, recorded)) => self.decayed(*score, *recorded, now) + 1.0,
+            None => 1.0,
+        }; // match
+
+        self.queries.insert(query, (score, now));
+
+        // Forget the least popular query if the log is full:
+        if self.queries.len() > self.maximum_queries {
+            let least_popular = self.queries
+                .iter()
+                .map(|(query, (score, recorded))| (query, self.decayed(*score, *recorded, now)))
+                .min_by(|(_, a), (_, b)| a.total_cmp(b))
+                .map(|(query, _score)| query.clone());
+
+            if let Some(least_popular) = least_popular {
+                self.queries.remove(&least_popular);
+            } // if
+        } // if
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Forgets all recorded queries.
+
+    pub fn clear(&mut self) {
+        self.queries.clear();
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns the given queries, most popular first.
+
+    fn most_popular<'a>(
+        &self,
+        queries: impl Iterator<Item = (&'a String, &'a (f64, SystemTime))>,
+        maximum_queries: &usize,
+    ) -> Vec<String> {
+        let now = self.clock.now();
+
+        let mut queries: Vec<(&String, f64)> = queries
+            .map(|(query, (score, recorded))| (query, self.decayed(*score, *recorded, now)))
+            .collect();
+
+        queries.sort_by(|(a_query, a_score), (b_query, b_score)|
+            match b_score.total_cmp(a_score) {
+                Ordering::Equal => a_query.cmp(b_query),
+                ordering => ordering,
+            } // match
+        ); // sort_by
+
+        queries
+            .into_iter()
+            .take(*maximum_queries)
+            .map(|(query, _score)| query.clone())
+            .collect()
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns the most popular queries, most popular first.
+
+    pub fn popular_queries(&self, maximum_queries: &usize) -> Vec<String> {
+        self.most_popular(self.queries.iter(), maximum_queries)
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns the most popular queries that begin with the given prefix, most
+    /// popular first. The prefix is normalized like recorded queries, except
+    /// that trailing whitespace is kept.
+
+    pub fn popular_completions(&self, prefix: &str, maximum_queries: &usize) -> Vec<String> {
+        let prefix = prefix.trim_start().to_lowercase();
+        self.most_popular(
+            self.queries
+                .range(prefix.clone()..)
+                .take_while(|(query, _)| query.starts_with(&prefix)),
+            maximum_queries,
+        ) // most_popular
+    } // fn
+
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl<K: Hash + Ord> SearchIndex<K> {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns autocompletion options that blend popular queries from a
+    /// [`QueryLog`] with the search index's own autocompletion options. Popular
+    /// queries that begin with the user's string are returned first, followed
+    /// by the search index's options. Duplicates are removed, and the
+    /// `maximum_autocomplete_options` setting is observed.
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # use indicium::simple::{QueryLog, SearchIndex};
+    /// # use pretty_assertions::assert_eq;
+    /// #
+    /// let mut search_index: SearchIndex<usize> = SearchIndex::default();
+    /// search_index.insert(&0, &"Cambodia");
+    /// search_index.insert(&1, &"Cameroon");
+    ///
+    /// let mut query_log = QueryLog::default();
+    /// query_log.record("cameroon");
+    ///
+    /// assert_eq!(
+    ///     search_index.autocomplete_with_query_log(&query_log, "cam"),
+    ///     vec!["cameroon".to_string(), "cambodia".to_string()]
+    /// );
+    /// ```
+    ///
+    /// [`QueryLog`]: struct.QueryLog.html
+
+    pub fn autocomplete_with_query_log(&self, query_log: &QueryLog, string: &str) -> Vec<String> {
+        let mut autocomplete_options: Vec<String> =
+            query_log.popular_completions(string, &self.maximum_autocomplete_options);
+
+        self.autocomplete(string)
+            .into_iter()
+            .for_each(|option| if !autocomplete_options.contains(&option) {
+                autocomplete_options.push(option)
+            }); // for_each
+
+        autocomplete_options.truncate(self.maximum_autocomplete_options);
+        autocomplete_options
+    } // fn
+
+} // impl
\ No newline at end of file
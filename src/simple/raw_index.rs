@@ -0,0 +1,170 @@
+use crate::simple::search_index::SearchIndex;
+use kstring::KString;
+use std::collections::{BTreeMap, BTreeSet};
+use std::{cmp::Ord, hash::Hash};
+
+// -----------------------------------------------------------------------------
+//
+/// A low-level, read-only view of a search index's posting sets (the keys
+/// attached to each keyword), obtained with `SearchIndex::raw`. These are the
+/// primitives that the search types are built from. They can be composed into
+/// custom query logic, such as custom scoring or joins against external data,
+/// without forking the crate.
+///
+/// Unlike the search methods, these primitives don't normalize keywords
+/// (other than `keywords`), don't use fuzzy matching, and don't apply
+/// `maximum_search_results` or `maximum_keys_per_keyword`.
+///
+/// Basic usage:
+///
+/// ```rust
+/// # use indicium::simple::SearchIndex;
+/// # use pretty_assertions::assert_eq;
+/// #
+/// let mut search_index: SearchIndex<usize> = SearchIndex::default();
+/// search_index.insert(&0, &"William the Conqueror");
+/// search_index.insert(&1, &"William Rufus");
+/// search_index.insert(&2, &"Rufus the Red");
+///
+/// let raw = search_index.raw();
+/// let keywords = raw.keywords("William RUFUS");
+/// assert_eq!(keywords, vec!["william".to_string(), "rufus".to_string()]);
+///
+/// let keywords: Vec<&str> = keywords.iter().map(|keyword| keyword.as_str()).collect();
+/// assert_eq!(raw.and(&keywords).into_iter().collect::<Vec<_>>(), vec![&1]);
+/// assert_eq!(raw.or(&keywords).get(&1), Some(&2));
+/// assert_eq!(raw.postings("rufus").map(|keys| keys.len()), Some(2));
+/// ```
+
+#[derive(Debug)]
+pub struct RawIndex<'a, K: Ord> {
+    search_index: &'a SearchIndex<K>,
+} // RawIndex
+
+// -----------------------------------------------------------------------------
+
+impl<K: Ord> Clone for RawIndex<'_, K> {
+    fn clone(&self) -> Self {
+        *self
+    } // fn
+} // impl
+
+impl<K: Ord> Copy for RawIndex<'_, K> {}
+
+// -----------------------------------------------------------------------------
+
+impl<K: Ord> SearchIndex<K> {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns a low-level view of the search index's posting sets. See
+    /// [`RawIndex`] for more information.
+    ///
+    /// [`RawIndex`]: struct.RawIndex.html
+
+    pub fn raw(&self) -> RawIndex<'_, K> {
+        RawIndex { search_index: self }
+    } // fn
+
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl<'a, K: Hash + Ord> RawIndex<'a, K> {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Splits a string into keywords according to the search index settings
+    /// (split pattern, case sensitivity, keyword lengths and excluded
+    /// keywords), in the same way a search string is split.
+
+    pub fn keywords(&self, string: &str) -> Vec<String> {
+        self.search_index
+            .query_keywords(string)
+            .iter()
+            .map(|keyword| keyword.to_string())
+            .collect()
+    } // fn
+
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl<'a, K: Ord> RawIndex<'a, K> {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns the posting set (the keys attached) for a keyword, using an
+    /// exact match.
+
+    pub fn postings(&self, keyword: &str) -> Option<&'a BTreeSet<K>> {
+        self.search_index.keyword_keys(keyword)
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns every keyword that starts with the prefix, along with its
+    /// posting set, in lexographic order.
+
+    pub fn prefix<'p>(
+        &self,
+        prefix: &'p str,
+    ) -> impl Iterator<Item = (&'a str, &'a BTreeSet<K>)> + 'p where 'a: 'p {
+        self.search_index.b_tree_map
+            // Get matching keywords starting with the prefix:
+            .range(KString::from_ref(prefix)..)
+            // Break iteration when we reach a keyword that does not start with
+            // the prefix:
+            .take_while(move |(keyword, _keys)| keyword.starts_with(prefix))
+            .map(|(keyword, keys)| (keyword.as_str(), keys))
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns the keys attached to every one of the keywords (an
+    /// intersection.) Returns an empty set if no keywords are given.
+
+    pub fn and(&self, keywords: &[&str]) -> BTreeSet<&'a K> {
+        // Look up every posting set. If any keyword is missing, the
+        // intersection is empty:
+        let Some(mut postings) = keywords
+            .iter()
+            .map(|keyword| self.postings(keyword))
+            .collect::<Option<Vec<&BTreeSet<K>>>>()
+        else {
+            return BTreeSet::new()
+        }; // let
+
+        // Intersect starting with the smallest posting set, so that as few
+        // keys as possible are examined:
+        postings.sort_unstable_by_key(|keys| keys.len());
+
+        match postings.split_first() {
+            Some((smallest, rest)) => smallest
+                .iter()
+                .filter(|key| rest.iter().all(|keys| keys.contains(key)))
+                .collect(),
+            None => BTreeSet::new(),
+        } // match
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns the keys attached to any of the keywords (a union), along with
+    /// the number of keywords each key is attached to (or hits.)
+
+    pub fn or(&self, keywords: &[&str]) -> BTreeMap<&'a K, usize> {
+        let mut hits: BTreeMap<&'a K, usize> = BTreeMap::new();
+
+        keywords
+            .iter()
+            .filter_map(|keyword| self.postings(keyword))
+            .for_each(|keys| keys
+                .iter()
+                .for_each(|key| *hits.entry(key).or_default() += 1)
+            ); // for_each
+
+        hits
+    } // fn
+
+} // impl
\ No newline at end of file
@@ -0,0 +1,107 @@
+use crate::simple::search_index::SearchIndex;
+use kstring::KString;
+use std::collections::{BTreeMap, BTreeSet};
+use std::{cmp::Ord, cmp::Ordering};
+
+// -----------------------------------------------------------------------------
+
+impl<K: Ord> SearchIndex<K> {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns the keywords that most frequently co-occur with the given
+    /// keyword across records, along with the number of records they share.
+    /// This may be used to power "searches related to..." suggestions under a
+    /// search box. The given keyword is not returned.
+    ///
+    /// The keyword must be an exact match. The most frequently co-occurring
+    /// keywords are returned first. This is most efficient when the reverse map
+    /// is maintained. See [`SearchIndexBuilder::reverse_map`].
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # use indicium::simple::{SearchIndex, SearchIndexBuilder};
+    /// # use pretty_assertions::assert_eq;
+    /// #
+    /// let mut search_index: SearchIndex<usize> = SearchIndexBuilder::default()
+    ///     .reverse_map(true)
+    ///     .build();
+    ///
+    /// search_index.insert(&0, &"William the Conqueror, King of England");
+    /// search_index.insert(&1, &"William Rufus, King of England");
+    /// search_index.insert(&2, &"William Adelin, Duke of Normandy");
+    ///
+    /// assert_eq!(
+    ///     search_index.related_keywords("william", &2),
+    ///     vec![("england".to_string(), 2), ("king".to_string(), 2)]
+    /// );
+    /// ```
+    ///
+    /// [`SearchIndexBuilder::reverse_map`]: struct.SearchIndexBuilder.html#method.reverse_map
+
+    #[tracing::instrument(level = "trace", name = "related keywords", skip(self))]
+    pub fn related_keywords(
+        &self,
+        keyword: &str,
+        maximum_keywords: &usize,
+    ) -> Vec<(String, usize)> {
+
+        // If case sensitivity set, leave case intact. Otherwise, normalize
+        // keyword to lower case:
+        let keyword: KString = match self.case_sensitive {
+            true => KString::from_ref(keyword),
+            false => KString::from(keyword.to_lowercase()),
+        }; // match
+
+        // Get the records attached to the keyword:
+        let Some(keys) = self.keyword_keys(&keyword) else { return Vec::new() };
+
+        // Count the records shared with every other keyword:
+        let mut counts: BTreeMap<&KString, usize> = BTreeMap::new();
+
+        match &self.reverse_map {
+            // Tally the keywords of each of the keyword's records:
+            Some(reverse_map) => keys
+                .iter()
+                .filter_map(|key| reverse_map.get(key))
+                .flatten()
+                .for_each(|other| *counts.entry(other).or_default() += 1),
+            // Intersect every keyword's records with the keyword's records:
+            None => self.b_tree_map
+                .iter()
+                .map(|(other, other_keys)| (
+                    other,
+                    other_keys.iter().filter(|key| keys.contains(key)).count(),
+                )) // map
+                .filter(|(_other, count)| *count > 0)
+                .for_each(|(other, count)| { counts.insert(other, count); }),
+        }; // match
+
+        // Don't return the keyword itself, or the `dump_keyword`:
+        let excluded: BTreeSet<&KString> = std::iter::once(&keyword)
+            .chain(self.dump_keyword.as_ref())
+            .collect();
+
+        // Order the keywords by count (highest first) and then by keyword:
+        let mut related: Vec<(&KString, usize)> = counts
+            .into_iter()
+            .filter(|(other, _count)| !excluded.contains(other))
+            .collect();
+
+        related.sort_by(|(a_keyword, a_count), (b_keyword, b_count)|
+            match b_count.cmp(a_count) {
+                Ordering::Equal => a_keyword.cmp(b_keyword),
+                ordering => ordering,
+            } // match
+        ); // sort_by
+
+        related
+            .into_iter()
+            .take(*maximum_keywords)
+            .map(|(keyword, count)| (keyword.to_string(), count))
+            .collect()
+
+    } // fn
+
+} // impl
\ No newline at end of file
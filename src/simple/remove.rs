@@ -7,8 +7,9 @@ use ahash::HashSet;
 use std::collections::HashSet;
 
 // Static dependencies:
-use crate::simple::{indexable::Indexable, search_index::SearchIndex};
+use crate::simple::{indexable::Indexable, search_index::SearchIndex, DuplicateKeyPolicy, IndexChange};
 use kstring::KString;
+use std::sync::Arc;
 use std::{clone::Clone, cmp::Ord};
 
 // -----------------------------------------------------------------------------
@@ -102,6 +103,13 @@ impl<K: Clone + Ord> SearchIndex<K> {
     #[tracing::instrument(level = "trace", name = "search index remove", skip(self, key, value))]
     pub fn remove(&mut self, key: &K, value: &dyn Indexable) {
 
+        // The indexed data is changing, so precomputed autocompletions are no
+        // longer current:
+        self.invalidate_autocompletions();
+
+        // If the changelog is enabled, record this removal:
+        self.record_op(|| IndexChange::Remove { key: key.clone(), strings: value.strings() });
+
         // Get all keywords for the `Indexable` record:
         let mut keywords: HashSet<KString> = self.indexable_keywords(value);
 
@@ -111,6 +119,46 @@ impl<K: Clone + Ord> SearchIndex<K> {
             keywords.insert(dump_keyword.as_ref().into());
         } // if
 
+        // If the key's value replaces any earlier one, the key is detached
+        // from all of its keywords in the reverse map. This includes keywords
+        // that it was attached to by `alias_keyword` or `rename_keyword`,
+        // which can't be derived from the value:
+        if self.duplicate_key_policy == DuplicateKeyPolicy::Upsert {
+            if let Some(key_keywords) = self.reverse_map.as_ref().and_then(|reverse_map| reverse_map.get(key)) {
+                keywords.extend(key_keywords.iter().cloned());
+            } // if
+        } // if
+
+        // If the reverse map is maintained, forget this key's keywords:
+        if let Some(reverse_map) = &mut self.reverse_map {
+            if let Some(key_keywords) = reverse_map.get_mut(key) {
+                keywords.iter().for_each(|keyword| { key_keywords.remove(keyword); });
+                if key_keywords.is_empty() { reverse_map.remove(key); }
+            } // if
+        } // if
+
+        // If posting timestamps are kept, forget this key's postings:
+        if let Some(posting_times) = &mut self.posting_times {
+            if let Some(keyword_times) = posting_times.get_mut(key) {
+                keywords.iter().for_each(|keyword| { keyword_times.remove(keyword); });
+                if keyword_times.is_empty() { posting_times.remove(key); }
+            } // if
+        } // if
+
+        // Forget any compacted whole-string keywords of this key:
+        self.detach_whole_strings(key, keywords.iter());
+
+        // If the search index keeps copies of its keywords, remember which
+        // keywords will have to be copied into them:
+        let touched: Vec<KString> = match self.has_mirrors() {
+            true => keywords.iter().cloned().collect(),
+            false => Vec::new(),
+        }; // match
+
+        // If a snapshot shares the keywords, they're copied before being
+        // changed:
+        let b_tree_map = Arc::make_mut(&mut self.b_tree_map);
+
         // Iterate over the keywords:
         keywords
             .into_iter()
@@ -119,7 +167,7 @@ impl<K: Clone + Ord> SearchIndex<K> {
             .for_each(|keyword| {
                 // Attempt to get mutuable reference to the _keyword entry_ in
                 // the search index:
-                let is_empty = if let Some(keys) = self.b_tree_map.get_mut(&keyword) {
+                let is_empty = if let Some(keys) = b_tree_map.get_mut(&keyword) {
                     // If keyword found in search index, remove the _key
                     // reference_ for this record from _keyword entry_:
                     keys.remove(key);
@@ -134,9 +182,73 @@ impl<K: Clone + Ord> SearchIndex<K> {
                 // If the _keyword entry_ no longer contains any _key
                 // references_, it is empty and we should remove the keyword
                 // from the search index:
-                if is_empty { self.b_tree_map.remove(&keyword); }
-            }) // for_each
+                if is_empty {
+                    b_tree_map.remove(&keyword);
+                    self.keyword_usage.forget(&keyword);
+                    self.whole_string_keywords.remove(&keyword);
+                } // if
+            }); // for_each
+
+        self.sync_mirrors_for_key(&touched, key);
+
+        // Forget which fields the key's keywords came from (see
+        // `update_field`):
+        self.field_keywords.remove(key);
+
+        // Forget the record's location (see `insert_with_location`):
+        #[cfg(feature = "geo")]
+        self.geo_index.remove(key);
+
+        // Record the size of the search index for metrics:
+        #[cfg(feature = "metrics")]
+        crate::simple::internal::metrics::record_index_size(self.b_tree_map.len());
+
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Detaches a key from the given keywords, removing any keywords that are
+    /// left without keys. Used by `insert` to drop the stale keywords of a key
+    /// that's inserted again.
+
+    pub(crate) fn detach_keywords(&mut self, key: &K, keywords: &[KString]) {
+        if keywords.is_empty() { return }
+
+        // Forget any compacted whole-string keywords of this key:
+        self.detach_whole_strings(key, keywords.iter());
+
+        // Forget the key's keywords in the reverse map:
+        if let Some(reverse_map) = &mut self.reverse_map {
+            if let Some(key_keywords) = reverse_map.get_mut(key) {
+                keywords.iter().for_each(|keyword| { key_keywords.remove(keyword); });
+                if key_keywords.is_empty() { reverse_map.remove(key); }
+            } // if
+        } // if
+
+        // Forget the key's posting timestamps:
+        if let Some(posting_times) = &mut self.posting_times {
+            if let Some(keyword_times) = posting_times.get_mut(key) {
+                keywords.iter().for_each(|keyword| { keyword_times.remove(keyword); });
+                if keyword_times.is_empty() { posting_times.remove(key); }
+            } // if
+        } // if
+
+        // If a snapshot shares the keywords, they're copied before being
+        // changed:
+        let b_tree_map = Arc::make_mut(&mut self.b_tree_map);
+
+        keywords.iter().for_each(|keyword| {
+            if let Some(keys) = b_tree_map.get_mut(keyword) {
+                keys.remove(key);
+                if keys.is_empty() {
+                    b_tree_map.remove(keyword);
+                    self.keyword_usage.forget(keyword);
+                    self.whole_string_keywords.remove(keyword);
+                } // if
+            } // if
+        }); // for_each
 
+        self.sync_mirrors_for_key(keywords, key);
     } // fn
 
 } // impl
\ No newline at end of file
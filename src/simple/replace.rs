@@ -1,4 +1,5 @@
-use crate::simple::{indexable::Indexable, search_index::SearchIndex};
+use crate::simple::{indexable::Indexable, search_index::SearchIndex, InsertReport, KeywordCapPolicy};
+use crate::Error;
 use std::clone::Clone;
 use std::cmp::Ord;
 
@@ -103,10 +104,70 @@ impl<K: Clone + Ord> SearchIndex<K> {
         before: &dyn Indexable,
         after: &dyn Indexable,
     ) {
+        // Updated records rejected by the `maximum_keywords_per_record` cap
+        // are skipped, and the old record is kept. Use `try_replace` to
+        // observe this:
+        if let Err(_error) = self.try_replace(key, before, after) {
+            // For debug builds:
+            #[cfg(debug_assertions)]
+            tracing::warn!("Record was not replaced: {}", _error);
+        } // if
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Replaces the value for a key-value pair in the search index, like
+    /// `replace`, and reports how the updated record was indexed, like
+    /// `try_insert`.
+    ///
+    /// If the updated record is rejected by the `maximum_keywords_per_record`
+    /// cap, an `Error::KeywordCapExceeded` error is returned and the old record
+    /// is left in the search index.
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # use indicium::simple::{KeywordCapPolicy, SearchIndex, SearchIndexBuilder};
+    /// # use pretty_assertions::assert_eq;
+    /// #
+    /// let mut search_index: SearchIndex<usize> = SearchIndexBuilder::default()
+    ///     .maximum_keywords_per_record(Some(2))
+    ///     .keyword_cap_policy(KeywordCapPolicy::Reject)
+    ///     .build();
+    ///
+    /// search_index.insert(&0, &"Harold");
+    /// assert!(search_index.try_replace(&0, &"Harold", &"Harold Godwinson of Wessex").is_err());
+    /// assert_eq!(search_index.search("harold"), vec![&0]);
+    /// ```
+
+    #[tracing::instrument(level = "trace", name = "search index try replace", skip(self, key, before, after))]
+    pub fn try_replace(
+        &mut self,
+        key: &K,
+        before: &dyn Indexable,
+        after: &dyn Indexable,
+    ) -> Result<InsertReport, Error> {
+        // Check the keyword cap before the old record is removed, so that a
+        // rejected update doesn't leave the key without any record:
+        if let (Some(maximum), KeywordCapPolicy::Reject) = (self.maximum_keywords_per_record, self.keyword_cap_policy) {
+            let keywords = self.indexable_keywords(after).len();
+            if keywords > maximum {
+                return Err(Error::KeywordCapExceeded { keywords, maximum })
+            } // if
+        } // if
+
+        // Keep the record's location (see `insert_with_location`):
+        #[cfg(feature = "geo")]
+        let location: Option<(f64, f64)> = self.geo_index.get(key).copied();
         // Remove all references to the old record and its keywords:
         self.remove(key, before);
         // Index the updated record:
-        self.insert(key, after);
+        let report = self.try_insert(key, after);
+        #[cfg(feature = "geo")]
+        if let Some((latitude, longitude)) = location {
+            self.geo_index.insert(key, latitude, longitude);
+        } // if
+        report
     } // fn
 
 } // impl
\ No newline at end of file
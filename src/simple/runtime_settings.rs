@@ -0,0 +1,181 @@
+use crate::simple::search_index::SearchIndex;
+use crate::Error;
+use std::cmp::Ord;
+
+// -----------------------------------------------------------------------------
+
+impl<K: Ord> SearchIndex<K> {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Sets the maximum number of search results in place, for example from an
+    /// administration console, without round-tripping through
+    /// `SearchIndexBuilder`. See `SearchIndexBuilder::max_search_results`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error::InvalidSetting` error if the maximum is zero, since
+    /// no search results could be returned. The setting is left unchanged.
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # use indicium::simple::{SearchIndex, SearchType};
+    /// # use pretty_assertions::assert_eq;
+    /// #
+    /// let mut search_index: SearchIndex<usize> = SearchIndex::default();
+    /// search_index.insert(&0, &"Edward the Confessor");
+    /// search_index.insert(&1, &"Edward the Elder");
+    ///
+    /// search_index.set_maximum_search_results(1).unwrap();
+    /// assert_eq!(search_index.search_type(&SearchType::And, "edward"), vec![&0]);
+    ///
+    /// assert!(search_index.set_maximum_search_results(0).is_err());
+    /// ```
+
+    pub fn set_maximum_search_results(&mut self, maximum_search_results: usize) -> Result<(), Error> {
+        if maximum_search_results == 0 {
+            return Err(Error::InvalidSetting {
+                setting: "max_search_results",
+                reason: "no search results could be returned".to_string(),
+            }) // Err
+        } // if
+
+        self.maximum_search_results = maximum_search_results;
+        self.keyword_cache.invalidate();
+        Ok(())
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Sets the maximum number of autocompletion options in place. Cached
+    /// autocompletion options are discarded. See
+    /// `SearchIndexBuilder::max_autocomplete_options`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error::InvalidSetting` error if the maximum is zero, since
+    /// no autocompletion options could be returned. The setting is left
+    /// unchanged.
+
+    pub fn set_maximum_autocomplete_options(&mut self, maximum_autocomplete_options: usize) -> Result<(), Error> {
+        if maximum_autocomplete_options == 0 {
+            return Err(Error::InvalidSetting {
+                setting: "max_autocomplete_options",
+                reason: "no autocompletion options could be returned".to_string(),
+            }) // Err
+        } // if
+
+        self.maximum_autocomplete_options = maximum_autocomplete_options;
+        self.keyword_cache.invalidate();
+        self.invalidate_autocompletions();
+        Ok(())
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Sets the fuzzy matching minimum score in place. Scores range from `0.0`
+    /// to `1.0`, so values outside of that range are saturated to it. Cached
+    /// autocompletion options are discarded. See
+    /// `SearchIndexBuilder::fuzzy_minimum_score`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error::InvalidSetting` error if the score is `NaN`. The
+    /// setting is left unchanged.
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # use indicium::simple::SearchIndex;
+    /// # use pretty_assertions::assert_eq;
+    /// #
+    /// let mut search_index: SearchIndex<usize> = SearchIndex::default();
+    ///
+    /// search_index.set_fuzzy_minimum_score(1.5).unwrap();
+    /// assert_eq!(search_index.query_config().fuzzy_minimum_score, 1.0);
+    ///
+    /// assert!(search_index.set_fuzzy_minimum_score(f64::NAN).is_err());
+    /// ```
+
+    pub fn set_fuzzy_minimum_score(&mut self, fuzzy_minimum_score: f64) -> Result<(), Error> {
+        if fuzzy_minimum_score.is_nan() {
+            return Err(Error::InvalidSetting {
+                setting: "fuzzy_minimum_score",
+                reason: "NaN is not a number".to_string(),
+            }) // Err
+        } // if
+
+        self.fuzzy_minimum_score = fuzzy_minimum_score.clamp(0.0, 1.0);
+        self.keyword_cache.invalidate();
+        self.invalidate_autocompletions();
+        Ok(())
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Sets the maximum number of keys per keyword in place. Raising the limit
+    /// only affects records inserted from now on, and keywords that were
+    /// saturated are no longer reported by `saturated_keywords`. See
+    /// `SearchIndexBuilder::max_keys_per_keyword`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error::InvalidSetting` error if the maximum is zero, or if a
+    /// keyword already has more keys than the new maximum, since lowering the
+    /// limit below that requires re-indexing the records. The setting is left
+    /// unchanged.
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # use indicium::simple::SearchIndex;
+    /// #
+    /// let mut search_index: SearchIndex<usize> = SearchIndex::default();
+    /// search_index.insert(&0, &"Edward the Confessor");
+    /// search_index.insert(&1, &"Edward the Elder");
+    ///
+    /// assert!(search_index.set_maximum_keys_per_keyword(1).is_err());
+    /// assert!(search_index.set_maximum_keys_per_keyword(2).is_ok());
+    /// ```
+
+    pub fn set_maximum_keys_per_keyword(&mut self, maximum_keys_per_keyword: usize) -> Result<(), Error> {
+        if maximum_keys_per_keyword == 0 {
+            return Err(Error::InvalidSetting {
+                setting: "max_keys_per_keyword",
+                reason: "no keys could be attached to any keyword".to_string(),
+            }) // Err
+        } // if
+
+        let largest = self.b_tree_map
+            .iter()
+            .filter(|(keyword, _keys)| self.dump_keyword.as_ref() != Some(keyword))
+            .max_by_key(|(_keyword, keys)| keys.len());
+
+        if let Some((keyword, keys)) = largest {
+            if keys.len() > maximum_keys_per_keyword {
+                return Err(Error::InvalidSetting {
+                    setting: "max_keys_per_keyword",
+                    reason: format!(
+                        "the keyword \"{}\" already has {} keys, so the records must be re-indexed",
+                        keyword,
+                        keys.len(),
+                    ), // format!
+                }) // Err
+            } // if
+        } // if
+
+        // Keywords with fewer keys than the new maximum are no longer
+        // saturated:
+        let b_tree_map = &self.b_tree_map;
+        self.saturated_keywords.retain(|keyword| b_tree_map
+            .get(keyword)
+            .is_some_and(|keys| keys.len() >= maximum_keys_per_keyword)
+        ); // retain
+
+        self.maximum_keys_per_keyword = maximum_keys_per_keyword;
+        self.keyword_cache.invalidate();
+        Ok(())
+    } // fn
+
+} // impl
\ No newline at end of file
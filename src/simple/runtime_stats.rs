@@ -0,0 +1,65 @@
+use crate::simple::{search_index::SearchIndex, FuzzyBackendChoice};
+use std::cmp::Ord;
+use std::time::SystemTime;
+
+// -----------------------------------------------------------------------------
+//
+/// A snapshot of the search index's activity counters. See
+/// [`SearchIndex::runtime_stats`].
+///
+/// [`SearchIndex::runtime_stats`]: struct.SearchIndex.html#method.runtime_stats
+
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct RuntimeStats {
+    /// The number of searches run against the search index.
+    pub searches: u64,
+    /// The number of autocompletions run against the search index.
+    pub autocompletes: u64,
+    /// The number of times a keyword had no exact matches and fuzzy matching
+    /// was used as a fall-back.
+    pub fuzzy_fallbacks: u64,
+    /// When the search index was last built, either by its builder or by
+    /// `build_from_iter`.
+    pub last_rebuild: SystemTime,
+    /// The crate that's used for fuzzy matching. If the `fuzzy_backend`
+    /// setting is `Auto`, this is the crate that was chosen for the keywords.
+    pub fuzzy_backend: FuzzyBackendChoice,
+} // RuntimeStats
+
+// -----------------------------------------------------------------------------
+
+impl<K: Ord> SearchIndex<K> {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns the search index's activity counters: the number of searches,
+    /// autocompletions and fuzzy fall-backs, and when the search index was
+    /// last built. The counters are cheap atomics that are always kept, so an
+    /// administration endpoint can report them without the `metrics` feature
+    /// or any other crates.
+    ///
+    /// A clone of the search index starts with a copy of the counters.
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # use indicium::simple::SearchIndex;
+    /// # use pretty_assertions::assert_eq;
+    /// #
+    /// let mut search_index: SearchIndex<usize> = SearchIndex::default();
+    /// search_index.insert(&0, &"William the Conqueror");
+    ///
+    /// search_index.search("william");
+    /// search_index.search("conqueror");
+    /// search_index.autocomplete("will");
+    ///
+    /// let stats = search_index.runtime_stats();
+    /// assert_eq!(stats.searches, 2);
+    /// assert_eq!(stats.autocompletes, 1);
+    /// ```
+
+    pub fn runtime_stats(&self) -> RuntimeStats {
+        self.runtime_counters.stats(self.active_fuzzy_backend)
+    } // fn
+
+} // impl
\ No newline at end of file
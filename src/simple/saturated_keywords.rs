@@ -0,0 +1,77 @@
+use crate::simple::{internal::SaturationHook, search_index::SearchIndex};
+use std::cmp::Ord;
+
+// -----------------------------------------------------------------------------
+
+impl<K: Ord> SearchIndex<K> {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns the keywords that have reached the `maximum_keys_per_keyword`
+    /// limit, in lexographic order. Once a keyword is saturated, further
+    /// records are silently not attached to it, which impacts the accuracy of
+    /// results. Saturated keywords are often good candidates for the
+    /// `exclude_keywords` list.
+    ///
+    /// A keyword is reported from the first time a key couldn't be attached to
+    /// it, until the search index is cleared.
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # use indicium::simple::{SearchIndex, SearchIndexBuilder};
+    /// # use pretty_assertions::assert_eq;
+    /// #
+    /// let mut search_index: SearchIndex<usize> = SearchIndexBuilder::default()
+    ///     .max_keys_per_keyword(2)
+    ///     .build();
+    ///
+    /// search_index.insert(&0, &"King Harold");
+    /// search_index.insert(&1, &"King William");
+    /// assert_eq!(search_index.saturated_keywords(), Vec::<&str>::new());
+    ///
+    /// search_index.insert(&2, &"King Henry");
+    /// assert_eq!(search_index.saturated_keywords(), vec!["king"]);
+    /// ```
+
+    pub fn saturated_keywords(&self) -> Vec<&str> {
+        self.saturated_keywords
+            .iter()
+            .map(|keyword| keyword.as_str())
+            .collect()
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Sets a callback that's invoked with the keyword when a keyword first
+    /// reaches the `maximum_keys_per_keyword` limit (see
+    /// `saturated_keywords`.) This can be used to log or alert on saturated
+    /// keywords. Setting a callback replaces any previous callback.
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # use indicium::simple::{SearchIndex, SearchIndexBuilder};
+    /// # use pretty_assertions::assert_eq;
+    /// # use std::sync::{Arc, Mutex};
+    /// #
+    /// let mut search_index: SearchIndex<usize> = SearchIndexBuilder::default()
+    ///     .max_keys_per_keyword(1)
+    ///     .build();
+    ///
+    /// let saturated = Arc::new(Mutex::new(Vec::new()));
+    /// let log = saturated.clone();
+    /// search_index.on_saturation(move |keyword| log.lock().unwrap().push(keyword.to_string()));
+    ///
+    /// search_index.insert(&0, &"King Harold");
+    /// search_index.insert(&1, &"King William");
+    /// search_index.insert(&2, &"King Henry");
+    ///
+    /// assert_eq!(*saturated.lock().unwrap(), vec!["king".to_string()]);
+    /// ```
+
+    pub fn on_saturation(&mut self, hook: impl Fn(&str) + Send + Sync + 'static) {
+        self.saturation_hook = SaturationHook::new(hook);
+    } // fn
+
+} // impl
\ No newline at end of file
@@ -1,7 +1,7 @@
-use crate::simple::internal::string_keywords::SplitContext;
+use crate::simple::internal::Keys;
 use crate::simple::search_index::SearchIndex;
 use kstring::KString;
-use std::{cmp::Ord, collections::BTreeSet, hash::Hash};
+use std::{cmp::Ord, hash::Hash, sync::Arc};
 
 // -----------------------------------------------------------------------------
 
@@ -99,91 +99,44 @@ impl<K: Hash + Ord> SearchIndex<K> {
         maximum_search_results: &usize,
         string: &str,
     ) -> Vec<&K> {
+        self.search_and_filtered(maximum_search_results, string, &|_key| true)
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// This is the `And` search with a caller-provided key filter. Keys that
+    /// don't pass the filter are discarded before `maximum_search_results` is
+    /// applied. This means that filtered-out keys do not use up room in the
+    /// search results.
+
+    #[tracing::instrument(level = "trace", name = "filtered and search", skip(self, filter))]
+    pub(crate) fn search_and_filtered(
+        &self,
+        maximum_search_results: &usize,
+        string: &str,
+        filter: &dyn Fn(&K) -> bool,
+    ) -> Vec<&K> {
 
         // Split search `String` into keywords (according to the `SearchIndex`
-        // settings). `string_keywords` will **not** allow "use entire string as
+        // settings). `query_keywords` will **not** allow "use entire string as
         // a keyword," even if enabled in user settings:
-        let keywords: Vec<KString> = self.string_keywords(
-            string,
-            SplitContext::Searching,
-        );
+        let keywords: Arc<[KString]> = self.query_keywords(string);
 
         // For debug builds:
         #[cfg(debug_assertions)]
         tracing::debug!("searching: {:?}", keywords);
 
-        // This `BTreeSet` is used to contain the search results:
-        let mut search_results: Option<BTreeSet<&K>> = None;
-
-        // Get each keyword from our `BTreeMap`, and intersect the resulting
-        // keys with our current keys:
-        keywords
-            // Iterate over the keywords supplied in the search string:
-            .into_iter()
-            // For each keyword in the search string:
-            .for_each(|keyword| {
-
-                // Attempt to retrieve keyword from search index. If keyword
-                // found, intersect keyword records with search results records.
-                // If keyword not found, empty search results:
-                match self.b_tree_map.get(&keyword) {
-
-                    // Keyword found. Update `search_results` with product of an
-                    // intersection with this keyword's records:
-                    Some(keyword_results) => search_results = Some(
-
-                        // Check if `search_results` is already populated:
-                        match &search_results {
-
-                            // If `search_results` is is not empty, intersect
-                            // the current keyword's results with the master
-                            // search results:
-                            Some(search_results) => search_results
-                                // Iterate over each search result record:
-                                .iter()
-                                // Intersect the search result record with the
-                                // keyword results. If the search result record
-                                // doesn't exist in this keyword's results,
-                                // filter it out:
-                                .filter(|key|
-                                    keyword_results.contains(key)
-                                )
-                                // Clone each key from the `Intersection`
-                                // iterator or we'll get a doubly-referenced
-                                // `&&K` key:
-                                .cloned()
-                                // And collect each key into a `BTreeSet` that
-                                // will become the new `search_results`:
-                                .collect(),
-
-                            // If `search_results` is currently empty,
-                            // initialize it with the first keyword's full
-                            // search results:
-                            None => self.internal_keyword_search(&keyword),
-
-                        } // match
-
-                    ), // Some
-
-                    // Any keyword that returns no results will short-circuit
-                    // the search results into an empty set:
-                    None => search_results = Some(BTreeSet::new()),
-
-                } // match
-
-            }); // for_each
+        // Intersect the keys of every keyword in the search string. Any
+        // keyword that isn't found short-circuits the search results into an
+        // empty set:
+        let search_results: Keys<K> = self.internal_search_and(&keywords);
 
         // Return search results:
-        match search_results {
-            // If `search_results` is is not empty, convert the `BTreeMap` to a
-            // `Vec` for caller while observing `maximum_search_results`:
-            Some(search_results) => search_results
-                .into_iter()
-                .take(*maximum_search_results)
-                .collect(),
-            // If `search_results` is empty, return an empty `Vec`:
-            None => Vec::new(),
-        } // match
+        search_results
+            .into_iter()
+            .filter(|key| filter(key))
+            .take(*maximum_search_results)
+            .collect()
 
     } // fn
 
@@ -0,0 +1,57 @@
+use crate::simple::{CancelToken, Cancelled, SearchIndex};
+use std::{cmp::Ord, hash::Hash};
+
+// -----------------------------------------------------------------------------
+
+impl<'a, K: 'a + Hash + Ord> SearchIndex<K> {
+
+    // -------------------------------------------------------------------------
+    //
+    /// This is `search` with a [`CancelToken`]. The token is checked as the
+    /// index is traversed. Once it has been cancelled, the remaining keys are
+    /// skipped and `Err(Cancelled)` is returned.
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # use indicium::simple::{CancelToken, Cancelled, SearchIndex};
+    /// # use pretty_assertions::assert_eq;
+    /// #
+    /// let mut search_index: SearchIndex<usize> = SearchIndex::default();
+    /// search_index.insert(&0, &"William the Conqueror");
+    ///
+    /// let token = CancelToken::new();
+    /// assert_eq!(search_index.search_cancellable("william", &token), Ok(vec![&0]));
+    ///
+    /// token.cancel();
+    /// assert_eq!(search_index.search_cancellable("william", &token), Err(Cancelled));
+    /// ```
+    ///
+    /// [`CancelToken`]: struct.CancelToken.html
+
+    #[tracing::instrument(level = "trace", name = "cancellable search", skip(self, cancel_token))]
+    pub fn search_cancellable(
+        &'a self,
+        string: &'a str,
+        cancel_token: &CancelToken,
+    ) -> Result<Vec<&'a K>, Cancelled> {
+
+        if cancel_token.is_cancelled() { return Err(Cancelled) }
+
+        let search_results: Vec<&'a K> = self.search_with_filtered(
+            &self.search_type,
+            &self.maximum_search_results,
+            string,
+            &|_key| !cancel_token.is_cancelled(),
+        ); // search_with_filtered
+
+        // The search results are incomplete if the token was cancelled during
+        // the search:
+        match cancel_token.is_cancelled() {
+            true => Err(Cancelled),
+            false => Ok(search_results),
+        } // match
+
+    } // fn
+
+} // impl
\ No newline at end of file
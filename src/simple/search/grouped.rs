@@ -0,0 +1,223 @@
+use crate::simple::search_index::SearchIndex;
+use std::cell::RefCell;
+use std::collections::{BTreeMap, BTreeSet};
+use std::{clone::Clone, cmp::Ord, hash::Hash};
+
+// -----------------------------------------------------------------------------
+
+impl<'a, K: 'a + Clone + Hash + Ord> SearchIndex<K> {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Searches the index and returns at most one result per group. The
+    /// `group` closure returns a grouping key (or group id) for each resulting
+    /// key. This may be used to collapse the variants of a record, such as the
+    /// sizes & colours of the same product, into a single search result.
+    ///
+    /// Grouping is performed while the index is traversed, so the
+    /// `maximum_search_results` setting counts groups rather than keys. The
+    /// search string is matched according to the search index's `SearchType`.
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # use indicium::simple::SearchIndex;
+    /// # use pretty_assertions::assert_eq;
+    /// #
+    /// let products = vec![
+    ///     ("Oxford Shirt", "Small"),
+    ///     ("Oxford Shirt", "Large"),
+    ///     ("Flannel Shirt", "Small"),
+    /// ];
+    ///
+    /// let mut search_index: SearchIndex<usize> = SearchIndex::default();
+    ///
+    /// products
+    ///     .iter()
+    ///     .enumerate()
+    ///     .for_each(|(index, (name, size))|
+    ///         search_index.insert(&index, &format!("{name} {size}"))
+    ///     );
+    ///
+    /// let search_results = search_index.search_grouped_by("shirt", |key| products[*key].0);
+    ///
+    /// assert_eq!(search_results, vec![&0, &2]);
+    /// ```
+
+    pub fn search_grouped_by<G: Ord>(
+        &'a self,
+        string: &'a str,
+        group: impl Fn(&K) -> G,
+    ) -> Vec<&'a K> {
+        self.search_grouped_by_with(string, &1, group)
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Searches the index and returns at most `maximum_per_group` results per
+    /// group. See [`search_grouped_by`] for more information.
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # use indicium::simple::SearchIndex;
+    /// # use pretty_assertions::assert_eq;
+    /// #
+    /// let products = vec![
+    ///     ("Oxford Shirt", "Small"),
+    ///     ("Oxford Shirt", "Medium"),
+    ///     ("Oxford Shirt", "Large"),
+    ///     ("Flannel Shirt", "Small"),
+    /// ];
+    ///
+    /// let mut search_index: SearchIndex<usize> = SearchIndex::default();
+    ///
+    /// products
+    ///     .iter()
+    ///     .enumerate()
+    ///     .for_each(|(index, (name, size))|
+    ///         search_index.insert(&index, &format!("{name} {size}"))
+    ///     );
+    ///
+    /// let search_results = search_index.search_grouped_by_with("shirt", &2, |key| products[*key].0);
+    ///
+    /// assert_eq!(search_results, vec![&0, &1, &3]);
+    /// ```
+    ///
+    /// [`search_grouped_by`]: struct.SearchIndex.html#method.search_grouped_by
+
+    #[tracing::instrument(level = "trace", name = "grouped search", skip(self, group))]
+    pub fn search_grouped_by_with<G: Ord>(
+        &'a self,
+        string: &'a str,
+        maximum_per_group: &usize,
+        group: impl Fn(&K) -> G,
+    ) -> Vec<&'a K> {
+
+        // The keys that have been admitted into the search results so far, by
+        // group. A key may be offered to the filter more than once (once per
+        // matching keyword), so admitted keys are remembered:
+        let admitted: RefCell<BTreeMap<G, BTreeSet<K>>> = RefCell::new(BTreeMap::new());
+
+        // Only admit a key if its group still has room:
+        let filter = |key: &K| {
+            let mut admitted = admitted.borrow_mut();
+            let keys = admitted.entry(group(key)).or_default();
+            if keys.contains(key) {
+                true
+            } else if keys.len() < *maximum_per_group {
+                keys.insert(key.clone());
+                true
+            } else {
+                false
+            } // if
+        }; // filter
+
+        let search_results: Vec<&'a K> = self.search_with_filtered(
+            &self.search_type,
+            &self.maximum_search_results.saturating_mul(*maximum_per_group),
+            string,
+            &filter,
+        ); // search_with_filtered
+
+        // Observe `maximum_search_results` as a number of groups:
+        let mut groups: BTreeSet<G> = BTreeSet::new();
+
+        search_results
+            .into_iter()
+            .filter(|key| {
+                let group = group(key);
+                groups.contains(&group) || (
+                    groups.len() < self.maximum_search_results && groups.insert(group)
+                )
+            }) // filter
+            .collect()
+
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Searches the index and returns the number of matching keys in each
+    /// group, along with a representative key for the group. The `group`
+    /// closure returns a grouping key (or group id) for each resulting key.
+    /// This may be used to show a summary like "42 results in Books, 17 in
+    /// Music" without a full faceting subsystem.
+    ///
+    /// Each tuple contains the group, the number of matching keys in the
+    /// group, and the group's representative key (its first search result.)
+    /// Groups are returned in search result order, and the
+    /// `maximum_search_results` setting counts groups rather than keys. Every
+    /// matching key is counted, even if there are more matching keys than
+    /// `maximum_search_results`.
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # use indicium::simple::SearchIndex;
+    /// # use pretty_assertions::assert_eq;
+    /// #
+    /// let albums = vec![
+    ///     ("Music", "The Best of Bach"),
+    ///     ("Books", "Bach: Music in the Castle of Heaven"),
+    ///     ("Music", "Bach: Cello Suites"),
+    /// ];
+    ///
+    /// let mut search_index: SearchIndex<usize> = SearchIndex::default();
+    ///
+    /// albums
+    ///     .iter()
+    ///     .enumerate()
+    ///     .for_each(|(index, (_category, title))| search_index.insert(&index, title));
+    ///
+    /// let group_counts = search_index.search_group_counts("bach", |key| albums[*key].0);
+    ///
+    /// assert_eq!(group_counts, vec![("Music", 2, &0), ("Books", 1, &1)]);
+    /// ```
+
+    #[tracing::instrument(level = "trace", name = "group counts search", skip(self, group))]
+    pub fn search_group_counts<G: Ord>(
+        &'a self,
+        string: &'a str,
+        group: impl Fn(&K) -> G,
+    ) -> Vec<(G, usize, &'a K)> {
+
+        // Every matching key is counted, so the search itself isn't limited
+        // to `maximum_search_results`. Only the number of groups is:
+        let search_results: Vec<&'a K> = self.search_with(
+            &self.search_type,
+            &usize::MAX,
+            string,
+        ); // search_with
+
+        // Count the keys of each group. A group's first search result is its
+        // representative, and its position orders the groups:
+        let mut groups: BTreeMap<G, (usize, usize, &'a K)> = BTreeMap::new();
+
+        search_results
+            .into_iter()
+            .enumerate()
+            .for_each(|(position, key)| {
+                groups
+                    .entry(group(key))
+                    .and_modify(|(_position, count, _representative)| *count += 1)
+                    .or_insert((position, 1, key));
+            }); // for_each
+
+        // Return the groups in search result order, observing
+        // `maximum_search_results` as a number of groups:
+        let mut groups: Vec<(usize, G, usize, &'a K)> = groups
+            .into_iter()
+            .map(|(group, (position, count, representative))| (position, group, count, representative))
+            .collect();
+
+        groups.sort_unstable_by_key(|(position, ..)| *position);
+
+        groups
+            .into_iter()
+            .take(self.maximum_search_results)
+            .map(|(_position, group, count, representative)| (group, count, representative))
+            .collect()
+
+    } // fn
+
+} // impl
\ No newline at end of file
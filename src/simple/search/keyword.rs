@@ -1,5 +1,4 @@
 use crate::simple::search_index::SearchIndex;
-use kstring::KString;
 use std::{cmp::Ord, hash::Hash};
 
 // -----------------------------------------------------------------------------
@@ -104,6 +103,23 @@ impl<K: Hash + Ord> SearchIndex<K> {
         maximum_search_results: &usize,
         keyword: &str,
     ) -> Vec<&K> {
+        self.search_keyword_filtered(maximum_search_results, keyword, &|_key| true)
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// This is the `Keyword` search with a caller-provided key filter. Keys that
+    /// don't pass the filter are discarded before `maximum_search_results` is
+    /// applied. This means that filtered-out keys do not use up room in the
+    /// search results.
+
+    #[tracing::instrument(level = "trace", name = "filtered keyword search", skip(self, filter))]
+    pub(crate) fn search_keyword_filtered(
+        &self,
+        maximum_search_results: &usize,
+        keyword: &str,
+        filter: &dyn Fn(&K) -> bool,
+    ) -> Vec<&K> {
 
         // If case sensitivity set, leave case intact. Otherwise, normalize
         // keyword to lower case:
@@ -116,14 +132,17 @@ impl<K: Hash + Ord> SearchIndex<K> {
         #[cfg(debug_assertions)]
         tracing::debug!("searching: {}", keyword);
 
-        // Attempt to get matching keys for the search keyword from BTreeMap:
-        if let Some(keys) = self.b_tree_map.get(&KString::from_ref(&keyword)) {
+        // Attempt to get matching keys for the search keyword from BTreeMap,
+        // or from the compacted whole strings (see `compact_whole_strings`):
+        if let Some(keys) = self.keyword_entry(&keyword) {
 
             // Attempt to get matching keys for search keyword:
             keys
                 // Iterate over all matching keys and only return
                 // `maximum_search_results` number of keys:
                 .iter()
+                // Discard keys that don't pass the caller's filter:
+                .filter(|key| filter(key))
                 // Only return `maximum_search_results` number of keys:
                 .take(*maximum_search_results)
                 // Insert a reference to each resulting key into the hash set:
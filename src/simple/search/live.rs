@@ -1,9 +1,10 @@
 #![allow(unused_mut)]
 
 use crate::simple::internal::string_keywords::SplitContext;
+use crate::simple::internal::Keys;
 use crate::simple::SearchIndex;
 use kstring::KString;
-use std::{cmp::Ord, collections::BTreeSet, hash::Hash};
+use std::{cmp::Ord, hash::Hash};
 
 // -----------------------------------------------------------------------------
 
@@ -107,7 +108,24 @@ impl<K: Hash + Ord> SearchIndex<K> {
         &self,
         maximum_search_results: &usize,
         string: &str,
-    ) -> BTreeSet<&K> {
+    ) -> Keys<'_, K> {
+        self.search_live_filtered(maximum_search_results, string, &|_key| true)
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// This is the `Live` search with a caller-provided key filter. Keys that
+    /// don't pass the filter are discarded while the index is traversed, before
+    /// `maximum_search_results` is applied. This means that filtered-out keys
+    /// do not use up room in the search results.
+
+    #[tracing::instrument(level = "trace", name = "filtered live search", skip(self, filter))]
+    pub(crate) fn search_live_filtered(
+        &self,
+        maximum_search_results: &usize,
+        string: &str,
+        filter: &dyn Fn(&K) -> bool,
+    ) -> Keys<'_, K> {
 
         // Split search `String` into keywords according to the `SearchIndex`
         // settings. Force "use entire string as a keyword" option off:
@@ -145,7 +163,7 @@ impl<K: Hash + Ord> SearchIndex<K> {
 
                 0 => {
 
-                    let mut search_results: BTreeSet<&K> = self.b_tree_map
+                    let mut search_results: Keys<K> = self.b_tree_map
                         // Get matching keywords starting with (partial) keyword
                         // string:
                         .range(last_keyword.to_owned()..)
@@ -165,13 +183,15 @@ impl<K: Hash + Ord> SearchIndex<K> {
                         // Flatten the `BTreeSet<K>` from each autocomplete
                         // keyword option into our collection:
                         .flat_map(|(_keyword, keys)| keys)
-                        // Collect all keyword search results into a `BTreeSet`:
+                        // Only keep keys that pass the caller's filter:
+                        .filter(|key| filter(key))
+                        // Collect all keyword search results:
                         .collect();
 
-                    // If `eddie` fuzzy matching enabled, examine the search
+                    // If `eddie` fuzzy matching is used, examine the search
                     // results before returning them:
                     #[cfg(feature = "eddie")]
-                    if search_results.is_empty() {
+                    if search_results.is_empty() && self.uses_eddie() {
                         // No search results were found for the user's last
                         // (partial) keyword. Attempt to use fuzzy string
                         // search to find other options:
@@ -186,18 +206,20 @@ impl<K: Hash + Ord> SearchIndex<K> {
                             // `BTreeSet<K>` from each search result into our
                             // collection:
                             .flat_map(|(_keyword, keys)| keys)
+                            // Only keep keys that pass the caller's filter:
+                            .filter(|key| filter(key))
                             // Only return `maximum_search_results` number of
                             // keys:
                             .take(*maximum_search_results)
-                            // Collect all keyword autocompletions into a
-                            // `BTreeSet`:
+                            // Collect all keyword autocompletions into
+                            // `Keys`:
                             .collect()
                     } // if
 
-                    // If `strsim` fuzzy matching enabled, examine the search
+                    // If `strsim` fuzzy matching is used, examine the search
                     // results before returning them:
-                    #[cfg(all(feature = "strsim", not(feature = "eddie")))]
-                    if search_results.is_empty() {
+                    #[cfg(feature = "strsim")]
+                    if search_results.is_empty() && self.uses_strsim() {
                         // No search results were found for the user's last
                         // (partial) keyword. Attempt to use fuzzy string
                         // search to find other options:
@@ -212,11 +234,13 @@ impl<K: Hash + Ord> SearchIndex<K> {
                             // `BTreeSet<K>` from each search result into our
                             // collection:
                             .flat_map(|(_keyword, keys)| keys)
+                            // Only keep keys that pass the caller's filter:
+                            .filter(|key| filter(key))
                             // Only return `maximum_search_results` number of
                             // keys:
                             .take(*maximum_search_results)
-                            // Collect all keyword autocompletions into a
-                            // `BTreeSet`:
+                            // Collect all keyword autocompletions into
+                            // `Keys`:
                             .collect()
                     } // if
 
@@ -240,11 +264,11 @@ impl<K: Hash + Ord> SearchIndex<K> {
 
                     // Perform `And` search for entire string, excluding the
                     // last (partial) keyword:
-                    let search_results: BTreeSet<&K> =
+                    let search_results: Keys<K> =
                         self.internal_search_and(keywords.as_slice());
 
                     // Get keys for the last (partial) keyword:
-                    let mut last_results: BTreeSet<&K> = self.b_tree_map
+                    let mut last_results: Keys<K> = self.b_tree_map
                         // Get matching keywords starting with (partial) keyword
                         // string:
                         .range(last_keyword.to_owned()..)
@@ -269,16 +293,18 @@ impl<K: Hash + Ord> SearchIndex<K> {
                         // options (produced from this iterator) with the search
                         // results produced above:
                         .filter(|key| search_results.contains(key))
+                        // Only keep keys that pass the caller's filter:
+                        .filter(|key| filter(key))
                         // Only return `maximum_search_results` number of keys:
                         .take(*maximum_search_results)
                         // Collect all keyword autocompletions into a
-                        // `BTreetSet`:
+                        // `Keys`:
                         .collect();
 
                     // If fuzzy string searching enabled, examine the search
                     // results before returning them:
                     #[cfg(feature = "eddie")]
-                    if last_results.is_empty() {
+                    if last_results.is_empty() && self.uses_eddie() {
                         // No search results were found for the user's last
                         // (partial) keyword. Attempt to use fuzzy string
                         // search to find other options:
@@ -290,31 +316,30 @@ impl<K: Hash + Ord> SearchIndex<K> {
                             // Only keep this result if hasn't already been used
                             // as a keyword:
                             .filter(|(keyword, _keys)| !keywords.contains(keyword))
-                            // Intersect the key results from the autocomplete
-                            // options (produced from this iterator) with the
-                            // search results produced at the top:
-                            .map(|(keyword, keys)| (
-                                keyword,
-                                keys.iter().filter(|key| search_results.contains(key)).collect::<BTreeSet<_>>(),
-                            )) // map
                             // Autocomplete returns both the keyword and keys.
                             // We're searching for the last (partial) keyword,
                             // so discard the keywords. Flatten the
                             // `BTreeSet<K>` from each search result into our
                             // collection:
                             .flat_map(|(_keyword, keys)| keys)
+                            // Intersect the key results from the autocomplete
+                            // options (produced from this iterator) with the
+                            // search results produced at the top:
+                            .filter(|key| search_results.contains(key))
+                            // Only keep keys that pass the caller's filter:
+                            .filter(|key| filter(key))
                             // Only return `maximum_search_results` number of
                             // keys:
                             .take(*maximum_search_results)
-                            // Collect all keyword autocompletions into a
-                            // `BTreeSet`:
+                            // Collect all keyword autocompletions into
+                            // `Keys`:
                             .collect()
                     } // if
 
                     // If fuzzy string searching enabled, examine the search
                     // results before returning them:
-                    #[cfg(all(feature = "strsim", not(feature = "eddie")))]
-                    if last_results.is_empty() {
+                    #[cfg(feature = "strsim")]
+                    if last_results.is_empty() && self.uses_strsim() {
                         // No search results were found for the user's last
                         // (partial) keyword. Attempt to use fuzzy string
                         // search to find other options:
@@ -326,24 +351,23 @@ impl<K: Hash + Ord> SearchIndex<K> {
                             // Only keep this result if hasn't already been used
                             // as a keyword:
                             .filter(|(keyword, _keys)| !keywords.contains(keyword))
-                            // Intersect the key results from the autocomplete
-                            // options (produced from this iterator) with the
-                            // search results produced at the top:
-                            .map(|(keyword, keys)| (
-                                keyword,
-                                keys.iter().filter(|key| search_results.contains(key)).collect::<BTreeSet<_>>(),
-                            )) // map
                             // Autocomplete returns both the keyword and keys.
                             // We're searching for the last (partial) keyword,
                             // so discard the keywords. Flatten the
                             // `BTreeSet<K>` from each search result into our
                             // collection:
                             .flat_map(|(_keyword, keys)| keys)
+                            // Intersect the key results from the autocomplete
+                            // options (produced from this iterator) with the
+                            // search results produced at the top:
+                            .filter(|key| search_results.contains(key))
+                            // Only keep keys that pass the caller's filter:
+                            .filter(|key| filter(key))
                             // Only return `maximum_search_results` number of
                             // keys:
                             .take(*maximum_search_results)
-                            // Collect all keyword autocompletions into a
-                            // `BTreeSet`:
+                            // Collect all keyword autocompletions into
+                            // `Keys`:
                             .collect()
                     } // if
 
@@ -357,8 +381,8 @@ impl<K: Hash + Ord> SearchIndex<K> {
         } else {
 
             // The search string did not have a last keyword to autocomplete (or
-            // any keywords to search for.) Return an empty `BTreeSet`:
-            BTreeSet::new()
+            // any keywords to search for.) Return empty results:
+            Keys::default()
 
         } // if
 
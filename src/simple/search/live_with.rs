@@ -0,0 +1,149 @@
+use crate::simple::internal::Keys;
+use crate::simple::{Conjunction, KeywordMatch, LiveStrategy, SearchIndex, TopScores};
+use kstring::KString;
+use std::{cmp::Ord, collections::BTreeMap, hash::Hash, sync::Arc};
+
+// -----------------------------------------------------------------------------
+
+impl<'a, K: 'a + Hash + Ord> SearchIndex<K> {
+
+    // -------------------------------------------------------------------------
+    //
+    /// This is a `Live` search where the caller chooses how the keywords are
+    /// combined, and how each keyword position is matched. See
+    /// [`LiveStrategy`] for more information.
+    ///
+    /// With `Conjunction::And`, search results are returned in lexographic
+    /// order. With `Conjunction::Or`, search results are returned in order of
+    /// descending relevance: records matching the most keyword positions are
+    /// the top results. Fuzzy matching isn't used.
+    ///
+    /// [`LiveStrategy`]: struct.LiveStrategy.html
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # use indicium::simple::{Conjunction, LiveStrategy, SearchIndex};
+    /// # use pretty_assertions::assert_eq;
+    /// #
+    /// let mut search_index: SearchIndex<usize> = SearchIndex::default();
+    /// search_index.insert(&0, &"Red cotton shirt");
+    /// search_index.insert(&1, &"Blue cotton trousers");
+    /// search_index.insert(&2, &"Red wool sweater");
+    ///
+    /// // The `Live` search type requires every preceding keyword:
+    /// assert_eq!(
+    ///     search_index.search_live_with(&LiveStrategy::new(), &10, "red wool s"),
+    ///     vec![&2]
+    /// );
+    ///
+    /// // "Or for all but last" finds any record matching a keyword position,
+    /// // ranking records that match the most positions first:
+    /// let strategy = LiveStrategy::new().conjunction(Conjunction::Or);
+    /// assert_eq!(
+    ///     search_index.search_live_with(&strategy, &10, "red wool s"),
+    ///     vec![&2, &0]
+    /// );
+    /// ```
+
+    #[tracing::instrument(level = "trace", name = "live search with strategy", skip(self))]
+    pub fn search_live_with(
+        &'a self,
+        strategy: &LiveStrategy,
+        maximum_search_results: &usize,
+        string: &'a str,
+    ) -> Vec<&'a K> {
+
+        // Split search `String` into keywords according to the `SearchIndex`
+        // settings:
+        let keywords: Arc<[KString]> = self.query_keywords(string);
+
+        // For debug builds:
+        #[cfg(debug_assertions)]
+        tracing::debug!("searching: {:?} with {:?}", keywords, strategy);
+
+        // The last keyword is the (partial) keyword being typed:
+        let last_position: usize = keywords.len().saturating_sub(1);
+
+        // Get the keys matching each keyword position, according to the
+        // strategy's match mode for that position:
+        let position_keys = keywords
+            .iter()
+            .enumerate()
+            .map(|(position, keyword)| {
+                let keyword_match = match position == last_position {
+                    true => strategy.last,
+                    false => strategy.preceding,
+                }; // match
+                self.keyword_match_keys(keyword, keyword_match)
+            }); // map
+
+        match strategy.conjunction {
+
+            // Intersect the keys for each keyword position:
+            Conjunction::And => {
+                let mut search_results: Option<Keys<K>> = None;
+
+                position_keys.for_each(|keys| search_results = Some(
+                    match search_results.take() {
+                        // Intersect with the keys of the previous positions:
+                        Some(search_results) => search_results.intersect(&keys),
+                        // This is the first keyword position:
+                        None => keys,
+                    } // match
+                )); // for_each
+
+                search_results
+                    .unwrap_or_default()
+                    .into_iter()
+                    // Only return `maximum_search_results` number of keys:
+                    .take(*maximum_search_results)
+                    .collect()
+            }, // And
+
+            // Tally the number of keyword positions each key matches, and
+            // return the keys with the most hits:
+            Conjunction::Or => {
+                let mut hits: BTreeMap<&K, usize> = BTreeMap::new();
+
+                position_keys.for_each(|keys| keys
+                    .into_iter()
+                    .for_each(|key| *hits.entry(key).or_default() += 1)
+                ); // for_each
+
+                let mut top_scores: TopScores<&K, usize> =
+                    TopScores::with_capacity(*maximum_search_results);
+
+                hits.into_iter().for_each(|(key, hits)| top_scores.insert(key, hits));
+
+                top_scores
+                    .results()
+                    .map(|(key, _hits)| key)
+                    .collect()
+            }, // Or
+
+        } // match
+
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns the keys for a keyword, matching it exactly or as a prefix.
+
+    fn keyword_match_keys(&self, keyword: &str, keyword_match: KeywordMatch) -> Keys<'_, K> {
+        match keyword_match {
+            KeywordMatch::Exact => self.internal_keyword_search(keyword),
+            KeywordMatch::Prefix => self.b_tree_map
+                // Get matching keywords starting with (partial) keyword
+                // string:
+                .range(KString::from_ref(keyword)..)
+                // Break iteration when we reach a keyword that does not start
+                // with the (partial) keyword:
+                .take_while(|(index_keyword, _keys)| index_keyword.starts_with(keyword))
+                // Flatten the keys from each matching keyword:
+                .flat_map(|(_index_keyword, keys)| keys)
+                .collect(),
+        } // match
+    } // fn
+
+} // impl
\ No newline at end of file
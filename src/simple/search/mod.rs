@@ -1,7 +1,15 @@
 mod and;
+mod cancellable;
+mod grouped;
 mod keyword;
 mod live;
+mod live_with;
+mod options;
 mod or;
+mod owned;
+
+#[cfg(feature = "simd")]
+mod simd;
 
 // -----------------------------------------------------------------------------
 
@@ -96,19 +104,28 @@ impl<'a, K: 'a + Hash + Ord> SearchIndex<K> {
     #[tracing::instrument(level = "trace", name = "search", skip(self))]
     pub fn search(&'a self, string: &'a str) -> Vec<&'a K> {
 
+        // Start timing the operation for metrics:
+        #[cfg(feature = "metrics")]
+        let start = std::time::Instant::now();
+
         let search_results: Vec<&'a K> = match self.search_type {
             SearchType::And =>
                 self.search_and(&self.maximum_search_results, string),
             SearchType::Keyword =>
                 self.search_keyword(&self.maximum_search_results, string),
             SearchType::Live =>
-                self.search_live(&self.maximum_search_results, string)
-                    .into_iter()
-                    .collect(),
+                self.search_live(&self.maximum_search_results, string).into_vec(),
             SearchType::Or =>
                 self.search_or(&self.maximum_search_results, string),
         }; // match
 
+        // Record the operation for metrics:
+        #[cfg(feature = "metrics")]
+        crate::simple::internal::metrics::record_search(&self.search_type, start);
+
+        // Count the search (see `runtime_stats`):
+        self.runtime_counters.record_search();
+
         // For debug builds:
         #[cfg(debug_assertions)]
         tracing::debug!(
@@ -207,19 +224,28 @@ impl<'a, K: 'a + Hash + Ord> SearchIndex<K> {
         string: &'a str,
     ) -> Vec<&'a K> {
 
+        // Start timing the operation for metrics:
+        #[cfg(feature = "metrics")]
+        let start = std::time::Instant::now();
+
         let search_results: Vec<&'a K> = match search_type {
             SearchType::And =>
                 self.search_and(&self.maximum_search_results, string),
             SearchType::Keyword =>
                 self.search_keyword(&self.maximum_search_results, string),
             SearchType::Live =>
-                self.search_live(&self.maximum_search_results, string)
-                    .into_iter()
-                    .collect(),
+                self.search_live(&self.maximum_search_results, string).into_vec(),
             SearchType::Or =>
                 self.search_or(&self.maximum_search_results, string),
         }; // match
 
+        // Record the operation for metrics:
+        #[cfg(feature = "metrics")]
+        crate::simple::internal::metrics::record_search(search_type, start);
+
+        // Count the search (see `runtime_stats`):
+        self.runtime_counters.record_search();
+
         // For debug builds:
         #[cfg(debug_assertions)]
         tracing::debug!(
@@ -324,20 +350,77 @@ impl<'a, K: 'a + Hash + Ord> SearchIndex<K> {
         maximum_search_results: &usize,
         string: &'a str,
     ) -> Vec<&'a K> {
+        self.search_with_filtered(search_type, maximum_search_results, string, &|_key| true)
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns search results using the index settings, only returning keys
+    /// that pass the filter. Filtered-out keys don't use up room in the search
+    /// results. For `Live` searches, the filter is also applied to the keys
+    /// of the autocompleted last keyword, so that hidden records don't affect
+    /// the results.
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # use indicium::simple::{SearchIndex, SearchIndexBuilder, SearchType};
+    /// # use pretty_assertions::assert_eq;
+    /// #
+    /// let mut search_index: SearchIndex<usize> = SearchIndexBuilder::default()
+    ///     .search_type(SearchType::Live)
+    ///     .build();
+    /// search_index.insert(&0, &"Public roadmap");
+    /// search_index.insert(&1, &"Private reorganization");
+    ///
+    /// assert_eq!(search_index.search_filtered("r", |key| *key != 1), vec![&0]);
+    /// ```
+
+    #[tracing::instrument(level = "trace", name = "filtered search", skip(self, filter))]
+    pub fn search_filtered(
+        &'a self,
+        string: &'a str,
+        filter: impl Fn(&K) -> bool,
+    ) -> Vec<&'a K> {
+        self.search_with_filtered(&self.search_type, &self.maximum_search_results, string, &filter)
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// This is `search_with` with a caller-provided key filter. Keys that don't
+    /// pass the filter are discarded before `maximum_search_results` is
+    /// applied, regardless of the search type.
+
+    pub(crate) fn search_with_filtered(
+        &'a self,
+        search_type: &SearchType,
+        maximum_search_results: &usize,
+        string: &'a str,
+        filter: &dyn Fn(&K) -> bool,
+    ) -> Vec<&'a K> {
+
+        // Start timing the operation for metrics:
+        #[cfg(feature = "metrics")]
+        let start = std::time::Instant::now();
 
         let search_results: Vec<&'a K> = match search_type {
             SearchType::And =>
-                self.search_and(maximum_search_results, string),
+                self.search_and_filtered(maximum_search_results, string, filter),
             SearchType::Keyword =>
-                self.search_keyword(maximum_search_results, string),
+                self.search_keyword_filtered(maximum_search_results, string, filter),
             SearchType::Live =>
-                self.search_live(maximum_search_results, string)
-                    .into_iter()
-                    .collect(),
+                self.search_live_filtered(maximum_search_results, string, filter).into_vec(),
             SearchType::Or =>
-                self.search_or(maximum_search_results, string),
+                self.search_or_filtered(maximum_search_results, string, filter),
         }; // match
 
+        // Record the operation for metrics:
+        #[cfg(feature = "metrics")]
+        crate::simple::internal::metrics::record_search(search_type, start);
+
+        // Count the search (see `runtime_stats`):
+        self.runtime_counters.record_search();
+
         // For debug builds:
         #[cfg(debug_assertions)]
         tracing::debug!(
@@ -0,0 +1,96 @@
+use crate::simple::{SearchIndex, SearchOptions, SearchType};
+use std::{cmp::Ord, hash::Hash};
+
+// -----------------------------------------------------------------------------
+
+impl<'a, K: 'a + Hash + Ord> SearchIndex<K> {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Searches the index using per-query [`SearchOptions`]. Options that
+    /// aren't set fall back to the search index's own settings.
+    ///
+    /// If a scoring closure is given, every matching key is scored before the
+    /// search results are truncated to `maximum_search_results`, so that
+    /// highly scored keys aren't lost to truncation.
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # use indicium::simple::{SearchIndex, SearchOptions, SearchType};
+    /// # use pretty_assertions::assert_eq;
+    /// #
+    /// let mut search_index: SearchIndex<usize> = SearchIndex::default();
+    ///
+    /// search_index.insert(&0, &"William the Conqueror");
+    /// search_index.insert(&1, &"William Rufus");
+    /// search_index.insert(&2, &"William Adelin");
+    ///
+    /// // The user recently viewed William Adelin:
+    /// let recently_viewed = vec![2_usize];
+    ///
+    /// let options = SearchOptions::new()
+    ///     .maximum_search_results(2)
+    ///     .scorer(|key: &usize| if recently_viewed.contains(key) { 1.0 } else { 0.0 });
+    ///
+    /// assert_eq!(search_index.search_with_options("william", &options), vec![&2, &0]);
+    /// ```
+    ///
+    /// [`SearchOptions`]: struct.SearchOptions.html
+
+    #[tracing::instrument(level = "trace", name = "search with options", skip(self, options))]
+    pub fn search_with_options(
+        &'a self,
+        string: &'a str,
+        options: &SearchOptions<K>,
+    ) -> Vec<&'a K> {
+
+        let search_type: &SearchType = options.search_type
+            .as_ref()
+            .unwrap_or(&self.search_type);
+
+        let maximum_search_results: usize = options.maximum_search_results
+            .unwrap_or(self.maximum_search_results);
+
+        // Without a scorer, this is an ordinary search:
+        let Some(scorer) = &options.scorer else {
+            return self.search_with(search_type, &maximum_search_results, string)
+        }; // let
+
+        // Get every matching key along with its base relevance. Only `Or`
+        // searches have a base relevance, the number of matching keywords:
+        let base_relevance: Vec<(&'a K, f32)> = match search_type {
+            SearchType::Or => {
+                let mut hits: Vec<(&'a K, usize)> = self
+                    .search_or_hits(string, &|_key| true)
+                    .into_iter()
+                    .collect();
+                hits.sort_by(|(_, a), (_, b)| b.cmp(a));
+                hits.into_iter().map(|(key, hits)| (key, hits as f32)).collect()
+            }, // Or
+            search_type => self
+                .search_with(search_type, &usize::MAX, string)
+                .into_iter()
+                .map(|key| (key, 0.0))
+                .collect(),
+        }; // match
+
+        // Combine the base relevance with the caller's score:
+        let mut search_results: Vec<(&'a K, f32)> = base_relevance
+            .into_iter()
+            .map(|(key, relevance)| (key, relevance + scorer(key)))
+            .collect();
+
+        // Order by combined score (highest first). The sort is stable, so ties
+        // keep the search's own ordering:
+        search_results.sort_by(|(_, a_score), (_, b_score)| b_score.total_cmp(a_score));
+
+        search_results
+            .into_iter()
+            .take(maximum_search_results)
+            .map(|(key, _score)| key)
+            .collect()
+
+    } // fn
+
+} // impl
\ No newline at end of file
@@ -1,8 +1,7 @@
-use crate::simple::internal::SearchTopScores;
-use crate::simple::internal::string_keywords::SplitContext;
+use crate::simple::TopScores;
 use crate::simple::search_index::SearchIndex;
 use kstring::KString;
-use std::{cmp::Ord, collections::BTreeMap, hash::Hash};
+use std::{cmp::Ord, collections::BTreeMap, hash::Hash, sync::Arc};
 
 // -----------------------------------------------------------------------------
 
@@ -105,14 +104,67 @@ impl<'a, K: 'a + Hash + Ord> SearchIndex<K> {
         maximum_search_results: &usize,
         string: &'a str,
     ) -> Vec<&'a K> {
+        self.search_or_filtered(maximum_search_results, string, &|_key| true)
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// This is the `Or` search with a caller-provided key filter. Keys that
+    /// don't pass the filter are discarded before `maximum_search_results` is
+    /// applied. This means that filtered-out keys do not use up room in the
+    /// search results.
+
+    #[tracing::instrument(level = "trace", name = "filtered or search", skip(self, filter))]
+    pub(crate) fn search_or_filtered(
+        &'a self,
+        maximum_search_results: &usize,
+        string: &'a str,
+        filter: &dyn Fn(&K) -> bool,
+    ) -> Vec<&'a K> {
+
+        // Get the number of hits for each resulting key:
+        let search_results: BTreeMap<&K, usize> = self.search_or_hits(string, filter);
+
+        // This structure will track the top scoring keys:
+
+        let mut top_scores: TopScores<&K, usize> =
+            TopScores::with_capacity(*maximum_search_results);
+
+        // Populate the top scores by iterating over each key's tally-count:
+
+        search_results
+            // Iterate over keys in the hash map:
+            .into_iter()
+            // Collect the tuple elements into a `Vec`:
+            .for_each(|(key, hits)| top_scores.insert(key, hits));
+
+        // Return the search results to the user:
+        top_scores
+            // Get the top scoring results from the `TopScores` struct:
+            .results()
+            // Remove the hit-count from the tuple, returning only the key:
+            .map(|(key, _hits)| key)
+            // Collect the keys into a `Vec`:
+            .collect()
+
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns every key matching at least one of the search string's keywords,
+    /// along with the number of keywords it matched (or hits.) Keys that don't
+    /// pass the caller's filter are discarded.
+
+    pub(crate) fn search_or_hits(
+        &'a self,
+        string: &'a str,
+        filter: &dyn Fn(&K) -> bool,
+    ) -> BTreeMap<&'a K, usize> {
 
         // Split search `String` into keywords (according to the `SearchIndex`
-        // settings). `string_keywords` will allow "use entire string as a
+        // settings). `query_keywords` will allow "use entire string as a
         // keyword" if enabled in user settings:
-        let keywords: Vec<KString> = self.string_keywords(
-            string,
-            SplitContext::Searching,
-        );
+        let keywords: Arc<[KString]> = self.query_keywords(string);
 
         // For debug builds:
         #[cfg(debug_assertions)]
@@ -127,13 +179,15 @@ impl<'a, K: 'a + Hash + Ord> SearchIndex<K> {
         // a our `BTreeMap`, and track the hit-count for each key:
         keywords
             // Iterate over the keywords supplied in the search string:
-            .into_iter()
+            .iter()
             // For each keyword in the search string:
             .for_each(|keyword| {
                 // Search for keyword in our `BTreeMap`:
-                self.internal_keyword_search(&keyword)
+                self.internal_keyword_search(keyword)
                     // Iterate over the resulting keys (if any):
                     .into_iter()
+                    // Discard keys that don't pass the caller's filter:
+                    .filter(|key| filter(key))
                     // For each resulting key from the keyword search:
                     .for_each(|key| match search_results.get_mut(key) {
                         // Add "hit" to counter for an already existing key:
@@ -146,28 +200,7 @@ impl<'a, K: 'a + Hash + Ord> SearchIndex<K> {
         // At this point, we have a list of resulting keys in a `BTreeMap`. The
         // hash map value holds the number of times each key has been returned
         // in the above keywords search.
-
-        // This structure will track the top scoring keys:
-
-        let mut top_scores: SearchTopScores<K> =
-            SearchTopScores::with_capacity(*maximum_search_results);
-
-        // Populate the top scores by iterating over each key's tally-count:
-
         search_results
-            // Iterate over keys in the hash map:
-            .into_iter()
-            // Collect the tuple elements into a `Vec`:
-            .for_each(|(key, hits)| top_scores.insert(key, hits));
-
-        // Return the search results to the user:
-        top_scores
-            // Get the top scoring results from the `SearchTopScores` struct:
-            .results()
-            // Remove the hit-count from the tuple, returning only the key:
-            .map(|(key, _hits)| key)
-            // Collect the keys into a `Vec`:
-            .collect()
 
     } // fn
 
@@ -0,0 +1,66 @@
+use crate::simple::{SearchIndex, SearchType};
+use std::{clone::Clone, cmp::Ord, hash::Hash};
+
+// -----------------------------------------------------------------------------
+
+impl<K: Clone + Hash + Ord> SearchIndex<K> {
+
+    // -------------------------------------------------------------------------
+    //
+    /// This is `search`, returning owned copies of the keys instead of
+    /// references. The results don't borrow the search index, so they can be
+    /// moved into a spawned task or returned from an `async` handler after a
+    /// lock on the search index has been released.
+    ///
+    /// Each key is cloned, which is cheap for integer keys but allocates for
+    /// keys such as `String` paths. For large keys, consider using `Arc<str>`
+    /// or another `Arc<T>` as the key type, so that cloning only bumps a
+    /// reference count.
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # use indicium::simple::SearchIndex;
+    /// # use pretty_assertions::assert_eq;
+    /// # use std::sync::Arc;
+    /// #
+    /// let mut search_index: SearchIndex<Arc<str>> = SearchIndex::default();
+    /// search_index.insert(&Arc::from("/kings/harold.md"), &"Harold Godwinson");
+    /// search_index.insert(&Arc::from("/kings/william.md"), &"William the Conqueror");
+    ///
+    /// let search_results: Vec<Arc<str>> = search_index.search_owned("harold");
+    /// drop(search_index);
+    ///
+    /// assert_eq!(search_results, vec![Arc::from("/kings/harold.md")]);
+    /// ```
+
+    pub fn search_owned(&self, string: &str) -> Vec<K> {
+        self.search(string).into_iter().cloned().collect()
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// This is `search_type`, returning owned copies of the keys instead of
+    /// references. See `search_owned`.
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # use indicium::simple::{SearchIndex, SearchType};
+    /// # use pretty_assertions::assert_eq;
+    /// #
+    /// let mut search_index: SearchIndex<String> = SearchIndex::default();
+    /// search_index.insert(&"harold".to_string(), &"Harold Godwinson");
+    /// search_index.insert(&"william".to_string(), &"William the Conqueror");
+    ///
+    /// assert_eq!(
+    ///     search_index.search_type_owned(&SearchType::Or, "harold william"),
+    ///     vec!["harold".to_string(), "william".to_string()]
+    /// );
+    /// ```
+
+    pub fn search_type_owned(&self, search_type: &SearchType, string: &str) -> Vec<K> {
+        self.search_type(search_type, string).into_iter().cloned().collect()
+    } // fn
+
+} // impl
\ No newline at end of file
@@ -0,0 +1,158 @@
+use crate::simple::internal::galloping::intersect_sorted;
+use crate::simple::internal::SortedPostings;
+use crate::simple::{IntegerKey, SearchIndex, SearchType};
+use kstring::KString;
+use std::{hash::Hash, sync::Arc};
+
+// -----------------------------------------------------------------------------
+
+impl<'a, K: 'a + Hash + IntegerKey> SearchIndex<K> {
+
+    // -------------------------------------------------------------------------
+    //
+    /// This search function will return keys as the search results. Each
+    /// resulting key can then be used to retrieve the full record from its
+    /// collection. _This search method accepts multiple keywords in the search
+    /// string._ Search keywords must be an exact match.
+    ///
+    /// This is an `And` search that is only available for `u32` and `u64`
+    /// keys. If the search index keeps sorted postings (see
+    /// `SearchIndexBuilder::sorted_postings`), each keyword's keys are read
+    /// from a contiguous, sorted `Vec` and intersected with a galloping search
+    /// that compares a block of keys at a time. The block comparison is
+    /// branch-free, so the compiler is able to vectorize it for integer keys.
+    /// This is considerably faster than walking `BTreeSet`s when common
+    /// keywords have many keys.
+    ///
+    /// Without sorted postings (or after the keywords were changed through
+    /// `DerefMut`, until the postings are rebuilt), this falls back to the
+    /// regular [`SearchType::And`] search. Either way, the results are the
+    /// same as those of `search_with(&SearchType::And, ..)`, and the
+    /// `maximum_keys_per_keyword` setting, metrics and runtime statistics
+    /// apply.
+    ///
+    /// Results are returned in ascending key order, just like the `And`
+    /// search type.
+    ///
+    /// [`SearchType::And`]: enum.SearchType.html#variant.And
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # use indicium::simple::{SearchIndex, SearchIndexBuilder};
+    /// # use pretty_assertions::assert_eq;
+    /// #
+    /// let mut search_index: SearchIndex<u32> = SearchIndexBuilder::default()
+    ///     .sorted_postings(true)
+    ///     .build();
+    ///
+    /// search_index.insert(&0, &"William the Conqueror");
+    /// search_index.insert(&1, &"William Rufus");
+    /// search_index.insert(&2, &"Rufus the Red");
+    ///
+    /// assert_eq!(search_index.search_and_simd(&10, "rufus william"), vec![&1]);
+    /// assert_eq!(search_index.search_and_simd(&10, "rufus"), vec![&1, &2]);
+    /// assert!(search_index.search_and_simd(&10, "rufus harold").is_empty());
+    /// ```
+
+    #[tracing::instrument(level = "trace", name = "simd and search", skip(self))]
+    pub fn search_and_simd(
+        &'a self,
+        maximum_search_results: &usize,
+        string: &'a str,
+    ) -> Vec<&'a K> {
+
+        // Without sorted postings, use the regular `And` search:
+        let Some(sorted_postings) = &self.sorted_postings else {
+            return self.search_with(&SearchType::And, maximum_search_results, string)
+        }; // let
+
+        // Start timing the operation for metrics:
+        #[cfg(feature = "metrics")]
+        let start = std::time::Instant::now();
+
+        // Split search `String` into keywords (according to the `SearchIndex`
+        // settings), just like the `And` search:
+        let keywords: Arc<[KString]> = self.query_keywords(string);
+
+        // For debug builds:
+        #[cfg(debug_assertions)]
+        tracing::debug!("searching: {:?}", keywords);
+
+        // If the sorted postings can't be used for a keyword, use the regular
+        // `And` search:
+        let Some(search_results) = self.sorted_search_and(
+            sorted_postings,
+            &keywords,
+            maximum_search_results,
+        ) else {
+            return self.search_with(&SearchType::And, maximum_search_results, string)
+        }; // let
+
+        // Record the operation for metrics:
+        #[cfg(feature = "metrics")]
+        crate::simple::internal::metrics::record_search(&SearchType::And, start);
+
+        // Count the search (see `runtime_stats`):
+        self.runtime_counters.record_search();
+
+        search_results
+
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Intersects the sorted postings of the keywords. Like the `And` search,
+    /// only the first `maximum_keys_per_keyword` keys of the first keyword are
+    /// considered. Returns `None` if the sorted postings are stale or a keyword
+    /// is the dump keyword, in which case the `BTreeMap` must be used.
+
+    fn sorted_search_and(
+        &self,
+        sorted_postings: &'a SortedPostings<K>,
+        keywords: &[KString],
+        maximum_search_results: &usize,
+    ) -> Option<Vec<&'a K>> {
+
+        // Look-up the sorted keys of each keyword. Any keyword that isn't
+        // found short-circuits the search into empty results:
+        let mut postings: Vec<&'a [K]> = Vec::with_capacity(keywords.len());
+        for keyword in keywords {
+            match sorted_postings.get(keyword)? {
+                Some(keys) => postings.push(keys),
+                None => return Some(Vec::new()),
+            } // match
+        } // for
+
+        // If there are no keywords in the search string, there are no results:
+        let Some((first, rest)) = postings.split_first_mut() else {
+            return Some(Vec::new())
+        }; // let
+        let first: &'a [K] = &first[..first.len().min(self.maximum_keys_per_keyword)];
+
+        // Intersect with the smallest postings first. This keeps the
+        // intermediate results as small as possible:
+        rest.sort_unstable_by_key(|keys| keys.len());
+
+        let mut intersection: Vec<K> = match rest.split_first() {
+            Some((keys, _rest)) => intersect_sorted(first, keys),
+            None => return Some(first.iter().take(*maximum_search_results).collect()),
+        }; // match
+
+        for keys in rest.iter().skip(1) {
+            if intersection.is_empty() { break }
+            intersection = intersect_sorted(&intersection, keys);
+        } // for
+
+        // Map the intersection back to references into the sorted postings
+        // while observing `maximum_search_results`:
+        Some(intersection
+            .iter()
+            .take(*maximum_search_results)
+            .filter_map(|key| first.binary_search(key).ok().map(|position| &first[position]))
+            .collect()
+        ) // Some
+
+    } // fn
+
+} // impl
\ No newline at end of file
@@ -0,0 +1,100 @@
+use crate::simple::search_index::SearchIndex;
+use kstring::KString;
+use std::cmp::Ord;
+use std::collections::BTreeSet;
+
+// -----------------------------------------------------------------------------
+
+impl<K: Ord> SearchIndex<K> {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns the keys of records with a keyword that contains the fragment
+    /// anywhere, not only at its start. This is meant for identifier-heavy
+    /// data such as part numbers, file names or symbols, where users often
+    /// type the middle of a keyword. For example, `ord` finds `harold` and
+    /// `oxford`. The fragment may also be written as `*ord*`, since any
+    /// leading and trailing asterisks are ignored. Unless the search index is
+    /// case sensitive, the fragment is lowercased.
+    ///
+    /// The search index must be built with `SearchIndexBuilder::permuterm`,
+    /// which keeps every rotation of every keyword so that a contains-query
+    /// is a single prefix scan. Its cost is bounded by the number of results:
+    /// the scan stops once `maximum_search_results` keys have been found. If
+    /// the permuterm index is disabled, or out of date after the keywords were
+    /// changed directly, every keyword is scanned instead.
+    ///
+    /// Keys are returned in order.
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # use indicium::simple::{SearchIndex, SearchIndexBuilder};
+    /// # use pretty_assertions::assert_eq;
+    /// #
+    /// let mut search_index: SearchIndex<usize> = SearchIndexBuilder::default()
+    ///     .permuterm(true)
+    ///     .build();
+    ///
+    /// search_index.insert(&0, &"parse_config");
+    /// search_index.insert(&1, &"ConfigLoader");
+    /// search_index.insert(&2, &"render_frame");
+    ///
+    /// assert_eq!(search_index.search_contains("config"), vec![&0, &1]);
+    /// assert_eq!(search_index.search_contains("*fram*"), vec![&2]);
+    /// assert_eq!(search_index.search_contains("xyz"), Vec::<&usize>::new());
+    ///
+    /// search_index.remove(&1, &"ConfigLoader");
+    /// assert_eq!(search_index.search_contains("config"), vec![&0]);
+    /// ```
+
+    #[tracing::instrument(level = "trace", name = "search contains", skip(self))]
+    pub fn search_contains(&self, fragment: &str) -> Vec<&K> {
+
+        let fragment: KString = match self.case_sensitive {
+            true => KString::from_ref(fragment.trim_matches('*')),
+            false => KString::from(fragment.trim_matches('*').to_lowercase()),
+        }; // match
+
+        if fragment.is_empty() { return Vec::new() }
+
+        let mut search_results: BTreeSet<&K> = BTreeSet::new();
+
+        // Use the permuterm index if it's current, otherwise scan every
+        // keyword:
+        let keywords: Box<dyn Iterator<Item = KString>> = match self.permuterm
+            .as_ref()
+            .and_then(|permuterm| permuterm.containing(&fragment)) {
+            Some(keywords) => Box::new(keywords),
+            None => Box::new(self.b_tree_map
+                .keys()
+                .filter(|keyword| keyword.contains(fragment.as_str()))
+                .cloned()
+            ), // None
+        }; // match
+
+        for keyword in keywords {
+            if self.dump_keyword.as_ref() == Some(&keyword) { continue }
+            if let Some(keys) = self.b_tree_map.get(&keyword) {
+                search_results.extend(keys.iter());
+            } // if
+            // Stop once enough keys have been found:
+            if search_results.len() >= self.maximum_search_results { break }
+        } // for
+
+        // For debug builds:
+        #[cfg(debug_assertions)]
+        tracing::debug!(
+            "{} search results for \"*{}*\".",
+            search_results.len(),
+            fragment,
+        ); // debug!
+
+        search_results
+            .into_iter()
+            .take(self.maximum_search_results)
+            .collect()
+
+    } // fn
+
+} // impl
\ No newline at end of file
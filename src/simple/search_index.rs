@@ -1,7 +1,14 @@
-use crate::simple::{AutocompleteType, EddieMetric, SearchType, StrsimMetric};
+#[cfg(feature = "geo")]
+use crate::simple::internal::GeoIndex;
+use crate::simple::internal::{Clock, FieldKeywords, FrontMap, InteriorTokens, KeywordCache, KeywordEnricher, KeywordUsage, Permuterm, RuntimeCounters, SaturationHook, SortedPostings, SuggestionFilter, WarmCache};
+use crate::simple::changelog::Changelog;
+use crate::simple::synonyms::SynonymMap;
+use crate::simple::{AutocompleteType, CharFilter, DuplicateKeyPolicy, EddieMetric, Elision, Evictions, FuzzyBackendChoice, KeywordCapPolicy, QueryLimitPolicy, SearchType, StrsimMetric, Synonyms, Transliteration};
 use kstring::KString;
 use std::cmp::Ord;
 use std::collections::{BTreeMap, BTreeSet};
+use std::sync::Arc;
+use std::time::SystemTime;
 
 // -----------------------------------------------------------------------------
 //
@@ -20,7 +27,7 @@ use std::collections::{BTreeMap, BTreeSet};
 #[derive(Clone, Debug, PartialEq, PartialOrd)]
 pub struct SearchIndex<K: Ord> {
     /// Search index data structure.
-    pub(crate) b_tree_map: BTreeMap<KString, BTreeSet<K>>,
+    pub(crate) b_tree_map: Arc<BTreeMap<KString, BTreeSet<K>>>,
     /// The `SearchType` for searches. This setting may be manually overridden
     /// by using the `search_type` method.
     pub(crate) search_type: SearchType,
@@ -33,6 +40,10 @@ pub struct SearchIndex<K: Ord> {
     /// Used for the `eddie` optional feature. The `EddieMetric` is used to
     /// select the string similarity metric (or algorithm) for fuzzy matching.
     pub(crate) eddie_metric: Option<EddieMetric>,
+    /// The crate used for fuzzy matching when both the `eddie` and `strsim`
+    /// features are enabled, or `Auto` to choose one. The crate that's
+    /// actually used is `active_fuzzy_backend`.
+    pub(crate) fuzzy_backend: FuzzyBackendChoice,
     /// Used for both the `strsim` and `eddie` optional features. Search index
     /// keyword must match the first _n_ characters of the user's keyword in
     /// order to be evaluated for fuzzy matching.
@@ -70,4 +81,144 @@ pub struct SearchIndex<K: Ord> {
     /// the search index. It should be made so that it's difficult or impossible
     /// for a user inadvertently trigger this behaviour.
     pub(crate) dump_keyword: Option<KString>,
+    /// Remembers the keywords for recently seen search strings, so that
+    /// repeated searches don't have to split & normalize the string again.
+    pub(crate) keyword_cache: KeywordCache,
+    /// An optional reverse map of the keywords attached to each key. This is
+    /// used to find records similar to a record (see `more_like_this`.) If
+    /// `None`, the reverse map is not maintained.
+    pub(crate) reverse_map: Option<BTreeMap<K, BTreeSet<KString>>>,
+    /// Precomputed autocompletion options for the hot prefixes given to
+    /// `warm_up`.
+    pub(crate) warm_cache: WarmCache,
+    /// Suggestions pinned to prefixes with `pin_suggestion`, which are shown
+    /// before organic autocompletion options.
+    pub(crate) pinned_suggestions: BTreeMap<KString, Vec<KString>>,
+    /// Whole-string keywords that were moved out of the keyword dictionary by
+    /// `compact_whole_strings`, and the single key attached to each. Shared
+    /// with snapshots, like the keyword dictionary.
+    pub(crate) whole_strings: Arc<BTreeMap<KString, K>>,
+    /// The keywords in the keyword dictionary that were indexed as whole
+    /// strings (see `maximum_string_length`), which `compact_whole_strings`
+    /// may compact.
+    pub(crate) whole_string_keywords: BTreeSet<KString>,
+    /// The keywords produced by each named field of each key (see
+    /// `update_field`). Only records that name their fields are tagged.
+    pub(crate) field_keywords: BTreeMap<K, FieldKeywords>,
+    /// Maximum number of keywords in the search index. If the cap is exceeded,
+    /// the least recently hit (and rarest) keywords are evicted. If `None`,
+    /// the number of keywords is unbounded.
+    pub(crate) maximum_keywords: Option<usize>,
+    /// When each keyword was last hit by a search. Only maintained when
+    /// `maximum_keywords` is set.
+    pub(crate) keyword_usage: KeywordUsage,
+    /// The number of keywords & keys evicted so far.
+    pub(crate) evictions: Evictions,
+    /// Keywords that have reached the `maximum_keys_per_keyword` limit (see
+    /// `saturated_keywords`.)
+    pub(crate) saturated_keywords: BTreeSet<KString>,
+    /// Invoked when a keyword first reaches the `maximum_keys_per_keyword`
+    /// limit (see `on_saturation`.)
+    pub(crate) saturation_hook: SaturationHook,
+    /// Counts searches, autocompletions and fuzzy fall-backs (see
+    /// `runtime_stats`.)
+    pub(crate) runtime_counters: RuntimeCounters,
+    /// Decides whether a keyword may be suggested by autocompletion (see
+    /// `set_suggestion_filter`.)
+    pub(crate) suggestion_filter: SuggestionFilter,
+    /// The source of the current time (see `set_clock`.)
+    pub(crate) clock: Clock,
+    /// Derives extra keywords from each keyword when records are indexed (see
+    /// `set_keyword_enricher`.)
+    pub(crate) keyword_enricher: KeywordEnricher,
+    /// The crate that's used for fuzzy matching, after any `Auto` choice has
+    /// been made (see `fuzzy_backend`.) This is never `Auto`.
+    pub(crate) active_fuzzy_backend: FuzzyBackendChoice,
+    /// Whether autocompletion also suggests whole-string keywords that contain
+    /// the last (partial) keyword as an interior token.
+    pub(crate) interior_autocomplete: bool,
+    /// The interior tokens of the whole-string keywords, if
+    /// `interior_autocomplete` is enabled.
+    pub(crate) interior_tokens: Option<InteriorTokens>,
+    /// When each key was attached to each of its keywords. This is used to
+    /// drop old postings (see `decay`.) If `None`, timestamps are not kept.
+    pub(crate) posting_times: Option<BTreeMap<K, BTreeMap<KString, SystemTime>>>,
+    /// Mutations recorded for replication (see `drain_ops`.) If `None`,
+    /// mutations are not recorded.
+    pub(crate) changelog: Option<Changelog<K>>,
+    /// The sequence number of the last op applied with `apply_ops`.
+    pub(crate) applied_sequence: u64,
+    /// Whether keyword lengths are counted in grapheme clusters rather than
+    /// chars (or codepoints.)
+    pub(crate) grapheme_lengths: bool,
+    /// An optional hash map copy of the keywords & keys, used for exact
+    /// keyword lookups. If `None`, the `BTreeMap` is used.
+    pub(crate) front_map: Option<FrontMap<K>>,
+    /// An optional index of every rotation of every keyword, used for
+    /// contains-queries (see `search_contains`.) If `None`, every keyword is
+    /// scanned instead.
+    pub(crate) permuterm: Option<Permuterm>,
+    /// A copy of the postings as sorted vectors, if enabled (see
+    /// `SearchIndexBuilder::sorted_postings`.)
+    pub(crate) sorted_postings: Option<SortedPostings<K>>,
+    /// Keywords attached to fewer keys than this aren't suggested by
+    /// autocompletion, but can still be searched for.
+    pub(crate) minimum_keys_for_autocomplete: usize,
+    /// Maximum number of keywords a single record may be attached to. If
+    /// `None`, the number of keywords per record is unbounded.
+    pub(crate) maximum_keywords_per_record: Option<usize>,
+    /// What to do with records that exceed `maximum_keywords_per_record`.
+    pub(crate) keyword_cap_policy: KeywordCapPolicy,
+    /// Maximum length of a search string, in chars. If `None`, search strings
+    /// may be of any length.
+    pub(crate) maximum_query_length: Option<usize>,
+    /// Maximum number of keywords in a search string. If `None`, search
+    /// strings may have any number of keywords.
+    pub(crate) maximum_query_keywords: Option<usize>,
+    /// Maximum number of keywords evaluated for each fuzzy match. If `None`,
+    /// every keyword within the `fuzzy_length` range is evaluated.
+    pub(crate) maximum_fuzzy_candidates: Option<usize>,
+    /// If set, the fuzzy matching range is chosen for each keyword: the
+    /// shortest prefix, no longer than `fuzzy_length`, that has at most this
+    /// many keywords in its range. If `None`, `fuzzy_length` is always used.
+    pub(crate) adaptive_fuzzy_length: Option<usize>,
+    /// If `true`, fuzzy matching that finds nothing is retried with the user's
+    /// first two characters transposed, and then against every keyword, so
+    /// that typos in the first character can be corrected.
+    pub(crate) fuzzy_first_letter_fallback: bool,
+    /// What to do with search strings that exceed the query limits.
+    pub(crate) query_limit_policy: QueryLimitPolicy,
+    /// What `insert` does when the key is already in the search index.
+    pub(crate) duplicate_key_policy: DuplicateKeyPolicy,
+    /// The number of preceding keywords used to constrain `Context`
+    /// autocompletion. If `None`, every preceding keyword is used.
+    pub(crate) context_window: Option<usize>,
+    /// If `true`, autocompleted search strings keep the user's original text in
+    /// front of the completed last keyword, rather than the normalized keywords
+    /// joined by single spaces.
+    pub(crate) preserve_query_prefix: bool,
+    /// Keywords that are searchable, but never suggested by autocompletion.
+    pub(crate) suggestion_blocklist: Option<Vec<KString>>,
+    /// If `true`, each pair of adjacent keywords in a string is also indexed as a
+    /// two-word keyword (a shingle.)
+    pub(crate) shingles: bool,
+    /// Characters removed from inside keywords after splitting. If `None`,
+    /// keywords are left as they were split.
+    pub(crate) char_filter: Option<CharFilter>,
+    /// Elided articles that are removed from the start of words (see
+    /// `Elision`.)
+    pub(crate) elision: Option<Elision>,
+    /// Whether the singular and plural variants of keywords are also
+    /// indexed (see `plural_variants`.)
+    pub(crate) plural_variants: bool,
+    /// Synonym rules that are applied to keywords (see `Synonyms`.)
+    pub(crate) synonyms: Option<Synonyms>,
+    /// The synonym rules, normalized and keyed by keyword (see `SynonymMap`.)
+    pub(crate) synonym_map: SynonymMap,
+    /// Whether keywords are transliterated into Latin (see
+    /// `Transliteration`.)
+    pub(crate) transliteration: Option<Transliteration>,
+    /// The location of records inserted with `insert_with_location`.
+    #[cfg(feature = "geo")]
+    pub(crate) geo_index: GeoIndex<K>,
 } // SearchIndex
\ No newline at end of file
@@ -0,0 +1,231 @@
+use crate::simple::{AutocompleteType, CharFilter, DuplicateKeyPolicy, EddieMetric, Elision, FuzzyBackendChoice, KeywordCapPolicy, QueryLimitPolicy, SearchIndex, SearchIndexBuilder, SearchType, StrsimMetric, Synonyms, Transliteration};
+use crate::Error;
+use std::{clone::Clone, cmp::Ord};
+
+// -----------------------------------------------------------------------------
+//
+/// The settings of a `SearchIndex`, separated from its keywords & keys. This
+/// allows the settings to be kept in an application's configuration file, and
+/// reviewed or diffed independently of the (much larger) index data.
+///
+/// When the `serde` feature is enabled, this struct may be serialized &
+/// deserialized. Missing fields fall back to their default values and unknown
+/// fields are ignored, so configuration files written by older or newer
+/// versions of Indicium can still be read.
+///
+/// For a description of each setting, see [`SearchIndexBuilder`]. The
+/// query-side subset of these settings may be changed on an existing search
+/// index, see [`QueryConfig`].
+///
+/// [`SearchIndexBuilder`]: struct.SearchIndexBuilder.html
+/// [`QueryConfig`]: struct.QueryConfig.html
+
+#[derive(Clone, Debug, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct SearchIndexConfig {
+    pub search_type: SearchType,
+    pub autocomplete_type: AutocompleteType,
+    pub strsim_metric: Option<StrsimMetric>,
+    pub eddie_metric: Option<EddieMetric>,
+    pub fuzzy_backend: FuzzyBackendChoice,
+    pub fuzzy_length: usize,
+    pub fuzzy_minimum_score: f64,
+    pub split_pattern: Option<Vec<char>>,
+    pub case_sensitive: bool,
+    pub minimum_keyword_length: usize,
+    pub maximum_keyword_length: usize,
+    pub maximum_string_length: Option<usize>,
+    pub exclude_keywords: Option<Vec<String>>,
+    pub maximum_autocomplete_options: usize,
+    pub maximum_search_results: usize,
+    pub maximum_keys_per_keyword: usize,
+    pub dump_keyword: Option<String>,
+    pub query_cache_size: usize,
+    pub reverse_map: bool,
+    pub maximum_keywords: Option<usize>,
+    pub interior_autocomplete: bool,
+    pub posting_timestamps: bool,
+    pub changelog: bool,
+    pub grapheme_lengths: bool,
+    pub front_map: bool,
+    pub permuterm: bool,
+    pub sorted_postings: bool,
+    pub minimum_keys_for_autocomplete: usize,
+    pub maximum_keywords_per_record: Option<usize>,
+    pub keyword_cap_policy: KeywordCapPolicy,
+    pub maximum_query_length: Option<usize>,
+    pub maximum_query_keywords: Option<usize>,
+    pub maximum_fuzzy_candidates: Option<usize>,
+    pub adaptive_fuzzy_length: Option<usize>,
+    pub fuzzy_first_letter_fallback: bool,
+    pub query_limit_policy: QueryLimitPolicy,
+    pub duplicate_key_policy: DuplicateKeyPolicy,
+    pub context_window: Option<usize>,
+    pub preserve_query_prefix: bool,
+    pub suggestion_blocklist: Option<Vec<String>>,
+    pub shingles: bool,
+    pub char_filter: Option<CharFilter>,
+    pub elision: Option<Elision>,
+    pub plural_variants: bool,
+    pub synonyms: Option<Synonyms>,
+    pub transliteration: Option<Transliteration>,
+} // SearchIndexConfig
+
+// -----------------------------------------------------------------------------
+
+impl Default for SearchIndexConfig {
+    /// Initialize `SearchIndexConfig` with the default `SearchIndex` settings.
+    fn default() -> Self {
+        SearchIndex::<usize>::default().config()
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl<K: Ord> SearchIndex<K> {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns a copy of the search index's settings, without any of its
+    /// keywords or keys.
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # use indicium::simple::{SearchIndex, SearchIndexBuilder, SearchType};
+    /// #
+    /// let search_index: SearchIndex<usize> = SearchIndexBuilder::default()
+    ///     .search_type(SearchType::And)
+    ///     .max_search_results(50)
+    ///     .build();
+    ///
+    /// let config = search_index.config();
+    /// assert_eq!(config.search_type, SearchType::And);
+    /// assert_eq!(config.maximum_search_results, 50);
+    ///
+    /// let copy: SearchIndex<usize> = SearchIndex::with_config(config.clone());
+    /// assert_eq!(copy.config(), config);
+    /// ```
+
+    #[tracing::instrument(level = "trace", name = "config", skip(self))]
+    pub fn config(&self) -> SearchIndexConfig {
+        SearchIndexConfig {
+            search_type: self.search_type.clone(),
+            autocomplete_type: self.autocomplete_type.clone(),
+            strsim_metric: self.strsim_metric.clone(),
+            eddie_metric: self.eddie_metric.clone(),
+            fuzzy_backend: self.fuzzy_backend,
+            fuzzy_length: self.fuzzy_length,
+            fuzzy_minimum_score: self.fuzzy_minimum_score,
+            split_pattern: self.split_pattern.clone(),
+            case_sensitive: self.case_sensitive,
+            minimum_keyword_length: self.minimum_keyword_length,
+            maximum_keyword_length: self.maximum_keyword_length,
+            maximum_string_length: self.maximum_string_length,
+            exclude_keywords: self.exclude_keywords
+                .as_ref()
+                .map(|exclude_keywords| exclude_keywords
+                    .iter()
+                    .map(|keyword| keyword.to_string())
+                    .collect()
+                ), // map
+            maximum_autocomplete_options: self.maximum_autocomplete_options,
+            maximum_search_results: self.maximum_search_results,
+            maximum_keys_per_keyword: self.maximum_keys_per_keyword,
+            dump_keyword: self.dump_keyword.as_ref().map(|keyword| keyword.to_string()),
+            query_cache_size: self.keyword_cache.capacity(),
+            reverse_map: self.reverse_map.is_some(),
+            maximum_keywords: self.maximum_keywords,
+            interior_autocomplete: self.interior_autocomplete,
+            posting_timestamps: self.posting_times.is_some(),
+            changelog: self.changelog.is_some(),
+            grapheme_lengths: self.grapheme_lengths,
+            front_map: self.front_map.is_some(),
+            permuterm: self.permuterm.is_some(),
+            sorted_postings: self.sorted_postings.is_some(),
+            minimum_keys_for_autocomplete: self.minimum_keys_for_autocomplete,
+            maximum_keywords_per_record: self.maximum_keywords_per_record,
+            keyword_cap_policy: self.keyword_cap_policy,
+            maximum_query_length: self.maximum_query_length,
+            maximum_query_keywords: self.maximum_query_keywords,
+            maximum_fuzzy_candidates: self.maximum_fuzzy_candidates,
+            adaptive_fuzzy_length: self.adaptive_fuzzy_length,
+            fuzzy_first_letter_fallback: self.fuzzy_first_letter_fallback,
+            query_limit_policy: self.query_limit_policy,
+            duplicate_key_policy: self.duplicate_key_policy,
+            context_window: self.context_window,
+            preserve_query_prefix: self.preserve_query_prefix,
+            suggestion_blocklist: self.suggestion_blocklist
+                .as_ref()
+                .map(|suggestion_blocklist| suggestion_blocklist
+                    .iter()
+                    .map(|keyword| keyword.to_string())
+                    .collect()
+                ), // map
+            shingles: self.shingles,
+            char_filter: self.char_filter.clone(),
+            elision: self.elision.clone(),
+            plural_variants: self.plural_variants,
+            synonyms: self.synonyms.clone(),
+            transliteration: self.transliteration.clone(),
+        } // SearchIndexConfig
+    } // fn
+
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl<K: Clone + Ord> SearchIndex<K> {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns a new, empty search index using the given settings.
+    ///
+    /// With the `serde` feature, settings may be loaded from a configuration
+    /// file. Missing settings use their default values:
+    ///
+    /// ```rust
+    /// # use indicium::simple::{SearchIndex, SearchIndexConfig, SearchType};
+    /// #
+    /// let config = SearchIndexConfig {
+    ///     search_type: SearchType::Or,
+    ///     maximum_search_results: 25,
+    ///     ..SearchIndexConfig::default()
+    /// };
+    ///
+    /// let mut search_index: SearchIndex<usize> = SearchIndex::with_config(config);
+    /// search_index.insert(&0, &"Harold Godwinson");
+    /// search_index.insert(&1, &"Harold Hardrada");
+    ///
+    /// assert_eq!(search_index.search("godwinson hardrada"), vec![&0, &1]);
+    /// ```
+
+    #[tracing::instrument(level = "trace", name = "with config", skip(config))]
+    pub fn with_config(config: SearchIndexConfig) -> Self {
+        SearchIndexBuilder::from(config).build()
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns a new, empty search index using the given settings, after
+    /// checking that the settings are usable. This is the fallible variant of
+    /// `with_config`, for settings loaded from configuration files.
+    ///
+    /// ```rust
+    /// # use indicium::simple::{SearchIndex, SearchIndexConfig};
+    /// #
+    /// let config = SearchIndexConfig {
+    ///     fuzzy_minimum_score: f64::NAN,
+    ///     ..SearchIndexConfig::default()
+    /// };
+    ///
+    /// assert!(SearchIndex::<usize>::try_with_config(config).is_err());
+    /// ```
+
+    #[tracing::instrument(level = "trace", name = "try with config", skip(config))]
+    pub fn try_with_config(config: SearchIndexConfig) -> Result<Self, Error> {
+        SearchIndexBuilder::from(config).try_build()
+    } // fn
+
+} // impl
\ No newline at end of file
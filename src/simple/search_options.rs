@@ -0,0 +1,90 @@
+use crate::simple::SearchType;
+
+// -----------------------------------------------------------------------------
+//
+/// A closure that scores a resulting key (see `SearchOptions::scorer`.)
+
+type Scorer<'a, K> = Box<dyn Fn(&K) -> f32 + 'a>;
+
+// -----------------------------------------------------------------------------
+//
+/// Per-query options for [`SearchIndex::search_with_options`]. Any option
+/// that isn't set falls back to the search index's own setting.
+///
+/// A scoring closure may be given to personalize the ordering of search
+/// results, for example to rank the user's recently viewed records first. Its
+/// score is added to the search's base relevance before the search results are
+/// truncated to `maximum_search_results`.
+///
+/// Basic usage:
+///
+/// ```rust
+/// # use indicium::simple::{SearchOptions, SearchType};
+/// #
+/// let recently_viewed = vec![2_usize];
+///
+/// let options = SearchOptions::new()
+///     .search_type(SearchType::Or)
+///     .maximum_search_results(10)
+///     .scorer(|key: &usize| if recently_viewed.contains(key) { 10.0 } else { 0.0 });
+/// ```
+///
+/// [`SearchIndex::search_with_options`]: struct.SearchIndex.html#method.search_with_options
+
+pub struct SearchOptions<'a, K> {
+    pub(crate) search_type: Option<SearchType>,
+    pub(crate) maximum_search_results: Option<usize>,
+    pub(crate) scorer: Option<Scorer<'a, K>>,
+} // SearchOptions
+
+// -----------------------------------------------------------------------------
+
+impl<K> Default for SearchOptions<'_, K> {
+    /// Initialize `SearchOptions` so that the search index's own settings are
+    /// used.
+    fn default() -> Self {
+        SearchOptions {
+            search_type: None,
+            maximum_search_results: None,
+            scorer: None,
+        } // SearchOptions
+    } // fn
+} // impl Default
+
+// -----------------------------------------------------------------------------
+
+impl<'a, K> SearchOptions<'a, K> {
+
+    /// Start the options chain with the search index's own settings.
+    pub fn new() -> Self {
+        Self::default()
+    } // fn
+
+    /// The `SearchType` for this query.
+    ///
+    /// **Default:** the search index's `search_type` setting.
+    pub fn search_type(mut self, search_type: SearchType) -> Self {
+        self.search_type = Some(search_type);
+        self
+    } // fn
+
+    /// Maximum number of search results to return for this query.
+    ///
+    /// **Default:** the search index's `maximum_search_results` setting.
+    pub fn maximum_search_results(mut self, maximum_search_results: usize) -> Self {
+        self.maximum_search_results = Some(maximum_search_results);
+        self
+    } // fn
+
+    /// A closure that scores each resulting key. The score is added to the
+    /// search's base relevance (the number of matching keywords for `Or`
+    /// searches, or zero otherwise) and the search results are ordered by the
+    /// combined score, highest first.
+    ///
+    /// **Default:** `None`
+    pub fn scorer(mut self, scorer: impl Fn(&K) -> f32 + 'a) -> Self {
+        self.scorer = Some(Box::new(scorer));
+        self
+    } // fn
+
+} // impl
\ No newline at end of file
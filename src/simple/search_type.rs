@@ -15,6 +15,7 @@
 /// [`SearchIndex::new()`]: struct.SearchIndex.html#method.new
 
 #[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub enum SearchType {
     /// Interactive `Live` search allows for "search as you type." It is a
     /// hybridization of `autocomplete` and `search`. This method will
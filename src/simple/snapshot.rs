@@ -0,0 +1,96 @@
+use crate::simple::search_index::SearchIndex;
+use kstring::KString;
+use std::collections::{BTreeMap, BTreeSet};
+use std::sync::Arc;
+use std::{cmp::Ord, ops::Deref};
+
+// -----------------------------------------------------------------------------
+//
+/// A read-only view of a search index's keywords and keys, as they were when
+/// the snapshot was taken. Dereferencing an `IndexSnapshot<K>` gives access to
+/// the `BTreeMap` of keywords, in the same way as dereferencing a
+/// `SearchIndex<K>`.
+///
+/// Snapshots are copy-on-write: taking (or cloning) a snapshot doesn't copy
+/// anything. The keywords are only copied the first time the search index is
+/// changed while a snapshot of it is alive.
+///
+/// When the search index is shared through a lock (such as
+/// `RwLock<SearchIndex<K>>`), a snapshot taken while holding the read lock
+/// never observes a partly applied `IndexBatch`, and remains unchanged after
+/// the lock has been released.
+///
+/// Whole-string keywords that have been compacted (see
+/// `SearchIndex::compact_whole_strings`) aren't in the `BTreeMap`. They're
+/// available from `whole_strings`.
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct IndexSnapshot<K> {
+    b_tree_map: Arc<BTreeMap<KString, BTreeSet<K>>>,
+    whole_strings: Arc<BTreeMap<KString, K>>,
+} // IndexSnapshot
+
+// -----------------------------------------------------------------------------
+
+impl<K> Deref for IndexSnapshot<K> {
+    type Target = BTreeMap<KString, BTreeSet<K>>;
+    fn deref(&self) -> &Self::Target {
+        &self.b_tree_map
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl<K> IndexSnapshot<K> {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns the compacted whole-string keywords (see
+    /// `SearchIndex::compact_whole_strings`) and their keys, as they were when
+    /// the snapshot was taken.
+
+    pub fn whole_strings(&self) -> &BTreeMap<KString, K> {
+        &self.whole_strings
+    } // fn
+
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl<K: Ord> SearchIndex<K> {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns a stable, read-only view of the search index's keywords and
+    /// keys. Long-running exports or consistency checks can read the snapshot
+    /// while the search index continues to be updated. See [`IndexSnapshot`]
+    /// for more information.
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # use indicium::simple::SearchIndex;
+    /// # use kstring::KString;
+    /// #
+    /// let mut search_index: SearchIndex<usize> = SearchIndex::default();
+    /// search_index.insert(&0, &"Harold Godwinson");
+    ///
+    /// let snapshot = search_index.snapshot();
+    /// search_index.insert(&1, &"Harold Hardrada");
+    ///
+    /// // The snapshot doesn't see the second insert:
+    /// assert_eq!(snapshot.get(&KString::from_static("harold")).map(|keys| keys.len()), Some(1));
+    /// assert_eq!(search_index.search("harold"), vec![&0, &1]);
+    /// ```
+    ///
+    /// [`IndexSnapshot`]: struct.IndexSnapshot.html
+
+    #[tracing::instrument(level = "trace", name = "search index snapshot", skip(self))]
+    pub fn snapshot(&self) -> IndexSnapshot<K> {
+        IndexSnapshot {
+            b_tree_map: Arc::clone(&self.b_tree_map),
+            whole_strings: Arc::clone(&self.whole_strings),
+        } // IndexSnapshot
+    } // fn
+
+} // impl
\ No newline at end of file
@@ -0,0 +1,210 @@
+use crate::simple::search_index::SearchIndex;
+use kstring::KString;
+use std::cmp::Ord;
+use std::ops::Range;
+
+// -----------------------------------------------------------------------------
+//
+/// A word found in the text being excerpted.
+
+struct Word {
+    /// The word's location in the text, in bytes.
+    bytes: Range<usize>,
+    /// The word's location in the text, in characters.
+    chars: Range<usize>,
+    /// Whether the word matches one of the search keywords.
+    matched: bool,
+} // Word
+
+// -----------------------------------------------------------------------------
+
+impl<K: Ord> SearchIndex<K> {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns a contextual excerpt of a long text, such as a record's body,
+    /// for rendering in a list of search results. The excerpt is the window of
+    /// at most `max_len` characters that contains the most words matching the
+    /// search string, and each matching word is wrapped in `<mark>` and
+    /// `</mark>` tags. An ellipsis (`…`) is added where the text was cut.
+    ///
+    /// The search string is split into keywords with the search index's
+    /// settings. A word matches when it starts with one of the keywords, so
+    /// partially typed keywords are also highlighted. If no words match, the
+    /// excerpt is taken from the start of the text.
+    ///
+    /// The `max_len` limit doesn't include the tags or ellipses. The text
+    /// isn't escaped, so HTML should be escaped by the caller first.
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # use indicium::simple::SearchIndex;
+    /// # use pretty_assertions::assert_eq;
+    /// #
+    /// let search_index: SearchIndex<usize> = SearchIndex::default();
+    ///
+    /// let text = "William the Conqueror landed at Pevensey in September. \
+    ///     Harold Godwinson marched south from York to meet him, and Harold \
+    ///     fell at the Battle of Hastings.";
+    ///
+    /// assert_eq!(
+    ///     search_index.snippet("harold hastings", text, 40),
+    ///     "…<mark>Harold</mark> fell at the Battle of <mark>Hastings</mark>"
+    /// );
+    ///
+    /// assert_eq!(
+    ///     search_index.snippet("william", text, 30),
+    ///     "<mark>William</mark> the Conqueror landed…"
+    /// );
+    /// ```
+
+    #[tracing::instrument(level = "trace", name = "snippet", skip(self, text))]
+    pub fn snippet(&self, query: &str, text: &str, max_len: usize) -> String {
+        self.snippet_with(query, text, max_len, "<mark>", "</mark>")
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns a contextual excerpt of a long text, like `snippet`, wrapping
+    /// each matching word in the given `pre_tag` and `post_tag` instead of
+    /// `<mark>` and `</mark>`.
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # use indicium::simple::SearchIndex;
+    /// # use pretty_assertions::assert_eq;
+    /// #
+    /// let search_index: SearchIndex<usize> = SearchIndex::default();
+    ///
+    /// assert_eq!(
+    ///     search_index.snippet_with("conq", "William the Conqueror", 80, "**", "**"),
+    ///     "William the **Conqueror**"
+    /// );
+    /// ```
+
+    #[tracing::instrument(level = "trace", name = "snippet with", skip(self, text))]
+    pub fn snippet_with(
+        &self,
+        query: &str,
+        text: &str,
+        max_len: usize,
+        pre_tag: &str,
+        post_tag: &str,
+    ) -> String {
+
+        let keywords: Vec<KString> = self.limited_query_keywords(query);
+
+        // Words are separated by the split pattern. If there is no split
+        // pattern, whitespace is used instead:
+        let separator = |char: char| match &self.split_pattern {
+            Some(split_pattern) => split_pattern.contains(&char),
+            None => char.is_whitespace(),
+        }; // closure
+
+        // Find the words in the text, and whether they match:
+        let mut words: Vec<Word> = Vec::new();
+        let mut start: Option<(usize, usize)> = None;
+        let mut total_chars: usize = 0;
+        for (char_index, (byte_index, char)) in text.char_indices().enumerate() {
+            match (separator(char), start) {
+                (true, Some((start_byte, start_char))) => {
+                    words.push(self.snippet_word(text, start_byte..byte_index, start_char..char_index, &keywords));
+                    start = None;
+                }, // true
+                (false, None) => start = Some((byte_index, char_index)),
+                _ => {},
+            } // match
+            total_chars = char_index + 1;
+        } // for
+        if let Some((start_byte, start_char)) = start {
+            words.push(self.snippet_word(text, start_byte..text.len(), start_char..total_chars, &keywords));
+        } // if
+
+        // Choose the window of words that fits in `max_len` characters and
+        // contains the most matching words. Ties go to the earliest window:
+        let window: Range<usize> = if total_chars <= max_len {
+            0..text.len()
+        } else {
+            let mut best: Option<(usize, usize, usize)> = None;
+            let mut last: usize = 0;
+            let mut matches: usize = 0;
+            for first in 0..words.len() {
+                if last < first {
+                    last = first;
+                    matches = 0;
+                } // if
+                while last < words.len() && words[last].chars.end - words[first].chars.start <= max_len {
+                    if words[last].matched { matches += 1 }
+                    last += 1;
+                } // while
+                if best.is_none_or(|(_, _, most)| matches > most) {
+                    best = Some((first, last, matches));
+                } // if
+                if words[first].matched && last > first { matches -= 1 }
+            } // for
+
+            match best {
+                // At least one word fits in the window:
+                Some((first, last, _)) if last > first =>
+                    words[first].bytes.start..words[last - 1].bytes.end,
+                // The first word is too long, so cut it at `max_len`:
+                _ => {
+                    let start = words.first().map_or(0, |word| word.bytes.start);
+                    let end = text[start..]
+                        .char_indices()
+                        .nth(max_len)
+                        .map_or(text.len(), |(index, _char)| start + index);
+                    start..end
+                }, // _
+            } // match
+        }; // if
+
+        // Render the excerpt, marking the matched words:
+        let mut snippet = String::new();
+        if text[..window.start].chars().any(|char| !separator(char)) { snippet.push('…') }
+        let mut position: usize = window.start;
+        words
+            .iter()
+            .filter(|word| word.matched && window.start <= word.bytes.start && word.bytes.end <= window.end)
+            .for_each(|word| {
+                snippet.push_str(&text[position..word.bytes.start]);
+                snippet.push_str(pre_tag);
+                snippet.push_str(&text[word.bytes.clone()]);
+                snippet.push_str(post_tag);
+                position = word.bytes.end;
+            }); // for_each
+        snippet.push_str(&text[position..window.end]);
+        if text[window.end..].chars().any(|char| !separator(char)) { snippet.push('…') }
+
+        snippet
+
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns a word of the text being excerpted, normalized the same way
+    /// keywords are to check whether it matches a search keyword.
+
+    fn snippet_word(
+        &self,
+        text: &str,
+        bytes: Range<usize>,
+        chars: Range<usize>,
+        keywords: &[KString],
+    ) -> Word {
+        let word: String = match self.case_sensitive {
+            true => text[bytes.clone()].to_string(),
+            false => text[bytes.clone()].to_lowercase(),
+        }; // match
+        let word: String = match &self.elision {
+            Some(elision) => elision.strip(&word),
+            None => word,
+        }; // match
+        let word = self.filter_chars(&word, &[]);
+        let matched = keywords.iter().any(|keyword| word.starts_with(keyword.as_str()));
+        Word { bytes, chars, matched }
+    } // fn
+
+} // impl
\ No newline at end of file
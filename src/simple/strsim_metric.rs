@@ -4,6 +4,7 @@
 /// [strsim](https://crates.io/crates/strsim) crate.
 
 #[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub enum StrsimMetric {
     /// Like optimal string alignment, but substrings can be edited an unlimited
     /// number of times, and the triangle inequality holds.
@@ -0,0 +1,56 @@
+use crate::simple::internal::SuggestionFilter;
+use crate::simple::search_index::SearchIndex;
+use std::cmp::Ord;
+
+// -----------------------------------------------------------------------------
+
+impl<K: Ord> SearchIndex<K> {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Sets a callback that decides whether a keyword may be suggested by
+    /// autocompletion. Keywords for which the callback returns `false` are
+    /// left out of every autocompletion type, including fuzzy matches and
+    /// compacted whole strings, but can still be searched for. This can be
+    /// used to hide internal tags, profanity, or keywords that only make sense
+    /// as part of a longer phrase. Setting a callback replaces any previous
+    /// callback.
+    ///
+    /// The `dump_keyword` and any keywords in the `exclude_keywords` list are
+    /// never suggested, with or without a callback.
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # use indicium::simple::SearchIndex;
+    /// # use pretty_assertions::assert_eq;
+    /// #
+    /// let mut search_index: SearchIndex<usize> = SearchIndex::default();
+    /// search_index.insert(&0, &"Harold Godwinson draftonly");
+    /// search_index.insert(&1, &"Harold Hardrada");
+    ///
+    /// search_index.set_suggestion_filter(|keyword| !keyword.ends_with("only"));
+    /// assert_eq!(search_index.autocomplete("harold dra"), Vec::<String>::new());
+    /// assert_eq!(search_index.search("draftonly"), vec![&0]);
+    ///
+    /// search_index.clear_suggestion_filter();
+    /// assert_eq!(search_index.autocomplete("harold dra"), vec!["harold draftonly"]);
+    /// ```
+
+    pub fn set_suggestion_filter(&mut self, filter: impl Fn(&str) -> bool + Send + Sync + 'static) {
+        self.suggestion_filter = SuggestionFilter::new(filter);
+        // Cached autocompletions may no longer be current:
+        self.invalidate_autocompletions();
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Removes the callback set by `set_suggestion_filter`.
+
+    pub fn clear_suggestion_filter(&mut self) {
+        self.suggestion_filter = SuggestionFilter::default();
+        // Cached autocompletions may no longer be current:
+        self.invalidate_autocompletions();
+    } // fn
+
+} // impl
\ No newline at end of file
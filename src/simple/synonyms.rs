@@ -0,0 +1,228 @@
+use crate::Error;
+use kstring::KString;
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter};
+use std::path::Path;
+
+// -----------------------------------------------------------------------------
+//
+/// Synonym rules, in the style of the Apache Solr & Lucene `synonyms.txt`
+/// file. See [`SearchIndexBuilder::synonyms`].
+///
+/// There are two kinds of rules:
+///
+/// * Equivalent synonyms, such as `couch, sofa, settee`. Records are also
+///   indexed under every other keyword in the group, so searching for any of
+///   them finds the others.
+///
+/// * Explicit mappings, such as `colour, color => colour`. Keywords on the
+///   left are replaced with the keywords on the right, both when indexing and
+///   when searching.
+///
+/// Rules are matched against single keywords, after a string has been split.
+///
+/// Basic usage:
+///
+/// ```rust
+/// # use indicium::simple::Synonyms;
+/// # use pretty_assertions::assert_eq;
+/// #
+/// let synonyms = Synonyms::parse(
+///     "# Furniture\ncouch, sofa, settee\ncolour, color => colour\n"
+/// );
+///
+/// assert_eq!(synonyms.to_string(), "couch, sofa, settee\ncolour, color => colour\n");
+/// ```
+///
+/// [`SearchIndexBuilder::synonyms`]: struct.SearchIndexBuilder.html#method.synonyms
+
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct Synonyms {
+    /// The synonym rules, in the order they were given.
+    pub rules: Vec<SynonymRule>,
+} // Synonyms
+
+// -----------------------------------------------------------------------------
+//
+/// A single synonym rule. See [`Synonyms`].
+///
+/// [`Synonyms`]: struct.Synonyms.html
+
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum SynonymRule {
+    /// Keywords that are equivalent to each other, such as
+    /// `couch, sofa, settee`.
+    Equivalent(Vec<String>),
+    /// Keywords that are replaced with other keywords, such as
+    /// `colour, color => colour`.
+    Explicit {
+        /// The keywords that are replaced.
+        from: Vec<String>,
+        /// The keywords that replace them.
+        to: Vec<String>,
+    },
+} // SynonymRule
+
+// -----------------------------------------------------------------------------
+
+impl Synonyms {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Parses synonym rules in the Solr `synonyms.txt` format. Each line is a
+    /// rule. Blank lines and lines starting with `#` are skipped. Commas
+    /// separate keywords and `=>` separates an explicit mapping. Commas may be
+    /// escaped as `\,`.
+
+    pub fn parse(text: &str) -> Self {
+        let rules: Vec<SynonymRule> = text
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+            .filter_map(|line| match line.split_once("=>") {
+                Some((from, to)) => {
+                    let (from, to) = (split_keywords(from), split_keywords(to));
+                    (!from.is_empty() && !to.is_empty()).then_some(SynonymRule::Explicit { from, to })
+                }, // Some
+                None => {
+                    let keywords = split_keywords(line);
+                    (keywords.len() > 1).then_some(SynonymRule::Equivalent(keywords))
+                }, // None
+            }) // filter_map
+            .collect();
+
+        Synonyms { rules }
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Reads and parses a Solr `synonyms.txt` file. See `parse`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error::Io` error if the file can't be read.
+
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self, Error> {
+        Ok(Self::parse(&std::fs::read_to_string(path)?))
+    } // fn
+
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl Display for Synonyms {
+    /// Writes the rules in the Solr `synonyms.txt` format.
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> std::fmt::Result {
+        let join = |keywords: &[String]| keywords
+            .iter()
+            .map(|keyword| keyword.replace(',', "\\,"))
+            .collect::<Vec<String>>()
+            .join(", ");
+
+        self.rules.iter().try_for_each(|rule| match rule {
+            SynonymRule::Equivalent(keywords) => writeln!(formatter, "{}", join(keywords)),
+            SynonymRule::Explicit { from, to } => writeln!(formatter, "{} => {}", join(from), join(to)),
+        }) // try_for_each
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+//
+/// The synonym rules, normalized once when the search index is built and keyed
+/// by keyword. Each keyword maps to the output of the first rule that matches
+/// it, so applying the rules to a keyword is a single lookup.
+
+#[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
+pub(crate) struct SynonymMap {
+    /// The keywords that replace each keyword when indexing. This includes
+    /// both explicit mappings and equivalent synonyms.
+    indexing: BTreeMap<KString, Vec<KString>>,
+    /// The keywords that replace each keyword when searching. Only explicit
+    /// mappings are applied to search keywords.
+    searching: BTreeMap<KString, Vec<KString>>,
+} // SynonymMap
+
+// -----------------------------------------------------------------------------
+
+impl SynonymMap {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Normalizes the synonym rules. Unless the search index is case
+    /// sensitive, the keywords of the rules are lowercased.
+
+    pub(crate) fn new(synonyms: &Synonyms, case_sensitive: bool) -> Self {
+        let normalize = |entry: &String| match case_sensitive {
+            true => KString::from_ref(entry),
+            false => KString::from(entry.to_lowercase()),
+        }; // closure
+
+        let mut synonym_map = SynonymMap::default();
+
+        // Rules are visited in order, and the first rule that matches a
+        // keyword wins:
+        synonyms.rules.iter().for_each(|rule| match rule {
+            SynonymRule::Explicit { from, to } => {
+                let to: Vec<KString> = to.iter().map(normalize).collect();
+                from.iter().map(normalize).for_each(|keyword| {
+                    synonym_map.indexing.entry(keyword.clone()).or_insert_with(|| to.clone());
+                    synonym_map.searching.entry(keyword).or_insert_with(|| to.clone());
+                }); // for_each
+            }, // Explicit
+            SynonymRule::Equivalent(keywords) => {
+                let keywords: Vec<KString> = keywords.iter().map(normalize).collect();
+                keywords.iter().for_each(|keyword| {
+                    synonym_map.indexing.entry(keyword.clone()).or_insert_with(|| {
+                        // The keyword itself, followed by the other keywords
+                        // of the group:
+                        let mut expanded: Vec<KString> = vec![keyword.clone()];
+                        expanded.extend(keywords.iter().filter(|entry| *entry != keyword).cloned());
+                        expanded
+                    }); // or_insert_with
+                }); // for_each
+            }, // Equivalent
+        }); // for_each
+
+        synonym_map
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Applies the synonym rules to a keyword. For explicit mappings, the
+    /// keyword is replaced. For equivalent synonyms, the other keywords of the
+    /// group are added when indexing. Returns `None` if no rule matches.
+
+    pub(crate) fn apply(&self, keyword: &str, indexing: bool) -> Option<&[KString]> {
+        match indexing {
+            true => self.indexing.get(keyword),
+            false => self.searching.get(keyword),
+        }.map(Vec::as_slice)
+    } // fn
+
+} // impl
+
+// -----------------------------------------------------------------------------
+//
+/// Splits a comma-separated list of keywords, honouring `\,` escapes.
+
+fn split_keywords(list: &str) -> Vec<String> {
+    let mut keywords: Vec<String> = Vec::new();
+    let mut keyword = String::new();
+    let mut chars = list.chars();
+    while let Some(char) = chars.next() {
+        match char {
+            '\\' => if let Some(escaped) = chars.next() { keyword.push(escaped) },
+            ',' => keywords.push(std::mem::take(&mut keyword)),
+            _ => keyword.push(char),
+        } // match
+    } // while
+    keywords.push(keyword);
+
+    keywords
+        .into_iter()
+        .map(|keyword| keyword.trim().to_string())
+        .filter(|keyword| !keyword.is_empty())
+        .collect()
+} // fn
\ No newline at end of file
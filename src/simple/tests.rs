@@ -153,23 +153,35 @@ fn simple() {
     let autocomplete_options = search_index.autocomplete_type(&AutocompleteType::Context, "108");
     assert_eq!(autocomplete_options, vec!["1087".to_string()]);
 
-    // Test internal global fuzzy keyword search interface:
+    // Test internal global fuzzy keyword search interfaces:
     #[cfg(feature = "eddie")]
-    let similar_keyword = search_index.eddie_global_keyword(&"Willy".to_lowercase());
+    assert_eq!(
+        search_index.eddie_global_keyword(&"Willy".to_lowercase()),
+        Some(&KString::from_ref("william"))
+    );
     #[cfg(feature = "strsim")]
-    let similar_keyword = search_index.strsim_global_keyword(&"Willy".to_lowercase());
-    #[cfg(any(feature = "eddie", feature = "strsim"))]
-    assert_eq!(similar_keyword, Some(&KString::from_ref("william")));
+    assert_eq!(
+        search_index.strsim_global_keyword(&"Willy".to_lowercase()),
+        Some(&KString::from_ref("william"))
+    );
 
-    // Test internal global fuzzy autocompletion interface:
+    // Test internal global fuzzy autocompletion interfaces:
     #[cfg(feature = "eddie")]
-    let similar_autocompletions = search_index.eddie_global_autocomplete(&"Normy".to_lowercase());
+    assert_eq!(
+        search_index.eddie_global_autocomplete(&"Normy".to_lowercase())
+            .into_iter()
+            .map(|(keyword, _keys)| keyword)
+            .collect::<Vec<&KString>>(),
+        vec![&"norman".to_string()]
+    );
     #[cfg(feature = "strsim")]
-    let similar_autocompletions = search_index.strsim_global_autocomplete(&"Normy".to_lowercase());
-    #[cfg(any(feature = "eddie", feature = "strsim"))]
-    let similar_autocompletions_vec: Vec<&KString> = similar_autocompletions.into_iter().map(|(keyword, _keys)| keyword).collect();
-    #[cfg(any(feature = "eddie", feature = "strsim"))]
-    assert_eq!(similar_autocompletions_vec, vec![&"norman".to_string()]);
+    assert_eq!(
+        search_index.strsim_global_autocomplete(&"Normy".to_lowercase())
+            .into_iter()
+            .map(|(keyword, _keys)| keyword)
+            .collect::<Vec<&KString>>(),
+        vec![&"norman".to_string()]
+    );
 
     // Test `Indexable` trait implementation for `ToString` generics:
     let my_vec: Vec<&str> = vec![
@@ -250,4 +262,1520 @@ fn simple() {
     #[cfg(any(feature = "eddie", feature = "strsim"))]
     assert_eq!(autocomplete_options, vec!["stars are dancing".to_string()]);
 
+} // fn
+// -----------------------------------------------------------------------------
+
+#[test]
+fn non_ascii_fuzzy() {
+
+    use crate::simple::{AutocompleteType, SearchIndex, SearchType};
+    use pretty_assertions::assert_eq;
+
+    let mut search_index: SearchIndex<usize> = SearchIndex::default();
+
+    search_index.insert(&0, &"Георгий Жуков");
+    search_index.insert(&1, &"Александр Суворов");
+    search_index.insert(&2, &"🦀🦀🦀 crab");
+
+    // The fuzzy match length (3 characters) falls inside of a multi-byte
+    // character. None of these may panic:
+    let search_results = search_index.search_type(&SearchType::Live, "Жуклв");
+    #[cfg(any(feature = "eddie", feature = "strsim"))]
+    assert_eq!(search_results, vec![&0]);
+    #[cfg(not(any(feature = "eddie", feature = "strsim")))]
+    assert_eq!(search_results, Vec::<&usize>::new());
+
+    let search_results = search_index.search_type(&SearchType::Live, "crab 🦀🦀🦀🦀");
+    #[cfg(any(feature = "eddie", feature = "strsim"))]
+    assert_eq!(search_results, vec![&2]);
+    #[cfg(not(any(feature = "eddie", feature = "strsim")))]
+    assert_eq!(search_results, Vec::<&usize>::new());
+
+    let autocomplete_options = search_index.autocomplete_type(&AutocompleteType::Global, "Суварав");
+    #[cfg(any(feature = "eddie", feature = "strsim"))]
+    assert_eq!(autocomplete_options, vec!["суворов".to_string()]);
+    #[cfg(not(any(feature = "eddie", feature = "strsim")))]
+    assert_eq!(autocomplete_options, Vec::<String>::new());
+
+    let autocomplete_options = search_index.autocomplete_type(&AutocompleteType::Context, "Георгий Жукоф");
+    #[cfg(any(feature = "eddie", feature = "strsim"))]
+    assert_eq!(autocomplete_options, vec!["георгий жуков".to_string()]);
+    #[cfg(not(any(feature = "eddie", feature = "strsim")))]
+    assert_eq!(autocomplete_options, Vec::<String>::new());
+
+    // Keywords shorter than the fuzzy match length aren't fuzzy matched:
+    let autocomplete_options = search_index.autocomplete_type(&AutocompleteType::Keyword, "Жю");
+    assert_eq!(autocomplete_options, Vec::<String>::new());
+
+} // fn
+// -----------------------------------------------------------------------------
+
+#[test]
+fn frozen_index() {
+
+    use crate::simple::SearchIndex;
+    use pretty_assertions::assert_eq;
+
+    let mut search_index: SearchIndex<usize> = SearchIndex::default();
+
+    // Enough keywords with shared prefixes to span several front-coded blocks:
+    (0..100).for_each(|index| search_index.insert(&index, &format!("sku{index:03}")));
+
+    let frozen = search_index.freeze();
+
+    assert_eq!(frozen.len(), search_index.len());
+    assert!(frozen.keyword_bytes() < frozen.len() * "sku000".len());
+
+    (0..100).for_each(|index| assert_eq!(frozen.keys(&format!("SKU{index:03}")), &[index]));
+    assert_eq!(frozen.keys("sku"), &[] as &[usize]);
+    assert_eq!(frozen.keys("sku100"), &[] as &[usize]);
+    assert_eq!(frozen.keys("a"), &[] as &[usize]);
+
+    assert_eq!(
+        frozen.keywords_with_prefix("sku04", &3),
+        vec!["sku040".to_string(), "sku041".to_string(), "sku042".to_string()]
+    );
+    assert_eq!(frozen.keywords_with_prefix("sku", &usize::MAX).len(), 100);
+    assert_eq!(frozen.keywords_with_prefix("z", &10), Vec::<String>::new());
+
+} // fn
+// -----------------------------------------------------------------------------
+
+#[test]
+fn concurrent_batches() {
+
+    use crate::simple::{IndexBatch, SearchIndex, SearchIndexBuilder};
+    use kstring::KString;
+    use std::sync::{Arc, RwLock};
+
+    let search_index: Arc<RwLock<SearchIndex<usize>>> = Arc::new(RwLock::new(
+        SearchIndexBuilder::default()
+            .max_search_results(usize::MAX)
+            .max_keys_per_keyword(usize::MAX)
+            .build()
+    ));
+
+    // Each batch inserts a pair of records, so readers must never see an odd
+    // number of them:
+    let writer = {
+        let search_index = Arc::clone(&search_index);
+        std::thread::spawn(move || (0..200).for_each(|index| {
+            let mut batch = IndexBatch::new();
+            batch.insert(index * 2, &"pair");
+            batch.insert(index * 2 + 1, &"pair");
+            search_index.write().unwrap().apply(batch).unwrap();
+        })) // spawn
+    }; // writer
+
+    let readers: Vec<_> = (0..4)
+        .map(|_| {
+            let search_index = Arc::clone(&search_index);
+            std::thread::spawn(move || (0..500).for_each(|_| {
+                // Queries run under the read lock:
+                let results = search_index.read().unwrap().search("pair").len();
+                assert_eq!(results % 2, 0);
+
+                // Snapshots stay consistent after the lock is released:
+                let snapshot = search_index.read().unwrap().snapshot();
+                let keys = snapshot
+                    .get(&KString::from_static("pair"))
+                    .map_or(0, |keys| keys.len());
+                assert_eq!(keys % 2, 0);
+            })) // spawn
+        }) // map
+        .collect();
+
+    writer.join().unwrap();
+    readers.into_iter().for_each(|reader| reader.join().unwrap());
+
+    assert_eq!(search_index.read().unwrap().search("pair").len(), 400);
+
+} // fn
+// -----------------------------------------------------------------------------
+
+#[cfg(feature = "fuzzing")]
+#[test]
+fn fuzzing_inputs() {
+
+    use crate::fuzzing::{fuzz_index_and_query, fuzz_tokenize};
+
+    // Inputs with multi-byte characters, empty lines and invalid UTF-8:
+    let inputs: [&[u8]; 6] = [
+        b"",
+        b"\n\n\n",
+        "Æthelred\nÆ".as_bytes(),
+        "ǅ İ ﬃ ß\nİ".as_bytes(),
+        b"\xff\xfe bad \xc3\nbad \xc3",
+        "a  very-big bird\na  VERY-big bi".as_bytes(),
+    ];
+
+    inputs.iter().for_each(|input| {
+        fuzz_tokenize(input);
+        fuzz_index_and_query(input);
+    }); // for_each
+
+} // fn
+
+// -----------------------------------------------------------------------------
+
+#[test]
+fn persisted_bytes() {
+
+    use crate::simple::{LazyIndex, SearchIndex, SearchIndexBuilder};
+
+    let mut search_index: SearchIndex<String> = SearchIndex::default();
+    search_index.insert(&"harold".to_string(), &"Harold Godwinson");
+    search_index.insert(&"edgar".to_string(), &"Edgar Ætheling");
+    search_index.compact_whole_strings();
+
+    let bytes: Vec<u8> = search_index.to_bytes();
+    let loaded = SearchIndex::<String>::from_bytes(SearchIndexBuilder::default(), &bytes).unwrap();
+    assert_eq!(loaded.search("ætheling"), vec![&"edgar".to_string()]);
+    assert_eq!(loaded.autocomplete("harold g"), vec!["harold godwinson".to_string()]);
+
+    let lazy: LazyIndex<String> = LazyIndex::from_bytes(bytes.clone()).unwrap();
+    let keys = lazy.keys("Harold Godwinson").unwrap().unwrap();
+    assert_eq!(keys.iter().collect::<Vec<_>>(), vec![&"harold".to_string()]);
+
+    // Truncated or corrupted bytes are rejected rather than panicking:
+    (0..bytes.len()).for_each(|length| {
+        assert!(SearchIndex::<String>::from_bytes(SearchIndexBuilder::default(), &bytes[..length]).is_err());
+    }); // for_each
+    let mut corrupted = bytes.clone();
+    corrupted[6] = 0xff;
+    assert!(LazyIndex::<String>::from_bytes(corrupted).is_err());
+
+    // Compacted whole strings stay compacted through a lazy index:
+    let mut search_index: SearchIndex<usize> = SearchIndex::default();
+    search_index.insert(&0, &"Harold Godwinson");
+    search_index.insert(&1, &"Harold Hardrada");
+    assert_eq!(search_index.compact_whole_strings(), 2);
+    assert_eq!(search_index.len(), 4);
+
+    let lazy: LazyIndex<usize> = LazyIndex::from_bytes(search_index.to_bytes()).unwrap();
+    let mut loaded = lazy.into_search_index(SearchIndexBuilder::default()).unwrap();
+    assert_eq!(loaded.len(), 4);
+    assert_eq!(loaded.autocomplete("harold g"), vec!["harold godwinson".to_string()]);
+
+    // Whole strings that weren't compacted are still known, so compaction
+    // still works:
+    loaded.insert(&2, &"Harold Bluetooth");
+    let lazy: LazyIndex<usize> = LazyIndex::from_bytes(loaded.to_bytes()).unwrap();
+    let mut loaded = lazy.into_search_index(SearchIndexBuilder::default()).unwrap();
+    assert_eq!(loaded.compact_whole_strings(), 1);
+    assert_eq!(loaded.len(), 5);
+    assert_eq!(loaded.autocomplete("harold b"), vec!["harold bluetooth".to_string()]);
+
+} // fn
+
+// -----------------------------------------------------------------------------
+
+#[cfg(feature = "zstd")]
+#[test]
+fn compressed_persistence() {
+
+    use crate::simple::{Compression, LazyIndex, SearchIndex, SearchIndexBuilder};
+
+    let search_index: SearchIndex<usize> = (0..500)
+        .map(|key| (key, format!("Monarch number {key} of the House of Wessex")))
+        .collect();
+
+    let mut compressed: Vec<u8> = Vec::new();
+    search_index.write_to(&mut compressed, Compression::Zstd(3)).unwrap();
+    assert!(compressed.len() * 2 < search_index.to_bytes().len());
+
+    let loaded = SearchIndex::<usize>::read_from(SearchIndexBuilder::default(), compressed.as_slice()).unwrap();
+    assert_eq!(loaded.search("wessex").len(), 500);
+    assert_eq!(loaded.search("250"), vec![&250]);
+
+    let lazy = LazyIndex::<usize>::read_from(compressed.as_slice()).unwrap();
+    assert_eq!(lazy.keys("499").unwrap().map(|keys| keys.len()), Some(1));
+
+    // Truncated compressed data is rejected:
+    assert!(SearchIndex::<usize>::read_from(SearchIndexBuilder::default(), &compressed[..compressed.len() / 2]).is_err());
+
+} // fn
+
+// -----------------------------------------------------------------------------
+
+#[test]
+fn solr_config_files() {
+
+    use crate::simple::{SearchIndex, SearchIndexBuilder};
+
+    let directory = std::env::temp_dir().join(format!("indicium-solr-{}", std::process::id()));
+    std::fs::create_dir_all(&directory).unwrap();
+    let stop_words = directory.join("stopwords.txt");
+    let synonyms = directory.join("synonyms.txt");
+    std::fs::write(&stop_words, "# Stop words\nthe a\nof | preposition\n").unwrap();
+    std::fs::write(&synonyms, "# Synonyms\nking, monarch\nengland, wessex => england\n").unwrap();
+
+    let mut search_index: SearchIndex<usize> = SearchIndexBuilder::default()
+        .exclude_keywords(None)
+        .stop_words_file(&stop_words).unwrap()
+        .synonyms_file(&synonyms).unwrap()
+        .build();
+
+    search_index.insert(&0, &"The King of Wessex");
+
+    assert!(search_index.search("the").is_empty());
+    assert_eq!(search_index.search("monarch"), vec![&0]);
+    assert_eq!(search_index.search("england"), vec![&0]);
+    assert_eq!(search_index.export_stop_words(), "the\na\nof\n");
+    assert_eq!(search_index.export_synonyms(), "king, monarch\nengland, wessex => england\n");
+
+    assert!(SearchIndexBuilder::<usize>::default().stop_words_file(directory.join("missing.txt")).is_err());
+    std::fs::remove_dir_all(&directory).unwrap();
+
+} // fn
+
+// -----------------------------------------------------------------------------
+
+#[test]
+fn replace_leaves_no_empty_keywords() {
+
+    use crate::simple::SearchIndex;
+
+    let mut search_index: SearchIndex<usize> = SearchIndex::default();
+
+    // Replace and re-insert the same keys many times:
+    for round in 0..50 {
+        let before = format!("William Harold{round} Edgar");
+        let after = format!("William Harold{} Henry{round}", round + 1);
+        search_index.insert(&(round % 5), &before);
+        search_index.replace(&(round % 5), &before, &after);
+        search_index.insert(&(round % 5), &format!("Stephen{round}"));
+    } // for
+
+    assert!(search_index.values().all(|keys| !keys.is_empty()));
+    assert!(search_index.gc().is_empty());
+
+} // fn
+
+
+// -----------------------------------------------------------------------------
+
+#[test]
+fn autocomplete_filtering_matrix() {
+
+    use crate::simple::{AutocompleteType, SearchIndex, SearchIndexBuilder};
+
+    for compacted in [false, true] {
+        let mut search_index: SearchIndex<usize> = SearchIndexBuilder::default()
+            .dump_keyword(Some("al".to_string()))
+            .exclude_keywords(None)
+            .build();
+
+        search_index.insert(&0, &"Alfred the Great");
+        search_index.insert(&1, &"Albion");
+        search_index.insert(&2, &"Also");
+        search_index.insert(&3, &"Alcuin of York");
+
+        // Exclude a keyword that's already in the search index:
+        let mut search_index: SearchIndex<usize> = SearchIndexBuilder::from(search_index)
+            .exclude_keywords(Some(vec!["also".to_string()]))
+            .build();
+
+        search_index.set_suggestion_filter(|keyword| !keyword.starts_with("albion"));
+        if compacted { search_index.compact_whole_strings(); }
+
+        for autocomplete_type in [
+            AutocompleteType::Context,
+            AutocompleteType::Global,
+            AutocompleteType::Hybrid,
+            AutocompleteType::Keyword,
+        ] {
+            let autocompletions = search_index.autocomplete_type(&autocomplete_type, "al");
+            assert!(
+                autocompletions.iter().any(|autocompletion| autocompletion.starts_with("alfred")),
+                "{autocomplete_type:?} {autocompletions:?}",
+            ); // assert!
+            assert!(
+                autocompletions.iter().all(|autocompletion|
+                    autocompletion != "al" && autocompletion != "also" && !autocompletion.starts_with("albion")
+                ), // all
+                "{autocomplete_type:?} {autocompletions:?}",
+            ); // assert!
+        } // for
+    } // for
+
+} // fn
+
+
+// -----------------------------------------------------------------------------
+
+#[test]
+fn interned_keys() {
+
+    use crate::simple::{InternedIndex, SearchIndexBuilder};
+
+    let mut search_index: InternedIndex<String> = SearchIndexBuilder::default().build_interned();
+
+    search_index.insert(&"harold".to_string(), &"Harold Godwinson");
+    search_index.insert(&"william".to_string(), &"William the Conqueror");
+    search_index.replace(&"william".to_string(), &"William the Conqueror", &"William Rufus");
+    assert_eq!(search_index.search("rufus"), vec!["william"]);
+    assert!(search_index.search("conqueror").is_empty());
+
+    // The id of a removed key is reused:
+    search_index.remove(&"harold".to_string(), &"Harold Godwinson");
+    search_index.insert(&"edgar".to_string(), &"Edgar Ætheling");
+    assert_eq!(search_index.key_id(&"edgar".to_string()), Some(0));
+    assert_eq!(search_index.key(0), Some(&"edgar".to_string()));
+    assert_eq!(search_index.search("edgar"), vec!["edgar"]);
+    assert!(search_index.search("harold").is_empty());
+    assert_eq!(search_index.len(), 2);
+
+} // fn
+
+
+// -----------------------------------------------------------------------------
+
+#[test]
+fn rename_keyword_replicates() {
+
+    use crate::simple::{SearchIndex, SearchIndexBuilder};
+
+    let mut source: SearchIndex<usize> = SearchIndexBuilder::default()
+        .changelog(true)
+        .posting_timestamps(true)
+        .build();
+    let mut replica: SearchIndex<usize> = SearchIndex::default();
+
+    source.insert(&0, &"Widget Classic");
+    source.insert(&1, &"Widget Pro");
+    source.insert(&2, &"Gadget");
+    assert_eq!(source.rename_keyword("WIDGET", "gadget"), 2);
+    assert_eq!(source.alias_keyword("pro", "premium"), 1);
+    assert_eq!(source.rename_keyword("missing", "gadget"), 0);
+
+    replica.apply_ops(source.drain_ops()).unwrap();
+
+    for search_index in [&source, &replica] {
+        assert_eq!(search_index.search("gadget"), vec![&0, &1, &2]);
+        assert_eq!(search_index.search("premium"), vec![&1]);
+        assert_eq!(search_index.search("pro"), vec![&1]);
+        assert!(!search_index.contains_keyword("widget"));
+    } // for
+
+    // The moved keyword is detached when the record is inserted again:
+    source.insert(&0, &"Widget Classic");
+    assert!(!source.get("gadget").unwrap().contains(&0));
+    assert!(source.get("widget").unwrap().contains(&0));
+
+} // fn
+
+
+// -----------------------------------------------------------------------------
+
+#[test]
+fn autocomplete_at_cursor() {
+
+    use crate::simple::SearchIndex;
+
+    let mut search_index: SearchIndex<usize> = SearchIndex::default();
+    search_index.insert(&0, &"William the Conqueror");
+    search_index.insert(&1, &"Harold Godwinson");
+
+    // The cursor at the end behaves like `autocomplete_last_keyword`:
+    assert_eq!(
+        search_index.autocomplete_at("harold god", 10),
+        (7..10, search_index.autocomplete_last_keyword("harold god")),
+    );
+
+    // The cursor in the middle of a keyword completes its first part, and
+    // the text after the keyword is context:
+    assert_eq!(
+        search_index.autocomplete_at("harold goXX", 9),
+        (7..11, vec!["godwinson".to_string()]),
+    );
+    assert_eq!(
+        search_index.autocomplete_at("conqu william", 5),
+        (0..5, vec!["conqueror".to_string()]),
+    );
+
+    // The cursor after a separator, or inside a multi-byte character:
+    assert_eq!(search_index.autocomplete_at("harold ", 7), (7..7, Vec::new()));
+    assert_eq!(search_index.autocomplete_at("ælfred", 1), (0..0, Vec::new()));
+    assert_eq!(search_index.autocomplete_at("harold", 99).0, 0..6);
+
+} // fn
+
+
+// -----------------------------------------------------------------------------
+
+#[test]
+fn insert_without_whole_strings() {
+
+    use crate::simple::{IndexChange, InsertOptions, SearchIndex, SearchIndexBuilder};
+
+    let options = InsertOptions { index_whole_strings: false };
+
+    let mut source: SearchIndex<usize> = SearchIndexBuilder::default()
+        .changelog(true)
+        .build();
+    let mut replica: SearchIndex<usize> = SearchIndex::default();
+
+    source.insert(&0, &"Harold Godwinson");
+    source.insert_with(&0, &"Harold Godwinson", options);
+    assert!(!source.contains_keyword("harold godwinson"));
+    assert_eq!(source.search("godwinson"), vec![&0]);
+
+    let ops = source.drain_ops();
+    assert!(matches!(ops[1].change, IndexChange::InsertWith { options: recorded, .. } if recorded == options));
+    replica.apply_ops(ops).unwrap();
+    assert!(!replica.contains_keyword("harold godwinson"));
+    assert_eq!(replica.search("harold"), vec![&0]);
+
+} // fn
+
+
+// -----------------------------------------------------------------------------
+
+#[cfg(all(feature = "eddie", feature = "strsim"))]
+#[test]
+fn fuzzy_backend_choice() {
+
+    use crate::simple::{AutocompleteType, FuzzyBackendChoice, SearchIndex, SearchIndexBuilder};
+
+    for fuzzy_backend in [FuzzyBackendChoice::Eddie, FuzzyBackendChoice::Strsim, FuzzyBackendChoice::Auto] {
+        let mut search_index: SearchIndex<usize> = SearchIndexBuilder::default()
+            .fuzzy_backend(fuzzy_backend)
+            .autocomplete_type(AutocompleteType::Global)
+            .build();
+        search_index.insert(&0, &"William the Conqueror");
+        search_index.insert(&1, &"Harold Godwinson");
+
+        let active = search_index.choose_fuzzy_backend();
+        assert_ne!(active, FuzzyBackendChoice::Auto);
+        if fuzzy_backend != FuzzyBackendChoice::Auto { assert_eq!(active, fuzzy_backend) }
+        assert_eq!(search_index.runtime_stats().fuzzy_backend, active);
+
+        // Fuzzy matching works with either crate:
+        assert_eq!(search_index.autocomplete("wilkiam"), vec!["william".to_string()], "{fuzzy_backend:?}");
+        assert_eq!(search_index.runtime_stats().fuzzy_fallbacks, 1, "{fuzzy_backend:?}");
+    } // for
+
+} // fn
+
+
+// -----------------------------------------------------------------------------
+
+#[cfg(feature = "select2")]
+#[test]
+fn all_keys_without_dump_keyword() {
+
+    use crate::select2::Request;
+    use crate::simple::{DuplicateKeyPolicy, SearchIndex, SearchIndexBuilder};
+
+    // `Upsert` always keeps the reverse map, so `Accumulate` is used to test
+    // without it:
+    for reverse_map in [false, true] {
+        let mut search_index: SearchIndex<usize> = SearchIndexBuilder::default()
+            .duplicate_key_policy(DuplicateKeyPolicy::Accumulate)
+            .dump_keyword(None)
+            .reverse_map(reverse_map)
+            .build();
+
+        search_index.insert(&1, &"William Rufus");
+        search_index.insert(&0, &"William the Conqueror");
+        search_index.insert(&2, &"Henry Beauclerc");
+
+        assert_eq!(search_index.all_keys().collect::<Vec<&usize>>(), vec![&0, &1, &2]);
+        assert_eq!(search_index.all_keys().count(), search_index.record_count());
+
+        // An empty `Select2` query lists every record without a dump keyword:
+        let request = Request { term: None, q: None, request_type: None, page: None };
+        assert_eq!(search_index.search_select2(&request), vec![&0, &1, &2]);
+        assert_eq!(search_index.search_select2_excluding(&request, &[1]), vec![&0, &2]);
+
+        search_index.remove(&1, &"William Rufus");
+        assert_eq!(search_index.all_keys().collect::<Vec<&usize>>(), vec![&0, &2]);
+
+        // Keys only found in the compacted whole strings are still listed:
+        search_index.insert(&3, &"Harold Godwinson");
+        search_index.compact_whole_strings();
+        search_index.remove(&3, &"Harold");
+        search_index.remove(&3, &"Godwinson");
+        assert_eq!(search_index.all_keys().collect::<Vec<&usize>>(), vec![&0, &2, &3]);
+    } // for
+
+} // fn
+
+
+// -----------------------------------------------------------------------------
+
+#[test]
+fn prepared_query() {
+
+    use crate::simple::{SearchIndex, SearchType};
+    use pretty_assertions::assert_eq;
+
+    let mut search_index: SearchIndex<usize> = SearchIndex::default();
+
+    search_index.insert(&0, &"William the Conqueror");
+    search_index.insert(&1, &"William Rufus");
+    search_index.insert(&2, &"Henry Beauclerc, son of William the Conqueror");
+
+    let prepared_query = search_index.compile("Conqueror William conqueror");
+    assert_eq!(prepared_query.keywords().collect::<Vec<&str>>(), vec!["conqueror", "william"]);
+    assert_eq!(search_index.run(&prepared_query), vec![&0, &2]);
+
+    // "conqueror" now has more keys than "william", so the compiled order is
+    // no longer smallest-first:
+    search_index.remove(&1, &"William Rufus");
+    search_index.insert(&3, &"The Conqueror");
+    search_index.insert(&4, &"Conqueror of Rome");
+    assert_eq!(search_index.run(&prepared_query), vec![&0, &2]);
+    assert_eq!(
+        search_index.run(&prepared_query),
+        search_index.search_type(&SearchType::And, "Conqueror William"),
+    );
+
+    // Missing keywords, or no keywords, return no results:
+    assert!(search_index.run(&search_index.compile("William Rufus")).is_empty());
+    assert!(search_index.run(&search_index.compile("")).is_empty());
+
+} // fn
+
+
+// -----------------------------------------------------------------------------
+
+#[cfg(any(feature = "eddie", feature = "strsim"))]
+#[test]
+fn adaptive_fuzzy_length() {
+
+    use crate::simple::{SearchIndex, SearchIndexBuilder};
+    use pretty_assertions::assert_eq;
+
+    let mut search_index: SearchIndex<usize> = SearchIndexBuilder::default()
+        .adaptive_fuzzy_length(Some(3))
+        .build();
+
+    search_index.insert(&0, &"William");
+
+    // Sparse dictionary. Every keyword is a candidate, so a typo in the first
+    // letter is corrected:
+    assert_eq!(search_index.fuzzy_index_range("gilliam"), Some(""));
+    assert_eq!(search_index.search("gilliam"), vec![&0]);
+
+    // Dense dictionary. The range narrows until few enough keywords are in it,
+    // but never past `fuzzy_length`:
+    search_index.insert(&1, &"Wilfred");
+    search_index.insert(&2, &"Wilbur");
+    search_index.insert(&3, &"Winifred");
+    assert_eq!(search_index.fuzzy_index_range("wilkiam"), Some("wil"));
+    assert_eq!(search_index.fuzzy_index_range("zed"), Some("z"));
+    assert_eq!(search_index.fuzzy_index_range("wi"), None);
+
+    // Without an adaptive length, the `fuzzy_length` range is always used:
+    let mut fuzzy_config = search_index.fuzzy_config();
+    fuzzy_config.adaptive_length = None;
+    search_index.set_fuzzy_config(fuzzy_config).unwrap();
+    assert_eq!(search_index.fuzzy_index_range("zed"), Some("zed"));
+
+} // fn
+
+
+// -----------------------------------------------------------------------------
+
+#[cfg(any(feature = "eddie", feature = "strsim"))]
+#[test]
+fn fuzzy_first_letter_fallback() {
+
+    use crate::simple::{SearchIndex, SearchIndexBuilder};
+    use pretty_assertions::assert_eq;
+
+    for fuzzy_first_letter_fallback in [false, true] {
+        let mut search_index: SearchIndex<usize> = SearchIndexBuilder::default()
+            .fuzzy_first_letter_fallback(fuzzy_first_letter_fallback)
+            .build();
+
+        search_index.insert(&0, &"William the Conqueror");
+        search_index.insert(&1, &"Henry Beauclerc");
+
+        let expected: Vec<&usize> = match fuzzy_first_letter_fallback {
+            true => vec![&0],
+            false => vec![],
+        }; // match
+
+        // Transposed first letters:
+        assert_eq!(search_index.search("iwlliam"), expected);
+        assert_eq!(search_index.autocomplete("iwllia").is_empty(), !fuzzy_first_letter_fallback);
+
+        // Wrong first letter:
+        assert_eq!(search_index.search("gilliam"), expected);
+        assert_eq!(search_index.autocomplete("fenr").is_empty(), !fuzzy_first_letter_fallback);
+    } // for
+
+} // fn
+
+
+// -----------------------------------------------------------------------------
+
+#[test]
+fn keyword_enricher() {
+
+    use crate::simple::{SearchIndex, SearchIndexBuilder};
+    use pretty_assertions::assert_eq;
+
+    let mut search_index: SearchIndex<usize> = SearchIndexBuilder::default()
+        .exclude_keywords(Some(vec!["the".to_string()]))
+        .min_keyword_len(2)
+        .keyword_enricher(|keyword| vec![
+            keyword.to_uppercase(),
+            keyword.chars().rev().collect(),
+            "The".to_string(),
+            "x".to_string(),
+        ])
+        .build();
+
+    search_index.insert(&0, &"Harold Godwinson");
+
+    // Derived keywords are normalized, and held to the exclusion list & length
+    // limits:
+    assert_eq!(search_index.search("dloraH"), vec![&0]);
+    assert!(!search_index.contains_keyword("the"));
+    assert!(!search_index.contains_keyword("x"));
+    assert_eq!(
+        search_index.keywords_with_prefix("harold").collect::<Vec<&str>>(),
+        vec!["harold", "harold godwinson"],
+    );
+
+    // The enricher is only used when indexing:
+    search_index.set_keyword_enricher(|_keyword| vec!["harold".to_string()]);
+    assert!(search_index.search("william").is_empty());
+
+} // fn
+
+
+// -----------------------------------------------------------------------------
+
+#[cfg(feature = "transliteration")]
+#[test]
+fn transliteration() {
+
+    use crate::simple::{SearchIndex, SearchIndexBuilder, Transliteration, TransliterationDirection};
+    use pretty_assertions::assert_eq;
+
+    // Latin variants are indexed alongside the originals:
+    let mut search_index: SearchIndex<usize> = SearchIndexBuilder::default()
+        .transliteration(Some(Transliteration::default()))
+        .build();
+
+    search_index.insert(&0, &"Москва");
+    search_index.insert(&1, &"Αθήνα");
+    search_index.insert(&2, &"Moskva River");
+
+    assert_eq!(search_index.search("moskva"), vec![&0, &2]);
+    assert_eq!(search_index.search("athina"), vec![&1]);
+    assert_eq!(search_index.search("Москва"), vec![&0]);
+
+    // Only the Latin variants are indexed, and search keywords are
+    // transliterated to match them:
+    let mut search_index: SearchIndex<usize> = SearchIndexBuilder::default()
+        .transliteration(Some(Transliteration {
+            direction: TransliterationDirection::Indexing,
+            keep_originals: false,
+        }))
+        .max_string_len(None)
+        .build();
+
+    search_index.insert(&0, &"Москва");
+    search_index.insert(&2, &"Moskva River");
+
+    assert!(!search_index.contains_keyword("москва"));
+    assert_eq!(search_index.search("Москва"), vec![&0, &2]);
+    assert_eq!(search_index.search("moskva"), vec![&0, &2]);
+
+} // fn
+
+// -----------------------------------------------------------------------------
+
+#[test]
+fn keyword_cache() {
+
+    use crate::simple::internal::KeywordCache;
+    use crate::simple::{CharFilter, SearchIndex, SearchIndexBuilder};
+    use kstring::KString;
+    use pretty_assertions::assert_eq;
+    use std::sync::Arc;
+
+    let mut search_index: SearchIndex<usize> = SearchIndex::default();
+    search_index.insert(&0, &"Æthelred the Unready");
+
+    // Cache hits share the memoized keywords rather than copying them:
+    let keywords = search_index.query_keywords("unready");
+    assert_eq!(keywords.as_ref(), [KString::from("unready")]);
+    assert!(Arc::ptr_eq(&keywords, &search_index.query_keywords("unready")));
+
+    // Changing a setting discards the memoized keywords:
+    search_index.set_config_queries(search_index.query_config());
+    assert!(!Arc::ptr_eq(&keywords, &search_index.query_keywords("unready")));
+
+    // Changing how strings are split gives the new split:
+    let search_index: SearchIndex<usize> = SearchIndexBuilder::from(search_index)
+        .char_filter(Some(CharFilter::Deny(vec!['y'])))
+        .build();
+    assert_eq!(search_index.query_keywords("unready").as_ref(), [KString::from("unread")]);
+
+    // Racing misses keep the first keywords cached for a search string, and
+    // the least recently used search string is evicted:
+    let keyword_cache = KeywordCache::with_capacity(2);
+    let first: Arc<[KString]> = Arc::from(vec![KString::from("first")]);
+    let second: Arc<[KString]> = Arc::from(vec![KString::from("second")]);
+    assert!(Arc::ptr_eq(&keyword_cache.insert("a", Arc::clone(&first)), &first));
+    assert!(Arc::ptr_eq(&keyword_cache.insert("a", second), &first));
+    keyword_cache.insert("b", Arc::from(vec![KString::from("b")]));
+    keyword_cache.get("a");
+    keyword_cache.insert("c", Arc::from(vec![KString::from("c")]));
+    assert!(keyword_cache.get("a").is_some());
+    assert!(keyword_cache.get("b").is_none());
+    assert!(keyword_cache.get("c").is_some());
+
+} // fn
+
+// -----------------------------------------------------------------------------
+
+#[cfg(feature = "geo")]
+#[test]
+fn geo() {
+
+    use crate::simple::internal::GeoIndex;
+    use crate::simple::{SearchIndex, SearchIndexBuilder};
+    use pretty_assertions::assert_eq;
+
+    // Returns the keys of the records near a point, in key order:
+    let near = |search_index: &SearchIndex<usize>, latitude: f64, longitude: f64, radius_km: f64| -> Vec<usize> {
+        let mut keys: Vec<usize> = search_index
+            .search_near("cafe", latitude, longitude, radius_km)
+            .into_iter()
+            .map(|(key, _distance)| *key)
+            .collect();
+        keys.sort_unstable();
+        keys
+    }; // near
+
+    let mut search_index: SearchIndex<usize> = SearchIndex::default();
+    search_index.insert_with_location(&0, &"Cafe Suva", -18.1416, 178.4419);
+    search_index.insert_with_location(&1, &"Cafe Apia", -13.8333, -171.7500);
+    search_index.insert_with_location(&2, &"Cafe Longyearbyen", 78.2232, 15.6267);
+
+    // Locations are kept by a builder round trip:
+    let mut search_index: SearchIndex<usize> = SearchIndexBuilder::from(search_index)
+        .max_search_results(10)
+        .build();
+    assert_eq!(search_index.location(&2), Some((78.2232, 15.6267)));
+
+    // Grid cells on either side of the antimeridian are searched:
+    assert_eq!(near(&search_index, -16.0, 180.0, 1_000.0), vec![0, 1]);
+
+    // Extreme bounds don't overflow, and fall back to checking every location:
+    assert_eq!(near(&search_index, 90.0, 0.0, f64::MAX), vec![0, 1, 2]);
+    assert_eq!(near(&search_index, -90.0, 0.0, f64::INFINITY), vec![0, 1, 2]);
+    let geo_index: GeoIndex<usize> = GeoIndex::default();
+    assert!(geo_index.near(f64::MAX, f64::MIN, 1.0).is_empty());
+
+    // A record's location is kept by `replace`, and removed by `remove`:
+    search_index.replace(&2, &"Cafe Longyearbyen", &"Cafe Svalbard");
+    assert_eq!(near(&search_index, 78.0, 15.0, 100.0), vec![2]);
+    search_index.remove(&2, &"Cafe Svalbard");
+    assert_eq!(search_index.location(&2), None);
+    assert!(near(&search_index, 78.0, 15.0, 100.0).is_empty());
+
+} // fn
+
+// -----------------------------------------------------------------------------
+
+#[test]
+fn search_group_counts() {
+
+    use crate::simple::{SearchIndex, SearchIndexBuilder};
+    use pretty_assertions::assert_eq;
+
+    let mut search_index: SearchIndex<usize> = SearchIndexBuilder::default()
+        .max_search_results(2)
+        .build();
+
+    // Five matching keys in two groups, more than `maximum_search_results`:
+    (0..5).for_each(|key| search_index.insert(&key, &"Bach"));
+    search_index.insert(&5, &"Handel");
+
+    let group_counts = search_index.search_group_counts("bach", |key| key % 2);
+    assert_eq!(group_counts, vec![(0, 3, &0), (1, 2, &1)]);
+
+    // Only the number of groups is limited:
+    let group_counts = search_index.search_group_counts("bach", |key| *key);
+    assert_eq!(group_counts, vec![(0, 1, &0), (1, 1, &1)]);
+
+} // fn
+
+// -----------------------------------------------------------------------------
+
+#[test]
+fn suggestion_filter_warm_cache() {
+
+    use crate::simple::SearchIndex;
+    use pretty_assertions::assert_eq;
+
+    let mut search_index: SearchIndex<usize> = SearchIndex::default();
+    search_index.insert(&0, &"Cambodia");
+    search_index.insert(&1, &"Canada");
+
+    // Warm the cache, then set a filter that blocks a cached suggestion:
+    search_index.warm_up(&["c"]);
+    assert_eq!(search_index.autocomplete("c"), vec!["cambodia", "canada"]);
+
+    search_index.set_suggestion_filter(|keyword| keyword != "canada");
+    assert_eq!(search_index.autocomplete("c"), vec!["cambodia"]);
+
+    search_index.clear_suggestion_filter();
+    assert_eq!(search_index.autocomplete("c"), vec!["cambodia", "canada"]);
+
+} // fn
+
+// -----------------------------------------------------------------------------
+
+#[test]
+fn keyword_usage_tracks_indexed_keywords() {
+
+    use crate::simple::{SearchIndex, SearchIndexBuilder};
+    use kstring::KString;
+    use pretty_assertions::assert_eq;
+
+    let mut search_index: SearchIndex<usize> = SearchIndexBuilder::default()
+        .maximum_keywords(Some(10))
+        .build();
+    search_index.insert(&0, &"Bach");
+
+    // Searching for keywords that aren't in the search index doesn't grow the
+    // usage record:
+    search_index.search("bach mozart");
+    search_index.search("vivaldi");
+    assert_eq!(search_index.keyword_usage.last_hit(&KString::from_ref("mozart")), 0);
+    assert_eq!(search_index.keyword_usage.last_hit(&KString::from_ref("vivaldi")), 0);
+    assert!(search_index.keyword_usage.last_hit(&KString::from_ref("bach")) > 0);
+
+} // fn
+
+// -----------------------------------------------------------------------------
+
+#[test]
+fn front_map_sync() {
+
+    use crate::simple::{SearchIndex, SearchIndexBuilder};
+    use pretty_assertions::assert_eq;
+
+    let mut search_index: SearchIndex<usize> = SearchIndexBuilder::default()
+        .front_map(true)
+        .build();
+
+    search_index.insert(&0, &"Harold Godwinson");
+    search_index.insert(&1, &"Harold Hardrada");
+    search_index.remove(&0, &"Harold Godwinson");
+
+    // The front map holds the same keys as the `BTreeMap`, apart from the dump
+    // keyword, which always falls back to the `BTreeMap`:
+    let front_map = search_index.front_map.as_ref().unwrap();
+    assert_eq!(front_map.get("harold"), Some(search_index.b_tree_map.get("harold")));
+    assert_eq!(front_map.get("godwinson"), Some(None));
+    assert_eq!(front_map.get("\0"), None);
+    assert_eq!(search_index.search("harold"), vec![&1]);
+
+} // fn
+
+// -----------------------------------------------------------------------------
+
+#[test]
+fn shingles_of_adjacent_words() {
+
+    use crate::simple::{SearchIndex, SearchIndexBuilder, Synonyms};
+    use pretty_assertions::assert_eq;
+
+    let mut search_index: SearchIndex<usize> = SearchIndexBuilder::default()
+        .shingles(true)
+        .synonyms(Some(Synonyms::parse("couch, sofa")))
+        .build();
+    search_index.insert(&0, &"Brown Leather Couch");
+
+    // Only the words of the string are paired, not their synonyms:
+    let shingles: Vec<&str> = search_index.b_tree_map
+        .keys()
+        .map(|keyword| keyword.as_str())
+        .filter(|keyword| keyword.matches(' ').count() == 1)
+        .collect();
+    assert_eq!(shingles, vec!["brown leather", "leather couch"]);
+
+} // fn
+
+// -----------------------------------------------------------------------------
+
+#[test]
+fn shingles_with_plural_variants() {
+
+    use crate::simple::{SearchIndex, SearchIndexBuilder};
+    use pretty_assertions::assert_eq;
+
+    let mut search_index: SearchIndex<usize> = SearchIndexBuilder::default()
+        .shingles(true)
+        .plural_variants(true)
+        .build();
+    search_index.insert(&0, &"Leather Couches Sale");
+
+    // Plural variants are indexed, but no shingle crosses from an original
+    // keyword to a variant, or from one variant to another:
+    assert!(search_index.b_tree_map.contains_key("couch"));
+    let shingles: Vec<&str> = search_index.b_tree_map
+        .keys()
+        .map(|keyword| keyword.as_str())
+        .filter(|keyword| keyword.matches(' ').count() == 1)
+        .collect();
+    assert_eq!(shingles, vec!["couches sale", "leather couches"]);
+
+} // fn
+
+// -----------------------------------------------------------------------------
+
+#[test]
+fn merge_keyword_remove_and_cap() {
+
+    use crate::simple::{SearchIndex, SearchIndexBuilder};
+    use pretty_assertions::assert_eq;
+
+    // Removing a record detaches its key from the keyword it was merged into:
+    let mut search_index: SearchIndex<usize> = SearchIndex::default();
+    search_index.insert(&0, &"Colour television");
+    search_index.insert(&1, &"Color printer");
+    assert_eq!(search_index.alias_keyword("colour", "color"), 1);
+    search_index.remove(&0, &"Colour television");
+    assert_eq!(search_index.search("color"), vec![&1]);
+
+    assert_eq!(search_index.rename_keyword("printer", "copier"), 1);
+    search_index.remove(&1, &"Color printer");
+    assert!(search_index.is_empty());
+
+    // The merged keys observe the maximum number of keys per keyword:
+    let mut search_index: SearchIndex<usize> = SearchIndexBuilder::default()
+        .max_keys_per_keyword(2)
+        .build();
+    (0..3).for_each(|key| search_index.insert(&key, &"Widget"));
+    search_index.insert(&3, &"Gadget");
+    assert_eq!(search_index.alias_keyword("widget", "gadget"), 1);
+    assert_eq!(search_index.search("gadget"), vec![&0, &3]);
+    assert!(search_index.saturated_keywords().contains(&"gadget"));
+
+} // fn
+
+// -----------------------------------------------------------------------------
+
+#[test]
+fn interned_keys_accumulate() {
+
+    use crate::simple::{DuplicateKeyPolicy, InternedIndex, SearchIndexBuilder};
+    use pretty_assertions::assert_eq;
+
+    let mut search_index: InternedIndex<String> = SearchIndexBuilder::default()
+        .duplicate_key_policy(DuplicateKeyPolicy::Accumulate)
+        .build_interned();
+
+    // The id isn't released while the key's other value is still indexed:
+    search_index.insert(&"harold".to_string(), &"Harold Godwinson");
+    search_index.insert(&"harold".to_string(), &"Earl of Wessex");
+    search_index.remove(&"harold".to_string(), &"Harold Godwinson");
+    search_index.insert(&"edgar".to_string(), &"Edgar Ætheling");
+    assert_eq!(search_index.key_id(&"edgar".to_string()), Some(1));
+    assert_eq!(search_index.search("wessex"), vec!["harold"]);
+
+    // Once its last value is removed, the id is reused:
+    search_index.remove(&"harold".to_string(), &"Earl of Wessex");
+    search_index.insert(&"william".to_string(), &"William Rufus");
+    assert_eq!(search_index.key_id(&"william".to_string()), Some(0));
+    assert!(search_index.search("wessex").is_empty());
+
+} // fn
+
+// -----------------------------------------------------------------------------
+
+#[test]
+fn lookup_keys_without_reverse_map() {
+
+    use crate::simple::{DuplicateKeyPolicy, SearchIndex, SearchIndexBuilder};
+    use pretty_assertions::assert_eq;
+
+    for dump_keyword in [Some("\0".to_string()), None] {
+        let mut search_index: SearchIndex<u32> = SearchIndexBuilder::default()
+            .duplicate_key_policy(DuplicateKeyPolicy::Accumulate)
+            .reverse_map(false)
+            .dump_keyword(dump_keyword)
+            .build();
+        search_index.insert(&1001, &"Harold Godwinson");
+        search_index.insert(&1002, &"Edgar Ætheling");
+        search_index.remove(&1002, &"Edgar Ætheling");
+        assert_eq!(search_index.lookup_keys("1002 1001"), vec![&1001]);
+    } // for
+
+} // fn
+
+// -----------------------------------------------------------------------------
+
+#[test]
+fn compacted_whole_strings_are_seen() {
+
+    use crate::simple::{AutocompleteType, SearchIndex, SearchIndexBuilder, SearchType};
+    use pretty_assertions::assert_eq;
+    use std::ops::Deref;
+
+    let builder = || SearchIndexBuilder::default()
+        .autocomplete_type(AutocompleteType::Global)
+        .shingles(true)
+        .interior_autocomplete(true);
+
+    let mut search_index: SearchIndex<usize> = builder().build();
+    search_index.insert(&0, &"William the Conqueror");
+    search_index.insert(&1, &"Harold Godwinson");
+    let fingerprint = search_index.fingerprint();
+    let keyword_count = search_index.keyword_count();
+
+    // Only the whole strings are compacted, not the shingles:
+    assert_eq!(search_index.compact_whole_strings(), 2);
+    assert!(search_index.deref().contains_key("william conqueror"));
+    assert!(!search_index.deref().contains_key("william the conqueror"));
+
+    // Every reader still sees the compacted keywords:
+    assert_eq!(search_index.fingerprint(), fingerprint);
+    assert_eq!(search_index.keyword_count(), keyword_count);
+    assert!(search_index.contains_keyword("William the Conqueror"));
+    assert_eq!(search_index.count_keyword("harold godwinson"), 1);
+    assert_eq!(
+        search_index.keywords_with_prefix("harold").collect::<Vec<&str>>(),
+        vec!["harold", "harold godwinson"]
+    );
+    assert_eq!(search_index.search_type(&SearchType::Keyword, "Harold Godwinson"), vec![&1]);
+    assert!(search_index.autocomplete("conq").contains(&"william the conqueror".to_string()));
+    assert_eq!(search_index.snapshot().whole_strings().len(), 2);
+    assert_eq!(search_index.freeze().keys("harold godwinson"), &[1]);
+
+    // Round trips keep the keywords compacted:
+    let rebuilt: SearchIndex<usize> = SearchIndexBuilder::from(search_index.clone()).build();
+    assert_eq!(rebuilt.len(), search_index.len());
+    assert_eq!(rebuilt.fingerprint(), fingerprint);
+    let mut loaded: SearchIndex<usize> =
+        SearchIndex::from_bytes(builder(), &search_index.to_bytes()).unwrap();
+    assert_eq!(loaded.len(), search_index.len());
+    assert_eq!(loaded.fingerprint(), fingerprint);
+
+    // And remember which keywords are whole strings:
+    loaded.insert(&1, &"Harold Godwinson, Wessex");
+    assert!(loaded.deref().contains_key("harold godwinson"));
+    assert_eq!(loaded.compact_whole_strings(), 1);
+
+} // fn
+
+// -----------------------------------------------------------------------------
+
+#[test]
+fn runtime_stats_count_every_autocomplete() {
+
+    use crate::simple::SearchIndex;
+    use pretty_assertions::assert_eq;
+
+    let mut search_index: SearchIndex<usize> = SearchIndex::default();
+    search_index.insert(&0, &"William the Conqueror");
+    search_index.warm_up(&["will"]);
+    let autocompletes = search_index.runtime_stats().autocompletes;
+
+    // Warm cache hits and filtered autocompletions are counted too:
+    assert_eq!(search_index.autocomplete("will").len(), 2);
+    assert_eq!(search_index.autocomplete_filtered("conq", |_key| true).len(), 1);
+    assert_eq!(search_index.runtime_stats().autocompletes, autocompletes + 2);
+
+} // fn
+
+// -----------------------------------------------------------------------------
+
+#[test]
+fn update_field_keeps_other_fields() {
+
+    use crate::simple::{Indexable, SearchIndex, SearchIndexBuilder};
+    use crate::Error;
+    use pretty_assertions::assert_eq;
+
+    struct Article(&'static str, &'static str);
+
+    impl Indexable for Article {
+        fn strings(&self) -> Vec<String> {
+            vec![self.0.to_string(), self.1.to_string()]
+        }
+        fn field_names(&self) -> Vec<String> {
+            vec!["title".to_string(), "body".to_string()]
+        }
+    }
+
+    let mut search_index: SearchIndex<usize> = SearchIndexBuilder::default()
+        .changelog(true)
+        .build();
+    search_index.insert(&0, &Article("Harold of Wessex", "Earl of Wessex, then king"));
+    search_index.insert(&1, &"Harold Hardrada");
+
+    // "wessex" is still produced by the body, so it stays attached:
+    let report = search_index.update_field(&0, "title", "Harold Godwinson").unwrap();
+    let mut fresh: SearchIndex<usize> = SearchIndex::default();
+    assert_eq!(Ok(report), fresh.try_insert(&0, &Article("Harold Godwinson", "Earl of Wessex, then king")).map_err(|_error| ()));
+    assert_eq!(search_index.search("wessex"), vec![&0]);
+    assert_eq!(search_index.search("godwinson"), vec![&0]);
+    assert!(!search_index.contains_keyword("harold of wessex"));
+    assert_eq!(search_index.search("harold"), vec![&0, &1]);
+
+    // Records without named fields, and unknown fields, can't be updated:
+    assert!(matches!(search_index.update_field(&1, "title", "Harald"), Err(Error::UnknownField { .. })));
+    assert!(matches!(search_index.update_field(&0, "summary", "King"), Err(Error::UnknownField { .. })));
+
+    // The update is replicated:
+    let mut replica: SearchIndex<usize> = SearchIndex::default();
+    replica.apply_ops(search_index.drain_ops()).unwrap();
+    assert_eq!(replica.search("godwinson"), vec![&0]);
+    assert!(!replica.contains_keyword("harold of wessex"));
+
+    // Removing the record forgets its fields:
+    search_index.remove(&0, &Article("Harold Godwinson", "Earl of Wessex, then king"));
+    assert!(search_index.search("wessex").is_empty());
+    assert!(search_index.update_field(&0, "title", "Harold").is_err());
+
+} // fn
+
+// -----------------------------------------------------------------------------
+
+#[test]
+fn rejected_postings_are_not_stamped() {
+
+    use crate::simple::{SearchIndex, SearchIndexBuilder};
+    use std::time::{Duration, SystemTime};
+
+    let mut search_index: SearchIndex<usize> = SearchIndexBuilder::default()
+        .posting_timestamps(true)
+        .max_keys_per_keyword(1)
+        .build();
+
+    search_index.insert(&0, &"apple");
+    search_index.insert(&1, &"apple banana");
+    search_index.insert_at(&2, &"apple", SystemTime::now());
+    assert_eq!(search_index.get("apple").unwrap().len(), 1);
+
+    // The rejected postings have no timestamps:
+    let posting_times = search_index.posting_times.as_ref().unwrap();
+    assert!(!posting_times[&1].contains_key("apple"));
+    assert!(!posting_times.contains_key(&2));
+
+    // Only `apple` for 0, and `banana` and `apple banana` for 1 were attached:
+    let cutoff = SystemTime::now() + Duration::from_secs(60);
+    assert_eq!(search_index.decay(cutoff), 3);
+    assert!(search_index.get("apple").is_none());
+    assert!(search_index.get("banana").is_none());
+
+} // fn
+
+// -----------------------------------------------------------------------------
+
+#[test]
+fn solr_config_files_are_normalized() {
+
+    use crate::simple::{SearchIndex, SearchIndexBuilder, Synonyms};
+
+    let directory = std::env::temp_dir().join(format!("indicium-solr-case-{}", std::process::id()));
+    std::fs::create_dir_all(&directory).unwrap();
+    let stop_words = directory.join("stopwords.txt");
+    std::fs::write(&stop_words, "The\nOF\n").unwrap();
+
+    let mut search_index: SearchIndex<usize> = SearchIndexBuilder::default()
+        .exclude_keywords(None)
+        .stop_words_file(&stop_words).unwrap()
+        .synonyms(Some(Synonyms::parse("King, Monarch\nmonarch => ruler\nWessex => England")))
+        .build();
+
+    search_index.insert(&0, &"The King of Wessex");
+
+    assert!(search_index.search("the").is_empty());
+    assert!(search_index.search("of").is_empty());
+    assert!(search_index.contains_keyword("monarch"));
+    assert_eq!(search_index.search("england"), vec![&0]);
+    assert!(!search_index.contains_keyword("wessex"));
+
+    // When searching, equivalent synonyms are skipped and the explicit
+    // mapping that follows them applies:
+    assert!(search_index.search("ruler").is_empty());
+    search_index.insert(&1, &"Ruler");
+    assert_eq!(search_index.search("monarch"), vec![&1]);
+
+    std::fs::remove_dir_all(&directory).unwrap();
+
+} // fn
+
+// -----------------------------------------------------------------------------
+
+#[test]
+fn pinned_suggestions_are_filtered() {
+
+    use crate::simple::{SearchIndex, SearchIndexBuilder};
+
+    let mut search_index: SearchIndex<usize> = SearchIndexBuilder::default()
+        .suggestion_blocklist(Some(vec!["wicked".to_string()]))
+        .build();
+    search_index.insert(&0, &"William the Conqueror");
+
+    // The same suggestion pinned to several prefixes is only shown once:
+    search_index.pin_suggestion("w", "william the conqueror");
+    search_index.pin_suggestion("w", "wessex");
+    search_index.pin_suggestion("wi", "william the conqueror");
+    search_index.pin_suggestion("wi", "wicked king");
+    assert_eq!(
+        search_index.autocomplete("wi"),
+        vec!["william the conqueror".to_string(), "william".to_string()],
+    );
+
+    // The suggestion filter also applies to pins:
+    search_index.set_suggestion_filter(|suggestion| !suggestion.contains("conqueror"));
+    assert_eq!(search_index.autocomplete("wi"), vec!["william".to_string()]);
+    search_index.clear_suggestion_filter();
+
+    // Pins survive a round trip through the builder:
+    let search_index: SearchIndex<usize> = SearchIndexBuilder::from(search_index).build();
+    assert_eq!(search_index.autocomplete("wi")[0], "william the conqueror");
+
+} // fn
+
+// -----------------------------------------------------------------------------
+
+#[test]
+fn builder_keeps_callbacks() {
+
+    use crate::simple::{SearchIndex, SearchIndexBuilder};
+    use std::sync::{Arc, Mutex};
+
+    let mut search_index: SearchIndex<usize> = SearchIndexBuilder::default()
+        .max_keys_per_keyword(1)
+        .build();
+
+    let saturated = Arc::new(Mutex::new(Vec::new()));
+    let log = saturated.clone();
+    search_index.on_saturation(move |keyword| log.lock().unwrap().push(keyword.to_string()));
+    search_index.set_suggestion_filter(|suggestion| suggestion != "harold");
+
+    // The callbacks survive a round trip through the builder:
+    let mut search_index: SearchIndex<usize> = SearchIndexBuilder::from(search_index).build();
+    search_index.insert(&0, &"King Harold");
+    search_index.insert(&1, &"King William");
+
+    assert_eq!(*saturated.lock().unwrap(), vec!["king".to_string()]);
+    assert!(search_index.autocomplete("har").is_empty());
+
+} // fn
+
+// -----------------------------------------------------------------------------
+
+#[test]
+fn interior_tokens_follow_the_keywords() {
+
+    use crate::simple::{AutocompleteType, SearchIndex, SearchIndexBuilder};
+    use std::ops::DerefMut;
+
+    let mut search_index: SearchIndex<usize> = SearchIndexBuilder::default()
+        .autocomplete_type(AutocompleteType::Global)
+        .interior_autocomplete(true)
+        .build();
+    search_index.insert(&0, &"William the Conqueror");
+    search_index.insert(&1, &"Harold Godwinson");
+
+    let interior = |search_index: &SearchIndex<usize>, keyword: &str| search_index
+        .interior_keywords(keyword)
+        .into_iter()
+        .map(|(keyword, _keys)| keyword.to_string())
+        .collect::<Vec<String>>();
+
+    assert_eq!(interior(&search_index, "conq"), vec!["william the conqueror"]);
+    assert_eq!(interior(&search_index, "god"), vec!["harold godwinson"]);
+    assert!(interior(&search_index, "will").is_empty());
+
+    // Compacted whole strings keep their interior tokens:
+    search_index.compact_whole_strings();
+    assert_eq!(interior(&search_index, "conq"), vec!["william the conqueror"]);
+
+    // Removed keywords lose them:
+    search_index.remove(&0, &"William the Conqueror");
+    assert!(interior(&search_index, "conq").is_empty());
+
+    // Changes through `DerefMut` can't be followed, so the keywords are
+    // scanned instead:
+    search_index.deref_mut().insert("edgar the aetheling".into(), [2].into());
+    assert_eq!(interior(&search_index, "aeth"), vec!["edgar the aetheling"]);
+
+} // fn
+
+// -----------------------------------------------------------------------------
+
+#[cfg(feature = "simd")]
+#[test]
+fn sorted_postings_match_the_and_search() {
+
+    use crate::simple::{SearchIndex, SearchIndexBuilder, SearchType};
+    use std::ops::DerefMut;
+
+    let mut search_index: SearchIndex<u32> = SearchIndexBuilder::default()
+        .sorted_postings(true)
+        .max_keys_per_keyword(40)
+        .build();
+    for key in 0..100_u32 {
+        let mut string = String::from("all");
+        if key % 2 == 0 { string.push_str(" even") }
+        if key % 3 == 0 { string.push_str(" third") }
+        if key % 7 == 0 { string.push_str(" seventh") }
+        search_index.insert(&key, &string);
+    } // for
+
+    let compare = |search_index: &SearchIndex<u32>, string: &str| assert_eq!(
+        search_index.search_and_simd(&25, string),
+        search_index.search_with(&SearchType::And, &25, string),
+        "{string}",
+    );
+
+    for string in ["all", "even", "third even", "seventh third all", "even odd", ""] {
+        compare(&search_index, string);
+    } // for
+    assert_eq!(search_index.search_and_simd(&3, "seventh even"), vec![&0, &14, &28]);
+
+    // Removed keys leave the sorted postings:
+    search_index.remove(&14, &"all even seventh");
+    assert_eq!(search_index.search_and_simd(&3, "seventh even"), vec![&0, &28, &42]);
+    compare(&search_index, "third even");
+
+    // Changes through `DerefMut` fall back to the `BTreeMap` until the
+    // sorted postings are rebuilt:
+    search_index.deref_mut().entry("seventh".into()).or_default().insert(14);
+    assert_eq!(search_index.search_and_simd(&3, "seventh even"), vec![&0, &28, &42]);
+    search_index.deref_mut().entry("even".into()).or_default().insert(14);
+    assert_eq!(search_index.search_and_simd(&3, "seventh even"), vec![&0, &14, &28]);
+    search_index.insert(&1, &"seventh");
+    assert_eq!(search_index.search_and_simd(&3, "seventh"), vec![&0, &1, &7]);
+    compare(&search_index, "seventh even");
+
+} // fn
+
+// -----------------------------------------------------------------------------
+
+#[test]
+fn rejected_batches_are_not_applied() {
+
+    use crate::simple::{IndexBatch, KeywordCapPolicy, SearchIndex, SearchIndexBuilder};
+    use crate::Error;
+
+    let mut search_index: SearchIndex<usize> = SearchIndexBuilder::default()
+        .maximum_keywords_per_record(Some(3))
+        .keyword_cap_policy(KeywordCapPolicy::Reject)
+        .build();
+    search_index.insert(&0, &"Harold Godwinson");
+    let before = search_index.clone();
+
+    // The rejected replacement comes after changes that would be accepted:
+    let mut batch = IndexBatch::new();
+    batch.remove(0, &"Harold Godwinson");
+    batch.insert(1, &"Edgar");
+    batch.replace(0, &"Harold Godwinson", &"Harold Godwinson, Earl of Wessex");
+    assert!(matches!(
+        search_index.apply(batch),
+        Err(Error::KeywordCapExceeded { maximum: 3, .. }),
+    ));
+    assert!(search_index == before);
+    assert_eq!(search_index.search("godwinson"), vec![&0]);
+    assert!(search_index.search("edgar").is_empty());
+
+    // Once the rejected change is left out, the batch is applied:
+    let mut batch = IndexBatch::new();
+    batch.insert(1, &"Edgar");
+    batch.replace(0, &"Harold Godwinson", &"Harold II");
+    search_index.apply(batch).unwrap();
+    assert_eq!(search_index.search("edgar"), vec![&1]);
+    assert_eq!(search_index.search("harold"), vec![&0]);
+
+} // fn
+
+// -----------------------------------------------------------------------------
+
+#[test]
+fn interned_keys_without_keywords() {
+
+    use crate::simple::{InternedIndex, KeywordCapPolicy, SearchIndexBuilder};
+    use pretty_assertions::assert_eq;
+
+    let mut search_index: InternedIndex<String> = SearchIndexBuilder::default()
+        .maximum_keywords_per_record(Some(3))
+        .keyword_cap_policy(KeywordCapPolicy::Reject)
+        .build_interned();
+
+    // Records that attach no keywords don't keep an id:
+    search_index.insert(&"blank".to_string(), &"");
+    search_index.insert(&"rejected".to_string(), &"Harold Godwinson, Earl of Wessex");
+    assert!(search_index.is_empty());
+    assert_eq!(search_index.key_id(&"rejected".to_string()), None);
+
+    search_index.insert(&"harold".to_string(), &"Harold");
+    assert_eq!(search_index.key_id(&"harold".to_string()), Some(0));
+
+    // Neither does a record that's replaced by one without keywords:
+    search_index.replace(&"harold".to_string(), &"Harold", &"");
+    assert!(search_index.is_empty());
+    search_index.insert(&"edgar".to_string(), &"Edgar");
+    assert_eq!(search_index.key_id(&"edgar".to_string()), Some(0));
+
+} // fn
+
+// -----------------------------------------------------------------------------
+
+#[cfg(feature = "select2")]
+#[test]
+fn select2_dump_settings() {
+
+    use crate::select2::dump::{DumpOptions, DumpOrder};
+    use crate::select2::Request;
+    use crate::simple::{SearchIndex, SearchIndexBuilder};
+    use crate::Error;
+
+    let mut search_index: SearchIndex<usize> = SearchIndex::default();
+    search_index.insert(&0, &"Canada");
+    search_index.insert(&1, &"Cameroon");
+    search_index.insert(&2, &"Cambodia");
+
+    let mut request = Request { term: None, q: None, request_type: None, page: None };
+    let insertion = DumpOptions { order: DumpOrder::Insertion, maximum_results: Some(2) };
+    let boost = DumpOptions { order: DumpOrder::Boost, maximum_results: Some(2) };
+
+    // Orders that can't be followed are rejected rather than ignored:
+    assert!(matches!(
+        search_index.search_select2_dump(&request, &insertion, |_key| false),
+        Err(Error::InvalidSetting { setting: "order", .. }),
+    ));
+    assert!(matches!(
+        search_index.search_select2_dump(&request, &boost, |_key| false),
+        Err(Error::InvalidSetting { setting: "order", .. }),
+    ));
+
+    // Boosted records are paged through, and promoted records come first:
+    let boosted = |search_index: &SearchIndex<usize>, request: &Request| search_index
+        .search_select2_dump_boosted(request, &boost, |key| *key == 2, |key| *key as f32)
+        .unwrap()
+        .into_iter()
+        .copied()
+        .collect::<Vec<usize>>();
+    assert_eq!(boosted(&search_index, &request), vec![2, 1]);
+    request.page = Some(2);
+    assert_eq!(boosted(&search_index, &request), vec![0]);
+    request.page = Some(3);
+    assert!(boosted(&search_index, &request).is_empty());
+
+    // With timestamps, records are listed in insertion order:
+    let mut search_index: SearchIndex<usize> = SearchIndexBuilder::default()
+        .posting_timestamps(true)
+        .build();
+    search_index.insert(&1, &"Cameroon");
+    request.page = None;
+    assert_eq!(search_index.search_select2_dump(&request, &insertion, |_key| false).unwrap(), vec![&1]);
+
 } // fn
\ No newline at end of file
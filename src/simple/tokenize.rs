@@ -0,0 +1,47 @@
+use crate::simple::internal::string_keywords::SplitContext;
+use crate::simple::{SearchIndex, TokenizeMode};
+use kstring::KString;
+use std::cmp::Ord;
+
+// -----------------------------------------------------------------------------
+
+impl<K: Ord> SearchIndex<K> {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns the keywords that the text would be split into with the search
+    /// index's current settings. This can be used to preview how a field will
+    /// be indexed, or how a query will be searched, for example in an "index
+    /// debugging" screen.
+    ///
+    /// Unlike searching, tokenizing doesn't use the keyword cache or record
+    /// keyword hits.
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # use indicium::simple::{SearchIndex, TokenizeMode};
+    /// # use pretty_assertions::assert_eq;
+    /// #
+    /// let search_index: SearchIndex<usize> = SearchIndex::default();
+    ///
+    /// assert_eq!(
+    ///     search_index.tokenize("The Bank of England", TokenizeMode::Indexing),
+    ///     vec!["bank", "england", "the bank of england"]
+    /// );
+    ///
+    /// assert_eq!(
+    ///     search_index.tokenize("The Bank of England", TokenizeMode::Searching),
+    ///     vec!["bank", "england"]
+    /// );
+    /// ```
+
+    #[tracing::instrument(level = "trace", name = "tokenize", skip(self))]
+    pub fn tokenize(&self, text: &str, mode: TokenizeMode) -> Vec<KString> {
+        match mode {
+            TokenizeMode::Indexing => self.split_string(text, SplitContext::Indexing),
+            TokenizeMode::Searching => self.limited_query_keywords(text),
+        } // match
+    } // fn
+
+} // impl
\ No newline at end of file
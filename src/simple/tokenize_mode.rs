@@ -0,0 +1,18 @@
+// -----------------------------------------------------------------------------
+//
+/// How a string is tokenized by [`SearchIndex::tokenize`]. Strings are split
+/// differently for indexing than for searching.
+///
+/// [`SearchIndex::tokenize`]: struct.SearchIndex.html#method.tokenize
+
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum TokenizeMode {
+    /// The keywords that a record's field would be indexed under. This
+    /// includes the whole string and shingles, if enabled.
+    #[default]
+    Indexing,
+    /// The keywords that a search string would be searched for, after the
+    /// query limits (see `maximum_query_length`) have been applied.
+    Searching,
+} // TokenizeMode
\ No newline at end of file
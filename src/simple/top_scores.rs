@@ -0,0 +1,240 @@
+// Conditionally select hash map type based on feature flags:
+#[cfg(feature = "gxhash")]
+type HashMap<K, V> = std::collections::HashMap<K, V, gxhash::GxBuildHasher>;
+#[cfg(all(feature = "ahash", not(feature = "gxhash")))]
+use ahash::HashMap;
+#[cfg(all(not(feature = "ahash"), not(feature = "gxhash")))]
+use std::collections::HashMap;
+
+// Static dependencies:
+use std::{clone::Clone, cmp::Ord, cmp::Ordering, cmp::PartialOrd, hash::Hash};
+
+// -----------------------------------------------------------------------------
+//
+/// Tracks the _n_ highest scoring items. This is what Indicium uses to rank
+/// `Or` search results, and it may be used to merge & re-rank the results of
+/// several searches (or several search indexes) the same way.
+///
+/// Scores only need to be `PartialOrd`, so floating-point scores may be used.
+/// Scores that can't be compared (such as `NaN`) are treated as equal. When
+/// scores are tied, the lowest items are kept and listed first, so the results
+/// don't depend on the order that items were inserted in.
+///
+/// Basic usage:
+///
+/// ```rust
+/// # use indicium::simple::TopScores;
+/// # use pretty_assertions::assert_eq;
+/// #
+/// let mut top_scores: TopScores<&str, f64> = TopScores::with_capacity(2);
+///
+/// top_scores.insert("harold", 0.5);
+/// top_scores.insert("edgar", 0.9);
+/// top_scores.insert("william", 0.7);
+/// // An item that's already tracked keeps its highest score:
+/// top_scores.insert("harold", 0.8);
+///
+/// assert_eq!(top_scores.results().collect::<Vec<_>>(), vec![("edgar", 0.9), ("harold", 0.8)]);
+/// ```
+
+#[derive(Clone, Debug)]
+pub struct TopScores<T: Hash + Ord, S: PartialOrd> {
+    /// Tracks the top _n_ scores.
+    top: HashMap<T, S>,
+    /// Tracks lowest of the top scores.
+    bottom: Option<(T, S)>,
+    /// Number of top scores to keep.
+    capacity: usize,
+} // TopScores
+
+// -----------------------------------------------------------------------------
+
+impl<T: Clone + Hash + Ord, S: Clone + PartialOrd> TopScores<T, S> {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Instantiates a new "top scores" struct with the caller provided
+    /// capacity. If the caller wants to track the "top 10 matches", the caller
+    /// would call `TopScores::with_capacity(10)`.
+
+    pub fn with_capacity(capacity: usize) -> Self {
+        TopScores {
+            top: HashMap::with_capacity_and_hasher(
+                capacity,
+                std::default::Default::default()
+            ), // HashMap
+            bottom: None,
+            capacity,
+        } // TopScores
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Attempts to insert the item & score into the top scores.
+    ///
+    /// If the top scores aren't full, or if the score beats the lowest top
+    /// score, the item is inserted and the lowest top score is dropped. If the
+    /// item is already in the top scores, it keeps the higher of its scores.
+
+    pub fn insert(&mut self, item: T, score: S) {
+
+        // The item is already tracked. Keep its highest score:
+        if let Some(top_score) = self.top.get_mut(&item) {
+            if score > *top_score {
+                *top_score = score;
+                // The lowest top score may have been raised:
+                self.bottom = None;
+            } // if
+            return
+        } // if
+
+        // Check if the `TopScores` struct has reached its maximum capacity:
+        if self.top.len() >= self.capacity {
+
+            // If the `TopScores` is at capacity and the lowest top score (the
+            // bottom) is currently unknown, find it:
+            if self.bottom.is_none() { self.find_bottom() }
+
+            // If the caller's item beats the lowest top score, replace it:
+            if let Some((bottom_item, bottom_score)) = &self.bottom {
+                if ranks_before((&item, &score), (bottom_item, bottom_score)) {
+                    self.top.remove(bottom_item);
+                    self.bottom = None;
+                    self.top.insert(item, score);
+                } // if
+            } // if
+
+        } else {
+
+            // The `TopScores` struct has not reached its capacity, we may
+            // blindly add the item & score without checking the lowest score:
+            self.top.insert(item, score);
+
+        } // if
+
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Finds and caches the lowest (or bottom) top score. Of the tied lowest
+    /// scores, the highest item is the bottom.
+
+    fn find_bottom(&mut self) {
+        self.bottom = self.top
+            .iter()
+            .min_by(|(a_item, a_score), (b_item, b_score)|
+                match ranks_before((a_item, a_score), (b_item, b_score)) {
+                    true => Ordering::Greater,
+                    false => Ordering::Less,
+                } // match
+            ) // min_by
+            .map(|(item, score)| (item.clone(), score.clone()));
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns the lowest of the top scores, if the top scores are full. Items
+    /// with this score or less won't be kept, so callers may use this to skip
+    /// scoring items that can't make it into the top scores.
+
+    pub fn threshold(&mut self) -> Option<&S> {
+        if self.top.len() < self.capacity {
+            return None
+        } // if
+        if self.bottom.is_none() { self.find_bottom() }
+        self.bottom.as_ref().map(|(_item, score)| score)
+    } // fn
+
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl<T: Hash + Ord, S: PartialOrd> TopScores<T, S> {
+
+    /// Returns the number of items in the top scores.
+    pub fn len(&self) -> usize {
+        self.top.len()
+    } // fn
+
+    /// Returns `true` if there are no items in the top scores.
+    pub fn is_empty(&self) -> bool {
+        self.top.is_empty()
+    } // fn
+
+    /// Returns the number of top scores that are kept.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns the top scoring items with their scores, in order of
+    /// descending score. Tied scores are in order of item, ascending.
+
+    pub fn results(self) -> impl Iterator<Item = (T, S)> {
+
+        // Dump the contents of the `HashMap` so that the top scores can be
+        // sorted:
+        let mut vec: Vec<(T, S)> = self.top
+            .into_iter()
+            .collect();
+
+        vec.sort_unstable_by(|(a_item, a_score), (b_item, b_score)|
+            b_score
+                .partial_cmp(a_score)
+                .unwrap_or(Ordering::Equal)
+                .then_with(|| a_item.cmp(b_item))
+        ); // sort_unstable_by
+
+        vec.into_iter()
+
+    } // fn
+
+} // impl
+
+// -----------------------------------------------------------------------------
+//
+/// Merges items & scores into the top scores, for example the results of
+/// another `TopScores`.
+
+impl<T: Clone + Hash + Ord, S: Clone + PartialOrd> Extend<(T, S)> for TopScores<T, S> {
+    fn extend<I: IntoIterator<Item = (T, S)>>(&mut self, iter: I) {
+        iter.into_iter().for_each(|(item, score)| self.insert(item, score));
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+//
+/// Returns `true` if the first item & score is ranked before the second: it
+/// has a higher score or, if the scores are tied, a lower item.
+
+fn ranks_before<T: Ord, S: PartialOrd>(a: (&T, &S), b: (&T, &S)) -> bool {
+    match a.1.partial_cmp(b.1).unwrap_or(Ordering::Equal) {
+        Ordering::Greater => true,
+        Ordering::Less => false,
+        Ordering::Equal => a.0 < b.0,
+    } // match
+} // fn
+
+// -----------------------------------------------------------------------------
+
+#[test]
+fn test_top_scores() {
+    let mut top_scores: TopScores<usize, usize> = TopScores::with_capacity(3);
+    assert!(top_scores.is_empty());
+    assert_eq!(top_scores.threshold(), None);
+
+    // Ties are broken by the item, whatever the insertion order:
+    top_scores.extend([(5, 1), (4, 1), (3, 1), (2, 1), (1, 1)]);
+    assert_eq!(top_scores.threshold(), Some(&1));
+    assert_eq!(top_scores.clone().results().collect::<Vec<_>>(), vec![(1, 1), (2, 1), (3, 1)]);
+
+    top_scores.insert(9, 2);
+    top_scores.insert(3, 3);
+    assert_eq!(top_scores.len(), 3);
+    assert_eq!(top_scores.results().collect::<Vec<_>>(), vec![(3, 3), (9, 2), (1, 1)]);
+
+    let mut empty: TopScores<usize, f32> = TopScores::with_capacity(0);
+    empty.insert(0, f32::MAX);
+    assert!(empty.is_empty());
+} // fn
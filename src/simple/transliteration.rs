@@ -0,0 +1,140 @@
+#[cfg(feature = "transliteration")]
+use crate::simple::internal::string_keywords::{exclude_keyword, SplitContext};
+#[cfg(feature = "transliteration")]
+use crate::simple::search_index::SearchIndex;
+#[cfg(feature = "transliteration")]
+use kstring::KString;
+#[cfg(feature = "transliteration")]
+use std::cmp::Ord;
+
+// -----------------------------------------------------------------------------
+//
+/// Which keywords are transliterated into Latin. See [`Transliteration`].
+///
+/// [`Transliteration`]: struct.Transliteration.html
+
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum TransliterationDirection {
+    /// Only keywords that are being indexed are transliterated. Typing
+    /// `moskva` finds `Москва`, and typing `Москва` only finds `Москва`.
+    #[default]
+    Indexing,
+    /// Keywords that are being indexed and keywords that are being searched
+    /// for are transliterated. Typing `Москва` also finds `Moskva`.
+    Both,
+} // TransliterationDirection
+
+// -----------------------------------------------------------------------------
+//
+/// Transliteration of keywords from other scripts, such as Cyrillic, Greek or
+/// Arabic, into Latin using the [`any_ascii`] crate. This lets users find
+/// records without switching keyboard layouts. Requires the `transliteration`
+/// feature. See [`SearchIndexBuilder::transliteration`].
+///
+/// Keywords that are already ASCII are left as they are. If a keyword's Latin
+/// variant is too short or too long, or is in the exclusion list, the keyword
+/// isn't transliterated.
+///
+/// [`any_ascii`]: https://crates.io/crates/any_ascii
+/// [`SearchIndexBuilder::transliteration`]: struct.SearchIndexBuilder.html#method.transliteration
+
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct Transliteration {
+    /// Which keywords are transliterated.
+    pub direction: TransliterationDirection,
+    /// If `true`, the Latin variant of a keyword is indexed alongside the
+    /// original keyword. If `false`, only the Latin variant is indexed, and
+    /// search keywords are always transliterated so that they still match.
+    pub keep_originals: bool,
+} // Transliteration
+
+// -----------------------------------------------------------------------------
+
+impl Default for Transliteration {
+    fn default() -> Self {
+        Self {
+            direction: TransliterationDirection::Indexing,
+            keep_originals: true,
+        } // Transliteration
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(feature = "transliteration")]
+impl<K: Ord> SearchIndex<K> {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns the Latin variant of a keyword, or `None` if the keyword is
+    /// unchanged by transliteration or its variant doesn't meet the keyword
+    /// criteria.
+
+    fn transliterate(&self, keyword: &str) -> Option<KString> {
+        if keyword.is_ascii() {
+            return None
+        } // if
+
+        let variant: KString = match self.case_sensitive {
+            true => KString::from(any_ascii::any_ascii(keyword)),
+            false => KString::from(any_ascii::any_ascii(keyword).to_lowercase()),
+        }; // match
+
+        let chars = self.keyword_length(&variant);
+
+        if  variant.as_str() != keyword &&
+            chars >= self.minimum_keyword_length &&
+            chars <= self.maximum_keyword_length &&
+            !exclude_keyword(&variant, &self.exclude_keywords) {
+                Some(variant)
+        } else {
+            None
+        } // if
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Applies the `transliteration` setting to split keywords. Keywords that
+    /// are replaced by their Latin variant are replaced in place. When
+    /// indexing with `keep_originals`, the originals are left in place and the
+    /// Latin variants that aren't already keywords are returned instead.
+
+    pub(crate) fn transliterate_keywords(
+        &self,
+        keywords: &mut [KString],
+        context: &SplitContext,
+    ) -> Vec<KString> {
+
+        let Some(transliteration) = &self.transliteration else {
+            return Vec::new()
+        }; // else
+
+        match context {
+            SplitContext::Indexing if transliteration.keep_originals => {
+                let mut variants: Vec<KString> = Vec::new();
+                for variant in keywords.iter().filter_map(|keyword| self.transliterate(keyword)) {
+                    if !keywords.contains(&variant) && !variants.contains(&variant) {
+                        variants.push(variant);
+                    } // if
+                } // for
+                variants
+            }, // Indexing
+            SplitContext::Searching if
+                transliteration.keep_originals &&
+                transliteration.direction == TransliterationDirection::Indexing
+                    => Vec::new(),
+            _ => {
+                for keyword in keywords.iter_mut() {
+                    if let Some(variant) = self.transliterate(keyword) {
+                        *keyword = variant;
+                    } // if
+                } // for
+                Vec::new()
+            }, // _
+        } // match
+
+    } // fn
+
+} // impl
\ No newline at end of file
@@ -0,0 +1,187 @@
+// Conditionally select hash map type based on feature flags:
+#[cfg(feature = "gxhash")]
+type HashSet<T> = std::collections::HashSet<T, gxhash::GxBuildHasher>;
+#[cfg(all(feature = "ahash", not(feature = "gxhash")))]
+use ahash::HashSet;
+#[cfg(all(not(feature = "ahash"), not(feature = "gxhash")))]
+use std::collections::HashSet;
+
+// Static dependencies:
+use crate::simple::{search_index::SearchIndex, IndexChange, InsertReport, KeywordCapPolicy};
+use crate::Error;
+use kstring::KString;
+use std::collections::BTreeSet;
+use std::{clone::Clone, cmp::Ord};
+
+// -----------------------------------------------------------------------------
+
+impl<K: Clone + Ord> SearchIndex<K> {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Re-indexes a single field of a record, without re-indexing its other
+    /// fields. Only the keywords that the field no longer produces are
+    /// detached from the key, and only the keywords that it newly produces are
+    /// attached. This is much cheaper than `replace` for records with large,
+    /// unchanged fields.
+    ///
+    /// The record must have been inserted with named fields (see
+    /// `Indexable::field_names`), under the `Upsert` duplicate key policy.
+    /// Keywords that another field of the record also produces are kept. The
+    /// field is indexed with the default `InsertOptions`. If the record would
+    /// exceed the `maximum_keywords_per_record` cap, the field's keywords are
+    /// truncated to fit alongside the other fields' keywords, or the update
+    /// is rejected, depending on the `keyword_cap_policy` setting.
+    ///
+    /// Which keywords came from which field isn't kept by `to_bytes`, so
+    /// records in a loaded search index must be inserted again before their
+    /// fields can be updated.
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # use indicium::simple::{Indexable, SearchIndex};
+    /// # use pretty_assertions::assert_eq;
+    /// #
+    /// struct Article {
+    ///     title: String,
+    ///     body: String,
+    /// }
+    ///
+    /// impl Indexable for Article {
+    ///     fn strings(&self) -> Vec<String> {
+    ///         vec![self.title.clone(), self.body.clone()]
+    ///     }
+    ///
+    ///     fn field_names(&self) -> Vec<String> {
+    ///         vec!["title".to_string(), "body".to_string()]
+    ///     }
+    /// }
+    ///
+    /// let mut search_index: SearchIndex<usize> = SearchIndex::default();
+    /// search_index.insert(&0, &Article {
+    ///     title: "Harold Godwinson".to_string(),
+    ///     body: "Last crowned Anglo-Saxon king of England.".to_string(),
+    /// });
+    ///
+    /// search_index.update_field(&0, "title", "Harold II").unwrap();
+    ///
+    /// assert!(search_index.search("godwinson").is_empty());
+    /// assert_eq!(search_index.search("harold"), vec![&0]);
+    /// assert_eq!(search_index.search("crowned"), vec![&0]);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error::UnknownField` error if the key's record wasn't
+    /// inserted with a field of that name, or an `Error::KeywordCapExceeded`
+    /// error if the update is rejected by the `maximum_keywords_per_record`
+    /// cap. The record is left unchanged.
+
+    #[tracing::instrument(level = "trace", name = "search index update field", skip(self, key, string))]
+    pub fn update_field(
+        &mut self,
+        key: &K,
+        field: &str,
+        string: &str,
+    ) -> Result<InsertReport, Error> {
+
+        // Only records that were inserted with the named field can have it
+        // updated:
+        let Some(fields) = self.field_keywords
+            .get(key)
+            .filter(|fields| fields.contains_key(field)) else {
+                return Err(Error::UnknownField { field: field.to_string() })
+            }; // else
+
+        // Get the keywords for the field's new string, and which of them is
+        // the whole string:
+        let (keywords, mut whole_strings): (HashSet<KString>, Vec<KString>) = self.field_keyword_parts(string);
+
+        // The keywords of the record's other fields stay attached, even if the
+        // updated field also had them:
+        let others: BTreeSet<&KString> = fields
+            .iter()
+            .filter(|(name, _keywords)| name.as_str() != field)
+            .flat_map(|(_name, keywords)| keywords)
+            .collect();
+
+        // The field's keywords, in lexographic order:
+        let mut new: Vec<KString> = keywords.into_iter().collect();
+        new.sort_unstable();
+
+        // If there's a cap on the number of keywords per record, enforce it
+        // according to the policy:
+        let mut truncated_keywords: usize = 0;
+        if let Some(maximum) = self.maximum_keywords_per_record {
+            let record_keywords: usize = others.len() + new
+                .iter()
+                .filter(|keyword| !others.contains(keyword))
+                .count();
+            if record_keywords > maximum {
+                match self.keyword_cap_policy {
+                    KeywordCapPolicy::Reject => return Err(Error::KeywordCapExceeded {
+                        keywords: record_keywords,
+                        maximum,
+                    }), // Err
+                    KeywordCapPolicy::Truncate => {
+                        // Keep the field's first keywords that fit alongside
+                        // the other fields' keywords:
+                        let mut room: usize = maximum.saturating_sub(others.len());
+                        new.retain(|keyword| match others.contains(keyword) {
+                            true => true,
+                            false if room > 0 => { room -= 1; true },
+                            false => false,
+                        }); // retain
+                        truncated_keywords = record_keywords - maximum;
+                    }, // Truncate
+                } // match
+            } // if
+        } // if
+
+        let new: BTreeSet<KString> = new.into_iter().collect();
+
+        // Detach the keywords that the field no longer produces, and attach
+        // the ones it newly produces:
+        let old: &BTreeSet<KString> = &fields[field];
+        let stale: Vec<KString> = old
+            .iter()
+            .filter(|keyword| !new.contains(*keyword) && !others.contains(keyword))
+            .cloned()
+            .collect();
+        let added: HashSet<KString> = new
+            .iter()
+            .filter(|keyword| !old.contains(*keyword) && !others.contains(keyword))
+            .cloned()
+            .collect();
+        whole_strings.retain(|whole_string| new.contains(whole_string));
+
+        let report = InsertReport {
+            keywords: others.len() + new.iter().filter(|keyword| !others.contains(keyword)).count(),
+            truncated_keywords,
+        }; // InsertReport
+
+        // The indexed data is changing, so precomputed autocompletions are no
+        // longer current:
+        self.invalidate_autocompletions();
+
+        // If the changelog is enabled, record this update:
+        self.record_op(|| IndexChange::UpdateField {
+            key: key.clone(),
+            field: field.to_string(),
+            string: string.to_string(),
+        }); // record_op
+
+        self.detach_keywords(key, &stale);
+        self.attach_keywords(key, added, whole_strings);
+
+        // Remember the field's new keywords:
+        if let Some(fields) = self.field_keywords.get_mut(key) {
+            fields.insert(field.to_string(), new);
+        } // if
+
+        Ok(report)
+
+    } // fn
+
+} // impl
\ No newline at end of file
@@ -0,0 +1,81 @@
+use crate::simple::search_index::SearchIndex;
+use std::{cmp::Ord, hash::Hash};
+
+// -----------------------------------------------------------------------------
+
+impl<K: Ord> SearchIndex<K> {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Discards the precomputed autocompletion options of the hot prefixes
+    /// (see `warm_up`.) This must be called by every change that may change
+    /// what autocompletion returns: the indexed data, the autocompletion
+    /// settings, the suggestion filter and the pinned suggestions.
+
+    pub(crate) fn invalidate_autocompletions(&mut self) {
+        self.warm_cache.invalidate();
+    } // fn
+
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl<K: Hash + Ord> SearchIndex<K> {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Precomputes and caches the autocompletion options for "hot" prefixes,
+    /// such as every single letter. The first keystrokes of a query match the
+    /// most keywords, so they're usually the slowest to autocomplete on large
+    /// indexes.
+    ///
+    /// Inserting or removing records invalidates the cached options, but the
+    /// hot prefixes are remembered. Their options are recomputed the next time
+    /// they're autocompleted, or `warm_up` may be called again after a batch
+    /// of changes.
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # use indicium::simple::SearchIndex;
+    /// # use pretty_assertions::assert_eq;
+    /// #
+    /// let mut search_index: SearchIndex<usize> = SearchIndex::default();
+    /// search_index.insert(&0, &"Cambodia");
+    /// search_index.insert(&1, &"Canada");
+    ///
+    /// let letters: Vec<String> = ('a'..='z').map(String::from).collect();
+    /// let letters: Vec<&str> = letters.iter().map(String::as_str).collect();
+    /// search_index.warm_up(&letters);
+    ///
+    /// assert_eq!(
+    ///     search_index.autocomplete("c"),
+    ///     vec!["cambodia".to_string(), "canada".to_string()]
+    /// );
+    /// ```
+
+    #[tracing::instrument(level = "trace", name = "warm up", skip(self))]
+    pub fn warm_up(&mut self, prefixes: &[&str]) {
+        prefixes
+            .iter()
+            .for_each(|prefix| self.warm_cache.add_prefix(&self.warm_cache_key(prefix)));
+
+        // Computing the autocompletion options also caches them:
+        prefixes
+            .iter()
+            .for_each(|prefix| { self.autocomplete(prefix); });
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Normalizes a string into its warm cache key, according to the search
+    /// index's case sensitivity.
+
+    pub(crate) fn warm_cache_key(&self, string: &str) -> String {
+        match self.case_sensitive {
+            true => string.to_string(),
+            false => string.to_lowercase(),
+        } // match
+    } // fn
+
+} // impl
\ No newline at end of file
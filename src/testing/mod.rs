@@ -0,0 +1,218 @@
+//! Shared fixtures for tests and benchmarks.
+//!
+//! * [`sample_index`] builds the "1066 kings" search index that is used
+//!   throughout this crate's documentation.
+//!
+//! * [`generated_index`] builds a larger search index from a generated corpus,
+//!   with a tunable number of records and vocabulary size. The corpus is
+//!   deterministic, so results are reproducible between runs.
+//!
+//! [`sample_index`]: fn.sample_index.html
+//! [`generated_index`]: fn.generated_index.html
+
+use crate::simple::{Indexable, SearchIndex};
+
+// -----------------------------------------------------------------------------
+//
+/// A record of the sample corpus: an English monarch (or claimant) from around
+/// the Norman Conquest.
+
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Monarch {
+    pub title: String,
+    pub year: u16,
+    pub body: String,
+} // Monarch
+
+impl Indexable for Monarch {
+    fn strings(&self) -> Vec<String> {
+        vec![
+            self.title.clone(),
+            self.year.to_string(),
+            self.body.clone(),
+        ]
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+//
+/// Returns the records of the sample corpus. The record's position in the
+/// `Vec` is its key in [`sample_index`].
+///
+/// [`sample_index`]: fn.sample_index.html
+
+pub fn sample_records() -> Vec<Monarch> {
+    [
+        ("Harold Godwinson", 1066, "Last crowned Anglo-Saxon king of England."),
+        ("Edgar Ætheling", 1066, "Last male member of the royal house of Cerdic of Wessex."),
+        ("William the Conqueror", 1066, "First Norman monarch of England."),
+        ("William Rufus", 1087, "Third son of William the Conqueror."),
+        ("Henry Beauclerc", 1100, "Fourth son of William the Conqueror."),
+    ]
+        .into_iter()
+        .map(|(title, year, body)| Monarch {
+            title: title.to_string(),
+            year,
+            body: body.to_string(),
+        }) // map
+        .collect()
+} // fn
+
+// -----------------------------------------------------------------------------
+//
+/// Returns a search index with the default settings, containing the sample
+/// corpus. See [`sample_records`].
+///
+/// [`sample_records`]: fn.sample_records.html
+///
+/// Basic usage:
+///
+/// ```rust
+/// # use indicium::testing::sample_index;
+/// # use pretty_assertions::assert_eq;
+/// #
+/// let search_index = sample_index();
+///
+/// assert_eq!(search_index.search("conqueror"), vec![&2, &3, &4]);
+/// assert_eq!(search_index.autocomplete("Edgar last c"), vec!["edgar last cerdic".to_string()]);
+/// ```
+
+pub fn sample_index() -> SearchIndex<usize> {
+    let mut search_index: SearchIndex<usize> = SearchIndex::default();
+
+    sample_records()
+        .iter()
+        .enumerate()
+        .for_each(|(index, element)| search_index.insert(&index, element));
+
+    search_index
+} // fn
+
+// -----------------------------------------------------------------------------
+//
+/// The shape of a generated corpus. See [`generated_corpus`].
+///
+/// [`generated_corpus`]: fn.generated_corpus.html
+
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct CorpusConfig {
+    /// The number of records.
+    pub records: usize,
+    /// The number of distinct words that records are made of.
+    pub vocabulary: usize,
+    /// The number of words in each record.
+    pub words_per_record: usize,
+    /// The seed for choosing words. The same seed produces the same corpus.
+    pub seed: u64,
+} // CorpusConfig
+
+impl Default for CorpusConfig {
+    fn default() -> Self {
+        CorpusConfig {
+            records: 1_000,
+            vocabulary: 500,
+            words_per_record: 8,
+            seed: 1066,
+        } // CorpusConfig
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+//
+/// Returns a deterministic corpus of generated records. Words are made of
+/// pronounceable syllables (for example `kabo` or `remitu`) so that prefixes
+/// are shared, like in natural language. Words are chosen with a skewed
+/// distribution, so that some words are much more common than others.
+///
+/// Basic usage:
+///
+/// ```rust
+/// # use indicium::testing::{generated_corpus, CorpusConfig};
+/// # use pretty_assertions::assert_eq;
+/// #
+/// let config = CorpusConfig { records: 3, ..CorpusConfig::default() };
+/// let corpus = generated_corpus(&config);
+///
+/// assert_eq!(corpus.len(), 3);
+/// assert_eq!(corpus, generated_corpus(&config));
+/// ```
+
+pub fn generated_corpus(config: &CorpusConfig) -> Vec<String> {
+    let vocabulary: Vec<String> = (0..config.vocabulary.max(1))
+        .map(generated_word)
+        .collect();
+
+    // A small xorshift generator keeps the corpus reproducible without any
+    // dependencies:
+    let mut state: u64 = config.seed | 1;
+    let mut next = move || {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        state
+    }; // next
+
+    (0..config.records)
+        .map(|_| (0..config.words_per_record)
+            .map(|_| {
+                // Multiplying two uniform draws skews the choice towards the
+                // first words of the vocabulary:
+                let a = next() % vocabulary.len() as u64;
+                let b = next() % vocabulary.len() as u64;
+                vocabulary[(a * b / vocabulary.len() as u64) as usize].as_str()
+            }) // map
+            .collect::<Vec<&str>>()
+            .join(" ")
+        ) // map
+        .collect()
+} // fn
+
+// -----------------------------------------------------------------------------
+//
+/// Returns a search index with the default settings, containing a generated
+/// corpus. Each record's key is its position in [`generated_corpus`].
+///
+/// [`generated_corpus`]: fn.generated_corpus.html
+///
+/// Basic usage:
+///
+/// ```rust
+/// # use indicium::testing::{generated_index, CorpusConfig};
+/// #
+/// let search_index = generated_index(&CorpusConfig::default());
+/// assert_eq!(search_index.record_count(), 1_000);
+/// ```
+
+pub fn generated_index(config: &CorpusConfig) -> SearchIndex<usize> {
+    let mut search_index: SearchIndex<usize> = SearchIndex::default();
+
+    generated_corpus(config)
+        .iter()
+        .enumerate()
+        .for_each(|(index, record)| search_index.insert(&index, record));
+
+    search_index
+} // fn
+
+// -----------------------------------------------------------------------------
+//
+/// Returns the generated word for a position in the vocabulary. Every position
+/// produces a distinct word.
+
+fn generated_word(mut index: usize) -> String {
+    const CONSONANTS: &[char] = &['b', 'd', 'f', 'g', 'k', 'l', 'm', 'n', 'p', 'r', 's', 't'];
+    const VOWELS: &[char] = &['a', 'e', 'i', 'o', 'u'];
+    let syllables: usize = CONSONANTS.len() * VOWELS.len();
+
+    // Write the position in base `syllables`, with at least two syllables:
+    let mut word = String::new();
+    loop {
+        let syllable = index % syllables;
+        word.push(CONSONANTS[syllable / VOWELS.len()]);
+        word.push(VOWELS[syllable % VOWELS.len()]);
+        index /= syllables;
+        if index == 0 && word.len() >= 4 { break }
+    } // loop
+
+    word
+} // fn
\ No newline at end of file
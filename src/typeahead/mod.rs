@@ -0,0 +1,123 @@
+//! Server-side support for Twitter's
+//! [typeahead.js](https://github.com/twitter/typeahead.js) and its
+//! [Bloodhound](https://github.com/twitter/typeahead.js/blob/master/doc/bloodhound.md)
+//! suggestion engine.
+//!
+//! Bloodhound's `remote` option issues a request to a URL containing a
+//! wildcard (usually `%QUERY`) that is replaced by the user's query. The
+//! response is expected to be a JSON array of datums. By default, typeahead.js
+//! displays the `value` property of each datum (see its `display` option).
+//!
+//! Steps for processing a Bloodhound remote request:
+//!
+//! 1. Extract the query from the request path or query-string using your web
+//!    framework.
+//!
+//! 2. Call [`autocomplete_typeahead`] for keyword suggestions, or
+//!    [`search_typeahead`] to suggest records from your collection.
+//!
+//! 3. Serialize the returned `Vec<Datum>` into `JSON` and return it to the
+//!    client.
+//!
+//! [`autocomplete_typeahead`]: ../simple/struct.SearchIndex.html#method.autocomplete_typeahead
+//! [`search_typeahead`]: ../simple/struct.SearchIndex.html#method.search_typeahead
+
+use crate::simple::{SearchIndex, SearchType};
+use serde::{Deserialize, Serialize};
+use std::{cmp::Ord, hash::Hash};
+
+// -----------------------------------------------------------------------------
+//
+/// A single suggestion for typeahead.js. The `id` field is omitted from the
+/// `JSON` output when it is not set.
+
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd, Deserialize, Serialize)]
+pub struct Datum {
+    /// The text displayed in the suggestion menu.
+    pub value: String,
+    /// An optional identifier for the record this suggestion represents.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub id: Option<String>,
+} // Datum
+
+// -----------------------------------------------------------------------------
+
+impl<K: Hash + Ord> SearchIndex<K> {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns the autocompletion options for the user's query as typeahead.js
+    /// datums.
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # use indicium::typeahead::Datum;
+    /// # use indicium::simple::SearchIndex;
+    /// # use pretty_assertions::assert_eq;
+    /// #
+    /// let mut search_index: SearchIndex<usize> = SearchIndex::default();
+    /// search_index.insert(&0, &"Cambodia");
+    ///
+    /// assert_eq!(
+    ///     search_index.autocomplete_typeahead("camb"),
+    ///     vec![Datum { value: "cambodia".to_string(), id: None }]
+    /// );
+    /// ```
+
+    #[tracing::instrument(level = "trace", name = "typeahead autocomplete", skip(self))]
+    pub fn autocomplete_typeahead(&self, query: &str) -> Vec<Datum> {
+        self.autocomplete(query)
+            .into_iter()
+            .map(|value| Datum { value, id: None })
+            .collect()
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Performs a `Live` search for the user's query and returns the matching
+    /// records as typeahead.js datums. The `collection_lookup` closure converts
+    /// each resulting key into a `Datum`. Keys that return `None` are skipped.
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # use indicium::typeahead::Datum;
+    /// # use indicium::simple::SearchIndex;
+    /// # use pretty_assertions::assert_eq;
+    /// #
+    /// let countries = vec!["Canada", "Cambodia"];
+    ///
+    /// let mut search_index: SearchIndex<usize> = SearchIndex::default();
+    ///
+    /// countries
+    ///     .iter()
+    ///     .enumerate()
+    ///     .for_each(|(index, country)| search_index.insert(&index, country));
+    ///
+    /// let datums = search_index.search_typeahead("camb", |key| {
+    ///     countries.get(*key).map(|country| Datum {
+    ///         value: country.to_string(),
+    ///         id: Some(key.to_string()),
+    ///     })
+    /// });
+    ///
+    /// assert_eq!(
+    ///     datums,
+    ///     vec![Datum { value: "Cambodia".to_string(), id: Some("1".to_string()) }]
+    /// );
+    /// ```
+
+    #[tracing::instrument(level = "trace", name = "typeahead search", skip(self, collection_lookup))]
+    pub fn search_typeahead(
+        &self,
+        query: &str,
+        collection_lookup: impl Fn(&K) -> Option<Datum>,
+    ) -> Vec<Datum> {
+        self.search_type(&SearchType::Live, query)
+            .into_iter()
+            .filter_map(collection_lookup)
+            .collect()
+    } // fn
+
+} // impl
\ No newline at end of file
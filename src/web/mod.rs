@@ -0,0 +1,228 @@
+//! Ready-made [Axum](https://crates.io/crates/axum) extractors and handlers
+//! for exposing a search index over HTTP.
+//!
+//! Steps for serving search requests:
+//!
+//! 1. Wrap your search index in an `Arc<RwLock<SearchIndex<K>>>` so that it
+//!    may be shared between the handler and the code that maintains it.
+//!
+//! 2. Route requests to a handler built by [`search_handler`], providing a
+//!    closure that renders each resulting key into a serializable record.
+//!
+//! 3. Clients may then search using the `q`, `type`, `limit` and `page`
+//!    query-string parameters. For example, `/search?q=william&type=and`.
+//!
+//! Users of other web frameworks may deserialize a [`SearchQuery`] from the
+//! query-string themselves and call its [`search`] method.
+//!
+//! [`search_handler`]: fn.search_handler.html
+//! [`SearchQuery`]: struct.SearchQuery.html
+//! [`search`]: struct.SearchQuery.html#method.search
+
+use crate::simple::{SearchIndex, SearchType};
+use axum::extract::{FromRequestParts, Query};
+use axum::extract::rejection::QueryRejection;
+use axum::http::request::Parts;
+use axum::routing::{get, MethodRouter};
+use axum::Json;
+use serde::{Deserialize, Serialize};
+use std::future::{ready, Ready};
+use std::sync::{Arc, RwLock};
+use std::{cmp::Ord, hash::Hash};
+
+// -----------------------------------------------------------------------------
+//
+/// The search type requested by the client, using the lowercase names `live`,
+/// `and`, `or` and `keyword` in the query-string.
+
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum QueryType {
+    /// A `Live` search. See [`SearchType::Live`](../simple/enum.SearchType.html#variant.Live).
+    Live,
+    /// An `And` search. See [`SearchType::And`](../simple/enum.SearchType.html#variant.And).
+    And,
+    /// An `Or` search. See [`SearchType::Or`](../simple/enum.SearchType.html#variant.Or).
+    Or,
+    /// A `Keyword` search. See [`SearchType::Keyword`](../simple/enum.SearchType.html#variant.Keyword).
+    Keyword,
+} // QueryType
+
+// -----------------------------------------------------------------------------
+
+impl From<QueryType> for SearchType {
+    fn from(query_type: QueryType) -> Self {
+        match query_type {
+            QueryType::Live => Self::Live,
+            QueryType::And => Self::And,
+            QueryType::Or => Self::Or,
+            QueryType::Keyword => Self::Keyword,
+        } // match
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+//
+/// The query-string parameters of a search request. All parameters are
+/// optional:
+///
+/// * `q` is the search string. If absent, no results are returned.
+///
+/// * `type` is the search type. If absent, the search index's default search
+///   type is used.
+///
+/// * `limit` is the number of results per page. If absent, or greater than the
+///   search index's `maximum_search_results` setting, that setting is used.
+///
+/// * `page` is the 1-based page number. If absent, the first page is returned.
+
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd, Deserialize, Serialize)]
+pub struct SearchQuery {
+    /// The search string.
+    #[serde(default)]
+    pub q: String,
+    /// The search type.
+    #[serde(rename = "type", default)]
+    pub search_type: Option<QueryType>,
+    /// The number of results per page.
+    #[serde(default)]
+    pub limit: Option<usize>,
+    /// The 1-based page number.
+    #[serde(default)]
+    pub page: Option<usize>,
+} // SearchQuery
+
+// -----------------------------------------------------------------------------
+
+impl SearchQuery {
+
+    // -------------------------------------------------------------------------
+    //
+    /// Performs the requested search and returns the keys on the requested
+    /// page.
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// # use indicium::simple::SearchIndex;
+    /// # use indicium::web::{QueryType, SearchQuery};
+    /// # use pretty_assertions::assert_eq;
+    /// #
+    /// let mut search_index: SearchIndex<usize> = SearchIndex::default();
+    ///
+    /// search_index.insert(&0, &"William the Conqueror");
+    /// search_index.insert(&1, &"William Rufus");
+    /// search_index.insert(&2, &"William Adelin");
+    ///
+    /// let query = SearchQuery {
+    ///     q: "william".to_string(),
+    ///     search_type: Some(QueryType::And),
+    ///     limit: Some(2),
+    ///     page: Some(2),
+    /// };
+    ///
+    /// assert_eq!(query.search(&search_index), vec![&2]);
+    /// ```
+
+    #[tracing::instrument(level = "trace", name = "web search", skip(search_index))]
+    pub fn search<'a, K: Hash + Ord>(&'a self, search_index: &'a SearchIndex<K>) -> Vec<&'a K> {
+
+        // Use the search index's settings for anything not in the request:
+        let search_type: SearchType = self.search_type
+            .map_or_else(|| search_index.search_type.clone(), SearchType::from);
+
+        let limit: usize = self.limit
+            .unwrap_or(search_index.maximum_search_results)
+            .min(search_index.maximum_search_results);
+
+        // Pages are 1-based. Page `0` is treated as the first page:
+        let page: usize = self.page.unwrap_or(1).max(1);
+
+        // Search for enough results to fill every page up to the requested
+        // page, and then skip the preceding pages:
+        search_index
+            .search_with(&search_type, &limit.saturating_mul(page), &self.q)
+            .into_iter()
+            .skip(limit.saturating_mul(page - 1))
+            .take(limit)
+            .collect()
+
+    } // fn
+
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl<S: Send + Sync> FromRequestParts<S> for SearchQuery {
+    type Rejection = QueryRejection;
+
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        Query::<SearchQuery>::from_request_parts(parts, state)
+            .await
+            .map(|Query(search_query)| search_query)
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+//
+/// Builds a `GET` handler that searches the shared search index using the
+/// request's [`SearchQuery`] and responds with a `JSON` array of records. The
+/// `render` closure converts each resulting key into a serializable record.
+/// Keys that return `None` are skipped.
+///
+/// Basic usage:
+///
+/// ```rust
+/// # use axum::Router;
+/// # use indicium::simple::SearchIndex;
+/// # use indicium::web::search_handler;
+/// # use std::sync::{Arc, RwLock};
+/// #
+/// let kings = vec!["William the Conqueror", "William Rufus", "Henry Beauclerc"];
+///
+/// let mut search_index: SearchIndex<usize> = SearchIndex::default();
+///
+/// kings
+///     .iter()
+///     .enumerate()
+///     .for_each(|(index, king)| search_index.insert(&index, king));
+///
+/// let search_index = Arc::new(RwLock::new(search_index));
+///
+/// let app: Router = Router::new().route(
+///     "/search",
+///     search_handler(search_index, move |key| kings.get(*key).map(|king| king.to_string())),
+/// );
+/// ```
+///
+/// [`SearchQuery`]: struct.SearchQuery.html
+
+pub fn search_handler<K, R, F, S>(
+    search_index: Arc<RwLock<SearchIndex<K>>>,
+    render: F,
+) -> MethodRouter<S>
+where
+    K: Hash + Ord + Send + Sync + 'static,
+    R: Serialize + Send + 'static,
+    F: Fn(&K) -> Option<R> + Clone + Send + Sync + 'static,
+    S: Clone + Send + Sync + 'static,
+{
+    get(move |search_query: SearchQuery| -> Ready<Json<Vec<R>>> {
+
+        // A poisoned lock still holds a usable search index, so it's recovered
+        // rather than failing every subsequent request:
+        let search_index = match search_index.read() {
+            Ok(search_index) => search_index,
+            Err(poisoned) => poisoned.into_inner(),
+        }; // match
+
+        let records: Vec<R> = search_query
+            .search(&search_index)
+            .into_iter()
+            .filter_map(&render)
+            .collect();
+
+        ready(Json(records))
+
+    }) // get
+} // fn
\ No newline at end of file